@@ -2,27 +2,16 @@
 #include <cstddef>
 #include <cstdlib>
 #include <dlfcn.h>
+#include <quick-lint-js/c-api.h>
 #include <string_view>
 
 using namespace std::literals::string_view_literals;
 
 namespace {
+// The C API's functions, loaded with dlsym. The declarations come from
+// rust/libs/c_api/include/quick-lint-js/c-api.h.
 struct c_api {
-  enum qljs_severity {
-    qljs_severity_error = 1,
-    qljs_severity_warning = 2,
-  };
-
-  struct diagnostic {
-    const char* message;
-    char code[6];  // null-terminated
-    qljs_severity severity;
-    // Offsets count UTF-16 code units.
-    int begin_offset;
-    int end_offset;
-  };
-
-  struct document;
+  using document = ::qljs_web_demo_document;
 
   static c_api load() {
     const char* dll_path = std::getenv("CPP_VS_RUST_DLL");
@@ -57,10 +46,10 @@ struct c_api {
     return api;
   }
 
-  document* (*create_document)(void);
-  void (*destroy_document)(document* document);
-  void (*set_text)(document* document, const void* text_utf_8, std::size_t text_byte_count);
-  const diagnostic* (*lint)(document* document);
+  decltype(&::qljs_web_demo_create_document) create_document;
+  decltype(&::qljs_web_demo_destroy_document) destroy_document;
+  decltype(&::qljs_web_demo_set_text) set_text;
+  decltype(&::qljs_web_demo_lint) lint;
 };
 
 void benchmark_lex(::benchmark::State &state, std::string_view raw_source) {
@@ -0,0 +1,740 @@
+// Copyright (C) 2020  Matthew "strager" Glazar
+// See end of file for extended copyright information.
+
+// This file was generated by rust/tools/generate_c_api_header from
+// rust/libs/c_api/src/c_api.rs.
+
+#ifndef QUICK_LINT_JS_C_API_H
+#define QUICK_LINT_JS_C_API_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#if defined(__cplusplus)
+extern "C" {
+#endif
+
+// The version of the C API's binary interface, returned by qljs_abi_version.
+//
+// Increment QLJS_ABI_VERSION whenever a change would break programs compiled
+// against an older header, such as changing a function's parameters or a
+// struct's layout. Adding a function or an enum member does not break such
+// programs.
+#define QLJS_ABI_VERSION 2
+
+// A bit set (i.e. flags) which tell qljs_web_demo_lint how to interpret a
+// qljs_web_demo_document's text.
+//
+// To associate options with a document, call
+// qljs_web_demo_set_language_options.
+typedef int qljs_language_options;
+
+enum {
+  // If set, parse JSX syntax. JSX is a JavaScript language extension.
+  //
+  // If unset, report a diagnostic if JSX syntax is encounted (e.g. E0177 or
+  // E0306).
+  //
+  // Ignored if qljs_language_options_config_json_bit is set.
+  qljs_language_options_jsx_bit = 1 << 0,
+
+  // If set, parse TypeScript instead of JavaScript.
+  //
+  // If unset, parse JavaScript, and report a diagnostic if TypeScript-specific
+  // syntax is encountered (e.g. E0222 or E0281).
+  //
+  // Ignored if qljs_language_options_config_json_bit is set.
+  qljs_language_options_typescript_bit = 1 << 1,
+
+  // If set, parse a quick-lint-js.config file instead of JavaScript.
+  //
+  // If unset, parse JavaScript or TypeScript.
+  qljs_language_options_config_json_bit = 1 << 2,
+};
+
+// What the offsets and columns given to and returned by qljs_web_demo_*
+// functions count. Like LSP's PositionEncodingKind.
+//
+// In every encoding, each byte of invalid UTF-8 counts as one unit.
+//
+// To change a document's position encoding, call
+// qljs_web_demo_set_position_encoding.
+typedef int qljs_position_encoding;
+
+enum {
+  // Count UTF-16 code units, like JavaScript strings. A character outside the
+  // Basic Multilingual Plane (e.g. U+1F496) counts as two units.
+  //
+  // This is the default.
+  qljs_position_encoding_utf_16 = 0,
+
+  // Count bytes of UTF-8.
+  qljs_position_encoding_utf_8 = 1,
+
+  // Count Unicode code points.
+  qljs_position_encoding_utf_32 = 2,
+};
+
+typedef enum qljs_severity {
+  qljs_severity_error = 1,
+  qljs_severity_warning = 2,
+  // A secondary message of the preceding diagnostic. Only given to
+  // qljs_web_demo_lint_callback's callback.
+  qljs_severity_note = 3,
+} qljs_severity;
+
+// Whether a qljs_web_demo_* function succeeded.
+typedef enum qljs_status {
+  qljs_status_ok = 0,
+  // quick-lint-js failed internally, for example because an internal check
+  // failed. The document is now poisoned. See NOTE[qljs_web_demo_document
+  // poisoning].
+  qljs_status_internal_error = 1,
+  // The document was poisoned by an earlier call. The function did nothing.
+  qljs_status_document_poisoned = 2,
+  // An argument was not valid. The function did nothing. The document is not
+  // poisoned.
+  qljs_status_invalid_argument = 3,
+} qljs_status;
+
+// How qljs_web_demo_highlight classifies a piece of a document's text.
+typedef enum qljs_highlight_class {
+  // Reserved words, such as 'if' and 'class'.
+  qljs_highlight_class_keyword = 0,
+  // Words which are only sometimes keywords, such as 'of' and 'async'.
+  qljs_highlight_class_contextual_keyword = 1,
+  qljs_highlight_class_identifier = 2,
+  // #name
+  qljs_highlight_class_private_identifier = 3,
+  qljs_highlight_class_string = 4,
+  // `text`, `text${, }text${, or }text`
+  qljs_highlight_class_template = 5,
+  qljs_highlight_class_number = 6,
+  qljs_highlight_class_regexp = 7,
+  qljs_highlight_class_comment = 8,
+  qljs_highlight_class_punctuator = 9,
+  // A JSX element's name, or one of the '<', '</', '/>', or '>' around it.
+  qljs_highlight_class_jsx_tag = 10,
+  // Text inside a JSX element, excluding leading and trailing whitespace.
+  qljs_highlight_class_jsx_text = 11,
+} qljs_highlight_class;
+
+// A qljs_web_demo_document is a text document.
+//
+// A qljs_web_demo_document contains the following state:
+//
+// * Text, changed using qljs_web_demo_set_text or qljs_web_demo_replace_text
+// * Language options, changed using qljs_web_demo_set_language_options
+// * Position encoding, changed using qljs_web_demo_set_position_encoding
+// * Configuration document, changed using qljs_web_demo_set_config
+// * Locale, changed using qljs_web_demo_set_locale
+// * Translation catalog, changed using qljs_web_demo_set_translation_catalog
+// * Output diagnostics, changed using qljs_web_demo_lint
+// * Output highlight tokens, changed using qljs_web_demo_highlight
+//
+// qljs_web_demo_document objects are allocated dynamically. To create a
+// qljs_web_demo_document, call qljs_web_demo_create_document. When you are
+// finished using a qljs_web_demo_document, call qljs_web_demo_destroy_document
+// to free resources.
+//
+// NOTE[qljs_web_demo_document threads]: In general, qljs_web_demo_* functions
+// can be called from multiple threads without synchronization. However, for a
+// given qljs_web_demo_document, functions accepting that qljs_web_demo_document
+// *cannot* be called from multiple threads without synchronization.
+//
+// In other words, you can create documents A, B, and C, and use document A on
+// thread 1, document B on thread 2, and document C on thread 3, with no
+// synchronization. However, if instead you want to call
+// qljs_web_demo_set_text(A, ...) on thread 1, then call qljs_web_demo_lint(A)
+// on thread 2, then these calls must be synchronized by you.
+//
+// A mutex is sufficient synchronization.
+//
+// NOTE[qljs_web_demo_document poisoning]: If quick-lint-js fails internally while
+// a qljs_web_demo_* function is using a document, the function returns
+// qljs_status_internal_error (or another documented failure value) instead of
+// crashing the process, and the document becomes poisoned. Functions given a
+// poisoned document do nothing and return qljs_status_document_poisoned (or
+// their documented failure value). A poisoned document cannot be unpoisoned;
+// destroy it and create a new document instead.
+//
+// Poisoning requires the library to be built with panic = "unwind", such as
+// with the release-c-api Cargo profile. Otherwise, an internal failure aborts
+// the process.
+//
+// To describe the failure in a bug report, call qljs_last_error and
+// qljs_last_error_input.
+//
+// qljs_list_locales, qljs_version, and qljs_abi_version do not depend on their
+// caller's input, so they do not report internal failures.
+typedef struct qljs_web_demo_document qljs_web_demo_document;
+
+typedef struct qljs_web_demo_diagnostic {
+  const char* message;
+  char code[6];  // null-terminated
+  qljs_severity severity;
+  // Offsets count units of the document's position encoding.
+  int begin_offset;
+  int end_offset;
+} qljs_web_demo_diagnostic;
+
+// A secondary message of a qljs_web_demo_diagnostic_v2, such as "variable already
+// declared here".
+typedef struct qljs_web_demo_diagnostic_note {
+  const char* message;
+  // Offsets count units of the document's position encoding.
+  int begin_offset;
+  int end_offset;
+  // See qljs_web_demo_diagnostic_v2::begin_line.
+  int begin_line;
+  int begin_column;
+  int end_line;
+  int end_column;
+} qljs_web_demo_diagnostic_note;
+
+// Like qljs_web_demo_diagnostic, but with the diagnostic's notes. Returned by
+// qljs_web_demo_lint_v2.
+//
+// The fields before notes are laid out like qljs_web_demo_diagnostic's fields.
+typedef struct qljs_web_demo_diagnostic_v2 {
+  const char* message;
+  char code[6];  // null-terminated
+  qljs_severity severity;
+  // Offsets count units of the document's position encoding.
+  int begin_offset;
+  int end_offset;
+  // An array of note_count notes. Null if note_count is 0.
+  const qljs_web_demo_diagnostic_note* notes;
+  size_t note_count;
+  // Lines and columns start at 0. Lines end with "\n", "\r\n", or "\r".
+  // Columns count units of the document's position encoding.
+  int begin_line;
+  int begin_column;
+  int end_line;
+  int end_column;
+} qljs_web_demo_diagnostic_v2;
+
+// A diagnostic or note given to the callback of qljs_web_demo_lint_callback.
+typedef struct qljs_web_demo_streamed_diagnostic {
+  // Not null-terminated. Valid only during the callback.
+  const char* message;
+  size_t message_length;
+  char code[6];  // null-terminated
+  qljs_severity severity;
+  // Offsets count units of the document's position encoding.
+  int begin_offset;
+  int end_offset;
+  // See qljs_web_demo_diagnostic_v2::begin_line.
+  int begin_line;
+  int begin_column;
+  int end_line;
+  int end_column;
+} qljs_web_demo_streamed_diagnostic;
+
+// A piece of a document's text, returned by qljs_web_demo_highlight.
+typedef struct qljs_web_demo_highlight_token {
+  // Offsets count units of the document's position encoding.
+  int begin_offset;
+  int end_offset;
+  qljs_highlight_class highlight_class;
+} qljs_web_demo_highlight_token;
+
+// See qljs_web_demo_lint_callback.
+typedef void (*qljs_web_demo_diagnostic_callback)(
+    void* user_data, const qljs_web_demo_streamed_diagnostic* diagnostic);
+
+// Create a new document.
+//
+// The new document ('d') has the following state:
+//
+// * No text, as if by qljs_web_demo_set_text(d, "", 0)
+// * No language options set, as if by qljs_web_demo_set_language_options(d, 0)
+// * UTF-16 positions, as if by
+//   qljs_web_demo_set_position_encoding(d, qljs_position_encoding_utf_16)
+// * No configuration document, as if by qljs_web_demo_set_config(d, NULL)
+// * A default locale, as if by qljs_web_demo_set_locale(d, default_locale)
+//   * TODO(strager): What is default_locale?
+// * Unspecified output diagnostics
+//
+// Returns null if quick-lint-js failed internally.
+//
+// Thread safety: Thread-safe. Not async-signal-safe.
+qljs_web_demo_document* qljs_web_demo_create_document(void);
+
+// Free resources which were allocated for the given document.
+//
+// After calling qljs_web_demo_destroy_document, the document pointer should
+// never be used.
+//
+// document may be poisoned.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() previously returned document.
+// Precondition: qljs_web_demo_destroy_document(document) was not previously
+//               called.
+void qljs_web_demo_destroy_document(qljs_web_demo_document* p);
+
+// Make qljs_web_demo_lint use this text.
+//
+// qljs_web_demo_set_text makes an internal copy of the given array. To change
+// the document's text, you cannot just modify the array pointed to by
+// text_utf_8; you must call qljs_web_demo_set_text again.
+//
+// If qljs_web_demo_set_config(js_document, document) was previously called,
+// then in order for the new config to take effect for js_document,
+// qljs_web_demo_lint(js_document) must be called. (You couldn't notice without
+// calling qljs_web_demo_lint anyway...)
+//
+// If text_byte_count is 2 GiB or more, returns qljs_status_invalid_argument
+// and does nothing.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: text_utf_8 points to an array of at least text_byte_count
+//               bytes.
+// Precondition: text_utf_8 is not null, even if text_byte_count is 0.
+qljs_status qljs_web_demo_set_text(qljs_web_demo_document* p,
+                                   const void* text_utf_8,
+                                   size_t text_byte_count);
+
+// Replace part of document's text with replacement_text_utf_8, leaving the
+// rest of the text unchanged.
+//
+// begin_offset and end_offset count units of document's position encoding,
+// like the offsets in qljs_web_demo_diagnostic. The characters from
+// begin_offset (inclusive) to end_offset (exclusive) are replaced. An offset
+// past the end of the text refers to the end of the text. With
+// qljs_position_encoding_utf_16, an offset in the middle of a surrogate pair
+// refers to the beginning of the pair.
+//
+// qljs_web_demo_replace_text makes an internal copy of the given array, like
+// qljs_web_demo_set_text.
+//
+// If begin_offset is negative or greater than end_offset, or if the new text
+// would be 2 GiB or more, returns qljs_status_invalid_argument and does
+// nothing.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: replacement_text_utf_8 points to an array of at least
+//               replacement_byte_count bytes.
+// Precondition: replacement_text_utf_8 is not null, even if
+//               replacement_byte_count is 0.
+qljs_status qljs_web_demo_replace_text(qljs_web_demo_document* p,
+                                       int begin_offset,
+                                       int end_offset,
+                                       const void* replacement_text_utf_8,
+                                       size_t replacement_byte_count);
+
+// An edit given to qljs_web_demo_replace_text_batch. See
+// qljs_web_demo_replace_text for the meaning of each field.
+typedef struct qljs_web_demo_text_edit {
+  // Offsets count units of the document's position encoding.
+  int begin_offset;
+  int end_offset;
+  const void* replacement_text_utf_8;
+  size_t replacement_byte_count;
+} qljs_web_demo_text_edit;
+
+// Apply several edits to document's text, in order, as if by calling
+// qljs_web_demo_replace_text for each edit.
+//
+// Each edit's offsets refer to the text after the previous edits were applied,
+// like the content changes of an LSP textDocument/didChange notification.
+//
+// If any edit's offsets are invalid (see qljs_web_demo_replace_text), returns
+// qljs_status_invalid_argument and applies none of the edits. If an edit fails
+// otherwise, the remaining edits are not applied.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: edits points to an array of at least edit_count edits.
+// Precondition: Each edit satisfies the preconditions of
+//               qljs_web_demo_replace_text.
+qljs_status qljs_web_demo_replace_text_batch(
+    qljs_web_demo_document* p,
+    const qljs_web_demo_text_edit* edits,
+    size_t edit_count);
+
+// When running qljs_web_demo_lint(js_document), treat config_document's text as
+// if it was js_document's associated quick-lint-js.config file.
+//
+// config_document's language options are ignored.
+//
+// config_document is optional. If null, reverts to the default config.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned js_document, and
+//               qljs_web_demo_destroy_document(js_document) has not been
+//               called.
+// Precondition: config_document is null, or: qljs_web_demo_create_document()
+//               returned config_document, and
+//               qljs_web_demo_destroy_document(config_document) has not been
+//               called.
+qljs_status qljs_web_demo_set_config(qljs_web_demo_document* js_document,
+                                     qljs_web_demo_document* config_document);
+
+// Change how qljs_web_demo_lint(document) parses and interprets document's
+// text.
+//
+// options is a bit set. See qljs_language_options for details.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: options is a bitwise-or of zero or more qljs_language_options
+//               members. (options==0 is permitted.)
+qljs_status qljs_web_demo_set_language_options(qljs_web_demo_document* p,
+                                               qljs_language_options options);
+
+// Change what offsets and columns count for document. This affects the
+// offsets given to qljs_web_demo_replace_text and the offsets and columns of
+// diagnostics returned by later calls to qljs_web_demo_lint (and its variants).
+//
+// encoding is one of the qljs_position_encoding members. If encoding is not,
+// returns qljs_status_invalid_argument.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+qljs_status qljs_web_demo_set_position_encoding(
+    qljs_web_demo_document* p, qljs_position_encoding encoding);
+
+// Change the human language which qljs_web_demo_lint(document) uses for its
+// diagnostics.
+//
+// locale can compare equal to a string returned by qljs_list_locales, or it can
+// be any other string. locale can be a POSIX locale name (e.g. "pt_BR.UTF-8"),
+// a BCP 47 language tag (e.g. "pt-BR"), or an HTTP Accept-Language list (e.g.
+// "de-CH, fr;q=0.8, en;q=0.5"). The most preferred supported locale is used.
+//
+// If locale matches no supported locales, then this sets document's locale to
+// the default locale (which corresponds to professional US English).
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: locale points to a C string.
+// Precondition: locale is not null.
+qljs_status qljs_web_demo_set_locale(qljs_web_demo_document* p,
+                                     const char* locale);
+
+// Make qljs_web_demo_lint(document) prefer the translations in a GNU gettext
+// catalog over the translations built into quick-lint-js. Messages which the
+// catalog does not translate use document's locale [1].
+//
+// catalog_data is the contents of a .po or .mo file encoded in UTF-8.
+// qljs_web_demo_set_translation_catalog does not keep a reference to
+// catalog_data.
+//
+// If catalog_data is null, remove document's translation catalog.
+//
+// Translations whose placeholders (such as "{0}") differ from the untranslated
+// message's placeholders are skipped; the rest of the catalog is still used.
+//
+// Returns false if the catalog could not be parsed. In this case, document's
+// translation catalog is not changed. Also returns false if quick-lint-js failed
+// internally or if document is poisoned; see NOTE[qljs_web_demo_document
+// poisoning].
+//
+// [1] qljs_web_demo_set_locale
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: catalog_data is null, or catalog_data points to an array of
+//               at least catalog_byte_count bytes.
+bool qljs_web_demo_set_translation_catalog(qljs_web_demo_document* p,
+                                           const void* catalog_data,
+                                           size_t catalog_byte_count);
+
+// Parse and lint document's text [1], according to its language options [2] and
+// config [3], and return a list of diagnostics according to document's
+// locale [4].
+//
+// The returned pointer refers to an array of qljs_web_demo_diagnostic objects.
+// The array is terminated by an item where:
+// * qljs_web_demo_diagnostic::message is null, and
+// * qljs_web_demo_diagnostic::code is an empty string.
+//
+// The returned pointer is valid until either the next call to
+// qljs_web_demo_lint(document) or qljs_web_demo_lint_v2(document) or a call to
+// qljs_web_demo_destroy_document(document), whichever comes first.
+//
+// Notes (secondary messages) are not returned. To get notes, call
+// qljs_web_demo_lint_v2 instead.
+//
+// If quick-lint-js failed internally or if document is poisoned, returns an
+// empty array. To distinguish this from a document without diagnostics, call
+// qljs_last_error(document). See NOTE[qljs_web_demo_document poisoning].
+//
+// [1] qljs_web_demo_set_text
+// [2] qljs_web_demo_set_language_options
+// [3] qljs_web_demo_set_config
+// [4] qljs_web_demo_set_locale
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: qljs_web_demo_destroy_document(config_document) has not been
+//               called, where config_document is the qljs_web_demo_document
+//               associated with this document via qljs_web_demo_set_config.
+// Postcondition: The returned value is not null.
+const qljs_web_demo_diagnostic* qljs_web_demo_lint(qljs_web_demo_document* p);
+
+// Like qljs_web_demo_lint, but return qljs_web_demo_diagnostic_v2 objects,
+// which include each diagnostic's notes.
+//
+// The returned array is terminated by an item where:
+// * qljs_web_demo_diagnostic_v2::message is null,
+// * qljs_web_demo_diagnostic_v2::code is an empty string, and
+// * qljs_web_demo_diagnostic_v2::note_count is 0.
+//
+// The returned pointer, and the notes it points to, are valid until either the
+// next call to qljs_web_demo_lint(document) or
+// qljs_web_demo_lint_v2(document) or a call to
+// qljs_web_demo_destroy_document(document), whichever comes first.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: See qljs_web_demo_lint.
+// Postcondition: The returned value is not null.
+const qljs_web_demo_diagnostic_v2* qljs_web_demo_lint_v2(
+    qljs_web_demo_document* p);
+
+// Like qljs_web_demo_lint, but instead of returning diagnostics, call
+// callback(user_data, diagnostic) for each diagnostic as it is found.
+//
+// Each of a diagnostic's notes is given to callback immediately after the
+// diagnostic, with severity qljs_severity_note.
+//
+// qljs_web_demo_lint_callback does not keep diagnostics after it returns, and
+// invalidates the pointer returned by a previous call to
+// qljs_web_demo_lint(document) or qljs_web_demo_lint_v2(document).
+//
+// If quick-lint-js fails internally, callback might have been called for some
+// diagnostics already.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads]. callback is called on
+// the calling thread.
+//
+// Precondition: See qljs_web_demo_lint.
+// Precondition: callback is not null.
+// Precondition: callback does not call qljs_web_demo_* functions with document.
+qljs_status qljs_web_demo_lint_callback(
+    qljs_web_demo_document* p,
+    qljs_web_demo_diagnostic_callback callback,
+    void* user_data);
+
+// Lint several documents in parallel, as if by calling
+// qljs_web_demo_lint_v2(documents[i]) for each document.
+//
+// The result for documents[i] is written to out_diagnostics[i]. See
+// qljs_web_demo_lint_v2 for how long each result is valid.
+//
+// thread_count is the maximum number of threads to lint with. If thread_count
+// is 0, one thread per CPU core is used.
+//
+// Returns qljs_status_ok if every document was linted. Otherwise, returns the
+// status of the first document (in array order) which failed, and each failed
+// document's result is an empty array. Other documents are still linted. See
+// NOTE[qljs_web_demo_document poisoning].
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads]. Each document is used
+// by one thread at a time, but not necessarily by the calling thread.
+//
+// Precondition: See qljs_web_demo_lint, for each document.
+// Precondition: documents points to an array of document_count distinct
+//               documents.
+// Precondition: out_diagnostics points to an array of at least document_count
+//               pointers.
+// Precondition: documents and out_diagnostics are not null, even if
+//               document_count is 0.
+qljs_status qljs_web_demo_lint_batch(
+    qljs_web_demo_document* const* documents,
+    size_t document_count,
+    size_t thread_count,
+    const qljs_web_demo_diagnostic_v2** out_diagnostics);
+
+// See qljs_web_demo_lint_files.
+typedef void (*qljs_web_demo_file_diagnostic_callback)(
+    void* user_data,
+    size_t path_index,
+    const qljs_web_demo_streamed_diagnostic* diagnostic);
+
+// Read and lint several files in parallel, and call
+// callback(user_data, path_index, diagnostic) for each diagnostic and note
+// found in paths[path_index].
+//
+// Each file is linted with settings_document's language options, position
+// encoding, locale, and translation catalog. settings_document's text and
+// config are ignored, and its diagnostics are not changed.
+//
+// thread_count is the maximum number of threads to lint with. If thread_count
+// is 0, one thread per CPU core is used.
+//
+// callback is called in order of path_index (all of paths[0]'s diagnostics,
+// then all of paths[1]'s diagnostics, etc.), and in the order
+// qljs_web_demo_lint_callback would give them for each file. Files are linted
+// in parallel with callback.
+//
+// If a file cannot be read, or if quick-lint-js fails internally while linting
+// a file, callback is given a single diagnostic for that file whose code is
+// empty and whose message describes the problem. Other files are still linted.
+// If quick-lint-js failed internally, qljs_web_demo_lint_files returns
+// qljs_status_internal_error, but settings_document is not poisoned.
+//
+// If settings_document is poisoned, returns qljs_status_document_poisoned and
+// does nothing.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads]. callback is called on
+// the calling thread.
+//
+// Precondition: qljs_web_demo_create_document() returned settings_document,
+//               and qljs_web_demo_destroy_document(settings_document) has not
+//               been called.
+// Precondition: paths points to an array of path_count C strings.
+// Precondition: paths is not null, even if path_count is 0.
+// Precondition: callback is not null.
+// Precondition: callback does not call qljs_web_demo_* functions with
+//               settings_document.
+qljs_status qljs_web_demo_lint_files(
+    qljs_web_demo_document* settings_document,
+    const char* const* paths,
+    size_t path_count,
+    size_t thread_count,
+    qljs_web_demo_file_diagnostic_callback callback,
+    void* user_data);
+
+// Classify document's text [1] for syntax highlighting, according to its
+// language options [2].
+//
+// A pointer to an array of tokens is written to *out_tokens, and the number of
+// tokens is written to *out_token_count. Tokens are sorted by begin_offset and
+// do not overlap. Whitespace between tokens has no token. A token can span
+// multiple lines; for LSP semantic tokens, split such tokens unless the client
+// supports multiline tokens.
+//
+// quick-lint-js guesses whether '/' begins a regexp and whether '<' begins a
+// JSX element based on the previous token.
+//
+// The array is valid until either the next call to
+// qljs_web_demo_highlight(document) or a call to
+// qljs_web_demo_destroy_document(document), whichever comes first.
+//
+// If quick-lint-js failed internally or if document is poisoned, writes null
+// and 0. See NOTE[qljs_web_demo_document poisoning].
+//
+// [1] qljs_web_demo_set_text
+// [2] qljs_web_demo_set_language_options
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: out_tokens and out_token_count are not null.
+qljs_status qljs_web_demo_highlight(
+    qljs_web_demo_document* p,
+    const qljs_web_demo_highlight_token** out_tokens,
+    size_t* out_token_count);
+
+// Returns a description of why document became poisoned, as a null-terminated
+// string. See NOTE[qljs_web_demo_document poisoning].
+//
+// Returns null if document is not poisoned.
+//
+// The returned pointer is valid until qljs_web_demo_destroy_document(document)
+// is called.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+const char* qljs_last_error(qljs_web_demo_document* p);
+
+// Returns document's text as it was when document became poisoned, so that the
+// failure can be reproduced. The text's length in bytes is written to
+// *out_byte_count. See NOTE[qljs_web_demo_document poisoning].
+//
+// Returns null and writes 0 if document is not poisoned.
+//
+// The returned pointer is valid until qljs_web_demo_destroy_document(document)
+// is called.
+//
+// Thread safety: See NOTE[qljs_web_demo_document threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: out_byte_count is not null.
+const void* qljs_last_error_input(qljs_web_demo_document* p,
+                                  size_t* out_byte_count);
+
+// Returns a null-terminated array of null-terminated strings.
+//
+// The array includes the pseudo-locale, "en_XA", whose messages are accented
+// and padded versions of the default locale's messages. Use it to find
+// untranslated or truncated text in user interfaces.
+//
+// Every call to qljs_list_locales will return the same pointer (for a given
+// process).
+//
+// Thread safety: Thread-safe. Not async-signal-safe.
+//
+// Postcondition: The returned value is not null.
+// Postcondition: The returned array contains at least one non-empty string.
+const char* const* qljs_list_locales(void);
+
+// Returns the version of quick-lint-js (e.g. "0.1.0") as a null-terminated
+// string.
+//
+// Thread safety: Thread-safe. Async-signal-safe.
+//
+// Postcondition: The returned value is not null.
+const char* qljs_version(void);
+
+// Returns the QLJS_ABI_VERSION which quick-lint-js was built with.
+//
+// If qljs_abi_version() != QLJS_ABI_VERSION, then the program was compiled
+// against a different version of this header than the loaded library, and
+// calling other qljs_* functions has undefined behavior.
+//
+// Thread safety: Thread-safe. Async-signal-safe.
+uint32_t qljs_abi_version(void);
+
+#if defined(__cplusplus)
+}
+#endif
+
+#endif
+
+// quick-lint-js finds bugs in JavaScript programs.
+// Copyright (C) 2020  Matthew "strager" Glazar
+//
+// This file is part of quick-lint-js.
+//
+// quick-lint-js is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// quick-lint-js is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with quick-lint-js.  If not, see <https://www.gnu.org/licenses/>.
@@ -0,0 +1,103 @@
+// A fixed number of threads which process a batch of items, such as documents
+// or files, and report results in a deterministic order.
+pub struct LintThreadPool {
+    thread_count: usize,
+}
+
+impl LintThreadPool {
+    // If thread_count is 0, use one thread per CPU core.
+    pub fn new(thread_count: usize) -> LintThreadPool {
+        LintThreadPool {
+            thread_count: if thread_count == 0 {
+                std::thread::available_parallelism()
+                    .map(|count: std::num::NonZeroUsize| count.get())
+                    .unwrap_or(1)
+            } else {
+                thread_count
+            },
+        }
+    }
+
+    pub fn thread_count(&self) -> usize {
+        self.thread_count
+    }
+
+    // Call work(state, index, item) for each item on the pool's threads.
+    //
+    // Each thread calls make_state once, then reuses its state for every item
+    // it works on. Put per-thread caches (such as a LexerAllocator) in state.
+    //
+    // deliver(index, result) is called on the calling thread for each item in
+    // order of index (0, then 1, then 2, etc.), regardless of which item's work
+    // finished first. deliver is called while other items are being worked on.
+    //
+    // If work panics, run stops starting new work, then panics with the same
+    // payload after delivering the results of earlier items.
+    pub fn run<Item: Sync, State, Result: Send>(
+        &self,
+        items: &[Item],
+        make_state: impl Fn() -> State + Sync,
+        work: impl Fn(&mut State, usize, &Item) -> Result + Sync,
+        mut deliver: impl FnMut(usize, Result),
+    ) {
+        let thread_count: usize = std::cmp::min(self.thread_count, items.len());
+        if thread_count <= 1 {
+            // Avoid the overhead of threads.
+            let mut state: State = make_state();
+            for (index, item) in items.iter().enumerate() {
+                deliver(index, work(&mut state, index, item));
+            }
+            return;
+        }
+
+        let next_index: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let cancelled: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+        let results: std::sync::Mutex<Vec<Option<std::thread::Result<Result>>>> =
+            std::sync::Mutex::new(items.iter().map(|_| None).collect());
+        let result_ready: std::sync::Condvar = std::sync::Condvar::new();
+
+        std::thread::scope(|scope| {
+            for _ in 0..thread_count {
+                scope.spawn(|| {
+                    let mut state: Option<State> = None;
+                    loop {
+                        if cancelled.load(std::sync::atomic::Ordering::Relaxed) {
+                            break;
+                        }
+                        let index: usize =
+                            next_index.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        if index >= items.len() {
+                            break;
+                        }
+                        let result: std::thread::Result<Result> =
+                            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                                let state: &mut State = state.get_or_insert_with(&make_state);
+                                work(state, index, &items[index])
+                            }));
+                        if result.is_err() {
+                            cancelled.store(true, std::sync::atomic::Ordering::Relaxed);
+                        }
+                        results.lock().unwrap()[index] = Some(result);
+                        result_ready.notify_all();
+                    }
+                });
+            }
+
+            for index in 0..items.len() {
+                let result: std::thread::Result<Result> = {
+                    let mut results = results.lock().unwrap();
+                    loop {
+                        if let Some(result) = results[index].take() {
+                            break result;
+                        }
+                        results = result_ready.wait(results).unwrap();
+                    }
+                };
+                match result {
+                    Ok(result) => deliver(index, result),
+                    Err(payload) => std::panic::resume_unwind(payload),
+                }
+            }
+        });
+    }
+}
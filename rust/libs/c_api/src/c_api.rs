@@ -1,14 +1,33 @@
+use crate::batch_lint::*;
 use crate::c_api_diag_reporter::*;
+use crate::web_demo_location::*;
+use cpp_vs_rust_fe::document::*;
+use cpp_vs_rust_fe::highlight::*;
+use cpp_vs_rust_fe::lex::*;
 use cpp_vs_rust_fe::linter::*;
+use cpp_vs_rust_i18n::locale::*;
+use cpp_vs_rust_i18n::pseudo_locale::*;
 use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_i18n::translation_catalog::*;
 use cpp_vs_rust_i18n::translation_table_generated::*;
+use cpp_vs_rust_util::assert::*;
 use cpp_vs_rust_util::c_string::*;
+use cpp_vs_rust_util::narrow_cast::*;
 use cpp_vs_rust_util::padded_string::*;
 use cpp_vs_rust_util::qljs_assert;
+use cpp_vs_rust_util::qljs_c_string;
 
 #[allow(non_camel_case_types)]
 pub type c_size_t = usize;
 
+// The version of the C API's binary interface, returned by qljs_abi_version.
+//
+// Increment QLJS_ABI_VERSION whenever a change would break programs compiled
+// against an older header, such as changing a function's parameters or a
+// struct's layout. Adding a function or an enum member does not break such
+// programs.
+pub const QLJS_ABI_VERSION: u32 = 2;
+
 // A bit set (i.e. flags) which tell qljs_web_demo_lint how to interpret a
 // QLJSWebDemoDocument's text.
 //
@@ -37,21 +56,89 @@ pub const QLJS_LANGUAGE_OPTIONS_TYPESCRIPT_BIT: QLJSLanguageOptions = 1 << 1;
 // If unset, parse JavaScript or TypeScript.
 pub const QLJS_LANGUAGE_OPTIONS_CONFIG_JSON_BIT: QLJSLanguageOptions = 1 << 2;
 
+// What the offsets and columns given to and returned by qljs_web_demo_*
+// functions count. Like LSP's PositionEncodingKind.
+//
+// In every encoding, each byte of invalid UTF-8 counts as one unit.
+//
+// To change a document's position encoding, call
+// qljs_web_demo_set_position_encoding.
+pub type QLJSPositionEncoding = std::ffi::c_int;
+
+// Count UTF-16 code units, like JavaScript strings. A character outside the
+// Basic Multilingual Plane (e.g. U+1F496) counts as two units.
+//
+// This is the default.
+pub const QLJS_POSITION_ENCODING_UTF_16: QLJSPositionEncoding = 0;
+
+// Count bytes of UTF-8.
+pub const QLJS_POSITION_ENCODING_UTF_8: QLJSPositionEncoding = 1;
+
+// Count Unicode code points.
+pub const QLJS_POSITION_ENCODING_UTF_32: QLJSPositionEncoding = 2;
+
+#[derive(Clone, Copy)]
 #[repr(C)]
 pub enum QLJSSeverity {
     Error = 1,
     Warning = 2,
+    // A secondary message of the preceding diagnostic. Only given to
+    // qljs_web_demo_lint_callback's callback.
+    Note = 3,
+}
+
+// Whether a qljs_web_demo_* function succeeded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub enum QLJSStatus {
+    Ok = 0,
+    // quick-lint-js failed internally, for example because an internal check
+    // failed. The document is now poisoned. See NOTE[QLJSWebDemoDocument
+    // poisoning].
+    InternalError = 1,
+    // The document was poisoned by an earlier call. The function did nothing.
+    DocumentPoisoned = 2,
+    // An argument was not valid. The function did nothing. The document is not
+    // poisoned.
+    InvalidArgument = 3,
+}
+
+// How qljs_web_demo_highlight classifies a piece of a document's text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub enum QLJSHighlightClass {
+    // Reserved words, such as 'if' and 'class'.
+    Keyword = 0,
+    // Words which are only sometimes keywords, such as 'of' and 'async'.
+    ContextualKeyword = 1,
+    Identifier = 2,
+    // #name
+    PrivateIdentifier = 3,
+    String = 4,
+    // `text`, `text${, }text${, or }text`
+    Template = 5,
+    Number = 6,
+    Regexp = 7,
+    Comment = 8,
+    Punctuator = 9,
+    // A JSX element's name, or one of the '<', '</', '/>', or '>' around it.
+    JSXTag = 10,
+    // Text inside a JSX element, excluding leading and trailing whitespace.
+    JSXText = 11,
 }
 
 // A QLJSWebDemoDocument is a text document.
 //
 // A QLJSWebDemoDocument contains the following state:
 //
-// * Text, changed using qljs_web_demo_set_text
+// * Text, changed using qljs_web_demo_set_text or qljs_web_demo_replace_text
 // * Language options, changed using qljs_web_demo_set_language_options
+// * Position encoding, changed using qljs_web_demo_set_position_encoding
 // * Configuration document, changed using qljs_web_demo_set_config
 // * Locale, changed using qljs_web_demo_set_locale
+// * Translation catalog, changed using qljs_web_demo_set_translation_catalog
 // * Output diagnostics, changed using qljs_web_demo_lint
+// * Output highlight tokens, changed using qljs_web_demo_highlight
 //
 // QLJSWebDemoDocument objects are allocated dynamically. To create a
 // QLJSWebDemoDocument, call qljs_web_demo_create_document. When you are
@@ -70,13 +157,43 @@ pub enum QLJSSeverity {
 // on thread 2, then these calls must be synchronized by you.
 //
 // A mutex is sufficient synchronization.
+//
+// NOTE[QLJSWebDemoDocument poisoning]: If quick-lint-js fails internally while
+// a qljs_web_demo_* function is using a document, the function returns
+// qljs_status_internal_error (or another documented failure value) instead of
+// crashing the process, and the document becomes poisoned. Functions given a
+// poisoned document do nothing and return qljs_status_document_poisoned (or
+// their documented failure value). A poisoned document cannot be unpoisoned;
+// destroy it and create a new document instead.
+//
+// Poisoning requires the library to be built with panic = "unwind", such as
+// with the release-c-api Cargo profile. Otherwise, an internal failure aborts
+// the process.
+//
+// To describe the failure in a bug report, call qljs_last_error and
+// qljs_last_error_input.
+//
+// qljs_list_locales, qljs_version, and qljs_abi_version do not depend on their
+// caller's input, so they do not report internal failures.
 pub struct QLJSWebDemoDocument {
-    text: PaddedString,
+    text: Document<WebDemoLocator</* HACK(strager) */ 'static>>,
     diag_reporter: CAPIDiagReporter</* HACK(strager) */ 'static>,
     linter_options: LinterOptions,
     is_config_json: bool,
+    position_encoding: WebDemoPositionEncoding,
     config_document: *mut QLJSWebDemoDocument,
     need_update_config: bool,
+    highlight_tokens: Vec<QLJSWebDemoHighlightToken>,
+    // If not None, the document is poisoned.
+    error: Option<DocumentError>,
+    // See fail_next_call_for_testing.
+    fail_next_call: bool,
+}
+
+struct DocumentError {
+    message: std::ffi::CString,
+    // The document's text when the failure happened.
+    input: Vec<u8>,
 }
 
 #[repr(C)]
@@ -84,7 +201,7 @@ pub struct QLJSWebDemoDiagnostic {
     pub message: *const u8,
     pub code: [std::ffi::c_char; 6], // null-terminated
     pub severity: QLJSSeverity,
-    // Offsets count UTF-16 code units.
+    // Offsets count units of the document's position encoding.
     pub begin_offset: std::ffi::c_int,
     pub end_offset: std::ffi::c_int,
 }
@@ -101,31 +218,151 @@ impl Default for QLJSWebDemoDiagnostic {
     }
 }
 
+// A secondary message of a QLJSWebDemoDiagnosticV2, such as "variable already
+// declared here".
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct QLJSWebDemoDiagnosticNote {
+    pub message: *const u8,
+    // Offsets count units of the document's position encoding.
+    pub begin_offset: std::ffi::c_int,
+    pub end_offset: std::ffi::c_int,
+    // See QLJSWebDemoDiagnosticV2::begin_line.
+    pub begin_line: std::ffi::c_int,
+    pub begin_column: std::ffi::c_int,
+    pub end_line: std::ffi::c_int,
+    pub end_column: std::ffi::c_int,
+}
+
+// Like QLJSWebDemoDiagnostic, but with the diagnostic's notes. Returned by
+// qljs_web_demo_lint_v2.
+//
+// The fields before notes are laid out like QLJSWebDemoDiagnostic's fields.
+#[repr(C)]
+pub struct QLJSWebDemoDiagnosticV2 {
+    pub message: *const u8,
+    pub code: [std::ffi::c_char; 6], // null-terminated
+    pub severity: QLJSSeverity,
+    // Offsets count units of the document's position encoding.
+    pub begin_offset: std::ffi::c_int,
+    pub end_offset: std::ffi::c_int,
+    // An array of note_count notes. Null if note_count is 0.
+    pub notes: *const QLJSWebDemoDiagnosticNote,
+    pub note_count: c_size_t,
+    // Lines and columns start at 0. Lines end with "\n", "\r\n", or "\r".
+    // Columns count units of the document's position encoding.
+    pub begin_line: std::ffi::c_int,
+    pub begin_column: std::ffi::c_int,
+    pub end_line: std::ffi::c_int,
+    pub end_column: std::ffi::c_int,
+}
+
+impl Default for QLJSWebDemoDiagnosticV2 {
+    fn default() -> Self {
+        QLJSWebDemoDiagnosticV2 {
+            message: std::ptr::null(),
+            code: [0; 6],
+            severity: QLJSSeverity::Error,
+            begin_offset: 0,
+            end_offset: 0,
+            notes: std::ptr::null(),
+            note_count: 0,
+            begin_line: 0,
+            begin_column: 0,
+            end_line: 0,
+            end_column: 0,
+        }
+    }
+}
+
+// A diagnostic or note given to the callback of qljs_web_demo_lint_callback.
+#[repr(C)]
+pub struct QLJSWebDemoStreamedDiagnostic {
+    // Not null-terminated. Valid only during the callback.
+    pub message: *const u8,
+    pub message_length: c_size_t,
+    pub code: [std::ffi::c_char; 6], // null-terminated
+    pub severity: QLJSSeverity,
+    // Offsets count units of the document's position encoding.
+    pub begin_offset: std::ffi::c_int,
+    pub end_offset: std::ffi::c_int,
+    // See QLJSWebDemoDiagnosticV2::begin_line.
+    pub begin_line: std::ffi::c_int,
+    pub begin_column: std::ffi::c_int,
+    pub end_line: std::ffi::c_int,
+    pub end_column: std::ffi::c_int,
+}
+
+// A piece of a document's text, returned by qljs_web_demo_highlight.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct QLJSWebDemoHighlightToken {
+    // Offsets count units of the document's position encoding.
+    pub begin_offset: std::ffi::c_int,
+    pub end_offset: std::ffi::c_int,
+    pub highlight_class: QLJSHighlightClass,
+}
+
+// See qljs_web_demo_lint_callback.
+pub type QLJSWebDemoDiagnosticCallback = unsafe extern "C" fn(
+    user_data: *mut std::ffi::c_void,
+    diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+);
+
 // Create a new document.
 //
 // The new document ('d') has the following state:
 //
 // * No text, as if by qljs_web_demo_set_text(d, "", 0)
 // * No language options set, as if by qljs_web_demo_set_language_options(d, 0)
+// * UTF-16 positions, as if by
+//   qljs_web_demo_set_position_encoding(d, qljs_position_encoding_utf_16)
 // * No configuration document, as if by qljs_web_demo_set_config(d, NULL)
 // * A default locale, as if by qljs_web_demo_set_locale(d, default_locale)
 //   * TODO(strager): What is default_locale?
 // * Unspecified output diagnostics
 //
-// Thread safety: Thread-safe. Not async-signal-safe.
+// Returns null if quick-lint-js failed internally.
 //
-// Postcondition: The returned value is not null.
+// Thread safety: Thread-safe. Not async-signal-safe.
 #[no_mangle]
 pub unsafe extern "C" fn qljs_web_demo_create_document() -> *mut QLJSWebDemoDocument {
-    let p: Box<QLJSWebDemoDocument> = Box::new(QLJSWebDemoDocument {
-        text: PaddedString::new(),
-        diag_reporter: CAPIDiagReporter::new(),
-        linter_options: LinterOptions::default(),
-        is_config_json: false,
-        config_document: std::ptr::null_mut(),
-        need_update_config: true,
-    });
-    Box::leak(p) as *mut _
+    std::panic::catch_unwind(|| {
+        let p: Box<QLJSWebDemoDocument> = Box::new(QLJSWebDemoDocument::new());
+        Box::leak(p) as *mut _
+    })
+    .unwrap_or(std::ptr::null_mut())
+}
+
+impl QLJSWebDemoDocument {
+    fn new() -> QLJSWebDemoDocument {
+        QLJSWebDemoDocument {
+            text: Document::new(),
+            diag_reporter: CAPIDiagReporter::new(),
+            linter_options: LinterOptions::default(),
+            is_config_json: false,
+            position_encoding: WebDemoPositionEncoding::UTF16,
+            config_document: std::ptr::null_mut(),
+            need_update_config: true,
+            highlight_tokens: vec![],
+            error: None,
+            fail_next_call: false,
+        }
+    }
+
+    // Create a document with no text, but with other's language options,
+    // position encoding, locale, and translation catalog.
+    fn new_with_settings_of(other: &QLJSWebDemoDocument) -> QLJSWebDemoDocument {
+        let mut doc: QLJSWebDemoDocument = QLJSWebDemoDocument::new();
+        doc.linter_options = other.linter_options;
+        doc.is_config_json = other.is_config_json;
+        doc.position_encoding = other.position_encoding;
+        doc.diag_reporter
+            .set_position_encoding(other.position_encoding);
+        doc.diag_reporter
+            .set_translator(other.diag_reporter.translator().clone());
+        doc
+    }
 }
 
 // Free resources which were allocated for the given document.
@@ -133,6 +370,8 @@ pub unsafe extern "C" fn qljs_web_demo_create_document() -> *mut QLJSWebDemoDocu
 // After calling qljs_web_demo_destroy_document, the document pointer should
 // never be used.
 //
+// document may be poisoned.
+//
 // Thread safety: See NOTE[QLJSWebDemoDocument threads].
 //
 // Precondition: qljs_web_demo_create_document() previously returned document.
@@ -141,7 +380,8 @@ pub unsafe extern "C" fn qljs_web_demo_create_document() -> *mut QLJSWebDemoDocu
 #[no_mangle]
 pub unsafe extern "C" fn qljs_web_demo_destroy_document(p: *mut QLJSWebDemoDocument) {
     let p: Box<QLJSWebDemoDocument> = Box::from_raw(p);
-    std::mem::drop(p);
+    // If dropping fails, leak whatever was not dropped.
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| std::mem::drop(p)));
 }
 
 // Make qljs_web_demo_lint use this text.
@@ -155,6 +395,9 @@ pub unsafe extern "C" fn qljs_web_demo_destroy_document(p: *mut QLJSWebDemoDocum
 // qljs_web_demo_lint(js_document) must be called. (You couldn't notice without
 // calling qljs_web_demo_lint anyway...)
 //
+// If text_byte_count is 2 GiB or more, returns qljs_status_invalid_argument
+// and does nothing.
+//
 // Thread safety: See NOTE[QLJSWebDemoDocument threads].
 //
 // Precondition: qljs_web_demo_create_document() returned document, and
@@ -167,11 +410,146 @@ pub unsafe extern "C" fn qljs_web_demo_set_text(
     p: *mut QLJSWebDemoDocument,
     text_utf_8: *const std::ffi::c_void,
     text_byte_count: c_size_t,
-) {
-    (*p).text = PaddedString::from_slice(std::slice::from_raw_parts(
-        text_utf_8 as *const u8,
-        text_byte_count,
-    ));
+) -> QLJSStatus {
+    if text_byte_count > MAX_TEXT_BYTE_COUNT {
+        return QLJSStatus::InvalidArgument;
+    }
+    status(guard(p, || {
+        (*p).text.set_text(std::slice::from_raw_parts(
+            text_utf_8 as *const u8,
+            text_byte_count,
+        ));
+    }))
+}
+
+// A document's text must be shorter than 2 GiB.
+const MAX_TEXT_BYTE_COUNT: usize = i32::MAX as usize;
+
+// Replace part of document's text with replacement_text_utf_8, leaving the
+// rest of the text unchanged.
+//
+// begin_offset and end_offset count units of document's position encoding,
+// like the offsets in qljs_web_demo_diagnostic. The characters from
+// begin_offset (inclusive) to end_offset (exclusive) are replaced. An offset
+// past the end of the text refers to the end of the text. With
+// qljs_position_encoding_utf_16, an offset in the middle of a surrogate pair
+// refers to the beginning of the pair.
+//
+// qljs_web_demo_replace_text makes an internal copy of the given array, like
+// qljs_web_demo_set_text.
+//
+// If begin_offset is negative or greater than end_offset, or if the new text
+// would be 2 GiB or more, returns qljs_status_invalid_argument and does
+// nothing.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: replacement_text_utf_8 points to an array of at least
+//               replacement_byte_count bytes.
+// Precondition: replacement_text_utf_8 is not null, even if
+//               replacement_byte_count is 0.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_replace_text(
+    p: *mut QLJSWebDemoDocument,
+    begin_offset: std::ffi::c_int,
+    end_offset: std::ffi::c_int,
+    replacement_text_utf_8: *const std::ffi::c_void,
+    replacement_byte_count: c_size_t,
+) -> QLJSStatus {
+    let Some(range) = text_edit_range(begin_offset, end_offset) else {
+        return QLJSStatus::InvalidArgument;
+    };
+    let result: Result<Result<(), QLJSStatus>, QLJSStatus> = guard(p, || {
+        (*p).text.locator_mut().set_encoding((*p).position_encoding);
+        let replaced_byte_count: usize =
+            narrow_cast::<usize, _>((*p).text.locator().from_range(&range).size());
+        let kept_byte_count: usize =
+            narrow_cast::<usize, _>((*p).text.string().len()) - replaced_byte_count;
+        if replacement_byte_count > MAX_TEXT_BYTE_COUNT - kept_byte_count {
+            return Err(QLJSStatus::InvalidArgument);
+        }
+        (*p).text.replace_text(
+            range,
+            std::slice::from_raw_parts(replacement_text_utf_8 as *const u8, replacement_byte_count),
+        );
+        Ok(())
+    });
+    status(result.and_then(|result: Result<(), QLJSStatus>| result))
+}
+
+// Returns None if the offsets do not satisfy 0 <= begin_offset <= end_offset.
+fn text_edit_range(
+    begin_offset: std::ffi::c_int,
+    end_offset: std::ffi::c_int,
+) -> Option<WebDemoSourceRange> {
+    if !(0 <= begin_offset && begin_offset <= end_offset) {
+        return None;
+    }
+    Some(WebDemoSourceRange {
+        begin: narrow_cast::<WebDemoSourceOffset, _>(begin_offset),
+        end: narrow_cast::<WebDemoSourceOffset, _>(end_offset),
+    })
+}
+
+// An edit given to qljs_web_demo_replace_text_batch. See
+// qljs_web_demo_replace_text for the meaning of each field.
+#[repr(C)]
+pub struct QLJSWebDemoTextEdit {
+    // Offsets count units of the document's position encoding.
+    pub begin_offset: std::ffi::c_int,
+    pub end_offset: std::ffi::c_int,
+    pub replacement_text_utf_8: *const std::ffi::c_void,
+    pub replacement_byte_count: c_size_t,
+}
+
+// Apply several edits to document's text, in order, as if by calling
+// qljs_web_demo_replace_text for each edit.
+//
+// Each edit's offsets refer to the text after the previous edits were applied,
+// like the content changes of an LSP textDocument/didChange notification.
+//
+// If any edit's offsets are invalid (see qljs_web_demo_replace_text), returns
+// qljs_status_invalid_argument and applies none of the edits. If an edit fails
+// otherwise, the remaining edits are not applied.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: edits points to an array of at least edit_count edits.
+// Precondition: Each edit satisfies the preconditions of
+//               qljs_web_demo_replace_text.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_replace_text_batch(
+    p: *mut QLJSWebDemoDocument,
+    edits: *const QLJSWebDemoTextEdit,
+    edit_count: c_size_t,
+) -> QLJSStatus {
+    if edit_count == 0 {
+        return status(guard(p, || {}));
+    }
+    let edits: &[QLJSWebDemoTextEdit] = std::slice::from_raw_parts(edits, edit_count);
+    if edits
+        .iter()
+        .any(|edit| text_edit_range(edit.begin_offset, edit.end_offset).is_none())
+    {
+        return QLJSStatus::InvalidArgument;
+    }
+    for edit in edits {
+        let result: QLJSStatus = qljs_web_demo_replace_text(
+            p,
+            edit.begin_offset,
+            edit.end_offset,
+            edit.replacement_text_utf_8,
+            edit.replacement_byte_count,
+        );
+        if result != QLJSStatus::Ok {
+            return result;
+        }
+    }
+    QLJSStatus::Ok
 }
 
 // When running qljs_web_demo_lint(js_document), treat config_document's text as
@@ -194,9 +572,11 @@ pub unsafe extern "C" fn qljs_web_demo_set_text(
 pub unsafe extern "C" fn qljs_web_demo_set_config(
     js_document: *mut QLJSWebDemoDocument,
     config_document: *mut QLJSWebDemoDocument,
-) {
-    (*js_document).need_update_config = true;
-    (*js_document).config_document = config_document;
+) -> QLJSStatus {
+    status(guard(js_document, || {
+        (*js_document).need_update_config = true;
+        (*js_document).config_document = config_document;
+    }))
 }
 
 // Change how qljs_web_demo_lint(document) parses and interprets document's
@@ -214,17 +594,49 @@ pub unsafe extern "C" fn qljs_web_demo_set_config(
 pub unsafe extern "C" fn qljs_web_demo_set_language_options(
     p: *mut QLJSWebDemoDocument,
     options: QLJSLanguageOptions,
-) {
-    (*p).linter_options.jsx = (options & QLJS_LANGUAGE_OPTIONS_JSX_BIT) != 0;
-    (*p).linter_options.typescript = (options & QLJS_LANGUAGE_OPTIONS_TYPESCRIPT_BIT) != 0;
-    (*p).is_config_json = (options & QLJS_LANGUAGE_OPTIONS_CONFIG_JSON_BIT) != 0;
+) -> QLJSStatus {
+    status(guard(p, || {
+        (*p).linter_options.jsx = (options & QLJS_LANGUAGE_OPTIONS_JSX_BIT) != 0;
+        (*p).linter_options.typescript = (options & QLJS_LANGUAGE_OPTIONS_TYPESCRIPT_BIT) != 0;
+        (*p).is_config_json = (options & QLJS_LANGUAGE_OPTIONS_CONFIG_JSON_BIT) != 0;
+    }))
+}
+
+// Change what offsets and columns count for document. This affects the
+// offsets given to qljs_web_demo_replace_text and the offsets and columns of
+// diagnostics returned by later calls to qljs_web_demo_lint (and its variants).
+//
+// encoding is one of the qljs_position_encoding members. If encoding is not,
+// returns qljs_status_invalid_argument.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_set_position_encoding(
+    p: *mut QLJSWebDemoDocument,
+    encoding: QLJSPositionEncoding,
+) -> QLJSStatus {
+    let encoding: WebDemoPositionEncoding = match encoding {
+        QLJS_POSITION_ENCODING_UTF_8 => WebDemoPositionEncoding::UTF8,
+        QLJS_POSITION_ENCODING_UTF_16 => WebDemoPositionEncoding::UTF16,
+        QLJS_POSITION_ENCODING_UTF_32 => WebDemoPositionEncoding::UTF32,
+        _ => return QLJSStatus::InvalidArgument,
+    };
+    status(guard(p, || {
+        (*p).position_encoding = encoding;
+        (*p).diag_reporter.set_position_encoding(encoding);
+    }))
 }
 
 // Change the human language which qljs_web_demo_lint(document) uses for its
 // diagnostics.
 //
 // locale can compare equal to a string returned by qljs_list_locales, or it can
-// be any other string.
+// be any other string. locale can be a POSIX locale name (e.g. "pt_BR.UTF-8"),
+// a BCP 47 language tag (e.g. "pt-BR"), or an HTTP Accept-Language list (e.g.
+// "de-CH, fr;q=0.8, en;q=0.5"). The most preferred supported locale is used.
 //
 // If locale matches no supported locales, then this sets document's locale to
 // the default locale (which corresponds to professional US English).
@@ -239,10 +651,66 @@ pub unsafe extern "C" fn qljs_web_demo_set_language_options(
 pub unsafe extern "C" fn qljs_web_demo_set_locale(
     p: *mut QLJSWebDemoDocument,
     locale: *const std::ffi::c_char,
-) {
-    let mut t: Translator = Translator::new_using_messages_from_source_code();
-    t.use_messages_from_locale(read_utf8_c_string(locale as *const u8));
-    (*p).diag_reporter.set_translator(t);
+) -> QLJSStatus {
+    status(guard(p, || {
+        let locales: Vec<&str> = parse_accept_language(read_utf8_c_string(locale as *const u8));
+        let t: &mut Translator = (*p).diag_reporter.translator_mut();
+        if !t.use_messages_from_locales(&locales) {
+            t.use_messages_from_source_code();
+        }
+    }))
+}
+
+// Make qljs_web_demo_lint(document) prefer the translations in a GNU gettext
+// catalog over the translations built into quick-lint-js. Messages which the
+// catalog does not translate use document's locale [1].
+//
+// catalog_data is the contents of a .po or .mo file encoded in UTF-8.
+// qljs_web_demo_set_translation_catalog does not keep a reference to
+// catalog_data.
+//
+// If catalog_data is null, remove document's translation catalog.
+//
+// Translations whose placeholders (such as "{0}") differ from the untranslated
+// message's placeholders are skipped; the rest of the catalog is still used.
+//
+// Returns false if the catalog could not be parsed. In this case, document's
+// translation catalog is not changed. Also returns false if quick-lint-js failed
+// internally or if document is poisoned; see NOTE[QLJSWebDemoDocument
+// poisoning].
+//
+// [1] qljs_web_demo_set_locale
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: catalog_data is null, or catalog_data points to an array of
+//               at least catalog_byte_count bytes.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_set_translation_catalog(
+    p: *mut QLJSWebDemoDocument,
+    catalog_data: *const std::ffi::c_void,
+    catalog_byte_count: c_size_t,
+) -> bool {
+    guard(p, || {
+        let t: &mut Translator = (*p).diag_reporter.translator_mut();
+        if catalog_data.is_null() {
+            t.clear_catalog();
+            return true;
+        }
+        match TranslationCatalog::from_bytes(std::slice::from_raw_parts(
+            catalog_data as *const u8,
+            catalog_byte_count,
+        )) {
+            Ok(catalog) => {
+                t.use_catalog(std::sync::Arc::new(catalog));
+                true
+            }
+            Err(_) => false,
+        }
+    })
+    .unwrap_or(false)
 }
 
 // Parse and lint document's text [1], according to its language options [2] and
@@ -255,9 +723,16 @@ pub unsafe extern "C" fn qljs_web_demo_set_locale(
 // * qljs_web_demo_diagnostic::code is an empty string.
 //
 // The returned pointer is valid until either the next call to
-// qljs_web_demo_lint(document) or a call to
+// qljs_web_demo_lint(document) or qljs_web_demo_lint_v2(document) or a call to
 // qljs_web_demo_destroy_document(document), whichever comes first.
 //
+// Notes (secondary messages) are not returned. To get notes, call
+// qljs_web_demo_lint_v2 instead.
+//
+// If quick-lint-js failed internally or if document is poisoned, returns an
+// empty array. To distinguish this from a document without diagnostics, call
+// qljs_last_error(document). See NOTE[QLJSWebDemoDocument poisoning].
+//
 // [1] qljs_web_demo_set_text
 // [2] qljs_web_demo_set_language_options
 // [3] qljs_web_demo_set_config
@@ -275,16 +750,567 @@ pub unsafe extern "C" fn qljs_web_demo_set_locale(
 pub unsafe extern "C" fn qljs_web_demo_lint(
     p: *mut QLJSWebDemoDocument,
 ) -> *const QLJSWebDemoDiagnostic {
+    guard(p, || {
+        lint(p);
+        (*p).diag_reporter.get_diagnostics()
+    })
+    .unwrap_or(&EMPTY_DIAGNOSTICS.v1)
+}
+
+// Like qljs_web_demo_lint, but return qljs_web_demo_diagnostic_v2 objects,
+// which include each diagnostic's notes.
+//
+// The returned array is terminated by an item where:
+// * qljs_web_demo_diagnostic_v2::message is null,
+// * qljs_web_demo_diagnostic_v2::code is an empty string, and
+// * qljs_web_demo_diagnostic_v2::note_count is 0.
+//
+// The returned pointer, and the notes it points to, are valid until either the
+// next call to qljs_web_demo_lint(document) or
+// qljs_web_demo_lint_v2(document) or a call to
+// qljs_web_demo_destroy_document(document), whichever comes first.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: See qljs_web_demo_lint.
+// Postcondition: The returned value is not null.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_lint_v2(
+    p: *mut QLJSWebDemoDocument,
+) -> *const QLJSWebDemoDiagnosticV2 {
+    guard(p, || {
+        lint(p);
+        (*p).diag_reporter.get_diagnostics_v2()
+    })
+    .unwrap_or(&EMPTY_DIAGNOSTICS.v2)
+}
+
+// Like qljs_web_demo_lint, but instead of returning diagnostics, call
+// callback(user_data, diagnostic) for each diagnostic as it is found.
+//
+// Each of a diagnostic's notes is given to callback immediately after the
+// diagnostic, with severity qljs_severity_note.
+//
+// qljs_web_demo_lint_callback does not keep diagnostics after it returns, and
+// invalidates the pointer returned by a previous call to
+// qljs_web_demo_lint(document) or qljs_web_demo_lint_v2(document).
+//
+// If quick-lint-js fails internally, callback might have been called for some
+// diagnostics already.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads]. callback is called on
+// the calling thread.
+//
+// Precondition: See qljs_web_demo_lint.
+// Precondition: callback is not null.
+// Precondition: callback does not call qljs_web_demo_* functions with document.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_lint_callback(
+    p: *mut QLJSWebDemoDocument,
+    callback: QLJSWebDemoDiagnosticCallback,
+    user_data: *mut std::ffi::c_void,
+) -> QLJSStatus {
+    status(guard(p, || {
+        (*p).diag_reporter.set_callback(Some(callback), user_data);
+        lint(p);
+        (*p).diag_reporter.set_callback(None, std::ptr::null_mut());
+    }))
+}
+
+unsafe fn lint(p: *mut QLJSWebDemoDocument) {
+    lint_with_allocator(p, &mut LexerAllocator::new());
+}
+
+unsafe fn lint_with_allocator(p: *mut QLJSWebDemoDocument, allocator: &mut LexerAllocator) {
+    let text: PaddedStringView<'static> = (*p).text.string();
     (*p).diag_reporter.reset();
-    (*p).diag_reporter.set_input((*p).text.view());
+    (*p).diag_reporter.set_input(text);
     if !(*p).is_config_json {
-        parse_and_lint((*p).text.view(), &(*p).diag_reporter, (*p).linter_options);
+        parse_and_lint_with_allocator(text, &(*p).diag_reporter, (*p).linter_options, allocator);
     }
-    (*p).diag_reporter.get_diagnostics()
 }
 
+// Lint several documents in parallel, as if by calling
+// qljs_web_demo_lint_v2(documents[i]) for each document.
+//
+// The result for documents[i] is written to out_diagnostics[i]. See
+// qljs_web_demo_lint_v2 for how long each result is valid.
+//
+// thread_count is the maximum number of threads to lint with. If thread_count
+// is 0, one thread per CPU core is used.
+//
+// Returns qljs_status_ok if every document was linted. Otherwise, returns the
+// status of the first document (in array order) which failed, and each failed
+// document's result is an empty array. Other documents are still linted. See
+// NOTE[QLJSWebDemoDocument poisoning].
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads]. Each document is used
+// by one thread at a time, but not necessarily by the calling thread.
+//
+// Precondition: See qljs_web_demo_lint, for each document.
+// Precondition: documents points to an array of document_count distinct
+//               documents.
+// Precondition: out_diagnostics points to an array of at least document_count
+//               pointers.
+// Precondition: documents and out_diagnostics are not null, even if
+//               document_count is 0.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_lint_batch(
+    documents: *const *mut QLJSWebDemoDocument,
+    document_count: c_size_t,
+    thread_count: c_size_t,
+    out_diagnostics: *mut *const QLJSWebDemoDiagnosticV2,
+) -> QLJSStatus {
+    let documents: &[SendPointer<QLJSWebDemoDocument>] =
+        std::slice::from_raw_parts(documents as *const SendPointer<_>, document_count);
+    let mut result: QLJSStatus = QLJSStatus::Ok;
+    LintThreadPool::new(thread_count).run(
+        documents,
+        LexerAllocator::new,
+        |allocator: &mut LexerAllocator, _index: usize, p: &SendPointer<QLJSWebDemoDocument>| {
+            guard(p.0, || lint_with_allocator(p.0, allocator))
+        },
+        |index: usize, lint_result: Result<(), QLJSStatus>| {
+            let p: *mut QLJSWebDemoDocument = documents[index].0;
+            *out_diagnostics.add(index) = match lint_result {
+                Ok(()) => (*p).diag_reporter.get_diagnostics_v2(),
+                Err(status) => {
+                    if result == QLJSStatus::Ok {
+                        result = status;
+                    }
+                    &EMPTY_DIAGNOSTICS.v2
+                }
+            };
+        },
+    );
+    result
+}
+
+// See qljs_web_demo_lint_files.
+pub type QLJSWebDemoFileDiagnosticCallback = unsafe extern "C" fn(
+    user_data: *mut std::ffi::c_void,
+    path_index: c_size_t,
+    diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+);
+
+// Read and lint several files in parallel, and call
+// callback(user_data, path_index, diagnostic) for each diagnostic and note
+// found in paths[path_index].
+//
+// Each file is linted with settings_document's language options, position
+// encoding, locale, and translation catalog. settings_document's text and
+// config are ignored, and its diagnostics are not changed.
+//
+// thread_count is the maximum number of threads to lint with. If thread_count
+// is 0, one thread per CPU core is used.
+//
+// callback is called in order of path_index (all of paths[0]'s diagnostics,
+// then all of paths[1]'s diagnostics, etc.), and in the order
+// qljs_web_demo_lint_callback would give them for each file. Files are linted
+// in parallel with callback.
+//
+// If a file cannot be read, or if quick-lint-js fails internally while linting
+// a file, callback is given a single diagnostic for that file whose code is
+// empty and whose message describes the problem. Other files are still linted.
+// If quick-lint-js failed internally, qljs_web_demo_lint_files returns
+// qljs_status_internal_error, but settings_document is not poisoned.
+//
+// If settings_document is poisoned, returns qljs_status_document_poisoned and
+// does nothing.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads]. callback is called on
+// the calling thread.
+//
+// Precondition: qljs_web_demo_create_document() returned settings_document,
+//               and qljs_web_demo_destroy_document(settings_document) has not
+//               been called.
+// Precondition: paths points to an array of path_count C strings.
+// Precondition: paths is not null, even if path_count is 0.
+// Precondition: callback is not null.
+// Precondition: callback does not call qljs_web_demo_* functions with
+//               settings_document.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_lint_files(
+    settings_document: *mut QLJSWebDemoDocument,
+    paths: *const *const std::ffi::c_char,
+    path_count: c_size_t,
+    thread_count: c_size_t,
+    callback: QLJSWebDemoFileDiagnosticCallback,
+    user_data: *mut std::ffi::c_void,
+) -> QLJSStatus {
+    if (*settings_document).error.is_some() {
+        return QLJSStatus::DocumentPoisoned;
+    }
+    let settings: SendPointer<QLJSWebDemoDocument> = SendPointer(settings_document);
+    let paths: &[SendPointer<std::ffi::c_char>] =
+        std::slice::from_raw_parts(paths as *const SendPointer<_>, path_count);
+    let new_worker_document =
+        || Box::new(QLJSWebDemoDocument::new_with_settings_of(&*{ settings }.0));
+
+    let mut result: QLJSStatus = QLJSStatus::Ok;
+    LintThreadPool::new(thread_count).run(
+        paths,
+        || (new_worker_document(), LexerAllocator::new()),
+        |(doc, allocator): &mut (Box<QLJSWebDemoDocument>, LexerAllocator),
+         _index: usize,
+         path: &SendPointer<std::ffi::c_char>|
+         -> Result<Vec<CollectedDiagnostic>, FileLintError> {
+            let path: &std::path::Path = path_from_c_string(path.0);
+            let text: Vec<u8> = std::fs::read(path).map_err(|error: std::io::Error| {
+                FileLintError::Read(format!("cannot read {}: {error}", path.display()))
+            })?;
+            let p: *mut QLJSWebDemoDocument = &mut **doc;
+            let mut diagnostics: Vec<CollectedDiagnostic> = vec![];
+            let lint_result: Result<(), QLJSStatus> = guard(p, || {
+                (*p).text.set_text(&text);
+                (*p).diag_reporter.set_callback(
+                    Some(collect_diagnostic),
+                    &mut diagnostics as *mut Vec<CollectedDiagnostic> as *mut std::ffi::c_void,
+                );
+                lint_with_allocator(p, allocator);
+                (*p).diag_reporter.set_callback(None, std::ptr::null_mut());
+            });
+            if lint_result.is_err() {
+                let message: String = std::ffi::CStr::from_ptr(qljs_last_error(p))
+                    .to_string_lossy()
+                    .into_owned();
+                // The poisoned document can't lint other files.
+                *doc = new_worker_document();
+                return Err(FileLintError::Internal(format!(
+                    "internal error while linting {}: {message}",
+                    path.display()
+                )));
+            }
+            Ok(diagnostics)
+        },
+        |index: usize, lint_result: Result<Vec<CollectedDiagnostic>, FileLintError>| {
+            let diagnostics: Vec<CollectedDiagnostic> = match lint_result {
+                Ok(diagnostics) => diagnostics,
+                Err(error) => {
+                    let message: String = match error {
+                        FileLintError::Read(message) => message,
+                        FileLintError::Internal(message) => {
+                            result = QLJSStatus::InternalError;
+                            message
+                        }
+                    };
+                    vec![CollectedDiagnostic {
+                        message: message.into_bytes(),
+                        diagnostic: QLJSWebDemoStreamedDiagnostic {
+                            message: std::ptr::null(),
+                            message_length: 0,
+                            code: [0; 6],
+                            severity: QLJSSeverity::Error,
+                            begin_offset: 0,
+                            end_offset: 0,
+                            begin_line: 0,
+                            begin_column: 0,
+                            end_line: 0,
+                            end_column: 0,
+                        },
+                    }]
+                }
+            };
+            for mut d in diagnostics {
+                d.diagnostic.message = d.message.as_ptr();
+                d.diagnostic.message_length = d.message.len();
+                callback(user_data, index, &d.diagnostic);
+            }
+        },
+    );
+    result
+}
+
+// A raw pointer which can be given to another thread. See
+// NOTE[QLJSWebDemoDocument threads].
+#[repr(transparent)]
+struct SendPointer<T>(*mut T);
+
+// Not derived, because #[derive(Clone, Copy)] would require T: Copy.
+impl<T> Clone for SendPointer<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SendPointer<T> {}
+
+unsafe impl<T> Send for SendPointer<T> {}
+unsafe impl<T> Sync for SendPointer<T> {}
+
+// A diagnostic copied out of a QLJSWebDemoDiagnosticCallback call.
+// diagnostic.message is not valid until it is pointed at message.
+struct CollectedDiagnostic {
+    message: Vec<u8>,
+    diagnostic: QLJSWebDemoStreamedDiagnostic,
+}
+
+// SAFETY: diagnostic.message is not used until the CollectedDiagnostic is back
+// on the calling thread.
+unsafe impl Send for CollectedDiagnostic {}
+
+enum FileLintError {
+    Read(String),
+    Internal(String),
+}
+
+unsafe extern "C" fn collect_diagnostic(
+    user_data: *mut std::ffi::c_void,
+    diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+) {
+    let diagnostics: &mut Vec<CollectedDiagnostic> =
+        &mut *(user_data as *mut Vec<CollectedDiagnostic>);
+    let d: &QLJSWebDemoStreamedDiagnostic = &*diagnostic;
+    diagnostics.push(CollectedDiagnostic {
+        message: std::slice::from_raw_parts(d.message, d.message_length).to_vec(),
+        diagnostic: QLJSWebDemoStreamedDiagnostic {
+            message: std::ptr::null(),
+            message_length: 0,
+            code: d.code,
+            severity: d.severity,
+            begin_offset: d.begin_offset,
+            end_offset: d.end_offset,
+            begin_line: d.begin_line,
+            begin_column: d.begin_column,
+            end_line: d.end_line,
+            end_column: d.end_column,
+        },
+    });
+}
+
+#[cfg(unix)]
+unsafe fn path_from_c_string<'a>(path: *const std::ffi::c_char) -> &'a std::path::Path {
+    use std::os::unix::ffi::OsStrExt;
+    std::path::Path::new(std::ffi::OsStr::from_bytes(
+        std::ffi::CStr::from_ptr(path).to_bytes(),
+    ))
+}
+
+#[cfg(not(unix))]
+unsafe fn path_from_c_string<'a>(path: *const std::ffi::c_char) -> &'a std::path::Path {
+    // Non-UTF-8 paths are not supported.
+    std::path::Path::new(read_utf8_c_string(path as *const u8))
+}
+
+// Classify document's text [1] for syntax highlighting, according to its
+// language options [2].
+//
+// A pointer to an array of tokens is written to *out_tokens, and the number of
+// tokens is written to *out_token_count. Tokens are sorted by begin_offset and
+// do not overlap. Whitespace between tokens has no token. A token can span
+// multiple lines; for LSP semantic tokens, split such tokens unless the client
+// supports multiline tokens.
+//
+// quick-lint-js guesses whether '/' begins a regexp and whether '<' begins a
+// JSX element based on the previous token.
+//
+// The array is valid until either the next call to
+// qljs_web_demo_highlight(document) or a call to
+// qljs_web_demo_destroy_document(document), whichever comes first.
+//
+// If quick-lint-js failed internally or if document is poisoned, writes null
+// and 0. See NOTE[QLJSWebDemoDocument poisoning].
+//
+// [1] qljs_web_demo_set_text
+// [2] qljs_web_demo_set_language_options
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: out_tokens and out_token_count are not null.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_web_demo_highlight(
+    p: *mut QLJSWebDemoDocument,
+    out_tokens: *mut *const QLJSWebDemoHighlightToken,
+    out_token_count: *mut c_size_t,
+) -> QLJSStatus {
+    *out_tokens = std::ptr::null();
+    *out_token_count = 0;
+    status(guard(p, || {
+        let text: PaddedStringView<'static> = (*p).text.string();
+        let mut linter_options: LinterOptions = (*p).linter_options;
+        if (*p).is_config_json {
+            linter_options.jsx = false;
+        }
+        let tokens: Vec<HighlightToken> = highlight(text, linter_options);
+
+        // Tokens are sorted, so count code units from the previous token
+        // instead of from the beginning of the text.
+        let mut counted_bytes: PaddedStringSizeType = 0;
+        let mut counted_units: WebDemoSourceOffset = 0;
+        let mut to_offset = |byte_offset: PaddedStringSizeType| -> std::ffi::c_int {
+            let locator: WebDemoLocator = WebDemoLocator::new_with_encoding(
+                text.substr(counted_bytes),
+                (*p).position_encoding,
+            );
+            counted_units += locator.position(text.c_str().offset(byte_offset as isize));
+            counted_bytes = byte_offset;
+            narrow_cast(counted_units)
+        };
+        let highlight_tokens: &mut Vec<QLJSWebDemoHighlightToken> = &mut (*p).highlight_tokens;
+        highlight_tokens.clear();
+        for token in tokens {
+            highlight_tokens.push(QLJSWebDemoHighlightToken {
+                begin_offset: to_offset(token.begin),
+                end_offset: to_offset(token.end),
+                highlight_class: match token.class {
+                    HighlightClass::Keyword => QLJSHighlightClass::Keyword,
+                    HighlightClass::ContextualKeyword => QLJSHighlightClass::ContextualKeyword,
+                    HighlightClass::Identifier => QLJSHighlightClass::Identifier,
+                    HighlightClass::PrivateIdentifier => QLJSHighlightClass::PrivateIdentifier,
+                    HighlightClass::String => QLJSHighlightClass::String,
+                    HighlightClass::Template => QLJSHighlightClass::Template,
+                    HighlightClass::Number => QLJSHighlightClass::Number,
+                    HighlightClass::Regexp => QLJSHighlightClass::Regexp,
+                    HighlightClass::Comment => QLJSHighlightClass::Comment,
+                    HighlightClass::Punctuator => QLJSHighlightClass::Punctuator,
+                    HighlightClass::JSXTag => QLJSHighlightClass::JSXTag,
+                    HighlightClass::JSXText => QLJSHighlightClass::JSXText,
+                },
+            });
+        }
+        *out_tokens = highlight_tokens.as_ptr();
+        *out_token_count = highlight_tokens.len();
+    }))
+}
+
+// Returns a description of why document became poisoned, as a null-terminated
+// string. See NOTE[QLJSWebDemoDocument poisoning].
+//
+// Returns null if document is not poisoned.
+//
+// The returned pointer is valid until qljs_web_demo_destroy_document(document)
+// is called.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_last_error(p: *mut QLJSWebDemoDocument) -> *const std::ffi::c_char {
+    match &(*p).error {
+        Some(error) => error.message.as_ptr(),
+        None => std::ptr::null(),
+    }
+}
+
+// Returns document's text as it was when document became poisoned, so that the
+// failure can be reproduced. The text's length in bytes is written to
+// *out_byte_count. See NOTE[QLJSWebDemoDocument poisoning].
+//
+// Returns null and writes 0 if document is not poisoned.
+//
+// The returned pointer is valid until qljs_web_demo_destroy_document(document)
+// is called.
+//
+// Thread safety: See NOTE[QLJSWebDemoDocument threads].
+//
+// Precondition: qljs_web_demo_create_document() returned document, and
+//               qljs_web_demo_destroy_document(document) has not been called.
+// Precondition: out_byte_count is not null.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_last_error_input(
+    p: *mut QLJSWebDemoDocument,
+    out_byte_count: *mut c_size_t,
+) -> *const std::ffi::c_void {
+    match &(*p).error {
+        Some(error) => {
+            *out_byte_count = error.input.len();
+            error.input.as_ptr() as *const std::ffi::c_void
+        }
+        None => {
+            *out_byte_count = 0;
+            std::ptr::null()
+        }
+    }
+}
+
+// Make the next qljs_web_demo_* function given document fail internally, as if
+// quick-lint-js had a bug, so that tests can check NOTE[QLJSWebDemoDocument
+// poisoning].
+//
+// For tests only. Not part of the C API.
+pub fn fail_next_call_for_testing(document: &mut QLJSWebDemoDocument) {
+    document.fail_next_call = true;
+}
+
+// Calls f unless p is poisoned. If f panics, poisons p.
+unsafe fn guard<R>(p: *mut QLJSWebDemoDocument, f: impl FnOnce() -> R) -> Result<R, QLJSStatus> {
+    if (*p).error.is_some() {
+        return Err(QLJSStatus::DocumentPoisoned);
+    }
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        if std::mem::take(&mut (*p).fail_next_call) {
+            panic!("fail_next_call_for_testing");
+        }
+        f()
+    })) {
+        Ok(result) => Ok(result),
+        Err(payload) => {
+            let message: String = take_last_assertion_failure().unwrap_or_else(|| {
+                if let Some(message) = payload.downcast_ref::<&str>() {
+                    message.to_string()
+                } else if let Some(message) = payload.downcast_ref::<String>() {
+                    message.clone()
+                } else {
+                    "unknown panic".to_string()
+                }
+            });
+            (*p).error = Some(DocumentError {
+                message: std::ffi::CString::new(message.replace('\0', "\\0")).unwrap(),
+                input: (*p).text.string().slice().to_vec(),
+            });
+            Err(QLJSStatus::InternalError)
+        }
+    }
+}
+
+fn status(result: Result<(), QLJSStatus>) -> QLJSStatus {
+    match result {
+        Ok(()) => QLJSStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+// Returned by qljs_web_demo_lint and qljs_web_demo_lint_v2 if they fail.
+struct EmptyDiagnostics {
+    v1: QLJSWebDemoDiagnostic,
+    v2: QLJSWebDemoDiagnosticV2,
+}
+
+// SAFETY: EmptyDiagnostics' pointers are null.
+unsafe impl Sync for EmptyDiagnostics {}
+
+static EMPTY_DIAGNOSTICS: EmptyDiagnostics = EmptyDiagnostics {
+    v1: QLJSWebDemoDiagnostic {
+        message: std::ptr::null(),
+        code: [0; 6],
+        severity: QLJSSeverity::Error,
+        begin_offset: 0,
+        end_offset: 0,
+    },
+    v2: QLJSWebDemoDiagnosticV2 {
+        message: std::ptr::null(),
+        code: [0; 6],
+        severity: QLJSSeverity::Error,
+        begin_offset: 0,
+        end_offset: 0,
+        notes: std::ptr::null(),
+        note_count: 0,
+        begin_line: 0,
+        begin_column: 0,
+        end_line: 0,
+        end_column: 0,
+    },
+};
+
 // Returns a null-terminated array of null-terminated strings.
 //
+// The array includes the pseudo-locale, "en_XA", whose messages are accented
+// and padded versions of the default locale's messages. Use it to find
+// untranslated or truncated text in user interfaces.
+//
 // Every call to qljs_list_locales will return the same pointer (for a given
 // process).
 //
@@ -297,7 +1323,7 @@ pub unsafe extern "C" fn qljs_list_locales() -> *const *const std::ffi::c_char {
     static mut LOCALES: *const *const std::ffi::c_char = std::ptr::null();
     static INIT_LOCALES: std::sync::Once = std::sync::Once::new();
     INIT_LOCALES.call_once(|| {
-        const LOCALE_COUNT: usize = (TRANSLATION_TABLE_LOCALE_COUNT + 1) as usize;
+        const LOCALE_COUNT: usize = (TRANSLATION_TABLE_LOCALE_COUNT + 2) as usize;
         let mut locales: Box<[*const std::ffi::c_char]> =
             Box::new([std::ptr::null(); LOCALE_COUNT + 1]);
 
@@ -308,6 +1334,10 @@ pub unsafe extern "C" fn qljs_list_locales() -> *const *const std::ffi::c_char {
             i += 1;
             l = &l[(l.iter().position(|c: &u8| *c == b'\0').unwrap() + 1)..];
         }
+        locales[i] = std::ffi::CString::new(PSEUDO_LOCALE_NAME)
+            .unwrap()
+            .into_raw();
+        i += 1;
         locales[i] = l.as_ptr() as *const std::ffi::c_char; // Default locale (empty string).
         i += 1;
         qljs_assert!(i == LOCALE_COUNT);
@@ -317,3 +1347,26 @@ pub unsafe extern "C" fn qljs_list_locales() -> *const *const std::ffi::c_char {
     });
     LOCALES
 }
+
+// Returns the version of quick-lint-js (e.g. "0.1.0") as a null-terminated
+// string.
+//
+// Thread safety: Thread-safe. Async-signal-safe.
+//
+// Postcondition: The returned value is not null.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_version() -> *const std::ffi::c_char {
+    qljs_c_string!(env!("CARGO_PKG_VERSION")) as *const std::ffi::c_char
+}
+
+// Returns the QLJS_ABI_VERSION which quick-lint-js was built with.
+//
+// If qljs_abi_version() != QLJS_ABI_VERSION, then the program was compiled
+// against a different version of this header than the loaded library, and
+// calling other qljs_* functions has undefined behavior.
+//
+// Thread safety: Thread-safe. Async-signal-safe.
+#[no_mangle]
+pub unsafe extern "C" fn qljs_abi_version() -> u32 {
+    QLJS_ABI_VERSION
+}
@@ -21,39 +21,105 @@ use cpp_vs_rust_util::qljs_const_assert;
 pub struct CAPIDiagReporter<'code> {
     translator: Translator,
     diagnostics: std::cell::UnsafeCell<Vec<QLJSWebDemoDiagnostic>>,
+    // The lines and columns of each diagnostic in diagnostics.
+    // QLJSWebDemoDiagnostic has no room for them.
+    diagnostic_line_columns: std::cell::UnsafeCell<Vec<LineColumnRange>>,
+    // Notes in the order they were reported. The usize is the index in
+    // diagnostics of the note's diagnostic.
+    notes: std::cell::UnsafeCell<Vec<(usize, QLJSWebDemoDiagnosticNote)>>,
+    diagnostics_v2: Vec<QLJSWebDemoDiagnosticV2>,
+    diagnostic_notes_v2: Vec<QLJSWebDemoDiagnosticNote>,
+    // The message being formatted. Reused between messages to avoid
+    // allocating.
+    current_message: std::cell::UnsafeCell<Vec<u8>>,
+    // If set, diagnostics are given to the callback instead of being stored in
+    // diagnostics and notes. See qljs_web_demo_lint_callback.
+    callback: Option<(QLJSWebDemoDiagnosticCallback, *mut std::ffi::c_void)>,
     _input: *const u8,
     input_phantom: std::marker::PhantomData<&'code [u8]>,
     locator: Option<WebDemoLocator<'code>>,
+    position_encoding: WebDemoPositionEncoding,
     string_allocator: MonotonicAllocator,
+    // The state of string_allocator before anything was allocated.
+    string_allocator_empty: LinkedBumpAllocatorRewindState,
 }
 
 impl<'code> CAPIDiagReporter<'code> {
     pub fn new() -> Self {
+        let string_allocator: MonotonicAllocator =
+            MonotonicAllocator::new("c_api_diag_reporter::string_allocator_");
+        let string_allocator_empty: LinkedBumpAllocatorRewindState =
+            string_allocator.prepare_for_rewind();
         CAPIDiagReporter {
             translator: Translator::new_using_messages_from_source_code(),
             diagnostics: std::cell::UnsafeCell::new(vec![]),
+            diagnostic_line_columns: std::cell::UnsafeCell::new(vec![]),
+            notes: std::cell::UnsafeCell::new(vec![]),
+            diagnostics_v2: vec![],
+            diagnostic_notes_v2: vec![],
+            current_message: std::cell::UnsafeCell::new(vec![]),
+            callback: None,
             _input: std::ptr::null(),
             input_phantom: std::marker::PhantomData,
             locator: None,
-            string_allocator: MonotonicAllocator::new("c_api_diag_reporter::string_allocator_"),
+            position_encoding: WebDemoPositionEncoding::UTF16,
+            string_allocator,
+            string_allocator_empty,
         }
     }
 
     pub fn set_input(&mut self, input: PaddedStringView<'code>) {
         self._input = input.c_str();
-        self.locator = Some(WebDemoLocator::new(input));
+        self.locator = Some(WebDemoLocator::new_with_encoding(
+            input,
+            self.position_encoding,
+        ));
     }
 
-    // Does not reset translator.
+    // Takes effect at the next call to set_input.
+    pub fn set_position_encoding(&mut self, encoding: WebDemoPositionEncoding) {
+        self.position_encoding = encoding;
+    }
+
+    // Does not reset translator or the callback.
+    //
+    // Invalidates messages returned by get_diagnostics and get_diagnostics_v2.
     pub fn reset(&mut self) {
         self.diagnostics.get_mut().clear();
-        // TODO(strager): Release allocated string memory.
+        self.diagnostic_line_columns.get_mut().clear();
+        self.notes.get_mut().clear();
+        self.diagnostics_v2.clear();
+        self.diagnostic_notes_v2.clear();
+        // Keep the allocator's first chunk so the next lint can reuse it.
+        unsafe {
+            self.string_allocator
+                .rewind(self.string_allocator_empty.clone());
+        }
+    }
+
+    // If callback is Some, report each diagnostic and note to the callback as
+    // it is formatted. Otherwise, collect diagnostics for get_diagnostics and
+    // get_diagnostics_v2.
+    pub fn set_callback(
+        &mut self,
+        callback: Option<QLJSWebDemoDiagnosticCallback>,
+        user_data: *mut std::ffi::c_void,
+    ) {
+        self.callback = callback.map(|callback| (callback, user_data));
     }
 
     pub fn set_translator(&mut self, t: Translator) {
         self.translator = t;
     }
 
+    pub fn translator(&self) -> &Translator {
+        &self.translator
+    }
+
+    pub fn translator_mut(&mut self) -> &mut Translator {
+        &mut self.translator
+    }
+
     pub fn get_diagnostics(&mut self) -> *const QLJSWebDemoDiagnostic {
         // Null-terminate the returned diagnostics.
         self.diagnostics
@@ -63,6 +129,49 @@ impl<'code> CAPIDiagReporter<'code> {
         self.diagnostics.get_mut().as_ptr()
     }
 
+    pub fn get_diagnostics_v2(&mut self) -> *const QLJSWebDemoDiagnosticV2 {
+        let notes: &[(usize, QLJSWebDemoDiagnosticNote)] = self.notes.get_mut();
+        // Fill diagnostic_notes_v2 before taking pointers into it so it doesn't
+        // reallocate.
+        self.diagnostic_notes_v2.clear();
+        self.diagnostic_notes_v2
+            .extend(notes.iter().map(|(_, note)| *note));
+
+        self.diagnostics_v2.clear();
+        let line_columns: &[LineColumnRange] = self.diagnostic_line_columns.get_mut();
+        let mut note_index: usize = 0;
+        for (diag_index, diag) in self.diagnostics.get_mut().iter().enumerate() {
+            let first_note_index: usize = note_index;
+            while note_index < notes.len() && notes[note_index].0 == diag_index {
+                note_index += 1;
+            }
+            let note_count: usize = note_index - first_note_index;
+            self.diagnostics_v2.push(QLJSWebDemoDiagnosticV2 {
+                message: diag.message,
+                code: diag.code,
+                severity: diag.severity,
+                begin_offset: diag.begin_offset,
+                end_offset: diag.end_offset,
+                notes: if note_count == 0 {
+                    std::ptr::null()
+                } else {
+                    self.diagnostic_notes_v2[first_note_index..].as_ptr()
+                },
+                note_count,
+                begin_line: line_columns[diag_index].begin_line,
+                begin_column: line_columns[diag_index].begin_column,
+                end_line: line_columns[diag_index].end_line,
+                end_column: line_columns[diag_index].end_column,
+            });
+        }
+        qljs_assert!(note_index == notes.len());
+
+        // Null-terminate the returned diagnostics.
+        self.diagnostics_v2.push(QLJSWebDemoDiagnosticV2::default());
+
+        self.diagnostics_v2.as_ptr()
+    }
+
     fn allocate_c_string<'this>(&'this self, string: &[u8]) -> &'this [u8] {
         let result: &'this mut [std::mem::MaybeUninit<u8>] = self
             .string_allocator
@@ -82,15 +191,17 @@ impl<'code> DiagReporter for CAPIDiagReporter<'code> {
 
 struct CAPIDiagFormatter<'code, 'reporter> {
     reporter: &'reporter CAPIDiagReporter<'code>,
-    current_message: Vec<u8>,
 }
 
 impl<'code, 'reporter> CAPIDiagFormatter<'code, 'reporter> {
     fn new(reporter: &'reporter CAPIDiagReporter<'code>) -> Self {
-        CAPIDiagFormatter {
-            reporter: reporter,
-            current_message: vec![],
-        }
+        CAPIDiagFormatter { reporter: reporter }
+    }
+
+    fn current_message(&mut self) -> &mut Vec<u8> {
+        // NOTE(strager): The reporter formats one message at a time, so nobody
+        // else is using current_message.
+        unsafe { &mut *self.reporter.current_message.get() }
     }
 }
 
@@ -98,22 +209,14 @@ impl<'code, 'reporter> DiagnosticFormatter for CAPIDiagFormatter<'code, 'reporte
     fn write_before_message(
         &mut self,
         _code: &str,
-        sev: DiagnosticSeverity,
+        _sev: DiagnosticSeverity,
         _origin: SourceCodeSpan<'_>,
     ) {
-        if sev == DiagnosticSeverity::Note {
-            // Don't write notes. Only write the main message.
-            return;
-        }
-        qljs_assert!(self.current_message.is_empty());
+        qljs_assert!(self.current_message().is_empty());
     }
 
-    fn write_message_part(&mut self, _code: &str, sev: DiagnosticSeverity, message: &[u8]) {
-        if sev == DiagnosticSeverity::Note {
-            // Don't write notes. Only write the main message.
-            return;
-        }
-        self.current_message.extend_from_slice(message);
+    fn write_message_part(&mut self, _code: &str, _sev: DiagnosticSeverity, message: &[u8]) {
+        self.current_message().extend_from_slice(message);
     }
 
     fn write_after_message(
@@ -122,33 +225,72 @@ impl<'code, 'reporter> DiagnosticFormatter for CAPIDiagFormatter<'code, 'reporte
         sev: DiagnosticSeverity,
         origin: SourceCodeSpan<'_>,
     ) {
+        let locator: &WebDemoLocator = self.reporter.locator.as_ref().unwrap();
+        let r: WebDemoSourceRange = locator.range(origin);
+        let begin_offset: std::ffi::c_int = narrow_cast::<i32, _>(r.begin);
+        let end_offset: std::ffi::c_int = narrow_cast::<i32, _>(r.end);
+        let line_columns: LineColumnRange = LineColumnRange::new(locator, origin);
         let diag_severity: QLJSSeverity = match sev {
-            DiagnosticSeverity::Note => {
-                // Don't write notes. Only write the main message.
-                return;
-            }
+            DiagnosticSeverity::Note => QLJSSeverity::Note,
             DiagnosticSeverity::Error => QLJSSeverity::Error,
             DiagnosticSeverity::Warning => QLJSSeverity::Warning,
         };
-        let mut diag: QLJSWebDemoDiagnostic = QLJSWebDemoDiagnostic::default();
-        let r: WebDemoSourceRange = self.reporter.locator.as_ref().unwrap().range(origin);
-        diag.begin_offset = narrow_cast::<i32, _>(r.begin);
-        diag.end_offset = narrow_cast::<i32, _>(r.end);
-
-        qljs_const_assert!(std::mem::size_of::<u8>() == std::mem::size_of::<std::ffi::c_char>());
-        diag.code[0..code.len()].copy_from_slice(unsafe {
-            std::mem::transmute::<&[u8], &[std::ffi::c_char]>(code.as_bytes())
-        });
-        diag.code[code.len()] = b'\0' as std::ffi::c_char;
-
-        diag.message = self
+
+        if let Some((callback, user_data)) = self.reporter.callback {
+            let message: &[u8] = self.current_message();
+            let diag: QLJSWebDemoStreamedDiagnostic = QLJSWebDemoStreamedDiagnostic {
+                message: message.as_ptr(),
+                message_length: message.len(),
+                code: make_c_code(code),
+                severity: diag_severity,
+                begin_offset,
+                end_offset,
+                begin_line: line_columns.begin_line,
+                begin_column: line_columns.begin_column,
+                end_line: line_columns.end_line,
+                end_column: line_columns.end_column,
+            };
+            unsafe {
+                callback(user_data, &diag);
+            }
+            self.current_message().clear();
+            return;
+        }
+
+        let message: *const u8 = self
             .reporter
-            .allocate_c_string(self.current_message.as_slice())
+            .allocate_c_string(self.current_message().as_slice())
             .as_ptr();
-        diag.severity = diag_severity;
+        self.current_message().clear();
 
+        if let QLJSSeverity::Note = diag_severity {
+            unsafe {
+                let diag_index: usize = (*self.reporter.diagnostics.get()).len() - 1;
+                (*self.reporter.notes.get()).push((
+                    diag_index,
+                    QLJSWebDemoDiagnosticNote {
+                        message,
+                        begin_offset,
+                        end_offset,
+                        begin_line: line_columns.begin_line,
+                        begin_column: line_columns.begin_column,
+                        end_line: line_columns.end_line,
+                        end_column: line_columns.end_column,
+                    },
+                ));
+            }
+            return;
+        }
+        let diag: QLJSWebDemoDiagnostic = QLJSWebDemoDiagnostic {
+            message,
+            code: make_c_code(code),
+            severity: diag_severity,
+            begin_offset,
+            end_offset,
+        };
         unsafe {
             (*self.reporter.diagnostics.get()).push(diag);
+            (*self.reporter.diagnostic_line_columns.get()).push(line_columns);
         }
     }
 
@@ -156,3 +298,34 @@ impl<'code, 'reporter> DiagnosticFormatter for CAPIDiagFormatter<'code, 'reporte
         self.reporter.translator.clone()
     }
 }
+
+struct LineColumnRange {
+    begin_line: std::ffi::c_int,
+    begin_column: std::ffi::c_int,
+    end_line: std::ffi::c_int,
+    end_column: std::ffi::c_int,
+}
+
+impl LineColumnRange {
+    fn new(locator: &WebDemoLocator, span: SourceCodeSpan<'_>) -> LineColumnRange {
+        let begin: WebDemoLineColumn = locator.line_column(span.begin_ptr());
+        let end: WebDemoLineColumn = locator.line_column(span.end_ptr());
+        LineColumnRange {
+            begin_line: narrow_cast::<i32, _>(begin.line),
+            begin_column: narrow_cast::<i32, _>(begin.column),
+            end_line: narrow_cast::<i32, _>(end.line),
+            end_column: narrow_cast::<i32, _>(end.column),
+        }
+    }
+}
+
+// Returns a null-terminated copy of code (e.g. "E0001").
+fn make_c_code(code: &str) -> [std::ffi::c_char; 6] {
+    let mut c_code: [std::ffi::c_char; 6] = [0; 6];
+    qljs_const_assert!(std::mem::size_of::<u8>() == std::mem::size_of::<std::ffi::c_char>());
+    c_code[0..code.len()].copy_from_slice(unsafe {
+        std::mem::transmute::<&[u8], &[std::ffi::c_char]>(code.as_bytes())
+    });
+    c_code[code.len()] = b'\0' as std::ffi::c_char;
+    c_code
+}
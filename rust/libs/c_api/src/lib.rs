@@ -1,3 +1,7 @@
+pub mod batch_lint;
+// Preconditions are documented in '// Precondition:' comments, which
+// generate_c_api_header copies into c-api.h, instead of '# Safety' sections.
+#[allow(clippy::missing_safety_doc)]
 pub mod c_api;
 pub mod c_api_diag_reporter;
 pub mod web_demo_location;
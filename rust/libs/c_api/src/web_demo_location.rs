@@ -11,13 +11,59 @@ pub struct WebDemoSourceRange {
     pub end: WebDemoSourceOffset,
 }
 
+// What a WebDemoSourceOffset counts. Similar to LSP's PositionEncodingKind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WebDemoPositionEncoding {
+    // Bytes.
+    UTF8,
+    // UTF-16 code units. This is the default, and is what JavaScript strings
+    // count.
+    UTF16,
+    // Unicode code points.
+    UTF32,
+}
+
+// A zero-based line number and a zero-based column within that line. The
+// column is counted in the locator's WebDemoPositionEncoding.
+//
+// Lines are terminated by "\n", "\r\n", or "\r", like in LSP.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WebDemoLineColumn {
+    pub line: WebDemoSourceOffset,
+    pub column: WebDemoSourceOffset,
+}
+
+// Invalid UTF-8 bytes count as one code unit each.
 pub struct WebDemoLocator<'code> {
     input: PaddedStringView<'code>,
+    encoding: WebDemoPositionEncoding,
+    // Byte offsets of the beginning of each line. Computed on first use by
+    // line_column.
+    line_begins: std::cell::OnceCell<Vec<PaddedStringSizeType>>,
 }
 
 impl<'code> WebDemoLocator<'code> {
     pub fn new(input: PaddedStringView<'code>) -> WebDemoLocator<'code> {
-        WebDemoLocator { input: input }
+        WebDemoLocator::new_with_encoding(input, WebDemoPositionEncoding::UTF16)
+    }
+
+    pub fn new_with_encoding(
+        input: PaddedStringView<'code>,
+        encoding: WebDemoPositionEncoding,
+    ) -> WebDemoLocator<'code> {
+        WebDemoLocator {
+            input: input,
+            encoding,
+            line_begins: std::cell::OnceCell::new(),
+        }
+    }
+
+    pub fn encoding(&self) -> WebDemoPositionEncoding {
+        self.encoding
+    }
+
+    pub fn set_encoding(&mut self, encoding: WebDemoPositionEncoding) {
+        self.encoding = encoding;
     }
 
     pub fn range(&self, span: SourceCodeSpan<'_>) -> WebDemoSourceRange {
@@ -29,10 +75,87 @@ impl<'code> WebDemoLocator<'code> {
 
     pub fn position(&self, c: *const u8) -> WebDemoSourceOffset {
         let byte_offset: i32 = narrow_cast::<i32, _>(unsafe { c.offset_from(self.input.c_str()) });
-        narrow_cast::<WebDemoSourceOffset, _>(count_lsp_characters_in_utf_8(
-            self.input,
-            byte_offset,
-        ))
+        self.count_code_units(self.input, byte_offset)
+    }
+
+    // The inverse of position.
+    //
+    // If offset is in the middle of a UTF-16 surrogate pair, returns a pointer
+    // to the pair's character. If offset is past the end of the input, returns
+    // a pointer to the end of the input.
+    pub fn from_position(&self, offset: WebDemoSourceOffset) -> *const u8 {
+        let byte_offset: PaddedStringSizeType = match self.encoding {
+            WebDemoPositionEncoding::UTF8 => std::cmp::min(
+                narrow_cast::<PaddedStringSizeType, _>(offset),
+                self.input.len(),
+            ),
+            WebDemoPositionEncoding::UTF16 => {
+                advance_lsp_characters_in_utf_8(self.input, offset as isize)
+            }
+            WebDemoPositionEncoding::UTF32 => advance_utf_8_characters(self.input, offset as usize),
+        };
+        unsafe { self.input.c_str().offset(byte_offset as isize) }
+    }
+
+    pub fn line_column(&self, c: *const u8) -> WebDemoLineColumn {
+        let byte_offset: PaddedStringSizeType =
+            narrow_cast::<PaddedStringSizeType, _>(unsafe { c.offset_from(self.input.c_str()) });
+        let line_begins: &[PaddedStringSizeType] =
+            self.line_begins.get_or_init(|| self.compute_line_begins());
+        // line_begins[0] is 0, so line is at least 0.
+        let line: usize = line_begins.partition_point(|begin| *begin <= byte_offset) - 1;
+        let line_begin: PaddedStringSizeType = line_begins[line];
+        WebDemoLineColumn {
+            line: narrow_cast::<WebDemoSourceOffset, _>(line),
+            column: self.count_code_units(self.input.substr(line_begin), byte_offset - line_begin),
+        }
+    }
+
+    fn compute_line_begins(&self) -> Vec<PaddedStringSizeType> {
+        let mut line_begins: Vec<PaddedStringSizeType> = vec![0];
+        let input: &[u8] = self.input.slice();
+        let mut i: usize = 0;
+        while i < input.len() {
+            match input[i] {
+                b'\r' if input.get(i + 1) == Some(&b'\n') => {
+                    i += 2;
+                }
+                b'\r' | b'\n' => {
+                    i += 1;
+                }
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            }
+            line_begins.push(narrow_cast::<PaddedStringSizeType, _>(i));
+        }
+        line_begins
+    }
+
+    fn count_code_units(
+        &self,
+        utf_8: PaddedStringView,
+        byte_count: PaddedStringSizeType,
+    ) -> WebDemoSourceOffset {
+        match self.encoding {
+            WebDemoPositionEncoding::UTF8 => narrow_cast::<WebDemoSourceOffset, _>(byte_count),
+            WebDemoPositionEncoding::UTF16 => narrow_cast::<WebDemoSourceOffset, _>(
+                count_lsp_characters_in_utf_8(utf_8, byte_count),
+            ),
+            WebDemoPositionEncoding::UTF32 => narrow_cast::<WebDemoSourceOffset, _>(
+                count_utf_8_characters(utf_8, byte_count as usize),
+            ),
+        }
+    }
+
+    pub fn from_range(&self, range: &WebDemoSourceRange) -> SourceCodeSpan<'code> {
+        unsafe {
+            SourceCodeSpan::new(
+                self.from_position(range.begin),
+                self.from_position(range.end),
+            )
+        }
     }
 }
 
@@ -46,4 +169,17 @@ impl<'code> LocatorLike<'code> for WebDemoLocator<'code> {
     fn range(&self, s: SourceCodeSpan<'_>) -> Self::RangeType {
         self.range(s)
     }
+
+    fn span(&self, range: &Self::RangeType) -> SourceCodeSpan<'code> {
+        self.from_range(range)
+    }
+
+    fn replace_text(
+        &mut self,
+        _range: &Self::RangeType,
+        _replacement_text: &[u8],
+        new_text: PaddedStringView<'code>,
+    ) {
+        *self = WebDemoLocator::new_with_encoding(new_text, self.encoding);
+    }
 }
@@ -0,0 +1,85 @@
+use cpp_vs_rust_c_api::batch_lint::*;
+
+#[test]
+fn zero_threads_means_at_least_one_thread() {
+    assert!(LintThreadPool::new(0).thread_count() >= 1);
+    assert_eq!(LintThreadPool::new(3).thread_count(), 3);
+}
+
+#[test]
+fn results_are_delivered_in_order() {
+    let items: Vec<u64> = (0..50).collect();
+    for thread_count in [1, 2, 8] {
+        let mut delivered: Vec<(usize, u64)> = vec![];
+        LintThreadPool::new(thread_count).run(
+            &items,
+            || (),
+            |_state: &mut (), _index: usize, item: &u64| {
+                // Make early items finish after later items.
+                std::thread::sleep(std::time::Duration::from_micros((50 - item) * 20));
+                item * 10
+            },
+            |index: usize, result: u64| delivered.push((index, result)),
+        );
+        let expected: Vec<(usize, u64)> = (0..50).map(|i: u64| (i as usize, i * 10)).collect();
+        assert_eq!(delivered, expected, "thread_count={thread_count}");
+    }
+}
+
+#[test]
+fn each_thread_reuses_its_state() {
+    let items: Vec<()> = vec![(); 100];
+    let states_made: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let mut total_uses: usize = 0;
+    LintThreadPool::new(4).run(
+        &items,
+        || {
+            states_made.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            0usize
+        },
+        |uses: &mut usize, _index: usize, _item: &()| {
+            *uses += 1;
+            *uses
+        },
+        |_index: usize, uses: usize| total_uses = std::cmp::max(total_uses, uses),
+    );
+    let states_made: usize = states_made.into_inner();
+    assert!((1..=4).contains(&states_made), "states_made={states_made}");
+    assert!(total_uses > 1);
+}
+
+#[test]
+fn empty_batch_delivers_nothing() {
+    let items: Vec<i32> = vec![];
+    LintThreadPool::new(4).run(
+        &items,
+        || (),
+        |_state: &mut (), _index: usize, _item: &i32| -> i32 {
+            panic!("work should not be called")
+        },
+        |_index: usize, _result: i32| panic!("deliver should not be called"),
+    );
+}
+
+#[test]
+#[cfg(panic = "unwind")]
+fn panic_in_work_is_propagated_after_earlier_results() {
+    let items: Vec<usize> = (0..20).collect();
+    let mut delivered: Vec<usize> = vec![];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        LintThreadPool::new(4).run(
+            &items,
+            || (),
+            |_state: &mut (), _index: usize, item: &usize| {
+                if *item == 5 {
+                    panic!("item 5 failed");
+                }
+                *item
+            },
+            |index: usize, _result: usize| delivered.push(index),
+        );
+    }));
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"item 5 failed"));
+    assert_eq!(delivered, vec![0, 1, 2, 3, 4]);
+}
@@ -1,5 +1,7 @@
 use cpp_vs_rust_c_api::c_api::*;
+use cpp_vs_rust_i18n::pseudo_locale::*;
 use cpp_vs_rust_i18n::translation_table_generated::*;
+use cpp_vs_rust_port::qljs_offset_of;
 use cpp_vs_rust_util::c_string::*;
 
 #[test]
@@ -49,6 +51,563 @@ fn lint_error_after_text_insertion() {
     }
 }
 
+unsafe fn replace_text(
+    p: *mut QLJSWebDemoDocument,
+    begin: i32,
+    end: i32,
+    text: &[u8],
+) -> QLJSStatus {
+    qljs_web_demo_replace_text(
+        p,
+        begin,
+        end,
+        text.as_ptr() as *const std::ffi::c_void,
+        text.len(),
+    )
+}
+
+// Make qljs_web_demo_set_text fail internally, poisoning p.
+unsafe fn set_text_failing_internally(p: *mut QLJSWebDemoDocument) -> QLJSStatus {
+    fail_next_call_for_testing(&mut *p);
+    let text: &[u8] = b"new text";
+    qljs_web_demo_set_text(p, text.as_ptr() as *const std::ffi::c_void, text.len())
+}
+
+#[test]
+fn lint_after_replace_text() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+
+        // U+1f496 has two UTF-16 code units.
+        let document_text: &[u8] = "let s = '\u{01f496}';".as_bytes();
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).message, std::ptr::null());
+
+        // Delete the closing quote.
+        replace_text(p, 11, 12, b"");
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!(
+            read_utf8_c_string_from_c_slice(&(*diagnostics.add(0)).code),
+            "E0040"
+        );
+        assert_eq!((*diagnostics.add(0)).begin_offset, 8);
+        assert_eq!((*diagnostics.add(0)).end_offset, 12);
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+
+        // Add the closing quote back.
+        replace_text(p, 11, 11, b"'");
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).message, std::ptr::null());
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn replace_text_batch_applies_edits_in_order() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+
+        let document_text: &[u8] = b"let x = 1;";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        let edit = |begin: i32, end: i32, text: &'static [u8]| QLJSWebDemoTextEdit {
+            begin_offset: begin,
+            end_offset: end,
+            replacement_text_utf_8: text.as_ptr() as *const std::ffi::c_void,
+            replacement_byte_count: text.len(),
+        };
+        let edits: [QLJSWebDemoTextEdit; 2] = [
+            // "let x = 1;" -> "let x = 'one;"
+            edit(8, 9, b"'one"),
+            // "let x = 'one;" -> "  let x = 'one;"
+            edit(0, 0, b"  "),
+        ];
+        qljs_web_demo_replace_text_batch(p, edits.as_ptr(), edits.len());
+
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!(
+            read_utf8_c_string_from_c_slice(&(*diagnostics.add(0)).code),
+            "E0040"
+        );
+        assert_eq!((*diagnostics.add(0)).begin_offset, 10);
+        assert_eq!((*diagnostics.add(0)).end_offset, 15);
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+
+        qljs_web_demo_replace_text_batch(p, std::ptr::null(), 0);
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).begin_offset, 10);
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn utf_8_position_encoding() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        assert_eq!(
+            qljs_web_demo_set_position_encoding(p, QLJS_POSITION_ENCODING_UTF_8),
+            QLJSStatus::Ok
+        );
+
+        // U+1f496 has four UTF-8 code units.
+        let document_text: &[u8] = "let s = '\u{01f496}';".as_bytes();
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        // Offsets given to qljs_web_demo_replace_text count bytes too. Delete
+        // the closing quote.
+        assert_eq!(replace_text(p, 13, 14, b""), QLJSStatus::Ok);
+
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = qljs_web_demo_lint_v2(p);
+        assert_eq!(
+            read_utf8_c_string_from_c_slice(&(*diagnostics.add(0)).code),
+            "E0040"
+        );
+        assert_eq!((*diagnostics.add(0)).begin_offset, 8);
+        assert_eq!((*diagnostics.add(0)).end_offset, 14);
+        assert_eq!((*diagnostics.add(0)).begin_line, 0);
+        assert_eq!((*diagnostics.add(0)).begin_column, 8);
+        assert_eq!((*diagnostics.add(0)).end_line, 0);
+        assert_eq!((*diagnostics.add(0)).end_column, 14);
+
+        // The encoding applies until it is changed again.
+        qljs_web_demo_set_position_encoding(p, QLJS_POSITION_ENCODING_UTF_32);
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).begin_offset, 8);
+        assert_eq!((*diagnostics.add(0)).end_offset, 11);
+        qljs_web_demo_set_position_encoding(p, QLJS_POSITION_ENCODING_UTF_16);
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).begin_offset, 8);
+        assert_eq!((*diagnostics.add(0)).end_offset, 12);
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn position_encoding_applies_to_line_columns_of_streamed_diagnostics() {
+    // (begin_offset, begin_line, begin_column, end_line, end_column)
+    type Position = (
+        std::ffi::c_int,
+        std::ffi::c_int,
+        std::ffi::c_int,
+        std::ffi::c_int,
+        std::ffi::c_int,
+    );
+
+    unsafe extern "C" fn collect(
+        user_data: *mut std::ffi::c_void,
+        diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+    ) {
+        let positions: &mut Vec<Position> = &mut *(user_data as *mut Vec<Position>);
+        positions.push((
+            (*diagnostic).begin_offset,
+            (*diagnostic).begin_line,
+            (*diagnostic).begin_column,
+            (*diagnostic).end_line,
+            (*diagnostic).end_column,
+        ));
+    }
+
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        qljs_web_demo_set_position_encoding(p, QLJS_POSITION_ENCODING_UTF_32);
+        let document_text: &[u8] = "\u{01f496};\r\n\u{01f496} '".as_bytes();
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        let mut positions: Vec<Position> = vec![];
+        qljs_web_demo_lint_callback(
+            p,
+            collect,
+            &mut positions as *mut Vec<Position> as *mut std::ffi::c_void,
+        );
+        assert_eq!(positions.last(), Some(&(6, 1, 2, 1, 3)));
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn unknown_position_encoding_is_rejected() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        assert_eq!(
+            qljs_web_demo_set_position_encoding(p, 12345),
+            QLJSStatus::InvalidArgument
+        );
+        assert_eq!(qljs_last_error(p), std::ptr::null());
+
+        // The encoding is unchanged (UTF-16).
+        let document_text: &[u8] = "'\u{01f496}".as_bytes();
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).end_offset, 3);
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn healthy_document_has_no_last_error() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        assert_eq!(
+            qljs_web_demo_set_text(
+                p,
+                document_text.as_ptr() as *const std::ffi::c_void,
+                document_text.len(),
+            ),
+            QLJSStatus::Ok
+        );
+        qljs_web_demo_lint(p);
+
+        assert_eq!(qljs_last_error(p), std::ptr::null());
+        let mut input_byte_count: usize = 12345;
+        assert_eq!(
+            qljs_last_error_input(p, &mut input_byte_count),
+            std::ptr::null()
+        );
+        assert_eq!(input_byte_count, 0);
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn replace_text_rejects_negative_or_backwards_offsets() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        for (begin, end) in [(-1, 0), (0, -1), (-5, -2), (i32::MIN, 3), (3, 2)] {
+            assert_eq!(
+                replace_text(p, begin, end, b"x"),
+                QLJSStatus::InvalidArgument,
+                "{begin}..{end}"
+            );
+        }
+
+        // The document is not poisoned or changed.
+        assert_eq!(qljs_last_error(p), std::ptr::null());
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!(
+            (*diagnostics.add(0)).end_offset as usize,
+            document_text.len()
+        );
+        assert_eq!(replace_text(p, 0, 1, b""), QLJSStatus::Ok);
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn set_text_and_replace_text_reject_text_of_2_gib_or_more() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        // The byte counts are checked before the text is read, so the text
+        // doesn't need to be that big.
+        let text: *const std::ffi::c_void = b"x".as_ptr() as *const std::ffi::c_void;
+        let max_byte_count: usize = i32::MAX as usize;
+        assert_eq!(
+            qljs_web_demo_set_text(p, text, max_byte_count + 1),
+            QLJSStatus::InvalidArgument
+        );
+        assert_eq!(
+            qljs_web_demo_replace_text(p, 0, 0, text, max_byte_count - document_text.len() + 1),
+            QLJSStatus::InvalidArgument
+        );
+        assert_eq!(
+            qljs_web_demo_replace_text(p, 0, 1, text, max_byte_count - document_text.len() + 2),
+            QLJSStatus::InvalidArgument
+        );
+        assert_eq!(
+            qljs_web_demo_replace_text(p, 0, 0, text, usize::MAX),
+            QLJSStatus::InvalidArgument
+        );
+
+        // The document is not poisoned or changed.
+        assert_eq!(qljs_last_error(p), std::ptr::null());
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!(
+            (*diagnostics.add(0)).end_offset as usize,
+            document_text.len()
+        );
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn replace_text_batch_with_invalid_edit_applies_no_edits() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        let edit = |begin: i32, end: i32, text: &'static [u8]| QLJSWebDemoTextEdit {
+            begin_offset: begin,
+            end_offset: end,
+            replacement_text_utf_8: text.as_ptr() as *const std::ffi::c_void,
+            replacement_byte_count: text.len(),
+        };
+        for bad_edit in [edit(-1, 0, b""), edit(2, 1, b"")] {
+            let edits: [QLJSWebDemoTextEdit; 2] = [
+                // Would close the string literal.
+                edit(11, 11, b"'"),
+                bad_edit,
+            ];
+            assert_eq!(
+                qljs_web_demo_replace_text_batch(p, edits.as_ptr(), edits.len()),
+                QLJSStatus::InvalidArgument
+            );
+        }
+
+        assert_eq!(qljs_last_error(p), std::ptr::null());
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!(
+            read_utf8_c_string_from_c_slice(&(*diagnostics.add(0)).code),
+            "E0040"
+        );
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+#[cfg(panic = "unwind")]
+fn internal_failure_poisons_document() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        assert_eq!(set_text_failing_internally(p), QLJSStatus::InternalError);
+
+        let error: &str = read_utf8_c_string(qljs_last_error(p) as *const u8);
+        assert!(error.contains("fail_next_call_for_testing"), "{error}");
+        let mut input_byte_count: usize = 0;
+        let input: *const std::ffi::c_void = qljs_last_error_input(p, &mut input_byte_count);
+        assert_eq!(
+            std::slice::from_raw_parts(input as *const u8, input_byte_count),
+            document_text
+        );
+
+        // Further calls do nothing.
+        assert_eq!(replace_text(p, 0, 0, b"x"), QLJSStatus::DocumentPoisoned);
+        assert_eq!(
+            qljs_web_demo_set_language_options(p, QLJS_LANGUAGE_OPTIONS_JSX_BIT),
+            QLJSStatus::DocumentPoisoned
+        );
+        assert!(!qljs_web_demo_set_translation_catalog(
+            p,
+            std::ptr::null(),
+            0
+        ));
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_eq!((*diagnostics.add(0)).message, std::ptr::null());
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = qljs_web_demo_lint_v2(p);
+        assert_eq!((*diagnostics.add(0)).message, std::ptr::null());
+        assert_eq!((*diagnostics.add(0)).note_count, 0);
+
+        // The first failure is kept.
+        assert_eq!(read_utf8_c_string(qljs_last_error(p) as *const u8), error);
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+#[cfg(panic = "unwind")]
+fn internal_failure_does_not_affect_other_documents() {
+    unsafe {
+        let bad: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let good: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            good,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        assert_eq!(set_text_failing_internally(bad), QLJSStatus::InternalError);
+        assert_ne!(qljs_last_error(bad), std::ptr::null());
+
+        assert_eq!(qljs_last_error(good), std::ptr::null());
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(good);
+        assert_eq!(
+            read_utf8_c_string((*diagnostics.add(0)).message),
+            "unclosed string literal"
+        );
+
+        qljs_web_demo_destroy_document(bad);
+        qljs_web_demo_destroy_document(good);
+    }
+}
+
+#[test]
+fn lint_v2_matches_lint() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = qljs_web_demo_lint_v2(p);
+        assert_eq!(
+            read_utf8_c_string((*diagnostics.add(0)).message),
+            "unclosed string literal"
+        );
+        assert_eq!(
+            read_utf8_c_string_from_c_slice(&(*diagnostics.add(0)).code),
+            "E0040"
+        );
+        assert_eq!((*diagnostics.add(0)).begin_offset as usize, b"".len());
+        assert_eq!(
+            (*diagnostics.add(0)).end_offset as usize,
+            b"'unfinished".len()
+        );
+        assert_eq!((*diagnostics.add(0)).note_count, 0);
+        assert_eq!((*diagnostics.add(0)).notes, std::ptr::null());
+
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+        assert_eq!(
+            read_utf8_c_string_from_c_slice(&(*diagnostics.add(1)).code),
+            ""
+        );
+        assert_eq!((*diagnostics.add(1)).note_count, 0);
+
+        // Switching between the APIs does not carry over diagnostics.
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_ne!((*diagnostics.add(0)).message, std::ptr::null());
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = qljs_web_demo_lint_v2(p);
+        assert_ne!((*diagnostics.add(0)).message, std::ptr::null());
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn lint_callback_streams_same_diagnostics_as_lint() {
+    // (message, code, begin_offset, end_offset)
+    type Message = (String, String, std::ffi::c_int, std::ffi::c_int);
+
+    unsafe extern "C" fn collect(
+        user_data: *mut std::ffi::c_void,
+        diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+    ) {
+        let messages: &mut Vec<Message> = &mut *(user_data as *mut Vec<Message>);
+        messages.push((
+            String::from_utf8(
+                std::slice::from_raw_parts((*diagnostic).message, (*diagnostic).message_length)
+                    .to_vec(),
+            )
+            .unwrap(),
+            String::from(read_utf8_c_string_from_c_slice(&(*diagnostic).code)),
+            (*diagnostic).begin_offset,
+            (*diagnostic).end_offset,
+        ));
+    }
+
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"#\n'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        let mut streamed: Vec<Message> = vec![];
+        qljs_web_demo_lint_callback(p, collect, &mut streamed as *mut _ as *mut std::ffi::c_void);
+
+        let mut linted: Vec<Message> = vec![];
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        let mut i: usize = 0;
+        while !(*diagnostics.add(i)).message.is_null() {
+            let diag: &QLJSWebDemoDiagnostic = &*diagnostics.add(i);
+            linted.push((
+                String::from(read_utf8_c_string(diag.message)),
+                String::from(read_utf8_c_string_from_c_slice(&diag.code)),
+                diag.begin_offset,
+                diag.end_offset,
+            ));
+            i += 1;
+        }
+
+        assert_eq!(linted.len(), 2);
+        assert_eq!(streamed, linted);
+
+        let mut streamed_again: Vec<Message> = vec![];
+        qljs_web_demo_lint_callback(
+            p,
+            collect,
+            &mut streamed_again as *mut _ as *mut std::ffi::c_void,
+        );
+        assert_eq!(streamed_again, streamed);
+        let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+        assert_ne!((*diagnostics.add(0)).message, std::ptr::null());
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn v2_diagnostic_starts_with_v1_diagnostic_layout() {
+    assert_eq!(
+        qljs_offset_of!(QLJSWebDemoDiagnosticV2, end_offset),
+        qljs_offset_of!(QLJSWebDemoDiagnostic, end_offset)
+    );
+    assert_eq!(
+        qljs_offset_of!(QLJSWebDemoDiagnosticV2, notes),
+        std::mem::size_of::<QLJSWebDemoDiagnostic>()
+    );
+}
+
 #[test]
 fn lint_new_error_after_second_text_insertion() {
     unsafe {
@@ -133,6 +692,94 @@ fn setting_locale_changes_messages_forever() {
     }
 }
 
+#[test]
+fn locale_can_be_bcp_47_tag_or_accept_language_list() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        let lint_message = |locale: &[u8]| -> String {
+            qljs_web_demo_set_locale(p, locale.as_ptr() as *const std::ffi::c_char);
+            let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+            String::from(read_utf8_c_string((*diagnostics.add(0)).message))
+        };
+
+        assert_eq!(lint_message(b"pt-BR\0"), "string n\u{e3}o encerrada");
+        assert_eq!(lint_message(b"de-CH\0"), "Zeichenkette ohne Ende");
+        assert_eq!(
+            lint_message(b"xx-YY, pt;q=0.5, de-DE;q=0.8\0"),
+            "Zeichenkette ohne Ende"
+        );
+        assert_eq!(
+            lint_message(b"en-US-u-va-posix, de\0"),
+            "unclosed string literal"
+        );
+        assert_eq!(lint_message(b"xx-YY\0"), "unclosed string literal");
+        assert_eq!(
+            lint_message(b"en-XA\0"),
+            "[\u{fb}ncl\u{f6}s\u{e9}d str\u{ee}ng l\u{ee}t\u{e9}r\u{e1}l ~~~~~~~~~~]"
+        );
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+fn translation_catalog_overrides_locale_messages() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        qljs_web_demo_set_locale(p, c"en_US@snarky".as_ptr());
+
+        let catalog: &[u8] = b"msgid \"unclosed string literal\"\n\
+                               msgstr \"string literal never ends\"\n";
+        assert!(qljs_web_demo_set_translation_catalog(
+            p,
+            catalog.as_ptr() as *const std::ffi::c_void,
+            catalog.len(),
+        ));
+
+        let lint_message = |document_text: &[u8]| -> String {
+            qljs_web_demo_set_text(
+                p,
+                document_text.as_ptr() as *const std::ffi::c_void,
+                document_text.len(),
+            );
+            let diagnostics: *const QLJSWebDemoDiagnostic = qljs_web_demo_lint(p);
+            String::from(read_utf8_c_string((*diagnostics.add(0)).message))
+        };
+        assert_eq!(lint_message(b"'unfinished"), "string literal never ends");
+        // Messages missing from the catalog use the locale.
+        assert_eq!(lint_message(b"`unfinished"), "`unclosed template");
+
+        // Changing the locale keeps the catalog.
+        qljs_web_demo_set_locale(p, c"".as_ptr());
+        assert_eq!(lint_message(b"'unfinished"), "string literal never ends");
+        assert_eq!(lint_message(b"`unfinished"), "unclosed template");
+
+        // A malformed catalog is rejected and leaves the old catalog in place.
+        let bad_catalog: &[u8] = b"msgid \"unclosed string literal\"\nmsgstr unquoted\n";
+        assert!(!qljs_web_demo_set_translation_catalog(
+            p,
+            bad_catalog.as_ptr() as *const std::ffi::c_void,
+            bad_catalog.len(),
+        ));
+        assert_eq!(lint_message(b"'unfinished"), "string literal never ends");
+
+        assert!(qljs_web_demo_set_translation_catalog(
+            p,
+            std::ptr::null(),
+            0
+        ));
+        assert_eq!(lint_message(b"'unfinished"), "unclosed string literal");
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
 #[test]
 fn locale_list() {
     unsafe {
@@ -151,9 +798,279 @@ fn locale_list() {
             expected_locale_strings.push(String::from(read_utf8_c_string_from_slice(l)));
             l = &l[(l.iter().position(|c: &u8| *c == b'\0').unwrap() + 1)..];
         }
+        expected_locale_strings.push(String::from(PSEUDO_LOCALE_NAME));
         expected_locale_strings.push(String::new());
         expected_locale_strings.sort();
 
         assert_eq!(locale_strings, expected_locale_strings);
     }
 }
+
+#[test]
+fn version() {
+    unsafe {
+        assert_eq!(
+            read_utf8_c_string(qljs_version() as *const u8),
+            env!("CARGO_PKG_VERSION")
+        );
+        assert_eq!(qljs_abi_version(), QLJS_ABI_VERSION);
+    }
+}
+
+unsafe fn diagnostic_messages_v2(diagnostics: *const QLJSWebDemoDiagnosticV2) -> Vec<String> {
+    let mut messages: Vec<String> = vec![];
+    let mut i: usize = 0;
+    while !(*diagnostics.add(i)).message.is_null() {
+        messages.push(String::from(read_utf8_c_string(
+            (*diagnostics.add(i)).message,
+        )));
+        i += 1;
+    }
+    messages
+}
+
+#[test]
+fn lint_batch_matches_lint_v2_of_each_document() {
+    let texts: Vec<String> = (0..20)
+        .map(|i: usize| "let x;\n".repeat(i) + if i % 2 == 1 { "'unfinished" } else { "" })
+        .collect();
+    unsafe {
+        let documents: Vec<*mut QLJSWebDemoDocument> = texts
+            .iter()
+            .map(|text: &String| {
+                let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+                qljs_web_demo_set_text(p, text.as_ptr() as *const std::ffi::c_void, text.len());
+                p
+            })
+            .collect();
+
+        let mut batch_results: Vec<*const QLJSWebDemoDiagnosticV2> =
+            vec![std::ptr::null(); documents.len()];
+        let status: QLJSStatus = qljs_web_demo_lint_batch(
+            documents.as_ptr(),
+            documents.len(),
+            4,
+            batch_results.as_mut_ptr(),
+        );
+        assert_eq!(status, QLJSStatus::Ok);
+        let batch_messages: Vec<Vec<String>> = batch_results
+            .iter()
+            .map(|diagnostics: &*const QLJSWebDemoDiagnosticV2| {
+                diagnostic_messages_v2(*diagnostics)
+            })
+            .collect();
+
+        for (i, p) in documents.iter().enumerate() {
+            let messages: Vec<String> = diagnostic_messages_v2(qljs_web_demo_lint_v2(*p));
+            assert_eq!(messages.len(), i % 2);
+            assert_eq!(batch_messages[i], messages, "document {i}");
+        }
+
+        for p in documents {
+            qljs_web_demo_destroy_document(p);
+        }
+    }
+}
+
+#[test]
+#[cfg(panic = "unwind")]
+fn lint_batch_reports_first_failed_document() {
+    unsafe {
+        let good: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let poisoned: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let text: &[u8] = b"'unfinished";
+        qljs_web_demo_set_text(good, text.as_ptr() as *const std::ffi::c_void, text.len());
+        assert_eq!(
+            set_text_failing_internally(poisoned),
+            QLJSStatus::InternalError
+        );
+
+        let documents: [*mut QLJSWebDemoDocument; 2] = [poisoned, good];
+        let mut results: [*const QLJSWebDemoDiagnosticV2; 2] = [std::ptr::null(); 2];
+        let status: QLJSStatus =
+            qljs_web_demo_lint_batch(documents.as_ptr(), 2, 2, results.as_mut_ptr());
+        assert_eq!(status, QLJSStatus::DocumentPoisoned);
+        assert_eq!(diagnostic_messages_v2(results[0]), Vec::<String>::new());
+        assert_eq!(
+            diagnostic_messages_v2(results[1]),
+            vec!["unclosed string literal".to_string()]
+        );
+
+        qljs_web_demo_destroy_document(good);
+        qljs_web_demo_destroy_document(poisoned);
+    }
+}
+
+#[test]
+fn lint_files_delivers_diagnostics_in_path_order() {
+    // (path_index, message, code, begin_offset)
+    type FileMessage = (usize, String, String, std::ffi::c_int);
+
+    unsafe extern "C" fn collect(
+        user_data: *mut std::ffi::c_void,
+        path_index: usize,
+        diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+    ) {
+        let messages: &mut Vec<FileMessage> = &mut *(user_data as *mut Vec<FileMessage>);
+        messages.push((
+            path_index,
+            String::from_utf8(
+                std::slice::from_raw_parts((*diagnostic).message, (*diagnostic).message_length)
+                    .to_vec(),
+            )
+            .unwrap(),
+            String::from(read_utf8_c_string_from_c_slice(&(*diagnostic).code)),
+            (*diagnostic).begin_offset,
+        ));
+    }
+
+    let dir: std::path::PathBuf = std::env::temp_dir().join(format!(
+        "cpp_vs_rust_c_api_test_c_api_lint_files_{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut paths: Vec<std::ffi::CString> = vec![];
+    for i in 0..10 {
+        let path: std::path::PathBuf = dir.join(format!("file{i}.js"));
+        std::fs::write(&path, "let x;\n".repeat(i) + "'unfinished").unwrap();
+        paths.push(std::ffi::CString::new(path.to_str().unwrap()).unwrap());
+    }
+    paths.insert(
+        3,
+        std::ffi::CString::new(dir.join("missing.js").to_str().unwrap()).unwrap(),
+    );
+    let path_pointers: Vec<*const std::ffi::c_char> = paths
+        .iter()
+        .map(|path: &std::ffi::CString| path.as_ptr())
+        .collect();
+
+    unsafe {
+        let settings: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        qljs_web_demo_set_locale(settings, c"en_US@snarky".as_ptr());
+        let mut messages: Vec<FileMessage> = vec![];
+        let status: QLJSStatus = qljs_web_demo_lint_files(
+            settings,
+            path_pointers.as_ptr(),
+            path_pointers.len(),
+            4,
+            collect,
+            &mut messages as *mut _ as *mut std::ffi::c_void,
+        );
+        assert_eq!(status, QLJSStatus::Ok);
+        qljs_web_demo_destroy_document(settings);
+
+        assert_eq!(messages.len(), paths.len());
+        for (path_index, message) in messages.iter().enumerate() {
+            assert_eq!(message.0, path_index);
+            if path_index == 3 {
+                assert_eq!(message.2, "");
+                assert!(message.1.contains("missing.js"), "{}", message.1);
+            } else {
+                let file_number: usize = if path_index < 3 {
+                    path_index
+                } else {
+                    path_index - 1
+                };
+                assert_eq!(message.2, "E0040");
+                assert_eq!(message.3 as usize, "let x;\n".len() * file_number);
+                assert_ne!(message.1, "unclosed string literal", "locale was ignored");
+            }
+        }
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+unsafe fn highlight_document(
+    p: *mut QLJSWebDemoDocument,
+) -> (
+    QLJSStatus,
+    Vec<(std::ffi::c_int, std::ffi::c_int, QLJSHighlightClass)>,
+) {
+    let mut tokens: *const QLJSWebDemoHighlightToken = std::ptr::null();
+    let mut token_count: usize = 0;
+    let status: QLJSStatus = qljs_web_demo_highlight(p, &mut tokens, &mut token_count);
+    if tokens.is_null() {
+        assert_eq!(token_count, 0);
+        return (status, vec![]);
+    }
+    let tokens: Vec<(std::ffi::c_int, std::ffi::c_int, QLJSHighlightClass)> =
+        std::slice::from_raw_parts(tokens, token_count)
+            .iter()
+            .map(|token: &QLJSWebDemoHighlightToken| {
+                (token.begin_offset, token.end_offset, token.highlight_class)
+            })
+            .collect();
+    (status, tokens)
+}
+
+#[test]
+fn highlight_uses_position_encoding_and_language_options() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        // U+1f496 has two UTF-16 code units and four UTF-8 code units.
+        let document_text: &[u8] = "'\u{01f496}'; <a>/x/".as_bytes();
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+
+        qljs_web_demo_set_language_options(p, 0);
+        assert_eq!(
+            highlight_document(p),
+            (
+                QLJSStatus::Ok,
+                vec![
+                    (0, 4, QLJSHighlightClass::String),
+                    (4, 5, QLJSHighlightClass::Punctuator),
+                    (6, 7, QLJSHighlightClass::Punctuator),
+                    (7, 8, QLJSHighlightClass::Identifier),
+                    (8, 9, QLJSHighlightClass::Punctuator),
+                    (9, 12, QLJSHighlightClass::Regexp),
+                ]
+            )
+        );
+
+        qljs_web_demo_set_position_encoding(p, QLJS_POSITION_ENCODING_UTF_8);
+        qljs_web_demo_set_language_options(p, QLJS_LANGUAGE_OPTIONS_JSX_BIT);
+        assert_eq!(
+            highlight_document(p),
+            (
+                QLJSStatus::Ok,
+                vec![
+                    (0, 6, QLJSHighlightClass::String),
+                    (6, 7, QLJSHighlightClass::Punctuator),
+                    (8, 9, QLJSHighlightClass::JSXTag),
+                    (9, 10, QLJSHighlightClass::JSXTag),
+                    (10, 11, QLJSHighlightClass::JSXTag),
+                    (11, 14, QLJSHighlightClass::JSXText),
+                ]
+            )
+        );
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
+
+#[test]
+#[cfg(panic = "unwind")]
+fn highlight_poisoned_document_returns_no_tokens() {
+    unsafe {
+        let p: *mut QLJSWebDemoDocument = qljs_web_demo_create_document();
+        let document_text: &[u8] = b"let x;";
+        qljs_web_demo_set_text(
+            p,
+            document_text.as_ptr() as *const std::ffi::c_void,
+            document_text.len(),
+        );
+        assert_eq!(highlight_document(p).1.len(), 3);
+
+        assert_eq!(set_text_failing_internally(p), QLJSStatus::InternalError);
+        assert_eq!(
+            highlight_document(p),
+            (QLJSStatus::DocumentPoisoned, vec![])
+        );
+
+        qljs_web_demo_destroy_document(p);
+    }
+}
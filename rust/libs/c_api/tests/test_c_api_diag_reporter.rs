@@ -0,0 +1,263 @@
+use cpp_vs_rust_c_api::c_api::*;
+use cpp_vs_rust_c_api::c_api_diag_reporter::*;
+use cpp_vs_rust_c_api::web_demo_location::*;
+use cpp_vs_rust_fe::diag_reporter::*;
+use cpp_vs_rust_fe::diagnostic_types::*;
+use cpp_vs_rust_fe::source_code_span::*;
+use cpp_vs_rust_util::c_string::*;
+use cpp_vs_rust_util::padded_string::*;
+
+fn span(code: &PaddedString, begin: usize, end: usize) -> SourceCodeSpan<'_> {
+    unsafe { SourceCodeSpan::new(code.c_str().add(begin), code.c_str().add(end)) }
+}
+
+#[test]
+fn notes_are_omitted_from_v1_diagnostics() {
+    let code = PaddedString::from_slice(b"hello world");
+    let mut reporter: CAPIDiagReporter = CAPIDiagReporter::new();
+    reporter.set_input(code.view());
+    report(
+        &reporter,
+        DiagMultipleMessageTest {
+            a: span(&code, 0, 5),
+            b: span(&code, 6, 11),
+        },
+    );
+
+    unsafe {
+        let diagnostics: *const QLJSWebDemoDiagnostic = reporter.get_diagnostics();
+        assert_eq!(
+            read_utf8_c_string((*diagnostics.add(0)).message),
+            "test for multiple messages"
+        );
+        assert_eq!((*diagnostics.add(0)).begin_offset, 0);
+        assert_eq!((*diagnostics.add(0)).end_offset, 5);
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+    }
+}
+
+#[test]
+fn notes_are_attached_to_their_v2_diagnostic() {
+    let code = PaddedString::from_slice(b"hello world #");
+    let mut reporter: CAPIDiagReporter = CAPIDiagReporter::new();
+    reporter.set_input(code.view());
+    report(
+        &reporter,
+        DiagMultipleMessageTest {
+            a: span(&code, 0, 5),
+            b: span(&code, 6, 11),
+        },
+    );
+    report(
+        &reporter,
+        DiagUnexpectedHashCharacter {
+            where_: span(&code, 12, 13),
+        },
+    );
+
+    unsafe {
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = reporter.get_diagnostics_v2();
+
+        let diag: &QLJSWebDemoDiagnosticV2 = &*diagnostics.add(0);
+        assert_eq!(
+            read_utf8_c_string(diag.message),
+            "test for multiple messages"
+        );
+        assert_eq!(read_utf8_c_string_from_c_slice(&diag.code), "E6969");
+        assert_eq!(diag.begin_offset, 0);
+        assert_eq!(diag.end_offset, 5);
+        assert_eq!(diag.note_count, 1);
+        let note: &QLJSWebDemoDiagnosticNote = &*diag.notes;
+        assert_eq!(read_utf8_c_string(note.message), "second message here");
+        assert_eq!(note.begin_offset, 6);
+        assert_eq!(note.end_offset, 11);
+
+        let diag: &QLJSWebDemoDiagnosticV2 = &*diagnostics.add(1);
+        assert_eq!(read_utf8_c_string(diag.message), "unexpected '#'");
+        assert_eq!(diag.note_count, 0);
+        assert_eq!(diag.notes, std::ptr::null());
+
+        let terminator: &QLJSWebDemoDiagnosticV2 = &*diagnostics.add(2);
+        assert_eq!(terminator.message, std::ptr::null());
+        assert_eq!(read_utf8_c_string_from_c_slice(&terminator.code), "");
+        assert_eq!(terminator.note_count, 0);
+    }
+}
+
+#[test]
+fn reset_forgets_notes() {
+    let code = PaddedString::from_slice(b"hello world");
+    let mut reporter: CAPIDiagReporter = CAPIDiagReporter::new();
+    reporter.set_input(code.view());
+    report(
+        &reporter,
+        DiagMultipleMessageTest {
+            a: span(&code, 0, 5),
+            b: span(&code, 6, 11),
+        },
+    );
+    reporter.get_diagnostics_v2();
+
+    reporter.reset();
+    report(
+        &reporter,
+        DiagUnexpectedHashCharacter {
+            where_: span(&code, 0, 1),
+        },
+    );
+    unsafe {
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = reporter.get_diagnostics_v2();
+        assert_eq!((*diagnostics.add(0)).note_count, 0);
+        assert_eq!((*diagnostics.add(1)).message, std::ptr::null());
+    }
+}
+
+#[test]
+fn reset_reuses_message_memory() {
+    let code = PaddedString::from_slice(b"#");
+    let mut reporter: CAPIDiagReporter = CAPIDiagReporter::new();
+    reporter.set_input(code.view());
+    let mut first_message: *const u8 = std::ptr::null();
+    for _ in 0..10_000 {
+        reporter.reset();
+        report(
+            &reporter,
+            DiagUnexpectedHashCharacter {
+                where_: span(&code, 0, 1),
+            },
+        );
+        let diagnostics: *const QLJSWebDemoDiagnostic = reporter.get_diagnostics();
+        let message: *const u8 = unsafe { (*diagnostics.add(0)).message };
+        if first_message.is_null() {
+            first_message = message;
+        }
+        assert_eq!(message, first_message);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct StreamedDiagnostic {
+    message: String,
+    code: String,
+    severity: std::ffi::c_int,
+    begin_offset: std::ffi::c_int,
+    end_offset: std::ffi::c_int,
+}
+
+unsafe extern "C" fn collect_streamed_diagnostic(
+    user_data: *mut std::ffi::c_void,
+    diagnostic: *const QLJSWebDemoStreamedDiagnostic,
+) {
+    let diagnostics: &mut Vec<StreamedDiagnostic> =
+        &mut *(user_data as *mut Vec<StreamedDiagnostic>);
+    let diagnostic: &QLJSWebDemoStreamedDiagnostic = &*diagnostic;
+    diagnostics.push(StreamedDiagnostic {
+        message: String::from_utf8(
+            std::slice::from_raw_parts(diagnostic.message, diagnostic.message_length).to_vec(),
+        )
+        .unwrap(),
+        code: String::from(read_utf8_c_string_from_c_slice(&diagnostic.code)),
+        severity: diagnostic.severity as std::ffi::c_int,
+        begin_offset: diagnostic.begin_offset,
+        end_offset: diagnostic.end_offset,
+    });
+}
+
+#[test]
+fn callback_receives_diagnostics_and_notes() {
+    let code = PaddedString::from_slice(b"hello world #");
+    let mut reporter: CAPIDiagReporter = CAPIDiagReporter::new();
+    reporter.set_input(code.view());
+    let mut streamed: Vec<StreamedDiagnostic> = vec![];
+    reporter.set_callback(
+        Some(collect_streamed_diagnostic),
+        &mut streamed as *mut Vec<StreamedDiagnostic> as *mut std::ffi::c_void,
+    );
+    report(
+        &reporter,
+        DiagMultipleMessageTest {
+            a: span(&code, 0, 5),
+            b: span(&code, 6, 11),
+        },
+    );
+    report(
+        &reporter,
+        DiagUnexpectedHashCharacter {
+            where_: span(&code, 12, 13),
+        },
+    );
+    reporter.set_callback(None, std::ptr::null_mut());
+
+    assert_eq!(
+        streamed,
+        vec![
+            StreamedDiagnostic {
+                message: String::from("test for multiple messages"),
+                code: String::from("E6969"),
+                severity: QLJSSeverity::Error as std::ffi::c_int,
+                begin_offset: 0,
+                end_offset: 5,
+            },
+            StreamedDiagnostic {
+                message: String::from("second message here"),
+                code: String::from("E6969"),
+                severity: QLJSSeverity::Note as std::ffi::c_int,
+                begin_offset: 6,
+                end_offset: 11,
+            },
+            StreamedDiagnostic {
+                message: String::from("unexpected '#'"),
+                code: String::from("E0052"),
+                severity: QLJSSeverity::Error as std::ffi::c_int,
+                begin_offset: 12,
+                end_offset: 13,
+            },
+        ]
+    );
+
+    // Streamed diagnostics are not kept.
+    unsafe {
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = reporter.get_diagnostics_v2();
+        assert_eq!((*diagnostics.add(0)).message, std::ptr::null());
+    }
+}
+
+#[test]
+fn v2_diagnostics_and_notes_have_lines_and_columns_in_position_encoding() {
+    // U+2603 has three UTF-8 code units and one code point.
+    let code = PaddedString::from_slice("\u{2603}a\nb\r\n#".as_bytes());
+    let mut reporter: CAPIDiagReporter = CAPIDiagReporter::new();
+    reporter.set_position_encoding(WebDemoPositionEncoding::UTF32);
+    reporter.set_input(code.view());
+    report(
+        &reporter,
+        DiagMultipleMessageTest {
+            a: span(&code, 3, 4),
+            b: span(&code, 5, 6),
+        },
+    );
+    report(
+        &reporter,
+        DiagUnexpectedHashCharacter {
+            where_: span(&code, 8, 9),
+        },
+    );
+
+    unsafe {
+        let diagnostics: *const QLJSWebDemoDiagnosticV2 = reporter.get_diagnostics_v2();
+
+        let diag: &QLJSWebDemoDiagnosticV2 = &*diagnostics.add(0);
+        assert_eq!((diag.begin_offset, diag.end_offset), (1, 2));
+        assert_eq!((diag.begin_line, diag.begin_column), (0, 1));
+        assert_eq!((diag.end_line, diag.end_column), (0, 2));
+        let note: &QLJSWebDemoDiagnosticNote = &*diag.notes;
+        assert_eq!((note.begin_offset, note.end_offset), (3, 4));
+        assert_eq!((note.begin_line, note.begin_column), (1, 0));
+        assert_eq!((note.end_line, note.end_column), (1, 1));
+
+        let diag: &QLJSWebDemoDiagnosticV2 = &*diagnostics.add(1);
+        assert_eq!((diag.begin_offset, diag.end_offset), (6, 7));
+        assert_eq!((diag.begin_line, diag.begin_column), (2, 0));
+        assert_eq!((diag.end_line, diag.end_column), (2, 1));
+    }
+}
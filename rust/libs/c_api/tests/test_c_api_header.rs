@@ -0,0 +1,263 @@
+// Check that include/quick-lint-js/c-api.h agrees with the Rust declarations
+// by compiling a C program against the header.
+//
+// The C compiler is $CC, or cc if CC is not set.
+
+use cpp_vs_rust_c_api::c_api::*;
+use cpp_vs_rust_port::qljs_offset_of;
+
+fn include_dir() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("include")
+}
+
+// Compiles and runs a C program which includes the C API header. Returns the
+// program's standard output.
+fn compile_and_run_c(test_name: &str, c_source: &str) -> String {
+    let dir: std::path::PathBuf = std::env::temp_dir().join(format!(
+        "cpp_vs_rust_c_api_test_c_api_header_{test_name}_{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let source_path: std::path::PathBuf = dir.join("test.c");
+    let exe_path: std::path::PathBuf = dir.join("test");
+    std::fs::write(&source_path, c_source).unwrap();
+
+    let cc: String = std::env::var("CC").unwrap_or_else(|_| String::from("cc"));
+    let compile: std::process::Output = std::process::Command::new(&cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-pedantic", "-Werror"])
+        .arg("-I")
+        .arg(include_dir())
+        .arg("-o")
+        .arg(&exe_path)
+        .arg(&source_path)
+        .output()
+        .unwrap_or_else(|error| panic!("could not run C compiler {cc}: {error}"));
+    assert!(
+        compile.status.success(),
+        "{cc} failed:\n{}",
+        String::from_utf8_lossy(&compile.stderr)
+    );
+
+    let run: std::process::Output = std::process::Command::new(&exe_path).output().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(run.status.success());
+    String::from_utf8(run.stdout).unwrap()
+}
+
+fn size_of_field<Struct, Field>(_get_field: fn(&Struct) -> &Field) -> usize {
+    std::mem::size_of::<Field>()
+}
+
+#[test]
+fn c_struct_layouts_match_rust() {
+    let output: String = compile_and_run_c(
+        "layouts",
+        r#"#include <quick-lint-js/c-api.h>
+#include <stdio.h>
+
+#define PRINT_SIZE(type) printf("sizeof(%s) = %zu\n", #type, sizeof(type))
+#define PRINT_OFFSET(type, field) \
+  printf("offsetof(%s, %s) = %zu, size %zu\n", #type, #field, \
+         offsetof(type, field), sizeof(((type*)0)->field))
+
+int main(void) {
+  PRINT_SIZE(qljs_severity);
+
+  PRINT_SIZE(qljs_web_demo_diagnostic);
+  PRINT_OFFSET(qljs_web_demo_diagnostic, message);
+  PRINT_OFFSET(qljs_web_demo_diagnostic, code);
+  PRINT_OFFSET(qljs_web_demo_diagnostic, severity);
+  PRINT_OFFSET(qljs_web_demo_diagnostic, begin_offset);
+  PRINT_OFFSET(qljs_web_demo_diagnostic, end_offset);
+
+  PRINT_SIZE(qljs_web_demo_diagnostic_note);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, message);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, begin_offset);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, end_offset);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, begin_line);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, begin_column);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, end_line);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_note, end_column);
+
+  PRINT_SIZE(qljs_web_demo_diagnostic_v2);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, message);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, code);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, severity);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, begin_offset);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, end_offset);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, notes);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, note_count);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, begin_line);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, begin_column);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, end_line);
+  PRINT_OFFSET(qljs_web_demo_diagnostic_v2, end_column);
+
+  PRINT_SIZE(qljs_web_demo_streamed_diagnostic);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, message);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, message_length);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, code);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, severity);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, begin_offset);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, end_offset);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, begin_line);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, begin_column);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, end_line);
+  PRINT_OFFSET(qljs_web_demo_streamed_diagnostic, end_column);
+
+  PRINT_SIZE(qljs_web_demo_text_edit);
+  PRINT_OFFSET(qljs_web_demo_text_edit, begin_offset);
+  PRINT_OFFSET(qljs_web_demo_text_edit, end_offset);
+  PRINT_OFFSET(qljs_web_demo_text_edit, replacement_text_utf_8);
+  PRINT_OFFSET(qljs_web_demo_text_edit, replacement_byte_count);
+
+  PRINT_SIZE(qljs_web_demo_highlight_token);
+  PRINT_OFFSET(qljs_web_demo_highlight_token, begin_offset);
+  PRINT_OFFSET(qljs_web_demo_highlight_token, end_offset);
+  PRINT_OFFSET(qljs_web_demo_highlight_token, highlight_class);
+  return 0;
+}
+"#,
+    );
+
+    let mut expected: String = String::new();
+    macro_rules! expect_size {
+        ($c_type:literal, $type:ty) => {
+            expected.push_str(&format!(
+                "sizeof({}) = {}\n",
+                $c_type,
+                std::mem::size_of::<$type>()
+            ));
+        };
+    }
+    macro_rules! expect_layout {
+        ($c_type:literal, $type:ty, [$($field:ident),* $(,)?]) => {
+            expect_size!($c_type, $type);
+            $(
+                expected.push_str(&format!(
+                    "offsetof({}, {}) = {}, size {}\n",
+                    $c_type,
+                    stringify!($field),
+                    qljs_offset_of!($type, $field),
+                    size_of_field(|x: &$type| &x.$field)
+                ));
+            )*
+        };
+    }
+    expect_size!("qljs_severity", QLJSSeverity);
+    expect_layout!(
+        "qljs_web_demo_diagnostic",
+        QLJSWebDemoDiagnostic,
+        [message, code, severity, begin_offset, end_offset]
+    );
+    expect_layout!(
+        "qljs_web_demo_diagnostic_note",
+        QLJSWebDemoDiagnosticNote,
+        [
+            message,
+            begin_offset,
+            end_offset,
+            begin_line,
+            begin_column,
+            end_line,
+            end_column
+        ]
+    );
+    expect_layout!(
+        "qljs_web_demo_diagnostic_v2",
+        QLJSWebDemoDiagnosticV2,
+        [
+            message,
+            code,
+            severity,
+            begin_offset,
+            end_offset,
+            notes,
+            note_count,
+            begin_line,
+            begin_column,
+            end_line,
+            end_column
+        ]
+    );
+    expect_layout!(
+        "qljs_web_demo_streamed_diagnostic",
+        QLJSWebDemoStreamedDiagnostic,
+        [
+            message,
+            message_length,
+            code,
+            severity,
+            begin_offset,
+            end_offset,
+            begin_line,
+            begin_column,
+            end_line,
+            end_column
+        ]
+    );
+    expect_layout!(
+        "qljs_web_demo_text_edit",
+        QLJSWebDemoTextEdit,
+        [
+            begin_offset,
+            end_offset,
+            replacement_text_utf_8,
+            replacement_byte_count
+        ]
+    );
+    expect_layout!(
+        "qljs_web_demo_highlight_token",
+        QLJSWebDemoHighlightToken,
+        [begin_offset, end_offset, highlight_class]
+    );
+
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn c_constants_match_rust() {
+    let output: String = compile_and_run_c(
+        "constants",
+        r#"#include <quick-lint-js/c-api.h>
+#include <stdio.h>
+
+int main(void) {
+  printf("%u\n", (unsigned)QLJS_ABI_VERSION);
+  printf("%d %d %d\n", qljs_severity_error, qljs_severity_warning, qljs_severity_note);
+  printf("%d %d %d\n", qljs_language_options_jsx_bit,
+         qljs_language_options_typescript_bit,
+         qljs_language_options_config_json_bit);
+  printf("%d %d %d %d\n", qljs_status_ok, qljs_status_internal_error,
+         qljs_status_document_poisoned, qljs_status_invalid_argument);
+  printf("%d %d %d\n", qljs_position_encoding_utf_8,
+         qljs_position_encoding_utf_16, qljs_position_encoding_utf_32);
+  printf("%d %d %d\n", qljs_highlight_class_keyword,
+         qljs_highlight_class_jsx_tag, qljs_highlight_class_jsx_text);
+  return 0;
+}
+"#,
+    );
+    assert_eq!(
+        output,
+        format!(
+            "{}\n{} {} {}\n{} {} {}\n{} {} {} {}\n{} {} {}\n{} {} {}\n",
+            QLJS_ABI_VERSION,
+            QLJSSeverity::Error as i32,
+            QLJSSeverity::Warning as i32,
+            QLJSSeverity::Note as i32,
+            QLJS_LANGUAGE_OPTIONS_JSX_BIT,
+            QLJS_LANGUAGE_OPTIONS_TYPESCRIPT_BIT,
+            QLJS_LANGUAGE_OPTIONS_CONFIG_JSON_BIT,
+            QLJSStatus::Ok as i32,
+            QLJSStatus::InternalError as i32,
+            QLJSStatus::DocumentPoisoned as i32,
+            QLJSStatus::InvalidArgument as i32,
+            QLJS_POSITION_ENCODING_UTF_8,
+            QLJS_POSITION_ENCODING_UTF_16,
+            QLJS_POSITION_ENCODING_UTF_32,
+            QLJSHighlightClass::Keyword as i32,
+            QLJSHighlightClass::JSXTag as i32,
+            QLJSHighlightClass::JSXText as i32,
+        )
+    );
+}
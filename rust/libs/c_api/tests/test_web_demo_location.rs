@@ -101,6 +101,168 @@ fn position_after_wide_multi_byte_character() {
     assert_eq!(l.position(x), 3);
 }
 
+#[test]
+fn from_position_is_inverse_of_position() {
+    let code: PaddedString =
+        PaddedString::from_slice("a\u{2603}b\n\u{01f496}c\r\nd\u{0100}".as_bytes());
+    let l: WebDemoLocator = WebDemoLocator::new(code.view());
+    for c in [
+        "a",
+        "\u{2603}",
+        "b",
+        "\n",
+        "\u{01f496}",
+        "c",
+        "\r",
+        "d",
+        "\u{0100}",
+    ] {
+        let position: usize = std::str::from_utf8(code.view().slice())
+            .unwrap()
+            .find(c)
+            .unwrap();
+        let p: *const u8 = unsafe { code.c_str().add(position) };
+        assert_eq!(l.from_position(l.position(p)), p, "{c:?}");
+    }
+    let end: *const u8 = unsafe { code.c_str().add(code.len() as usize) };
+    assert_eq!(l.from_position(l.position(end)), end);
+}
+
+#[test]
+fn from_position_in_middle_of_surrogate_pair_is_start_of_character() {
+    // U+1f496 has two UTF-16 code units: D83D DC96
+    let code: PaddedString = PaddedString::from_slice("x\u{01f496}y".as_bytes());
+    let l: WebDemoLocator = WebDemoLocator::new(code.view());
+    assert_eq!(l.from_position(2), unsafe { code.c_str().add(1) });
+    assert_eq!(l.from_position(3), unsafe { code.c_str().add(5) });
+}
+
+#[test]
+fn from_position_after_end_is_end() {
+    let code: PaddedString = PaddedString::from_slice(b"hello");
+    let l: WebDemoLocator = WebDemoLocator::new(code.view());
+    let end: *const u8 = unsafe { code.c_str().add(5) };
+    assert_eq!(l.from_position(5), end);
+    assert_eq!(l.from_position(6), end);
+    assert_eq!(l.from_position(1000), end);
+}
+
+#[test]
+fn from_position_counts_invalid_utf_8_bytes_individually() {
+    let code: PaddedString = PaddedString::from_slice(b"a\xff\xfeb");
+    let l: WebDemoLocator = WebDemoLocator::new(code.view());
+    let b: *const u8 = strchr(code.view(), b'b');
+    assert_eq!(l.position(b), 3);
+    assert_eq!(l.from_position(3), b);
+}
+
+#[test]
+fn positions_in_each_encoding() {
+    // U+2603 has three UTF-8 code units, one UTF-16 code unit, and one code point.
+    // U+1f496 has four UTF-8 code units, two UTF-16 code units, and one code
+    // point.
+    let code: PaddedString = PaddedString::from_slice("\u{2603}\u{01f496}x".as_bytes());
+    let x: *const u8 = strchr(code.view(), b'x');
+    for (encoding, expected_x_position) in [
+        (WebDemoPositionEncoding::UTF8, 7),
+        (WebDemoPositionEncoding::UTF16, 3),
+        (WebDemoPositionEncoding::UTF32, 2),
+    ] {
+        let l: WebDemoLocator = WebDemoLocator::new_with_encoding(code.view(), encoding);
+        assert_eq!(l.position(x), expected_x_position, "{encoding:?}");
+        assert_eq!(l.from_position(expected_x_position), x, "{encoding:?}");
+    }
+}
+
+#[test]
+fn invalid_utf_8_bytes_count_as_one_unit_in_each_encoding() {
+    let code: PaddedString = PaddedString::from_slice(b"a\xff\xf0\x9f\x92b");
+    let b: *const u8 = strchr(code.view(), b'b');
+    for encoding in [
+        WebDemoPositionEncoding::UTF8,
+        WebDemoPositionEncoding::UTF16,
+        WebDemoPositionEncoding::UTF32,
+    ] {
+        let l: WebDemoLocator = WebDemoLocator::new_with_encoding(code.view(), encoding);
+        assert_eq!(l.position(b), 5, "{encoding:?}");
+        assert_eq!(l.from_position(5), b, "{encoding:?}");
+    }
+}
+
+#[test]
+fn utf_8_from_position_after_end_is_end() {
+    let code: PaddedString = PaddedString::from_slice("\u{2603}".as_bytes());
+    let l: WebDemoLocator =
+        WebDemoLocator::new_with_encoding(code.view(), WebDemoPositionEncoding::UTF8);
+    let end: *const u8 = unsafe { code.c_str().add(3) };
+    assert_eq!(l.from_position(3), end);
+    assert_eq!(l.from_position(4), end);
+}
+
+#[test]
+fn line_column_after_each_line_terminator() {
+    for line_terminator in ["\n", "\r", "\r\n"] {
+        let code: PaddedString =
+            PaddedString::from_slice(format!("ab{line_terminator}cd{line_terminator}").as_bytes());
+        let d: *const u8 = strchr(code.view(), b'd');
+        let l: WebDemoLocator = WebDemoLocator::new(code.view());
+        assert_eq!(
+            l.line_column(code.c_str()),
+            WebDemoLineColumn { line: 0, column: 0 },
+            "{line_terminator:?}"
+        );
+        assert_eq!(
+            l.line_column(d),
+            WebDemoLineColumn { line: 1, column: 1 },
+            "{line_terminator:?}"
+        );
+        let end: *const u8 = unsafe { code.c_str().add(code.len() as usize) };
+        assert_eq!(
+            l.line_column(end),
+            WebDemoLineColumn { line: 2, column: 0 },
+            "{line_terminator:?}"
+        );
+    }
+}
+
+#[test]
+fn lf_cr_is_two_lines() {
+    let code: PaddedString = PaddedString::from_slice(b"a\n\rb");
+    let b: *const u8 = strchr(code.view(), b'b');
+    let l: WebDemoLocator = WebDemoLocator::new(code.view());
+    assert_eq!(l.line_column(b), WebDemoLineColumn { line: 2, column: 0 });
+}
+
+#[test]
+fn line_separator_does_not_end_line() {
+    let code: PaddedString = PaddedString::from_slice("a\u{2028}b".as_bytes());
+    let b: *const u8 = strchr(code.view(), b'b');
+    let l: WebDemoLocator = WebDemoLocator::new(code.view());
+    assert_eq!(l.line_column(b), WebDemoLineColumn { line: 0, column: 2 });
+}
+
+#[test]
+fn column_counts_units_of_encoding() {
+    // U+1f496 (f0 9f 92 96), then an invalid byte.
+    let code: PaddedString = PaddedString::from_slice(b"x\n\xf0\x9f\x92\x96\xffy");
+    let y: *const u8 = strchr(code.view(), b'y');
+    for (encoding, expected_column) in [
+        (WebDemoPositionEncoding::UTF8, 5),
+        (WebDemoPositionEncoding::UTF16, 3),
+        (WebDemoPositionEncoding::UTF32, 2),
+    ] {
+        let l: WebDemoLocator = WebDemoLocator::new_with_encoding(code.view(), encoding);
+        assert_eq!(
+            l.line_column(y),
+            WebDemoLineColumn {
+                line: 1,
+                column: expected_column
+            },
+            "{encoding:?}"
+        );
+    }
+}
+
 fn strchr(haystack: PaddedStringView, needle: u8) -> *const u8 {
     let position: Option<usize> = haystack.slice().iter().position(|c: &u8| *c == needle);
     unsafe { haystack.c_str().add(position.unwrap()) }
@@ -0,0 +1,209 @@
+// Compares classifying non-ASCII identifier characters with the UTF-8 trie
+// (look_up_identifier_character_utf_8) against decoding each character and
+// looking up its code point (decode_utf_8 followed by
+// is_initial_identifier_character and is_identifier_character). Also reports
+// the throughput of the whole lexer on each corpus.
+//
+// Usage: cargo bench -p cpp_vs_rust_fe --bench lex_unicode
+
+use cpp_vs_rust_fe::diag_reporter::*;
+use cpp_vs_rust_fe::lex::*;
+use cpp_vs_rust_fe::token::*;
+use cpp_vs_rust_util::padded_string::*;
+use cpp_vs_rust_util::utf_8::*;
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+const ITERATIONS: usize = 20;
+const CORPUS_SIZE: usize = 1 << 20;
+
+struct Corpus {
+    name: &'static str,
+    // Characters used to build identifiers.
+    identifier_characters: &'static [char],
+    // Text used in comments between statements.
+    comment: &'static str,
+}
+
+const CORPORA: &[Corpus] = &[
+    Corpus {
+        name: "CJK",
+        identifier_characters: &[
+            '\u{5909}', '\u{6570}', '\u{5024}', '\u{95a2}', '\u{6570}', '\u{914d}', '\u{5217}',
+            '\u{6587}', '\u{5b57}', '\u{5217}', '\u{756a}', '\u{53f7}', '\u{3042}', '\u{30a2}',
+            '\u{d55c}', '\u{ad6d}',
+        ],
+        comment: "\u{3053}\u{308c}\u{306f}\u{30b3}\u{30e1}\u{30f3}\u{30c8}\u{3067}\u{3059}\u{3002}",
+    },
+    Corpus {
+        name: "Cyrillic and Greek",
+        identifier_characters: &[
+            '\u{437}', '\u{43d}', '\u{430}', '\u{447}', '\u{435}', '\u{43d}', '\u{438}', '\u{435}',
+            '\u{3c4}', '\u{3b9}', '\u{3bc}', '\u{3ae}', '\u{3bb}', '\u{3b1}', '\u{3b2}', '\u{3b3}',
+        ],
+        comment: "\u{43a}\u{43e}\u{43c}\u{43c}\u{435}\u{43d}\u{442}\u{430}\u{440}\u{438}\u{439}",
+    },
+    Corpus {
+        name: "supplementary planes",
+        identifier_characters: &[
+            '\u{1d465}',
+            '\u{1d466}',
+            '\u{1d467}',
+            '\u{1d44e}',
+            '\u{20000}',
+            '\u{20001}',
+            '\u{2a6d6}',
+            '\u{10400}',
+            '\u{10401}',
+            '\u{1e900}',
+            '\u{16a40}',
+            '\u{1d7ce}',
+        ],
+        comment: "\u{1f600}\u{1f680}\u{20000}\u{20001}",
+    },
+];
+
+// Generates JavaScript like:
+//
+//   let 変数値 = 関数(配列, 文字列); // これはコメントです。
+fn make_source(corpus: &Corpus) -> String {
+    let mut source: String = String::new();
+    let mut next_character: usize = 0;
+    let mut identifier = |length: usize| -> String {
+        let mut identifier: String = String::new();
+        for _ in 0..length {
+            identifier.push(
+                corpus.identifier_characters[next_character % corpus.identifier_characters.len()],
+            );
+            next_character += 1;
+        }
+        identifier
+    };
+    while source.len() < CORPUS_SIZE {
+        let line: String = format!(
+            "let {} = {}({}, {}); // {}\n",
+            identifier(3),
+            identifier(2),
+            identifier(4),
+            identifier(3),
+            corpus.comment,
+        );
+        source.push_str(&line);
+    }
+    source
+}
+
+fn time(mut f: impl FnMut()) -> Duration {
+    // Warm up.
+    f();
+    let mut best: Duration = Duration::MAX;
+    for _ in 0..ITERATIONS {
+        let start: Instant = Instant::now();
+        f();
+        best = best.min(start.elapsed());
+    }
+    best
+}
+
+fn report(corpus: &Corpus, name: &str, bytes: usize, duration: Duration) {
+    println!(
+        "{:<24} {:<24} {:>8.3} ms {:>9.1} MiB/s",
+        corpus.name,
+        name,
+        duration.as_secs_f64() * 1e3,
+        (bytes as f64) / (1024.0 * 1024.0) / duration.as_secs_f64(),
+    );
+}
+
+// Returns the number of identifier start and identifier part characters.
+fn classify_with_code_point_table(text: PaddedStringView) -> (usize, usize) {
+    let mut counts: (usize, usize) = (0, 0);
+    let mut offset: PaddedStringSizeType = 0;
+    while offset < text.len() {
+        let character: DecodeUTF8Result = decode_utf_8(text.substr(offset));
+        if let Some(code_point) = character.code_point {
+            counts.0 += is_initial_identifier_character(code_point as u32) as usize;
+            counts.1 +=
+                is_identifier_character(code_point as u32, IdentifierKind::JavaScript) as usize;
+        }
+        offset += character.size as PaddedStringSizeType;
+    }
+    counts
+}
+
+// Returns the number of identifier start and identifier part characters.
+fn classify_with_utf_8_trie(text: PaddedStringView) -> (usize, usize) {
+    let mut counts: (usize, usize) = (0, 0);
+    let mut offset: PaddedStringSizeType = 0;
+    while offset < text.len() {
+        match look_up_identifier_character_utf_8(text.substr(offset)) {
+            Some(character) => {
+                counts.0 += character.is_initial_identifier_character as usize;
+                counts.1 += character.is_identifier_character as usize;
+                offset += character.size as PaddedStringSizeType;
+            }
+            None => {
+                // ASCII or invalid UTF-8.
+                offset += 1;
+            }
+        }
+    }
+    counts
+}
+
+fn lex(text: PaddedStringView) -> usize {
+    let allocator: LexerAllocator = LexerAllocator::new();
+    let mut lexer: Lexer = Lexer::new(text, null_diag_reporter(), &allocator);
+    let mut token_count: usize = 0;
+    while lexer.peek().type_ != TokenType::EndOfFile {
+        token_count += 1;
+        lexer.skip();
+    }
+    token_count
+}
+
+fn main() {
+    for corpus in CORPORA {
+        let source: PaddedString = PaddedString::from_slice(make_source(corpus).as_bytes());
+        // Only non-ASCII characters are interesting. Strip ASCII so the
+        // benchmarks below measure lookups, not loop overhead.
+        let non_ascii: PaddedString = PaddedString::from_slice(
+            &source
+                .as_slice()
+                .iter()
+                .copied()
+                .filter(|&byte| byte >= 0x80)
+                .collect::<Vec<u8>>(),
+        );
+
+        assert_eq!(
+            classify_with_code_point_table(non_ascii.view()),
+            classify_with_utf_8_trie(non_ascii.view()),
+        );
+        report(
+            corpus,
+            "decode + code point table",
+            non_ascii.len() as usize,
+            time(|| {
+                black_box(classify_with_code_point_table(black_box(non_ascii.view())));
+            }),
+        );
+        report(
+            corpus,
+            "UTF-8 trie",
+            non_ascii.len() as usize,
+            time(|| {
+                black_box(classify_with_utf_8_trie(black_box(non_ascii.view())));
+            }),
+        );
+        report(
+            corpus,
+            "lexer",
+            source.len() as usize,
+            time(|| {
+                black_box(lex(black_box(source.view())));
+            }),
+        );
+    }
+}
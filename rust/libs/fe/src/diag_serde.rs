@@ -0,0 +1,202 @@
+use crate::language::*;
+use crate::source_code_span::*;
+use cpp_vs_rust_port::allocator::*;
+use cpp_vs_rust_util::narrow_cast::*;
+use cpp_vs_rust_util::padded_string::*;
+
+// Diagnostics point into the code they were reported for, so they cannot be
+// serialized on their own. A serialized diagnostic instead refers to code with
+// byte offsets relative to the input, and has copies of strings which are not
+// part of the input (such as DiagConfusableIdentifier::skeleton).
+//
+// To serialize a diagnostic, wrap it in a DiagSerializer:
+//
+//   serde_json::to_string(&DiagSerializer::new(&diag, input))
+//
+// To deserialize a diagnostic, give a DiagDeserializer (a DeserializeSeed) the
+// same input:
+//
+//   DiagDeserializer::<AnyDiag>::new(input, &allocator).deserialize(&mut deserializer)
+//
+// The qljs_diagnostic attribute implements DiagSerde for each diagnostic
+// struct. AnyDiag also implements DiagSerde.
+pub trait DiagSerde<'code>: Sized {
+    type Serde: serde::Serialize + serde::de::DeserializeOwned;
+
+    fn to_serde(&self, input: PaddedStringView<'code>) -> Result<Self::Serde, DiagSerdeError>;
+
+    // Strings which are not part of input are copied into allocator.
+    fn from_serde(
+        value: Self::Serde,
+        input: PaddedStringView<'code>,
+        allocator: &'code dyn Allocator,
+    ) -> Result<Self, DiagSerdeError>;
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SourceCodeSpanOffsets {
+    pub begin: PaddedStringSizeType,
+    pub end: PaddedStringSizeType,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DiagSerdeError {
+    // A diagnostic's span does not point into the input given to
+    // DiagSerializer.
+    SpanOutsideInput,
+    // A deserialized span is backwards or goes past the end of the input
+    // given to DiagDeserializer.
+    InvalidSpan(SourceCodeSpanOffsets),
+}
+
+impl std::fmt::Display for DiagSerdeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            DiagSerdeError::SpanOutsideInput => write!(f, "span is outside the input"),
+            DiagSerdeError::InvalidSpan(offsets) => write!(
+                f,
+                "invalid span: {}..{} (input might be different from the input which was serialized)",
+                offsets.begin, offsets.end
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DiagSerdeError {}
+
+// Serializes a diagnostic (or anything else implementing DiagSerde) with spans
+// relative to input.
+pub struct DiagSerializer<'diag, 'code, Diag: DiagSerde<'code>> {
+    diag: &'diag Diag,
+    input: PaddedStringView<'code>,
+}
+
+impl<'diag, 'code, Diag: DiagSerde<'code>> DiagSerializer<'diag, 'code, Diag> {
+    pub fn new(diag: &'diag Diag, input: PaddedStringView<'code>) -> Self {
+        DiagSerializer { diag, input }
+    }
+}
+
+impl<'diag, 'code, Diag: DiagSerde<'code>> serde::Serialize for DiagSerializer<'diag, 'code, Diag> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value: Diag::Serde = self
+            .diag
+            .to_serde(self.input)
+            .map_err(serde::ser::Error::custom)?;
+        serde::Serialize::serialize(&value, serializer)
+    }
+}
+
+// Deserializes a diagnostic (or anything else implementing DiagSerde) which was
+// serialized by a DiagSerializer with the same input.
+pub struct DiagDeserializer<'code, Diag: DiagSerde<'code>> {
+    input: PaddedStringView<'code>,
+    allocator: &'code dyn Allocator,
+    phantom: std::marker::PhantomData<Diag>,
+}
+
+impl<'code, Diag: DiagSerde<'code>> DiagDeserializer<'code, Diag> {
+    pub fn new(input: PaddedStringView<'code>, allocator: &'code dyn Allocator) -> Self {
+        DiagDeserializer {
+            input,
+            allocator,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, 'code, Diag: DiagSerde<'code>> serde::de::DeserializeSeed<'de>
+    for DiagDeserializer<'code, Diag>
+{
+    type Value = Diag;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Diag, D::Error> {
+        let value: Diag::Serde = serde::Deserialize::deserialize(deserializer)?;
+        Diag::from_serde(value, self.input, self.allocator).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<'code> DiagSerde<'code> for SourceCodeSpan<'code> {
+    type Serde = SourceCodeSpanOffsets;
+
+    fn to_serde(
+        &self,
+        input: PaddedStringView<'code>,
+    ) -> Result<SourceCodeSpanOffsets, DiagSerdeError> {
+        // Compare addresses instead of using offset_from because the span
+        // might not point into input.
+        let offset = |c: *const u8| -> Option<PaddedStringSizeType> {
+            let offset: usize = (c as usize).checked_sub(input.c_str() as usize)?;
+            if offset > narrow_cast::<usize, _>(input.len()) {
+                return None;
+            }
+            Some(narrow_cast(offset))
+        };
+        match (offset(self.begin_ptr()), offset(self.end_ptr())) {
+            (Some(begin), Some(end)) => Ok(SourceCodeSpanOffsets { begin, end }),
+            _ => Err(DiagSerdeError::SpanOutsideInput),
+        }
+    }
+
+    fn from_serde(
+        value: SourceCodeSpanOffsets,
+        input: PaddedStringView<'code>,
+        _allocator: &'code dyn Allocator,
+    ) -> Result<SourceCodeSpan<'code>, DiagSerdeError> {
+        if !(0 <= value.begin && value.begin <= value.end && value.end <= input.len()) {
+            return Err(DiagSerdeError::InvalidSpan(value));
+        }
+        Ok(SourceCodeSpan::from_slice(
+            &input.slice()[narrow_cast::<usize, _>(value.begin)..narrow_cast(value.end)],
+        ))
+    }
+}
+
+impl<'code> DiagSerde<'code> for &'code [u8] {
+    type Serde = Vec<u8>;
+
+    fn to_serde(&self, _input: PaddedStringView<'code>) -> Result<Vec<u8>, DiagSerdeError> {
+        Ok(self.to_vec())
+    }
+
+    fn from_serde(
+        value: Vec<u8>,
+        _input: PaddedStringView<'code>,
+        allocator: &'code dyn Allocator,
+    ) -> Result<&'code [u8], DiagSerdeError> {
+        let layout: std::alloc::Layout = std::alloc::Layout::array::<u8>(value.len()).unwrap();
+        let memory: std::ptr::NonNull<[u8]> = allocator
+            .allocate(layout)
+            .unwrap_or_else(|_| std::alloc::handle_alloc_error(layout));
+        unsafe {
+            let data: *mut u8 = memory.as_ptr() as *mut u8;
+            std::ptr::copy_nonoverlapping(value.as_ptr(), data, value.len());
+            Ok(std::slice::from_raw_parts(data, value.len()))
+        }
+    }
+}
+
+// Fields which do not refer to code are serialized as-is.
+macro_rules! impl_diag_serde_for_plain_types {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl<'code> DiagSerde<'code> for $type {
+                type Serde = $type;
+
+                fn to_serde(&self, _input: PaddedStringView<'code>) -> Result<$type, DiagSerdeError> {
+                    Ok(*self)
+                }
+
+                fn from_serde(
+                    value: $type,
+                    _input: PaddedStringView<'code>,
+                    _allocator: &'code dyn Allocator,
+                ) -> Result<$type, DiagSerdeError> {
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_diag_serde_for_plain_types!(u8, usize, EnumKind, StatementKind, VariableKind);
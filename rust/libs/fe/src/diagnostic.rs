@@ -3,6 +3,7 @@ use cpp_vs_rust_i18n::translation::*;
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DiagnosticSeverity {
     Error,
     Note,
@@ -18,6 +19,7 @@ pub enum DiagnosticArgType {
     Char8,
     EnumKind,
     Identifier,
+    Integer,
     SourceCodeSpan,
     StatementKind,
     String8View,
@@ -34,15 +36,15 @@ const DIAGNOSTIC_MAX_ARG_COUNT: usize = 3;
 //
 // For example, if DIAGNOSTIC_MESSAGE_ARG_OFFSET_SHIFT is 3, then an arg must be 8-byte aligned.
 const DIAGNOSTIC_MESSAGE_ARG_OFFSET_SHIFT: u8 = 1;
-const DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS: u8 = 5;
+const DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS: u8 = 8;
 
 #[repr(C)]
 pub struct DiagnosticMessageArgInfo {
     // C++ equivalent:
     //
-    // std::uint8_t compact_offset : offset_bits;
-    // diagnostic_arg_type type : (8 - offset_bits);
-    data: u8,
+    // std::uint16_t compact_offset : offset_bits;
+    // diagnostic_arg_type type : (16 - offset_bits);
+    data: u16,
 }
 
 impl DiagnosticMessageArgInfo {
@@ -51,7 +53,7 @@ impl DiagnosticMessageArgInfo {
     }
 
     pub const fn new(offset: usize, type_: DiagnosticArgType) -> DiagnosticMessageArgInfo {
-        let offset = offset as u8;
+        let offset = offset as u16;
         assert!(
             (offset >> DIAGNOSTIC_MESSAGE_ARG_OFFSET_SHIFT)
                 < (1 << DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS),
@@ -63,17 +65,17 @@ impl DiagnosticMessageArgInfo {
         );
         DiagnosticMessageArgInfo {
             data: (offset >> DIAGNOSTIC_MESSAGE_ARG_OFFSET_SHIFT)
-                | ((type_ as u8) << DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS),
+                | ((type_ as u16) << DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS),
         }
     }
 
     pub const fn offset(&self) -> usize {
-        const MASK: u8 = (1 << DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS) - 1;
+        const MASK: u16 = (1 << DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS) - 1;
         ((self.data & MASK) << DIAGNOSTIC_MESSAGE_ARG_OFFSET_SHIFT) as usize
     }
 
     pub const fn type_(&self) -> DiagnosticArgType {
-        unsafe { std::mem::transmute(self.data >> DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS) }
+        unsafe { std::mem::transmute((self.data >> DIAGNOSTIC_MESSAGE_ARG_OFFSET_BITS) as u8) }
     }
 }
 
@@ -4,8 +4,9 @@ use crate::language::*;
 use crate::source_code_span::*;
 use cpp_vs_rust_i18n::qljs_translatable;
 use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_message_format::*;
 use cpp_vs_rust_util::narrow_cast::*;
-use cpp_vs_rust_util::qljs_assert;
+use std::borrow::Cow;
 
 pub trait DiagnosticFormatter {
     fn write_before_message(
@@ -57,50 +58,40 @@ pub trait DiagnosticFormatter {
             unsafe { get_argument_source_code_span(args, diagnostic, 0) };
         self.write_before_message(code, severity, origin_span);
 
-        let mut remaining_message: &str = self.translator().translate(message_format);
-        loop {
-            let Some((before_left_curly, after_left_curly))
-                = remaining_message.split_once('{') else {break;};
-            qljs_assert!(
-                !after_left_curly.is_empty(),
-                "invalid message format: { at end of string has no matching }"
-            );
-
-            if after_left_curly.as_bytes()[0] == b'{' {
-                // "{{"; the '{' is escaped.
-                let before_and_including_left_curly: &str =
-                    &remaining_message[0..(before_left_curly.len() + 1)];
-                self.write_message_part(code, severity, before_and_including_left_curly.as_bytes());
-                remaining_message = &after_left_curly[1..];
-                continue;
-            }
-
-            self.write_message_part(code, severity, before_left_curly.as_bytes());
-
-            let Some((curly_content, after_right_curly))
-                = after_left_curly.split_once('}') else {
-                    panic!("invalid message format: missing }}"); };
-
-            let expanded_parameter: &[u8] = unsafe {
-                if curly_content == "0" {
-                    expand_argument(args, diagnostic, 0)
-                } else if curly_content == "1" {
-                    expand_argument(args, diagnostic, 1)
-                } else if curly_content == "1:headlinese" {
-                    expand_argument_headlinese(self.translator(), args, diagnostic, 1)
-                } else if curly_content == "1:singular" {
-                    expand_argument_singular(self.translator(), args, diagnostic, 1)
-                } else if curly_content == "2" {
-                    expand_argument(args, diagnostic, 2)
-                } else {
-                    panic!("invalid message format: unrecognized placeholder");
+        let translator: Translator = self.translator();
+        let (message, plural_forms) = translator.translate_with_plural_forms(message_format);
+        for part in parse_message_format(message) {
+            let placeholder: Placeholder = match part {
+                Ok(MessageFormatPart::Text(text)) => {
+                    self.write_message_part(code, severity, text.as_bytes());
+                    continue;
                 }
+                Ok(MessageFormatPart::Placeholder(placeholder)) => placeholder,
+                Err(error) => panic!("invalid message format: {error}"),
             };
-
-            self.write_message_part(code, severity, expanded_parameter);
-            remaining_message = after_right_curly;
+            let arg_index: i32 = narrow_cast(placeholder.arg_index);
+            let expanded_parameter: Cow<[u8]> = unsafe {
+                match placeholder.form {
+                    PlaceholderForm::Plain => expand_argument(args, diagnostic, arg_index),
+                    PlaceholderForm::Headlinese => {
+                        expand_argument_headlinese(&translator, args, diagnostic, arg_index)
+                    }
+                    PlaceholderForm::Singular => {
+                        expand_argument_singular(&translator, args, diagnostic, arg_index)
+                    }
+                    PlaceholderForm::Plural(_) => {
+                        let n: usize = get_argument_integer(args, diagnostic, arg_index);
+                        Cow::Borrowed(
+                            placeholder
+                                .form
+                                .plural_form(plural_forms.select(n as u64))
+                                .as_bytes(),
+                        )
+                    }
+                }
+            };
+            self.write_message_part(code, severity, &expanded_parameter);
         }
-        self.write_message_part(code, severity, remaining_message.as_bytes());
 
         self.write_after_message(code, severity, origin_span);
     }
@@ -119,7 +110,30 @@ unsafe fn get_argument_source_code_span<'code>(
 
         DiagnosticArgType::Char8
         | DiagnosticArgType::EnumKind
+        | DiagnosticArgType::Integer
+        | DiagnosticArgType::Invalid
+        | DiagnosticArgType::StatementKind
+        | DiagnosticArgType::String8View
+        | DiagnosticArgType::VariableKind => {
+            unreachable!();
+        }
+    }
+}
+
+unsafe fn get_argument_integer(
+    args: &DiagnosticMessageArgs,
+    diagnostic: *const u8,
+    arg_index: i32,
+) -> usize {
+    let (arg_data, arg_type) = get_arg(args, diagnostic, arg_index);
+    match arg_type {
+        DiagnosticArgType::Integer => *(arg_data as *const usize),
+
+        DiagnosticArgType::Char8
+        | DiagnosticArgType::EnumKind
+        | DiagnosticArgType::Identifier
         | DiagnosticArgType::Invalid
+        | DiagnosticArgType::SourceCodeSpan
         | DiagnosticArgType::StatementKind
         | DiagnosticArgType::String8View
         | DiagnosticArgType::VariableKind => {
@@ -132,14 +146,21 @@ unsafe fn expand_argument<'diag>(
     args: &DiagnosticMessageArgs,
     diagnostic: *const u8,
     arg_index: i32,
-) -> &'diag [u8] {
+) -> Cow<'diag, [u8]> {
     let (arg_data, arg_type) = get_arg(args, diagnostic, arg_index);
     match arg_type {
-        DiagnosticArgType::Char8 => std::slice::from_raw_parts(arg_data, 1),
+        DiagnosticArgType::Char8 => Cow::Borrowed(std::slice::from_raw_parts(arg_data, 1)),
 
-        DiagnosticArgType::Identifier => (*(arg_data as *const Identifier)).span().as_slice(),
-        DiagnosticArgType::SourceCodeSpan => (*(arg_data as *const SourceCodeSpan)).as_slice(),
-        DiagnosticArgType::String8View => *(arg_data as *const &[u8]),
+        DiagnosticArgType::Identifier => {
+            Cow::Borrowed((*(arg_data as *const Identifier)).span().as_slice())
+        }
+        DiagnosticArgType::Integer => {
+            Cow::Owned((*(arg_data as *const usize)).to_string().into_bytes())
+        }
+        DiagnosticArgType::SourceCodeSpan => {
+            Cow::Borrowed((*(arg_data as *const SourceCodeSpan)).as_slice())
+        }
+        DiagnosticArgType::String8View => Cow::Borrowed(*(arg_data as *const &[u8])),
 
         DiagnosticArgType::EnumKind
         | DiagnosticArgType::Invalid
@@ -150,59 +171,79 @@ unsafe fn expand_argument<'diag>(
     }
 }
 
-fn expand_argument_headlinese(
-    translator: Translator,
+// Arguments which are not kinds (e.g. Identifier) are expanded like {N}.
+unsafe fn expand_argument_headlinese<'translator>(
+    translator: &'translator Translator,
     args: &DiagnosticMessageArgs,
     diagnostic: *const u8,
     arg_index: i32,
-) -> &'static [u8] {
+) -> Cow<'translator, [u8]> {
     let (arg_data, arg_type) = get_arg(args, diagnostic, arg_index);
     match arg_type {
         DiagnosticArgType::EnumKind => {
-            headlinese_enum_kind(unsafe { *(arg_data as *const EnumKind) }).as_bytes()
+            Cow::Borrowed(headlinese_enum_kind(*(arg_data as *const EnumKind)).as_bytes())
         }
 
-        DiagnosticArgType::StatementKind => translator
-            .translate(headlinese_statement_kind(unsafe {
-                *(arg_data as *const StatementKind)
-            }))
-            .as_bytes(),
+        DiagnosticArgType::StatementKind => Cow::Borrowed(
+            translator
+                .translate(headlinese_statement_kind(
+                    *(arg_data as *const StatementKind),
+                ))
+                .as_bytes(),
+        ),
+
+        DiagnosticArgType::VariableKind => Cow::Borrowed(
+            translator
+                .translate(headlinese_variable_kind(*(arg_data as *const VariableKind)))
+                .as_bytes(),
+        ),
 
         DiagnosticArgType::Char8
         | DiagnosticArgType::Identifier
-        | DiagnosticArgType::Invalid
+        | DiagnosticArgType::Integer
         | DiagnosticArgType::SourceCodeSpan
-        | DiagnosticArgType::String8View
-        | DiagnosticArgType::VariableKind => {
+        | DiagnosticArgType::String8View => expand_argument(args, diagnostic, arg_index),
+
+        DiagnosticArgType::Invalid => {
             unreachable!();
         }
     }
 }
 
-fn expand_argument_singular(
-    translator: Translator,
+// Arguments which are not kinds (e.g. Identifier) are expanded like {N}.
+unsafe fn expand_argument_singular<'translator>(
+    translator: &'translator Translator,
     args: &DiagnosticMessageArgs,
     diagnostic: *const u8,
     arg_index: i32,
-) -> &'static [u8] {
+) -> Cow<'translator, [u8]> {
     let (arg_data, arg_type) = get_arg(args, diagnostic, arg_index);
     match arg_type {
-        DiagnosticArgType::StatementKind => translator
-            .translate(singular_statement_kind(unsafe {
-                *(arg_data as *const StatementKind)
-            }))
-            .as_bytes(),
+        DiagnosticArgType::EnumKind => Cow::Borrowed(
+            translator
+                .translate(singular_enum_kind(*(arg_data as *const EnumKind)))
+                .as_bytes(),
+        ),
 
-        DiagnosticArgType::EnumKind => {
-            unimplemented!();
-        }
+        DiagnosticArgType::StatementKind => Cow::Borrowed(
+            translator
+                .translate(singular_statement_kind(*(arg_data as *const StatementKind)))
+                .as_bytes(),
+        ),
+
+        DiagnosticArgType::VariableKind => Cow::Borrowed(
+            translator
+                .translate(singular_variable_kind(*(arg_data as *const VariableKind)))
+                .as_bytes(),
+        ),
 
         DiagnosticArgType::Char8
         | DiagnosticArgType::Identifier
-        | DiagnosticArgType::Invalid
+        | DiagnosticArgType::Integer
         | DiagnosticArgType::SourceCodeSpan
-        | DiagnosticArgType::String8View
-        | DiagnosticArgType::VariableKind => {
+        | DiagnosticArgType::String8View => expand_argument(args, diagnostic, arg_index),
+
+        DiagnosticArgType::Invalid => {
             unreachable!();
         }
     }
@@ -227,6 +268,15 @@ fn headlinese_enum_kind(ek: EnumKind) -> &'static str {
     }
 }
 
+fn singular_enum_kind(ek: EnumKind) -> TranslatableMessage {
+    match ek {
+        EnumKind::ConstEnum => qljs_translatable!("a const enum"),
+        EnumKind::DeclareConstEnum => qljs_translatable!("a declare const enum"),
+        EnumKind::DeclareEnum => qljs_translatable!("a declare enum"),
+        EnumKind::Normal => qljs_translatable!("an enum"),
+    }
+}
+
 fn headlinese_statement_kind(sk: StatementKind) -> TranslatableMessage {
     match sk {
         StatementKind::DoWhileLoop => qljs_translatable!("'do-while' loop"),
@@ -248,3 +298,53 @@ fn singular_statement_kind(sk: StatementKind) -> TranslatableMessage {
         StatementKind::LabelledStatement => qljs_translatable!("a labelled statement"),
     }
 }
+
+fn headlinese_variable_kind(vk: VariableKind) -> TranslatableMessage {
+    match vk {
+        VariableKind::ArrowParameter
+        | VariableKind::FunctionParameter
+        | VariableKind::FunctionTypeParameter => qljs_translatable!("parameter"),
+        VariableKind::Catch => qljs_translatable!("'catch' variable"),
+        VariableKind::Class => qljs_translatable!("class"),
+        VariableKind::Const => qljs_translatable!("'const' variable"),
+        VariableKind::Enum => qljs_translatable!("enum"),
+        VariableKind::Function => qljs_translatable!("function"),
+        VariableKind::GenericParameter => qljs_translatable!("generic parameter"),
+        VariableKind::Import => qljs_translatable!("import"),
+        VariableKind::ImportAlias => qljs_translatable!("import alias"),
+        VariableKind::ImportType => qljs_translatable!("type import"),
+        VariableKind::IndexSignatureParameter => {
+            qljs_translatable!("index signature parameter")
+        }
+        VariableKind::Interface => qljs_translatable!("interface"),
+        VariableKind::Let => qljs_translatable!("'let' variable"),
+        VariableKind::Namespace => qljs_translatable!("namespace"),
+        VariableKind::TypeAlias => qljs_translatable!("type alias"),
+        VariableKind::Var => qljs_translatable!("'var' variable"),
+    }
+}
+
+fn singular_variable_kind(vk: VariableKind) -> TranslatableMessage {
+    match vk {
+        VariableKind::ArrowParameter
+        | VariableKind::FunctionParameter
+        | VariableKind::FunctionTypeParameter => qljs_translatable!("a parameter"),
+        VariableKind::Catch => qljs_translatable!("a 'catch' variable"),
+        VariableKind::Class => qljs_translatable!("a class"),
+        VariableKind::Const => qljs_translatable!("a 'const' variable"),
+        VariableKind::Enum => qljs_translatable!("an enum"),
+        VariableKind::Function => qljs_translatable!("a function"),
+        VariableKind::GenericParameter => qljs_translatable!("a generic parameter"),
+        VariableKind::Import => qljs_translatable!("an import"),
+        VariableKind::ImportAlias => qljs_translatable!("an import alias"),
+        VariableKind::ImportType => qljs_translatable!("a type import"),
+        VariableKind::IndexSignatureParameter => {
+            qljs_translatable!("an index signature parameter")
+        }
+        VariableKind::Interface => qljs_translatable!("an interface"),
+        VariableKind::Let => qljs_translatable!("a 'let' variable"),
+        VariableKind::Namespace => qljs_translatable!("a namespace"),
+        VariableKind::TypeAlias => qljs_translatable!("a type alias"),
+        VariableKind::Var => qljs_translatable!("a 'var' variable"),
+    }
+}
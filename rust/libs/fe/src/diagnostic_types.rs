@@ -336,6 +336,124 @@ pub struct DiagUnexpectedBomBeforeShebang<'code> {
     pub bom: SourceCodeSpan<'code>,
 }
 
+#[qljs_diagnostic(
+    "E0800", DiagnosticSeverity::Warning,
+    (qljs_translatable!("unclosed Unicode bidirectional control character"), character),
+)]
+pub struct DiagUnclosedBidiControlCharacter<'code> {
+    pub character: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0801", DiagnosticSeverity::Warning,
+    (qljs_translatable!("Unicode bidirectional control character has no matching opening character"), character),
+)]
+pub struct DiagUnmatchedBidiControlCharacter<'code> {
+    pub character: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0802", DiagnosticSeverity::Warning,
+    (qljs_translatable!("identifier mixes characters from different scripts"), identifier),
+)]
+pub struct DiagMixedScriptIdentifier<'code> {
+    pub identifier: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0803", DiagnosticSeverity::Warning,
+    (qljs_translatable!("identifier contains characters which look like ASCII characters"), identifier),
+    // TRANSLATORS: {1} is the identifier spelled with ASCII characters, such
+    // as 'scope' for an identifier written with Cyrillic letters.
+    (qljs_translatable!("identifier looks like '{1}'"), identifier, skeleton),
+)]
+pub struct DiagConfusableIdentifier<'code> {
+    pub identifier: SourceCodeSpan<'code>,
+    pub skeleton: &'code [u8],
+}
+
+#[qljs_diagnostic(
+    "E0804", DiagnosticSeverity::Warning,
+    // TRANSLATORS: {1} is the ASCII character to use instead, usually a space.
+    (qljs_translatable!("irregular whitespace character; replace it with '{1}'"), whitespace, replacement),
+)]
+pub struct DiagIrregularWhitespace<'code> {
+    pub whitespace: SourceCodeSpan<'code>,
+    pub replacement: u8,
+}
+
+#[qljs_diagnostic(
+    "E0805", DiagnosticSeverity::Warning,
+    (qljs_translatable!("zero-width character in identifier; delete it"), character),
+)]
+pub struct DiagZeroWidthCharacterInIdentifier<'code> {
+    pub character: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0806", DiagnosticSeverity::Warning,
+    (qljs_translatable!("unknown HTML entity: {0}"), entity),
+)]
+pub struct DiagUnknownJSXEntity<'code> {
+    pub entity: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0807", DiagnosticSeverity::Warning,
+    (qljs_translatable!("HTML entity is missing ';': {0}"), entity),
+)]
+pub struct DiagUnterminatedJSXEntity<'code> {
+    pub entity: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0808", DiagnosticSeverity::Warning,
+    (qljs_translatable!("legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead"), escape_sequence),
+)]
+pub struct DiagLegacyOctalEscapeSequence<'code> {
+    pub escape_sequence: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0809", DiagnosticSeverity::Error,
+    (qljs_translatable!("octal escape sequence {0} is not allowed in template literals or strict mode code"), escape_sequence),
+)]
+pub struct DiagLegacyOctalEscapeSequenceNotAllowed<'code> {
+    pub escape_sequence: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0810", DiagnosticSeverity::Warning,
+    (qljs_translatable!("unnecessary escape sequence {0}; remove the backslash"), escape_sequence),
+)]
+pub struct DiagNonOctalDecimalEscapeSequence<'code> {
+    pub escape_sequence: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0811", DiagnosticSeverity::Error,
+    (qljs_translatable!("escape sequence {0} is not allowed in template literals or strict mode code"), escape_sequence),
+)]
+pub struct DiagNonOctalDecimalEscapeSequenceNotAllowed<'code> {
+    pub escape_sequence: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0812", DiagnosticSeverity::Warning,
+    (qljs_translatable!("escape sequence {0} produces an unpaired UTF-16 surrogate"), escape_sequence),
+)]
+pub struct DiagUnpairedSurrogateEscapeSequence<'code> {
+    pub escape_sequence: SourceCodeSpan<'code>,
+}
+
+#[qljs_diagnostic(
+    "E0813", DiagnosticSeverity::Warning,
+    (qljs_translatable!("zero-width whitespace character; delete it"), character),
+)]
+pub struct DiagZeroWidthWhitespace<'code> {
+    pub character: SourceCodeSpan<'code>,
+}
+
 #[qljs_diagnostic(
     "E6969", DiagnosticSeverity::Error,
     (qljs_translatable!("test for multiple messages"), a),
@@ -355,7 +473,7 @@ pub trait HasDiagType {
 
 qljs_make_has_diag_type_impls!();
 
-// NOTE(strager): For use in tests only.
+// A copy of any diagnostic struct. See Diagnostic::diag in vec_diag_reporter.
 // NOTE(port): This was called diag_collector::diag in the C++ code.
 qljs_make_any_diag_enum!();
 
@@ -1,12 +1,23 @@
 use crate::source_code_span::*;
 use cpp_vs_rust_util::narrow_cast::*;
 use cpp_vs_rust_util::padded_string::*;
+use cpp_vs_rust_util::qljs_assert;
 
 pub trait LocatorLike<'code> {
     type RangeType;
 
     fn new(s: PaddedStringView<'code>) -> Self;
     fn range(&self, span: SourceCodeSpan<'_>) -> Self::RangeType;
+    // The inverse of range.
+    fn span(&self, range: &Self::RangeType) -> SourceCodeSpan<'code>;
+    // Called after Document::replace_text replaced the text within range.
+    // new_text is the entire new text.
+    fn replace_text(
+        &mut self,
+        range: &Self::RangeType,
+        replacement_text: &[u8],
+        new_text: PaddedStringView<'code>,
+    );
 }
 
 pub struct Document<Locator> {
@@ -40,8 +51,39 @@ impl<'code, Locator: LocatorLike<'code>> Document<Locator> {
         self.locator = Locator::new(content.view());
     }
 
-    pub fn replace_text(_range: Locator::RangeType, _replacement_text: &[u8]) {
-        unimplemented!();
+    // Precondition: range's beginning is not after its end. Callers with
+    //               untrusted ranges (such as qljs_web_demo_replace_text) must
+    //               check this.
+    pub fn replace_text(&mut self, range: Locator::RangeType, replacement_text: &[u8]) {
+        let (old_content, new_content): (&PaddedString, &mut PaddedString) = unsafe {
+            let [buffer_0, buffer_1] = &mut *self.content_buffers.get();
+            if self.active_content_buffer == 0 {
+                (buffer_0, buffer_1)
+            } else {
+                (buffer_1, buffer_0)
+            }
+        };
+
+        let span: SourceCodeSpan = self.locator.span(&range);
+        let start: usize = unsafe { span.begin_ptr().offset_from(old_content.c_str()) } as usize;
+        let end: usize = unsafe { span.end_ptr().offset_from(old_content.c_str()) } as usize;
+        qljs_assert!(start <= end);
+
+        let old_slice: &[u8] = old_content.view().slice();
+        new_content.resize(narrow_cast::<i32, _>(
+            start + replacement_text.len() + (old_slice.len() - end),
+        ));
+        let out: &mut [u8] = new_content.as_mut_slice();
+        let (out_before, out_rest) = out.split_at_mut(start);
+        let (out_replacement, out_after) = out_rest.split_at_mut(replacement_text.len());
+        out_before.copy_from_slice(&old_slice[..start]);
+        out_replacement.copy_from_slice(replacement_text);
+        out_after.copy_from_slice(&old_slice[end..]);
+
+        self.locator.replace_text(&range, replacement_text, unsafe {
+            (*self.content_buffers.get().cast_const())[1 - self.active_content_buffer].view()
+        });
+        self.active_content_buffer = 1 - self.active_content_buffer;
     }
 
     pub fn string<'this>(&'this mut self) -> PaddedStringView<'this> {
@@ -51,4 +93,10 @@ impl<'code, Locator: LocatorLike<'code>> Document<Locator> {
     pub fn locator<'this>(&'this mut self) -> &'this Locator {
         &self.locator
     }
+
+    // set_text replaces the locator, discarding changes made through
+    // locator_mut.
+    pub fn locator_mut(&mut self) -> &mut Locator {
+        &mut self.locator
+    }
 }
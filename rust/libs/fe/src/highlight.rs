@@ -0,0 +1,489 @@
+use crate::diag_reporter::*;
+use crate::lex::*;
+use crate::linter::*;
+use crate::qljs_case_contextual_keyword;
+use crate::qljs_case_strict_only_reserved_keyword;
+use crate::qljs_case_strict_reserved_keyword;
+use crate::token::*;
+use cpp_vs_rust_util::narrow_cast::*;
+use cpp_vs_rust_util::padded_string::*;
+
+// What a piece of source code looks like to a syntax highlighter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HighlightClass {
+    // Reserved words, such as 'if' and 'class'.
+    Keyword,
+    // Words which are only sometimes keywords, such as 'of' and 'async'.
+    ContextualKeyword,
+    Identifier,
+    // #name
+    PrivateIdentifier,
+    String,
+    // `text`, `text${, }text${, or }text`
+    Template,
+    Number,
+    Regexp,
+    Comment,
+    Punctuator,
+    // A JSX element's name, or one of the '<', '</', '/>', or '>' around it.
+    JSXTag,
+    // Text inside a JSX element, excluding leading and trailing whitespace.
+    JSXText,
+}
+
+impl HighlightClass {
+    // The LSP semantic token type which best matches this class. Several
+    // classes map to the same token type.
+    //
+    // See: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#semanticTokenTypes
+    pub fn lsp_semantic_token_type(self) -> &'static str {
+        match self {
+            HighlightClass::Keyword | HighlightClass::ContextualKeyword => "keyword",
+            HighlightClass::Identifier => "variable",
+            HighlightClass::PrivateIdentifier => "property",
+            HighlightClass::String | HighlightClass::Template | HighlightClass::JSXText => "string",
+            HighlightClass::Number => "number",
+            HighlightClass::Regexp => "regexp",
+            HighlightClass::Comment => "comment",
+            HighlightClass::Punctuator => "operator",
+            HighlightClass::JSXTag => "type",
+        }
+    }
+}
+
+// A range of bytes in the highlighted code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HighlightToken {
+    pub begin: PaddedStringSizeType,
+    pub end: PaddedStringSizeType,
+    pub class: HighlightClass,
+}
+
+// Classify code for syntax highlighting.
+//
+// The returned tokens are sorted and do not overlap. Whitespace is not
+// included. Tokens can span multiple lines (e.g. block comments and templates).
+//
+// quick-lint-js does not parse code for highlighting. Instead, the lexer guesses
+// whether '/' begins a regexp and whether '<' begins a JSX element (if
+// linter_options.jsx) based on the previous token.
+pub fn highlight(code: PaddedStringView<'_>, linter_options: LinterOptions) -> Vec<HighlightToken> {
+    let allocator: LexerAllocator = LexerAllocator::new();
+    let mut h: Highlighter = Highlighter {
+        lexer: Lexer::new(code, null_diag_reporter(), &allocator),
+        code_begin: code.c_str(),
+        jsx: linter_options.jsx,
+        frames: vec![],
+        previous_type: None,
+        tokens: vec![],
+    };
+    h.add_comments(h.code_begin, h.lexer.peek().begin);
+    h.highlight();
+    h.tokens
+}
+
+// Classify a token lexed by Lexer::skip.
+pub fn classify_token_type(type_: TokenType) -> HighlightClass {
+    match type_ {
+        qljs_case_strict_reserved_keyword!() => HighlightClass::Keyword,
+        qljs_case_contextual_keyword!() => HighlightClass::ContextualKeyword,
+        TokenType::Identifier | TokenType::ReservedKeywordWithEscapeSequence => {
+            HighlightClass::Identifier
+        }
+        TokenType::PrivateIdentifier => HighlightClass::PrivateIdentifier,
+        TokenType::String => HighlightClass::String,
+        TokenType::CompleteTemplate | TokenType::IncompleteTemplate => HighlightClass::Template,
+        TokenType::Number => HighlightClass::Number,
+        TokenType::Regexp => HighlightClass::Regexp,
+        _ => HighlightClass::Punctuator,
+    }
+}
+
+// What the highlighter is in the middle of. The innermost Frame is last in
+// Highlighter::frames. If there are no frames, we are in top-level code.
+#[derive(Clone, Copy)]
+enum Frame {
+    // '{' in code. Ends with '}'.
+    Curly,
+    // '${' in a template. Ends with '}', which continues the template.
+    TemplateSubstitution {
+        template_begin: *const u8,
+    },
+    // '{' inside a JSX tag, such as '<div class={c}>'. Ends with '}'.
+    JSXTagExpression,
+    // '{' inside a JSX element's children. Ends with '}'.
+    JSXChildExpression,
+    // Between '<' and '>' of a JSX tag.
+    JSXTag {
+        is_closing_tag: bool,
+        is_self_closing: bool,
+        name: JSXTagNameState,
+    },
+    // Between a JSX element's opening tag and closing tag.
+    JSXChildren,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum JSXTagNameState {
+    // Example: '<' in '<a.b>'
+    BeforeName,
+    // Example: 'a' in '<a.b>'
+    InName,
+    // Example: '.' in '<a.b>'
+    AfterNameSeparator,
+    // Example: 'x' in '<a.b x="y">'
+    AfterName,
+}
+
+struct Highlighter<'alloc: 'code, 'code, 'reporter: 'alloc> {
+    lexer: Lexer<'alloc, 'code, 'reporter>,
+    code_begin: *const u8,
+    jsx: bool,
+    frames: Vec<Frame>,
+    // The type of the most recent token in code (outside JSX tags). Used to
+    // guess whether '/' and '<' begin an expression.
+    previous_type: Option<TokenType>,
+    tokens: Vec<HighlightToken>,
+}
+
+impl<'alloc: 'code, 'code, 'reporter: 'alloc> Highlighter<'alloc, 'code, 'reporter> {
+    fn highlight(&mut self) {
+        while self.lexer.peek().type_ != TokenType::EndOfFile {
+            match self.frames.last().copied() {
+                Some(Frame::JSXTag {
+                    is_closing_tag,
+                    is_self_closing,
+                    name,
+                }) => self.highlight_in_jsx_tag(is_closing_tag, is_self_closing, name),
+                Some(Frame::JSXChildren) => self.highlight_in_jsx_children(),
+                _ => self.highlight_in_code(),
+            }
+        }
+    }
+
+    fn highlight_in_code(&mut self) {
+        let type_: TokenType = self.lexer.peek().type_;
+        match type_ {
+            TokenType::LeftCurly => {
+                self.add_current_token(HighlightClass::Punctuator);
+                self.frames.push(Frame::Curly);
+                self.previous_type = Some(type_);
+                self.skip();
+            }
+
+            TokenType::IncompleteTemplate => {
+                let template_begin: *const u8 = self.lexer.peek().begin;
+                self.add_current_token(HighlightClass::Template);
+                self.frames
+                    .push(Frame::TemplateSubstitution { template_begin });
+                self.previous_type = Some(type_);
+                self.skip();
+            }
+
+            TokenType::RightCurly => match self.frames.pop() {
+                Some(Frame::TemplateSubstitution { template_begin }) => {
+                    self.add_current_token(HighlightClass::Template);
+                    self.lexer.skip_in_template(template_begin);
+                    self.add_current_token(HighlightClass::Template);
+                    let template_type: TokenType = self.lexer.peek().type_;
+                    if template_type == TokenType::IncompleteTemplate {
+                        self.frames
+                            .push(Frame::TemplateSubstitution { template_begin });
+                    }
+                    self.previous_type = Some(template_type);
+                    self.skip();
+                }
+                Some(Frame::JSXTagExpression) => {
+                    self.add_current_token(HighlightClass::Punctuator);
+                    self.skip_in_jsx();
+                }
+                Some(Frame::JSXChildExpression) => {
+                    self.add_current_token(HighlightClass::Punctuator);
+                    self.skip_in_jsx_children();
+                }
+                _ => {
+                    // Frame::Curly, or an unmatched '}'.
+                    self.add_current_token(HighlightClass::Punctuator);
+                    self.previous_type = Some(type_);
+                    self.skip();
+                }
+            },
+
+            TokenType::Slash | TokenType::SlashEqual if self.is_expression_expected() => {
+                self.lexer.reparse_as_regexp();
+                self.add_current_token(HighlightClass::Regexp);
+                self.previous_type = Some(TokenType::Regexp);
+                self.skip();
+            }
+
+            TokenType::Less if self.jsx && self.is_expression_expected() => {
+                self.add_current_token(HighlightClass::JSXTag);
+                self.frames.push(Frame::JSXTag {
+                    is_closing_tag: false,
+                    is_self_closing: false,
+                    name: JSXTagNameState::BeforeName,
+                });
+                self.skip_in_jsx();
+            }
+
+            _ => {
+                self.add_current_token(classify_token_type(type_));
+                self.previous_type = Some(type_);
+                self.skip();
+            }
+        }
+    }
+
+    fn highlight_in_jsx_tag(
+        &mut self,
+        is_closing_tag: bool,
+        is_self_closing: bool,
+        name: JSXTagNameState,
+    ) {
+        let set_frame = |h: &mut Self, is_self_closing: bool, name: JSXTagNameState| {
+            *h.frames.last_mut().unwrap() = Frame::JSXTag {
+                is_closing_tag,
+                is_self_closing,
+                name,
+            };
+        };
+        let name: JSXTagNameState = if name == JSXTagNameState::InName {
+            match self.lexer.peek().type_ {
+                TokenType::Dot | TokenType::Colon => JSXTagNameState::AfterNameSeparator,
+                _ => JSXTagNameState::AfterName,
+            }
+        } else {
+            name
+        };
+        match self.lexer.peek().type_ {
+            TokenType::Identifier
+                if matches!(
+                    name,
+                    JSXTagNameState::BeforeName | JSXTagNameState::AfterNameSeparator
+                ) =>
+            {
+                self.add_current_token(HighlightClass::JSXTag);
+                set_frame(self, is_self_closing, JSXTagNameState::InName);
+                self.skip_in_jsx();
+            }
+
+            TokenType::Dot | TokenType::Colon if name == JSXTagNameState::AfterNameSeparator => {
+                self.add_current_token(HighlightClass::JSXTag);
+                set_frame(self, is_self_closing, name);
+                self.skip_in_jsx();
+            }
+
+            TokenType::Identifier => {
+                // An attribute name.
+                self.add_current_token(HighlightClass::Identifier);
+                set_frame(self, is_self_closing, name);
+                self.skip_in_jsx();
+            }
+
+            TokenType::Slash => {
+                self.add_current_token(HighlightClass::JSXTag);
+                set_frame(self, true, name);
+                self.skip_in_jsx();
+            }
+
+            TokenType::LeftCurly => {
+                self.add_current_token(HighlightClass::Punctuator);
+                set_frame(self, is_self_closing, name);
+                self.frames.push(Frame::JSXTagExpression);
+                self.previous_type = Some(TokenType::LeftCurly);
+                self.skip();
+            }
+
+            TokenType::Greater => {
+                self.add_current_token(HighlightClass::JSXTag);
+                self.frames.pop();
+                if is_closing_tag || is_self_closing {
+                    self.end_jsx_element();
+                } else {
+                    self.frames.push(Frame::JSXChildren);
+                    self.skip_in_jsx_children();
+                }
+            }
+
+            type_ => {
+                self.add_current_token(classify_token_type(type_));
+                set_frame(self, is_self_closing, name);
+                self.skip_in_jsx();
+            }
+        }
+    }
+
+    fn highlight_in_jsx_children(&mut self) {
+        match self.lexer.peek().type_ {
+            TokenType::Less => {
+                self.add_current_token(HighlightClass::JSXTag);
+                self.skip_in_jsx();
+                let is_closing_tag: bool = self.lexer.peek().type_ == TokenType::Slash;
+                if is_closing_tag {
+                    self.add_current_token(HighlightClass::JSXTag);
+                    self.frames.pop();
+                    self.skip_in_jsx();
+                }
+                self.frames.push(Frame::JSXTag {
+                    is_closing_tag,
+                    is_self_closing: false,
+                    name: JSXTagNameState::BeforeName,
+                });
+            }
+
+            TokenType::LeftCurly => {
+                self.add_current_token(HighlightClass::Punctuator);
+                self.frames.push(Frame::JSXChildExpression);
+                self.previous_type = Some(TokenType::LeftCurly);
+                self.skip();
+            }
+
+            TokenType::Greater | TokenType::RightCurly => {
+                self.add_current_token(HighlightClass::Punctuator);
+                self.skip_in_jsx_children();
+            }
+
+            type_ => {
+                self.add_current_token(classify_token_type(type_));
+                self.skip_in_jsx();
+            }
+        }
+    }
+
+    // Precondition: The current token is the '>' ending a closing tag or a
+    //               self-closing tag.
+    fn end_jsx_element(&mut self) {
+        if let Some(Frame::JSXChildren) = self.frames.last() {
+            // The element is a child of another element.
+            self.skip_in_jsx_children();
+        } else {
+            // The element is an expression.
+            self.previous_type = Some(TokenType::RightParen);
+            self.skip();
+        }
+    }
+
+    // Guess whether the current token begins an expression (as opposed to
+    // continuing an expression), judging by the previous token.
+    fn is_expression_expected(&self) -> bool {
+        match self.previous_type {
+            None => true,
+            Some(
+                TokenType::Identifier
+                | TokenType::ReservedKeywordWithEscapeSequence
+                | TokenType::PrivateIdentifier
+                | TokenType::Number
+                | TokenType::String
+                | TokenType::CompleteTemplate
+                | TokenType::Regexp
+                | TokenType::RightParen
+                | TokenType::RightSquare
+                | TokenType::PlusPlus
+                | TokenType::MinusMinus
+                | TokenType::KWFalse
+                | TokenType::KWNull
+                | TokenType::KWSuper
+                | TokenType::KWThis
+                | TokenType::KWTrue,
+            ) => false,
+            Some(qljs_case_contextual_keyword!()) => false,
+            Some(_) => true,
+        }
+    }
+
+    fn skip(&mut self) {
+        let previous_end: *const u8 = self.lexer.peek().end;
+        self.lexer.skip();
+        self.add_comments(previous_end, self.lexer.peek().begin);
+    }
+
+    fn skip_in_jsx(&mut self) {
+        let previous_end: *const u8 = self.lexer.peek().end;
+        self.lexer.skip_in_jsx();
+        self.add_comments(previous_end, self.lexer.peek().begin);
+    }
+
+    fn skip_in_jsx_children(&mut self) {
+        let previous_end: *const u8 = self.lexer.peek().end;
+        self.lexer.skip_in_jsx_children();
+        let text_begin: *const u8 = self.skip_whitespace_forward(previous_end);
+        let mut text_end: *const u8 = self.lexer.peek().begin;
+        while text_end > text_begin && is_whitespace_byte(unsafe { *text_end.sub(1) }) {
+            text_end = unsafe { text_end.sub(1) };
+        }
+        if text_end > text_begin {
+            self.add_token(text_begin, text_end, HighlightClass::JSXText);
+        }
+    }
+
+    fn skip_whitespace_forward(&self, mut c: *const u8) -> *const u8 {
+        let end: *const u8 = self.lexer.peek().begin;
+        while c < end && is_whitespace_byte(unsafe { *c }) {
+            c = unsafe { c.add(1) };
+        }
+        c
+    }
+
+    // Add a Comment token for each comment between begin and end. The bytes
+    // between begin and end must contain only whitespace and comments.
+    fn add_comments(&mut self, begin: *const u8, end: *const u8) {
+        let starts_with = |c: *const u8, prefix: &[u8]| -> bool {
+            (end as usize - c as usize) >= prefix.len()
+                && unsafe { std::slice::from_raw_parts(c, prefix.len()) } == prefix
+        };
+        let mut c: *const u8 = begin;
+        while c < end {
+            let comment_end: Option<*const u8> = if starts_with(c, b"/*") {
+                let mut e: *const u8 = unsafe { c.add(2) };
+                while e < end && !starts_with(e, b"*/") {
+                    e = unsafe { e.add(1) };
+                }
+                Some(if e < end { unsafe { e.add(2) } } else { end })
+            } else if starts_with(c, b"//")
+                || starts_with(c, b"<!--")
+                || starts_with(c, b"-->")
+                || starts_with(c, b"#!")
+            {
+                let mut e: *const u8 = c;
+                while e < end && !matches!(unsafe { *e }, b'\n' | b'\r') {
+                    e = unsafe { e.add(1) };
+                }
+                Some(e)
+            } else {
+                None
+            };
+            match comment_end {
+                Some(comment_end) => {
+                    self.add_token(c, comment_end, HighlightClass::Comment);
+                    c = comment_end;
+                }
+                None => {
+                    c = unsafe { c.add(1) };
+                }
+            }
+        }
+    }
+
+    fn add_current_token(&mut self, class: HighlightClass) {
+        let token: &Token = self.lexer.peek();
+        self.add_token(token.begin, token.end, class);
+    }
+
+    fn add_token(&mut self, begin: *const u8, end: *const u8, class: HighlightClass) {
+        if begin == end {
+            return;
+        }
+        let offset = |c: *const u8| -> PaddedStringSizeType {
+            narrow_cast(unsafe { c.offset_from(self.code_begin) })
+        };
+        self.tokens.push(HighlightToken {
+            begin: offset(begin),
+            end: offset(end),
+            class,
+        });
+    }
+}
+
+fn is_whitespace_byte(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\r' | b'\x0b' | b'\x0c')
+}
@@ -1,4 +1,5 @@
 #[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatementKind {
     DoWhileLoop,
     ForLoop, // TODO(strager): c_style_for_loop + for_in_loop + for_of_loop?
@@ -9,6 +10,7 @@ pub enum StatementKind {
 }
 
 #[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EnumKind {
     DeclareConstEnum,
     ConstEnum,
@@ -17,6 +19,7 @@ pub enum EnumKind {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VariableKind {
     ArrowParameter,
     Catch,
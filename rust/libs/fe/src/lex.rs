@@ -1,6 +1,8 @@
 use crate::buffering_diag_reporter::*;
 use crate::diag_reporter::*;
 use crate::diagnostic_types::*;
+use crate::jsx_entity::*;
+use crate::lex_confusables_generated::*;
 use crate::lex_keyword::*;
 use crate::lex_unicode_generated::*;
 use crate::qljs_case_contextual_keyword;
@@ -89,22 +91,76 @@ fn look_up_in_unicode_table(table: &[u8], code_point: u32) -> bool {
     (slot & (1 << (bit_in_chunk % BITS_PER_BYTE))) != 0
 }
 
+fn look_up_script_extensions(code_point: u32) -> Option<&'static ScriptSet> {
+    let index: usize =
+        SCRIPT_EXTENSION_RANGES.partition_point(|&(_first, last, _set_index)| last < code_point);
+    match SCRIPT_EXTENSION_RANGES.get(index) {
+        Some(&(first, _last, set_index)) if first <= code_point => {
+            Some(&SCRIPT_EXTENSION_SETS[set_index as usize])
+        }
+        _ => None,
+    }
+}
+
+fn look_up_confusable_prototype(code_point: u32) -> Option<&'static [u8]> {
+    CONFUSABLE_PROTOTYPES
+        .binary_search_by_key(&code_point, |&(c, _prototype)| c)
+        .ok()
+        .map(|index| CONFUSABLE_PROTOTYPES[index].1)
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum IdentifierKind {
     JavaScript,
     JSX, // Allows '-'.
 }
 
+#[derive(Clone, Copy, Default)]
+pub struct LexerOptions {
+    // If true, report identifiers which mix scripts or which contain characters
+    // which look like ASCII characters. See Unicode Technical Standard #39.
+    pub confusable_identifiers: bool,
+
+    // If true, report non-ASCII whitespace between tokens and zero-width
+    // characters in identifiers.
+    pub irregular_whitespace: bool,
+
+    // If true, treat the input as strict mode code (e.g. an ES module). Legacy
+    // octal escape sequences in string literals are errors instead of
+    // warnings.
+    pub strict_mode: bool,
+}
+
 pub struct LexerAllocator {
     allocator: MonotonicAllocator,
     transaction_allocator: MonotonicAllocator,
+    // The states of allocator and transaction_allocator before anything was
+    // allocated.
+    allocator_empty: LinkedBumpAllocatorRewindState,
+    transaction_allocator_empty: LinkedBumpAllocatorRewindState,
 }
 
 impl LexerAllocator {
     pub fn new() -> LexerAllocator {
+        let allocator: MonotonicAllocator = MonotonicAllocator::new("LexerAllocator::allocator");
+        let transaction_allocator: MonotonicAllocator =
+            MonotonicAllocator::new("LexerAllocator::transaction_allocator");
         LexerAllocator {
-            allocator: MonotonicAllocator::new("LexerAllocator::allocator"),
-            transaction_allocator: MonotonicAllocator::new("LexerAllocator::transaction_allocator"),
+            allocator_empty: allocator.prepare_for_rewind(),
+            transaction_allocator_empty: transaction_allocator.prepare_for_rewind(),
+            allocator,
+            transaction_allocator,
+        }
+    }
+
+    // Free everything allocated by Lexers using this LexerAllocator. Keeps
+    // some memory so the next Lexer can reuse it without allocating.
+    pub fn reset(&mut self) {
+        // &mut self means no Lexer or Token still refers to our memory.
+        unsafe {
+            self.allocator.rewind(self.allocator_empty.clone());
+            self.transaction_allocator
+                .rewind(self.transaction_allocator_empty.clone());
         }
     }
 }
@@ -116,7 +172,10 @@ impl LexerAllocator {
 // string, boolean, etc.).
 //
 // Whitespace and comments are not interpreted as tokens.
-pub struct Lexer<'alloc: 'reporter, 'code, 'reporter> {
+//
+// 'alloc outlives 'code so that diagnostics can refer to strings allocated by
+// the Lexer, such as DiagConfusableIdentifier::skeleton.
+pub struct Lexer<'alloc: 'reporter + 'code, 'code, 'reporter> {
     last_token: Token<'alloc, 'code>,
     last_last_token_end: *const u8,
     input: InputPointer,
@@ -124,13 +183,27 @@ pub struct Lexer<'alloc: 'reporter, 'code, 'reporter> {
     original_input: PaddedStringView<'code>,
 
     allocator: &'alloc LexerAllocator,
+    options: LexerOptions,
+
+    // Unicode bidirectional control characters in the comment, string literal,
+    // or template currently being lexed. Always empty between tokens.
+    bidi_control_characters: BidiControlCharacterStack,
 }
 
-impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
+impl<'alloc: 'code, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
     pub fn new(
         input: PaddedStringView<'code>,
         diag_reporter: &'reporter dyn DiagReporter,
         allocator: &'alloc LexerAllocator,
+    ) -> Lexer<'alloc, 'code, 'reporter> {
+        Lexer::new_with_options(input, diag_reporter, allocator, LexerOptions::default())
+    }
+
+    pub fn new_with_options(
+        input: PaddedStringView<'code>,
+        diag_reporter: &'reporter dyn DiagReporter,
+        allocator: &'alloc LexerAllocator,
+        options: LexerOptions,
     ) -> Lexer<'alloc, 'code, 'reporter> {
         let mut lexer = Lexer {
             last_token: Token {
@@ -146,6 +219,8 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
             diag_reporter: diag_reporter,
             original_input: input,
             allocator: allocator,
+            options,
+            bidi_control_characters: BidiControlCharacterStack::new(),
         };
         lexer.parse_bom_before_shebang();
         lexer.parse_current_token();
@@ -747,6 +822,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
 
     fn parse_string_literal(&mut self) -> InputPointer {
         let opening_quote: u8 = self.input[0];
+        let mut paired_low_surrogate_begin: *const u8 = std::ptr::null();
 
         let mut c: InputPointer = self.input + 1;
         loop {
@@ -759,6 +835,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                                 string_literal: unsafe { SourceCodeSpan::new(self.input.0, c.0) },
                             },
                         );
+                        self.bidi_control_characters.finish(self.diag_reporter);
                         return c;
                     } else {
                         c += 1;
@@ -798,6 +875,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                             string_literal: unsafe { SourceCodeSpan::new(self.input.0, c.0) },
                         },
                     );
+                    self.bidi_control_characters.finish(self.diag_reporter);
                     return c;
                 }
 
@@ -815,6 +893,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                                         },
                                     },
                                 );
+                                self.bidi_control_characters.finish(self.diag_reporter);
                                 return c;
                             } else {
                                 c += 1;
@@ -843,14 +922,26 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                             }
                         }
                         b'u' => {
-                            c = InputPointer(
-                                self.parse_unicode_escape(
-                                    escape_sequence_start,
-                                    self.diag_reporter,
-                                )
-                                .end,
+                            let escape: ParsedUnicodeEscape = self
+                                .parse_unicode_escape(escape_sequence_start, self.diag_reporter);
+                            c = InputPointer(escape.end);
+                            self.check_surrogate_escape(
+                                escape_sequence_start,
+                                &escape,
+                                &mut paired_low_surrogate_begin,
+                                self.diag_reporter,
+                            );
+                        }
+                        qljs_case_decimal_digit!() => {
+                            c = self.parse_digit_escape(
+                                escape_sequence_start,
+                                /*is_template=*/ false,
+                                self.diag_reporter,
                             );
                         }
+                        0xe2 => {
+                            // Let the outer loop look for bidi control characters.
+                        }
                         _ => {
                             c += 1;
                         }
@@ -860,11 +951,17 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                 b'"' | b'\'' => {
                     if c[0] == opening_quote {
                         c += 1;
+                        self.bidi_control_characters.finish(self.diag_reporter);
                         return c;
                     }
                     c += 1;
                 }
 
+                0xe2 => {
+                    self.bidi_control_characters.visit(c, self.diag_reporter);
+                    c += 1;
+                }
+
                 _ => {
                     c += 1;
                 }
@@ -887,6 +984,10 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                 );
                 return c.0;
             }
+            if c[0] == b'&' {
+                c += self.check_jsx_entity(c);
+                continue;
+            }
             c += 1;
             // Loop.
         }
@@ -985,6 +1086,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
         diag_reporter: &dyn DiagReporter,
     ) -> ParsedTemplateBody<'alloc, 'code> {
         let mut escape_sequence_diagnostics: Option<&'alloc mut BufferingDiagReporter> = None;
+        let mut paired_low_surrogate_begin: *const u8 = std::ptr::null();
         let mut c: InputPointer = input;
         loop {
             match c[0] {
@@ -998,6 +1100,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                                 },
                             },
                         );
+                        self.bidi_control_characters.finish(diag_reporter);
                         return ParsedTemplateBody {
                             type_: TokenType::CompleteTemplate,
                             end: c.0,
@@ -1010,6 +1113,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
 
                 b'`' => {
                     c += 1;
+                    self.bidi_control_characters.finish(diag_reporter);
                     return ParsedTemplateBody {
                         type_: TokenType::CompleteTemplate,
                         end: c.0,
@@ -1031,6 +1135,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                                         },
                                     },
                                 );
+                                self.bidi_control_characters.finish(diag_reporter);
                                 return ParsedTemplateBody {
                                     type_: TokenType::CompleteTemplate,
                                     end: c.0,
@@ -1041,20 +1146,36 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                             }
                         }
                         b'u' => {
-                            if escape_sequence_diagnostics.is_none() {
-                                escape_sequence_diagnostics = Some(unsafe {
-                                    &mut *self.allocator.allocator.new_object(
-                                        BufferingDiagReporter::new(&self.allocator.allocator),
-                                    )
-                                });
-                            }
                             let inner_reporter: &mut BufferingDiagReporter =
-                                unsafe { escape_sequence_diagnostics.as_mut().unwrap_unchecked() };
-                            c = InputPointer(
-                                self.parse_unicode_escape(escape_sequence_start, inner_reporter)
-                                    .end,
+                                Self::get_escape_sequence_diagnostics(
+                                    self.allocator,
+                                    &mut escape_sequence_diagnostics,
+                                );
+                            let escape: ParsedUnicodeEscape =
+                                self.parse_unicode_escape(escape_sequence_start, inner_reporter);
+                            c = InputPointer(escape.end);
+                            self.check_surrogate_escape(
+                                escape_sequence_start,
+                                &escape,
+                                &mut paired_low_surrogate_begin,
+                                inner_reporter,
                             );
                         }
+                        qljs_case_decimal_digit!() => {
+                            let inner_reporter: &mut BufferingDiagReporter =
+                                Self::get_escape_sequence_diagnostics(
+                                    self.allocator,
+                                    &mut escape_sequence_diagnostics,
+                                );
+                            c = self.parse_digit_escape(
+                                escape_sequence_start,
+                                /*is_template=*/ true,
+                                inner_reporter,
+                            );
+                        }
+                        0xe2 => {
+                            // Let the outer loop look for bidi control characters.
+                        }
                         _ => {
                             c += 1;
                         }
@@ -1064,6 +1185,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                 b'$' => {
                     if c[1] == b'{' {
                         c += 2;
+                        self.bidi_control_characters.finish(diag_reporter);
                         return ParsedTemplateBody {
                             type_: TokenType::IncompleteTemplate,
                             end: c.0,
@@ -1073,6 +1195,11 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     c += 1;
                 }
 
+                0xe2 => {
+                    self.bidi_control_characters.visit(c, diag_reporter);
+                    c += 1;
+                }
+
                 _ => {
                     c += 1;
                 }
@@ -1884,6 +2011,122 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
         )
     }
 
+    // Returns the reporter for errors in a template's escape sequences,
+    // creating it if necessary. See ParsedTemplateBody.
+    fn get_escape_sequence_diagnostics<'diags>(
+        allocator: &'alloc LexerAllocator,
+        escape_sequence_diagnostics: &'diags mut Option<
+            &'alloc mut BufferingDiagReporter<'alloc, 'code>,
+        >,
+    ) -> &'diags mut BufferingDiagReporter<'alloc, 'code> {
+        escape_sequence_diagnostics.get_or_insert_with(|| unsafe {
+            &mut *allocator
+                .allocator
+                .new_object(BufferingDiagReporter::new(&allocator.allocator))
+        })
+    }
+
+    // Parses an escape sequence starting with a digit, such as "\0", "\012" or
+    // "\8". escape_sequence_begin must point to the backslash.
+    //
+    // Legacy octal escape sequences and "\8" and "\9" are errors in template
+    // literals and in strict mode code, and are deprecated elsewhere.
+    fn parse_digit_escape(
+        &self,
+        escape_sequence_begin: *const u8,
+        is_template: bool,
+        reporter: &dyn DiagReporter,
+    ) -> InputPointer {
+        let mut c: InputPointer = InputPointer(escape_sequence_begin) + 1;
+        let first_digit: u8 = c[0];
+        c += 1;
+        let is_allowed: bool = !is_template && !self.options.strict_mode;
+        let get_escape_span =
+            |end: InputPointer| unsafe { SourceCodeSpan::new(escape_sequence_begin, end.0) };
+        match first_digit {
+            b'0' if !is_digit(c[0]) => {
+                // "\0" is a null character, not an octal escape sequence.
+            }
+            b'8' | b'9' => {
+                if is_allowed {
+                    report(
+                        reporter,
+                        DiagNonOctalDecimalEscapeSequence {
+                            escape_sequence: get_escape_span(c),
+                        },
+                    );
+                } else {
+                    report(
+                        reporter,
+                        DiagNonOctalDecimalEscapeSequenceNotAllowed {
+                            escape_sequence: get_escape_span(c),
+                        },
+                    );
+                }
+            }
+            _ => {
+                // Octal escape sequences go up to "\377".
+                let max_digits: usize = if first_digit <= b'3' { 3 } else { 2 };
+                for _ in 1..max_digits {
+                    if !is_octal_digit(c[0]) {
+                        break;
+                    }
+                    c += 1;
+                }
+                if is_allowed {
+                    report(
+                        reporter,
+                        DiagLegacyOctalEscapeSequence {
+                            escape_sequence: get_escape_span(c),
+                        },
+                    );
+                } else {
+                    report(
+                        reporter,
+                        DiagLegacyOctalEscapeSequenceNotAllowed {
+                            escape_sequence: get_escape_span(c),
+                        },
+                    );
+                }
+            }
+        }
+        c
+    }
+
+    // Reports escape sequences such as "\uD800" which produce a UTF-16
+    // surrogate without its partner. "\uD83D\uDE00" is fine.
+    //
+    // paired_low_surrogate_begin remembers where a high surrogate's partner
+    // begins so the partner isn't reported when it is lexed.
+    fn check_surrogate_escape(
+        &self,
+        escape_sequence_begin: *const u8,
+        escape: &ParsedUnicodeEscape,
+        paired_low_surrogate_begin: &mut *const u8,
+        reporter: &dyn DiagReporter,
+    ) {
+        let is_paired: bool = match escape.code_point {
+            Some(0xd800..=0xdbff) => matches!(
+                peek_unicode_escape_code_point(InputPointer(escape.end)),
+                Some(0xdc00..=0xdfff)
+            ),
+            Some(0xdc00..=0xdfff) => escape_sequence_begin == *paired_low_surrogate_begin,
+            _ => return,
+        };
+        if is_paired {
+            *paired_low_surrogate_begin = escape.end;
+        } else {
+            report(
+                reporter,
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: unsafe {
+                        SourceCodeSpan::new(escape_sequence_begin, escape.end)
+                    },
+                },
+            );
+        }
+    }
+
     fn parse_unicode_escape(
         &mut self,
         input: *const u8,
@@ -2209,6 +2452,28 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
         }
 
         loop {
+            // Fast path: legal non-ASCII identifier characters.
+            if !is_ascii_code_unit(input[0]) && !self.options.irregular_whitespace {
+                if let Some(character) = look_up_identifier_character_utf_8(unsafe {
+                    PaddedStringView::from_begin_end(input.0, self.original_input.null_terminator())
+                }) {
+                    let is_legal_character: bool = if input.0 == identifier_begin {
+                        character.is_initial_identifier_character
+                    } else {
+                        character.is_identifier_character
+                    };
+                    if is_legal_character {
+                        let character_end: InputPointer =
+                            input + narrow_cast::<isize, _>(character.size);
+                        normalized.extend_from_slice(
+                            unsafe { SourceCodeSpan::new(input.0, character_end.0) }.as_slice(),
+                        );
+                        input = character_end;
+                        continue;
+                    }
+                }
+            }
+
             let mut decode_result: DecodeUTF8Result = decode_utf_8(unsafe {
                 PaddedStringView::from_begin_end(input.0, self.original_input.null_terminator())
             });
@@ -2300,6 +2565,15 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     }
                 }
 
+                if self.options.irregular_whitespace && is_zero_width_character(code_point) {
+                    report(
+                        self.diag_reporter,
+                        DiagZeroWidthCharacterInIdentifier {
+                            character: character_span,
+                        },
+                    );
+                }
+
                 normalized.extend_from_slice(character_span.as_slice());
                 input = character_end;
             }
@@ -2307,6 +2581,13 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
 
         let normalized_slice: &[u8] = unsafe { &*normalized.release() };
 
+        if self.options.confusable_identifiers {
+            self.check_confusable_identifier(
+                unsafe { SourceCodeSpan::new(private_identifier_begin, input.0) },
+                normalized_slice,
+            );
+        }
+
         ParsedIdentifier {
             after: input.0,
             normalized: normalized_slice,
@@ -2314,6 +2595,67 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
         }
     }
 
+    fn check_confusable_identifier(
+        &mut self,
+        identifier: SourceCodeSpan<'code>,
+        normalized: &[u8],
+    ) {
+        let normalized: &str = match std::str::from_utf8(normalized) {
+            Ok(normalized) => normalized,
+            // parse_identifier_slow reported DiagInvalidUTF8Sequence already.
+            Err(_) => return,
+        };
+
+        let mut has_confusable_characters: bool = false;
+        let mut resolved_scripts: ScriptSet = [!0; SCRIPT_SET_WORDS];
+        for c in normalized.chars() {
+            let code_point: u32 = c as u32;
+            if look_up_confusable_prototype(code_point).is_some() {
+                has_confusable_characters = true;
+            }
+            if let Some(scripts) = look_up_script_extensions(code_point) {
+                for (resolved, script) in resolved_scripts.iter_mut().zip(scripts) {
+                    *resolved &= *script;
+                }
+            }
+        }
+
+        // Single-script identifiers, such as Cyrillic "данные", are fine even
+        // if some of their characters are confusable. Only warn if the
+        // identifier could be mistaken for an ASCII identifier or if it mixes
+        // scripts.
+        if has_confusable_characters {
+            let mut skeleton: BumpVector<u8, MonotonicAllocator> = BumpVector::new(
+                "check_confusable_identifier skeleton",
+                &self.allocator.allocator,
+            );
+            for c in normalized.chars() {
+                match look_up_confusable_prototype(c as u32) {
+                    Some(prototype) => skeleton.extend_from_slice(prototype),
+                    None => {
+                        let mut encoded: [u8; 4] = [0; 4];
+                        skeleton.extend_from_slice(c.encode_utf8(&mut encoded).as_bytes());
+                    }
+                }
+            }
+            if skeleton.as_slice().is_ascii() && skeleton.as_slice() != normalized.as_bytes() {
+                // The skeleton lives in self.allocator, which outlives 'code.
+                let skeleton: &'alloc [u8] = unsafe { &*skeleton.release() };
+                report(
+                    self.diag_reporter,
+                    DiagConfusableIdentifier {
+                        identifier,
+                        skeleton,
+                    },
+                );
+                return;
+            }
+        }
+        if resolved_scripts.iter().all(|&scripts| scripts == 0) {
+            report(self.diag_reporter, DiagMixedScriptIdentifier { identifier });
+        }
+    }
+
     #[allow(unreachable_code)]
     fn skip_whitespace(&mut self) {
         let mut input: InputPointer = self.input;
@@ -2335,6 +2677,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     0xe1 => {
                         if c1 == 0x9a && c2 == 0x80 {
                             // U+1680 Ogham Space Mark
+                            self.found_irregular_whitespace(input, 3);
                             input += 3;
                             continue;
                         } else {
@@ -2357,6 +2700,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                                 | 0x89  // U+2009 Thin Space
                                 | 0x8a  // U+200A Hair Space
                                 | 0xaf => { // U+202F Narrow No-Break Space (NNBSP)
+                                    self.found_irregular_whitespace(input, 3);
                                     input += 3;
                                     continue;
                                 }
@@ -2376,6 +2720,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                         } else if c1 == 0x81 {
                             if c2 == 0x9f {
                                 // U+205F Medium Mathematical Space (MMSP)
+                                self.found_irregular_whitespace(input, 3);
                                 input += 3;
                                 continue;
                             } else {
@@ -2389,6 +2734,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     0xe3 => {
                         if c1 == 0x80 && c2 == 0x80 {
                             // U+3000 Ideographic Space
+                            self.found_irregular_whitespace(input, 3);
                             input += 3;
                             continue;
                         } else {
@@ -2399,6 +2745,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     0xef => {
                         if c1 == 0xbb && c2 == 0xbf {
                             // U+FEFF Zero Width No-Break Space (BOM, ZWNBSP)
+                            self.found_zero_width_whitespace(input, 3);
                             input += 3;
                             continue;
                         } else {
@@ -2409,6 +2756,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     0xc2 => {
                         if c1 == 0xa0 {
                             // U+00A0 No-Break Space (NBSP)
+                            self.found_irregular_whitespace(input, 2);
                             input += 2;
                             continue;
                         } else {
@@ -2429,6 +2777,31 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
         self.input = input;
     }
 
+    fn found_irregular_whitespace(&self, whitespace: InputPointer, size: isize) {
+        if self.options.irregular_whitespace {
+            report(
+                self.diag_reporter,
+                DiagIrregularWhitespace {
+                    whitespace: unsafe { SourceCodeSpan::new(whitespace.0, (whitespace + size).0) },
+                    replacement: b' ',
+                },
+            );
+        }
+    }
+
+    // Unlike other irregular whitespace, zero-width whitespace should be
+    // deleted instead of replaced with a space.
+    fn found_zero_width_whitespace(&self, whitespace: InputPointer, size: isize) {
+        if self.options.irregular_whitespace {
+            report(
+                self.diag_reporter,
+                DiagZeroWidthWhitespace {
+                    character: unsafe { SourceCodeSpan::new(whitespace.0, (whitespace + size).0) },
+                },
+            );
+        }
+    }
+
     fn skip_block_comment(&mut self) {
         qljs_slow_assert!(self.input[0] == b'/' && self.input[1] == b'*');
         let mut c: InputPointer = self.input + 2;
@@ -2472,6 +2845,9 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                         if cc[0] == b'\0' {
                             return found_end_of_file(self);
                         }
+                        if cc[0] == 0xe2 {
+                            self.bidi_control_characters.visit(cc, self.diag_reporter);
+                        }
                     }
                 }
             }
@@ -2486,7 +2862,8 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
             loop {
                 let chars: CharVector = unsafe { CharVector::load_raw(c.0) };
                 let matches: BoolVector = chars.lane_eq(CharVector::repeated(b'\0'))
-                    | chars.lane_eq(CharVector::repeated(b'*'));
+                    | chars.lane_eq(CharVector::repeated(b'*'))
+                    | chars.lane_eq(CharVector::repeated(0xe2));
                 let mask: u32 = matches.mask();
                 if mask != 0 {
                     for i in mask.trailing_zeros()..(chars.len() as u32) {
@@ -2499,6 +2876,9 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                             if cc[0] == b'\0' {
                                 return found_end_of_file(this);
                             }
+                            if cc[0] == 0xe2 {
+                                this.bidi_control_characters.visit(cc, this.diag_reporter);
+                            }
                         }
                     }
                 }
@@ -2510,11 +2890,13 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
             this: &mut Lexer<'alloc, 'code, 'reporter>,
             c: InputPointer,
         ) {
+            this.bidi_control_characters.finish(this.diag_reporter);
             this.input = c + 2;
             this.skip_whitespace();
         }
 
         fn found_end_of_file(this: &mut Lexer) {
+            this.bidi_control_characters.finish(this.diag_reporter);
             report(
                 this.diag_reporter,
                 DiagUnclosedBlockComment {
@@ -2557,7 +2939,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
 
         let new_line: CharVector = CharVector::repeated(b'\n');
         let carriage_return: CharVector = CharVector::repeated(b'\r');
-        let unicode_first_byte: CharVector = CharVector::repeated(0xe2); // U+2028 U+2029
+        let unicode_first_byte: CharVector = CharVector::repeated(0xe2); // U+2028 U+2029 U+202A etc.
         let zero: CharVector = CharVector::repeated(0);
 
         loop {
@@ -2580,6 +2962,7 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     let n: usize = newline_character_size(self.input);
 
                     if n == 1 {
+                        self.bidi_control_characters.finish(self.diag_reporter);
                         self.input += 1;
                         self.skip_whitespace();
                         true
@@ -2587,12 +2970,18 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     // U+2028 Line Separator
                     // U+2029 Paragraph Separator
                     else if n == 3 {
+                        self.bidi_control_characters.finish(self.diag_reporter);
                         self.input += 3;
                         self.skip_whitespace();
                         true
                     } else if self.input[0] == b'\0' && self.is_eof(self.input.0) {
+                        self.bidi_control_characters.finish(self.diag_reporter);
                         true
                     } else {
+                        if self.input[0] == 0xe2 {
+                            self.bidi_control_characters
+                                .visit(self.input, self.diag_reporter);
+                        }
                         self.input += 1;
                         false
                     }
@@ -2632,6 +3021,11 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
                     );
                 }
 
+                b'&' => {
+                    c += self.check_jsx_entity(c);
+                    continue;
+                }
+
                 b'\0' => {
                     if self.is_eof(c.0) {
                         break;
@@ -2646,6 +3040,37 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
         self.input = c;
     }
 
+    // Reports unknown or unterminated character references such as "&nbps;".
+    // c must point to '&'. Returns the number of bytes to skip.
+    fn check_jsx_entity(&self, c: InputPointer) -> isize {
+        let rest: &[u8] =
+            unsafe { slice_from_begin_end(c.0, self.original_input.null_terminator()) };
+        match parse_jsx_entity(rest) {
+            JSXEntity::Valid { size, .. } => narrow_cast(size),
+            JSXEntity::Unknown { size } => {
+                let size: isize = narrow_cast(size);
+                report(
+                    self.diag_reporter,
+                    DiagUnknownJSXEntity {
+                        entity: unsafe { SourceCodeSpan::new(c.0, (c + size).0) },
+                    },
+                );
+                size
+            }
+            JSXEntity::Unterminated { size } => {
+                let size: isize = narrow_cast(size);
+                report(
+                    self.diag_reporter,
+                    DiagUnterminatedJSXEntity {
+                        entity: unsafe { SourceCodeSpan::new(c.0, (c + size).0) },
+                    },
+                );
+                size
+            }
+            JSXEntity::NotEntity => 1,
+        }
+    }
+
     fn is_eof(&self, input: *const u8) -> bool {
         qljs_assert!(unsafe { *input } == b'\0');
         input == self.original_input.null_terminator()
@@ -2657,6 +3082,114 @@ impl<'alloc, 'code, 'reporter: 'alloc> Lexer<'alloc, 'code, 'reporter> {
     }
 }
 
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum BidiControlCharacterKind {
+    // U+202A Left-to-Right Embedding (LRE)
+    // U+202B Right-to-Left Embedding (RLE)
+    // U+202D Left-to-Right Override (LRO)
+    // U+202E Right-to-Left Override (RLO)
+    Embedding,
+    // U+202C Pop Directional Formatting (PDF)
+    PopEmbedding,
+    // U+2066 Left-to-Right Isolate (LRI)
+    // U+2067 Right-to-Left Isolate (RLI)
+    // U+2068 First Strong Isolate (FSI)
+    Isolate,
+    // U+2069 Pop Directional Isolate (PDI)
+    PopIsolate,
+}
+
+fn bidi_control_character_kind(input: InputPointer) -> Option<BidiControlCharacterKind> {
+    use BidiControlCharacterKind::*;
+    if input[0] != 0xe2 {
+        return None;
+    }
+    match (input[1], input[2]) {
+        (0x80, 0xaa | 0xab | 0xad | 0xae) => Some(Embedding),
+        (0x80, 0xac) => Some(PopEmbedding),
+        (0x81, 0xa6..=0xa8) => Some(Isolate),
+        (0x81, 0xa9) => Some(PopIsolate),
+        _ => None,
+    }
+}
+
+// BidiControlCharacterStack detects Trojan Source attacks (CVE-2021-42574).
+//
+// Embeddings, overrides, and isolates which are still open at the end of a
+// comment, string literal, or template can make the code after it render in a
+// different order than it is parsed. Pops without a matching opener are not
+// harmful by themselves, but they do not belong in honest code either.
+//
+// Matching follows the Unicode Bidirectional Algorithm (UAX #9): a PDF only
+// closes an embedding or override opened inside the innermost isolate, and a
+// PDI closes the innermost isolate along with anything opened inside it.
+struct BidiControlCharacterStack {
+    // Pointers to the first byte of each opening character.
+    openers: Vec<(*const u8, BidiControlCharacterKind)>,
+}
+
+impl BidiControlCharacterStack {
+    fn new() -> BidiControlCharacterStack {
+        BidiControlCharacterStack {
+            openers: Vec::new(),
+        }
+    }
+
+    // Call visit for each 0xe2 byte. Bytes which do not start a bidi control
+    // character are ignored.
+    fn visit(&mut self, c: InputPointer, diag_reporter: &dyn DiagReporter) {
+        use BidiControlCharacterKind::*;
+        let kind: BidiControlCharacterKind = match bidi_control_character_kind(c) {
+            Some(kind) => kind,
+            None => return,
+        };
+        match kind {
+            Embedding | Isolate => {
+                self.openers.push((c.0, kind));
+            }
+            PopEmbedding => {
+                if let Some(&(_, Embedding)) = self.openers.last() {
+                    self.openers.pop();
+                } else {
+                    report_unmatched_bidi_control_character(c, diag_reporter);
+                }
+            }
+            PopIsolate => {
+                match self
+                    .openers
+                    .iter()
+                    .rposition(|&(_, opener_kind)| opener_kind == Isolate)
+                {
+                    Some(isolate_index) => self.openers.truncate(isolate_index),
+                    None => report_unmatched_bidi_control_character(c, diag_reporter),
+                }
+            }
+        }
+    }
+
+    // Call finish at the end of each comment, string literal, or template.
+    fn finish(&mut self, diag_reporter: &dyn DiagReporter) {
+        for &(opener, _kind) in &self.openers {
+            report(
+                diag_reporter,
+                DiagUnclosedBidiControlCharacter {
+                    character: unsafe { SourceCodeSpan::new(opener, opener.add(3)) },
+                },
+            );
+        }
+        self.openers.clear();
+    }
+}
+
+fn report_unmatched_bidi_control_character(c: InputPointer, diag_reporter: &dyn DiagReporter) {
+    report(
+        diag_reporter,
+        DiagUnmatchedBidiControlCharacter {
+            character: unsafe { SourceCodeSpan::new(c.0, (c + 3).0) },
+        },
+    );
+}
+
 struct ParsedUnicodeEscape {
     end: *const u8,
     code_point: Option<u32>,
@@ -2741,6 +3274,32 @@ fn is_digit(c: u8) -> bool {
     matches!(c, qljs_case_decimal_digit!())
 }
 
+// Returns the code point of the well-formed "\\uXXXX" or "\\u{X...}" escape
+// sequence at input, without reporting diagnostics.
+fn peek_unicode_escape_code_point(input: InputPointer) -> Option<u32> {
+    if input[0] != b'\\' || input[1] != b'u' {
+        return None;
+    }
+    let (hex_begin, hex_end): (InputPointer, InputPointer) = if input[2] == b'{' {
+        let mut end: InputPointer = input + 3;
+        while is_hex_digit(end[0]) {
+            end += 1;
+        }
+        if end[0] != b'}' {
+            return None;
+        }
+        (input + 3, end)
+    } else {
+        let end: InputPointer = input + 6;
+        (input + 2, end)
+    };
+    let hex: &[u8] = unsafe { slice_from_begin_end(hex_begin.0, hex_end.0) };
+    if hex.is_empty() || !hex.iter().all(|c| is_hex_digit(*c)) {
+        return None;
+    }
+    u32::from_str_radix(unsafe { std::str::from_utf8_unchecked(hex) }, 16).ok()
+}
+
 fn is_hex_digit(c: u8) -> bool {
     matches!(c, qljs_case_decimal_digit!() | b'a'..=b'f' | b'A'..=b'F')
 }
@@ -2764,6 +3323,84 @@ pub fn is_identifier_character(code_point: u32, kind: IdentifierKind) -> bool {
     look_up_in_unicode_table(&IDENTIFIER_PART_CHUNK_INDEXES, code_point)
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UTF8IdentifierCharacter {
+    pub size: usize,
+    pub is_initial_identifier_character: bool,
+    // Ignores IdentifierKind::JSX's '-'.
+    pub is_identifier_character: bool,
+}
+
+// Classifies the non-ASCII character at the beginning of input without
+// decoding it into a code point. Equivalent to decode_utf_8 followed by
+// is_initial_identifier_character and is_identifier_character, but faster.
+//
+// Returns None if input does not begin with a valid multi-byte UTF-8 sequence.
+#[inline]
+pub fn look_up_identifier_character_utf_8(
+    input: PaddedStringView,
+) -> Option<UTF8IdentifierCharacter> {
+    fn is_continuation_byte(byte: u8) -> bool {
+        (byte & 0b1100_0000) == 0b1000_0000
+    }
+    fn payload(byte: u8) -> usize {
+        (byte & 0b0011_1111) as usize
+    }
+    let input_slice: &[u8] = input.slice_with_padding();
+    // Bytes past the end of input are padding, which never contains
+    // continuation bytes.
+    qljs_const_assert!(PADDED_STRING_PADDING_SIZE >= 3);
+    let c = |index: usize| unsafe { *input_slice.get_unchecked(index) };
+    if input.len() == 0 {
+        return None;
+    }
+
+    // Walk the trie one byte at a time. The leading byte and the continuation
+    // bytes (except the last) select a leaf, then the last byte selects a bit
+    // in the leaf.
+    let (size, leaf_index): (usize, UTF8TrieLeafIndexType) = match c(0) {
+        0xc2..=0xdf if is_continuation_byte(c(1)) => {
+            (2, UTF8_TRIE_BMP_LEAF_INDEXES[(c(0) & 0b0001_1111) as usize])
+        }
+        0xe0..=0xef
+            if (match c(0) {
+                0xe0 => (0xa0..=0xbf).contains(&c(1)),
+                0xed => (0x80..=0x9f).contains(&c(1)),
+                _ => is_continuation_byte(c(1)),
+            }) && is_continuation_byte(c(2)) =>
+        {
+            (
+                3,
+                UTF8_TRIE_BMP_LEAF_INDEXES[(((c(0) & 0b0000_1111) as usize) << 6) | payload(c(1))],
+            )
+        }
+        0xf0..=0xf4
+            if (match c(0) {
+                0xf0 => (0x90..=0xbf).contains(&c(1)),
+                0xf4 => (0x80..=0x8f).contains(&c(1)),
+                _ => is_continuation_byte(c(1)),
+            }) && is_continuation_byte(c(2))
+                && is_continuation_byte(c(3)) =>
+        {
+            let block_index: u8 = UTF8_TRIE_SUPPLEMENTARY_BLOCK_INDEXES
+                [(((c(0) & 0b0000_0111) as usize) << 6) | payload(c(1))];
+            (
+                4,
+                UTF8_TRIE_SUPPLEMENTARY_LEAF_INDEXES
+                    [(block_index as usize) * UTF8_TRIE_LEAF_SIZE + payload(c(2))],
+            )
+        }
+        _ => return None,
+    };
+    let leaf: &[u64; 2] = &UTF8_TRIE_LEAVES[leaf_index as usize];
+    let bit: usize = payload(c(size - 1));
+    Some(UTF8IdentifierCharacter {
+        size,
+        is_initial_identifier_character: (leaf[0] >> bit) & 1 != 0,
+        is_identifier_character: (leaf[1] >> bit) & 1 != 0,
+    })
+}
+
 fn is_non_ascii_whitespace_character(code_point: u32) -> bool {
     qljs_assert!(code_point >= 0x80);
     const NON_ASCII_WHITESPACE_CODE_POINTS: &[u16] = &[
@@ -2796,6 +3433,12 @@ fn is_non_ascii_whitespace_character(code_point: u32) -> bool {
     }
 }
 
+// Zero-width characters which are allowed in identifiers.
+fn is_zero_width_character(code_point: u32) -> bool {
+    code_point == 0x200c // U+200C Zero Width Non-Joiner (ZWNJ)
+        || code_point == 0x200d // U+200D Zero Width Joiner (ZWJ)
+}
+
 fn is_ascii_code_unit(code_unit: u8) -> bool {
     code_unit < 0x80
 }
@@ -0,0 +1,1236 @@
+// Copyright (C) 2020  Matthew "strager" Glazar
+// See end of file for extended copyright information.
+
+// This file was generated by rust/tools/generate_lex_unicode from Unicode 17.0.0.
+
+pub const SCRIPT_SET_WORDS: usize = 3;
+
+// A bit set of scripts. See SCRIPT_EXTENSION_SETS.
+pub type ScriptSet = [u64; SCRIPT_SET_WORDS];
+
+// Script_Extensions values of identifier characters, augmented as described by
+// UTS #39 section 5.1 (Mixed-Script Detection).
+#[rustfmt::skip]
+pub const SCRIPT_EXTENSION_SETS: [ScriptSet; 243] = [
+    [0x0000000000000001, 0x0000000000000000, 0x0000000000000000], // Latin
+    [0x002800000800000d, 0x0040001010024680, 0x0000700400004000], // Avestan Carian Coptic Duployan Elbasan Georgian Glagolitic Gothic Greek Gunjala_Gondi Han Han_with_Bopomofo Japanese Korean Latin Lydian Mahajani Old_Permic Shavian
+    [0x0200000000806011, 0x0000000000000000, 0x0000002000000000], // Bengali Cyrillic Devanagari Latin Lisu Thai Toto
+    [0x0000000000000003, 0x0000000000000000, 0x0000100000000000], // Bopomofo Han_with_Bopomofo Latin
+    [0x0200000000000001, 0x0000000000000000, 0x0000000000000000], // Latin Lisu
+    [0x000002004000001d, 0x0000000000000400, 0x0000000000000400], // Cherokee Coptic Cyrillic Greek Latin Old_Permic Sunuwar Tai_Le
+    [0x0000020040000015, 0x0000000000110000, 0x0000000000000400], // Cherokee Cyrillic Greek Latin Osage Sunuwar Tai_Le Todhri
+    [0x0010000040000011, 0x0000000000000000, 0x0000000000000000], // Cherokee Cyrillic Latin Tifinagh
+    [0x0008000000800101, 0x0000000000000000, 0x0000000000000400], // Glagolitic Latin Sunuwar Syriac Thai
+    [0x001000004000011d, 0x0000000000150200, 0x0000000000000000], // Caucasian_Albanian Cherokee Coptic Cyrillic Gothic Greek Latin Osage Syriac Tifinagh Todhri
+    [0x0088000000000009, 0x0000000000020200, 0x0000200000000000], // Coptic Elbasan Glagolitic Gothic Japanese Katakana Latin
+    [0x0010000000000015, 0x0000000000000400, 0x0000000000000000], // Cyrillic Greek Latin Old_Permic Tifinagh
+    [0x0010020000000149, 0x0000000000100400, 0x0000000400000000], // Coptic Duployan Hebrew Latin Old_Permic Syriac Tai_Le Tifinagh Todhri
+    [0x0010020000000175, 0x0000000000000600, 0x0000000400000000], // Armenian Cyrillic Duployan Gothic Greek Hebrew Latin Old_Permic Syriac Tai_Le Tifinagh
+    [0x0010000000000001, 0x0000000000000000, 0x0000000000000000], // Latin Tifinagh
+    [0x0000000000000101, 0x0000000000000000, 0x0000000400000000], // Duployan Latin Syriac
+    [0x0000000040000011, 0x0000000000010000, 0x0000000000000000], // Cherokee Cyrillic Latin Osage
+    [0x0000020040000001, 0x0000000000000000, 0x0000000000000000], // Cherokee Latin Tai_Le
+    [0x0000000000000001, 0x0000000000000000, 0x0000000000000400], // Latin Sunuwar
+    [0x0000000020000001, 0x0000000000000000, 0x0000000000000000], // Ethiopic Latin
+    [0x0000000000000011, 0x0000000000100000, 0x0000000000000000], // Cyrillic Latin Todhri
+    [0x0000000000000005, 0x0000000000100400, 0x0000000000000000], // Greek Latin Old_Permic Todhri
+    [0x0090000040000101, 0x0000000000000000, 0x0000200400000000], // Cherokee Duployan Japanese Katakana Latin Syriac Tifinagh
+    [0x0000000040000101, 0x0000000000000000, 0x0000000400000000], // Cherokee Duployan Latin Syriac
+    [0x0000000000000101, 0x0000000000000000, 0x0000000000000000], // Latin Syriac
+    [0x0000000000000101, 0x0000000000000000, 0x0000000000000400], // Latin Sunuwar Syriac
+    [0x0000000040000101, 0x0000000000000000, 0x0000000000000000], // Cherokee Latin Syriac
+    [0x0000000040800101, 0x0000000000040200, 0x0000000000000400], // Caucasian_Albanian Cherokee Gothic Latin Sunuwar Syriac Thai
+    [0x0000000000000004, 0x0000000000000000, 0x0000000000000000], // Greek
+    [0x0000000000000001, 0x0000000000010000, 0x0000000000000000], // Latin Osage
+    [0x0000000000000001, 0x0000000000140000, 0x0000000000000000], // Caucasian_Albanian Latin Todhri
+    [0x000000000000000c, 0x0000000000000000, 0x0000000000000000], // Coptic Greek
+    [0x0000000000000008, 0x0000000000000000, 0x0000000000000000], // Coptic
+    [0x0000000000000010, 0x0000000000000000, 0x0000000000000000], // Cyrillic
+    [0x0000000000000010, 0x0000000000000400, 0x0000000000000000], // Cyrillic Old_Permic
+    [0x0008000000000010, 0x0000000000000000, 0x0000000000000000], // Cyrillic Glagolitic
+    [0x0000000000000011, 0x0000000000000000, 0x0000000000000000], // Cyrillic Latin
+    [0x0000000000000020, 0x0000000000000000, 0x0000000000000000], // Armenian
+    [0x0000000000000040, 0x0000000000000000, 0x0000000000000000], // Hebrew
+    [0x0000000000000080, 0x0000000000000000, 0x0000000000000000], // Arabic
+    [0x0000000000001180, 0x0000c48800000000, 0x0000080000000000], // Adlam Arabic Hanifi_Rohingya Mandaic Manichaean Old_Uyghur Psalter_Pahlavi Sogdian Syriac
+    [0x0000000000000180, 0x0000000000000000, 0x0000000000000000], // Arabic Syriac
+    [0x0000000000000280, 0x0000100000000000, 0x0000000000000000], // Arabic Thaana Yezidi
+    [0x0000000000000100, 0x0000000000000000, 0x0000000000000000], // Syriac
+    [0x0000000000000200, 0x0000000000000000, 0x0000000000000000], // Thaana
+    [0x0000000000000400, 0x0000000000000000, 0x0000000000000000], // Nko
+    [0x0000000000000800, 0x0000000000000000, 0x0000000000000000], // Samaritan
+    [0x0000000000001000, 0x0000000000000000, 0x0000000000000000], // Mandaic
+    [0x0000000000002000, 0x0000000000000000, 0x0000000000000000], // Devanagari
+    [0x00000000003fe001, 0x6880000000000000, 0x0000000000000040], // Bengali Devanagari Grantha Gujarati Gurmukhi Kannada Latin Malayalam Nandinagari Newa Oriya Sharada Tamil Telugu Tirhuta
+    [0x00000000003fe001, 0x6800000000000000, 0x0000000000000000], // Bengali Devanagari Grantha Gujarati Gurmukhi Kannada Latin Malayalam Newa Oriya Tamil Telugu Tirhuta
+    [0x0000000000002000, 0x0048000000000000, 0x0000000000000008], // Devanagari Dogra Kaithi Mahajani
+    [0x0000000000004000, 0x0000000000000000, 0x0000000000000000], // Bengali
+    [0x1000000000004000, 0x0020000000000000, 0x0000000000000000], // Bengali Chakma Syloti_Nagri
+    [0x0000000000008000, 0x0000000000000000, 0x0000000000000000], // Gurmukhi
+    [0x0000000000008000, 0x0200000000000000, 0x0000000000000000], // Gurmukhi Multani
+    [0x0000000000010000, 0x0000000000000000, 0x0000000000000000], // Gujarati
+    [0x0000000000010000, 0x0100000000000000, 0x0000000000000000], // Gujarati Khojki
+    [0x0000000000020000, 0x0000000000000000, 0x0000000000000000], // Oriya
+    [0x0000000000040000, 0x0000000000000000, 0x0000000000000000], // Tamil
+    [0x0000000000040000, 0x0800000000000000, 0x0000000000000000], // Grantha Tamil
+    [0x0000000000080000, 0x0000000000000000, 0x0000000000000000], // Telugu
+    [0x0000000000100000, 0x0000000000000000, 0x0000000000000000], // Kannada
+    [0x0000000000100000, 0x1000000000000000, 0x0000000000000040], // Kannada Nandinagari Tulu_Tigalari
+    [0x0000000000200000, 0x0000000000000000, 0x0000000000000000], // Malayalam
+    [0x0000000000400000, 0x0000000000000000, 0x0000000000000000], // Sinhala
+    [0x0000000000800000, 0x0000000000000000, 0x0000000000000000], // Thai
+    [0x0000000001000000, 0x0000000000000000, 0x0000000000000000], // Lao
+    [0x0000000002000000, 0x0000000000000000, 0x0000000000000000], // Tibetan
+    [0x0000000004000000, 0x0000000000000000, 0x0000000000000000], // Myanmar
+    [0x0000020004000000, 0x0020000000000000, 0x0000000000000000], // Chakma Myanmar Tai_Le
+    [0x0000000008000000, 0x0000000000000000, 0x0000000000000000], // Georgian
+    [0x0000000010000000, 0x0000000000000000, 0x0000400000000000], // Hangul Korean
+    [0x0000000020000000, 0x0000000000000000, 0x0000000000000000], // Ethiopic
+    [0x0000000040000000, 0x0000000000000000, 0x0000000000000000], // Cherokee
+    [0x0000000080000000, 0x0000000000000000, 0x0000000000000000], // Canadian_Aboriginal
+    [0x0000000100000000, 0x0000000000000000, 0x0000000000000000], // Ogham
+    [0x0000000200000000, 0x0000000000000000, 0x0000000000000000], // Runic
+    [0x0000000400000000, 0x0000000000000000, 0x0000000000000000], // Tagalog
+    [0x0000000800000000, 0x0000000000000000, 0x0000000000000000], // Hanunoo
+    [0x0000001000000000, 0x0000000000000000, 0x0000000000000000], // Buhid
+    [0x0000002000000000, 0x0000000000000000, 0x0000000000000000], // Tagbanwa
+    [0x0000004000000000, 0x0000000000000000, 0x0000000000000000], // Khmer
+    [0x0000008000000000, 0x0000000000000000, 0x0000000000000000], // Mongolian
+    [0x0000010000000000, 0x0000000000000000, 0x0000000000000000], // Limbu
+    [0x0000020000000000, 0x0000000000000000, 0x0000000000000000], // Tai_Le
+    [0x0000040000000000, 0x0000000000000000, 0x0000000000000000], // New_Tai_Lue
+    [0x0000080000000000, 0x0000000000000000, 0x0000000000000000], // Buginese
+    [0x0000100000000000, 0x0000000000000000, 0x0000000000000000], // Tai_Tham
+    [0x0000200000000000, 0x0000000000000000, 0x0000000000000000], // Balinese
+    [0x0000400000000000, 0x0000000000000000, 0x0000000000000000], // Sundanese
+    [0x0000800000000000, 0x0000000000000000, 0x0000000000000000], // Batak
+    [0x0001000000000000, 0x0000000000000000, 0x0000000000000000], // Lepcha
+    [0x0002000000000000, 0x0000000000000000, 0x0000000000000000], // Ol_Chiki
+    [0x0000000000106000, 0x0800000000000000, 0x0000000000000000], // Bengali Devanagari Grantha Kannada
+    [0x0000000000086000, 0x6000000000000000, 0x0000000000000000], // Bengali Devanagari Newa Telugu Tirhuta
+    [0x0000000000086000, 0x0000000000000000, 0x0000000000000000], // Bengali Devanagari Telugu
+    [0x0000000000002000, 0x2080000000000000, 0x0000000000000000], // Devanagari Newa Sharada
+    [0x0000000000086000, 0x2000000000000000, 0x0000000000000000], // Bengali Devanagari Newa Telugu
+    [0x0000000000002000, 0x0080000000000000, 0x0000000000000000], // Devanagari Sharada
+    [0x00000000003e2000, 0x0000000000000000, 0x0000000000000000], // Devanagari Kannada Malayalam Oriya Tamil Telugu
+    [0x0000000000006000, 0x0000000000000000, 0x0000000000000000], // Bengali Devanagari
+    [0x0000000000002000, 0x6000000000000000, 0x0000000000000000], // Devanagari Newa Tirhuta
+    [0x0000000000002000, 0x2000000000000000, 0x0000000000000040], // Devanagari Nandinagari Newa
+    [0x0000000000006000, 0x0080000000000000, 0x0000000000000000], // Bengali Devanagari Sharada
+    [0x0000000000002000, 0x2000000000000000, 0x0000000000000000], // Devanagari Newa
+    [0x0000000000006000, 0x2080000000000000, 0x0000000000000000], // Bengali Devanagari Newa Sharada
+    [0x00000000007a6000, 0x5800000000000000, 0x0000000000000040], // Bengali Devanagari Grantha Kannada Malayalam Nandinagari Oriya Sinhala Telugu Tirhuta Tulu_Tigalari
+    [0x0000000000002000, 0x0800000000000000, 0x0000000000000000], // Devanagari Grantha
+    [0x0000000000102000, 0x1800000000000000, 0x0000000000000000], // Devanagari Grantha Kannada Tulu_Tigalari
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000040], // Nandinagari
+    [0x0000000000000111, 0x0000000000000000, 0x0000000000000000], // Cyrillic Latin Syriac
+    [0x0000000000002001, 0x0800000000000000, 0x0000000000000000], // Devanagari Grantha Latin
+    [0x0008000000000000, 0x0000000000000000, 0x0000000000000000], // Glagolitic
+    [0x0010000000000000, 0x0000000000000000, 0x0000000000000000], // Tifinagh
+    [0x0020000000000000, 0x0000000000000000, 0x0000700000000000], // Han Han_with_Bopomofo Japanese Korean
+    [0x0020000000000002, 0x0000000000000000, 0x0000700000000000], // Bopomofo Han Han_with_Bopomofo Japanese Korean
+    [0x00c0000000000000, 0x0000000000000000, 0x0000200000000000], // Hiragana Japanese Katakana
+    [0x00e0000000000000, 0x0000000000000000, 0x0000700000000000], // Han Han_with_Bopomofo Hiragana Japanese Katakana Korean
+    [0x0040000000000000, 0x0000000000000000, 0x0000200000000000], // Hiragana Japanese
+    [0x0080000000000000, 0x0000000000000000, 0x0000200000000000], // Japanese Katakana
+    [0x01e0000010000002, 0x0000000000000000, 0x0000700000000000], // Bopomofo Han Han_with_Bopomofo Hangul Hiragana Japanese Katakana Korean Yi
+    [0x0000000000000002, 0x0000000000000000, 0x0000100000000000], // Bopomofo Han_with_Bopomofo
+    [0x0100000000000000, 0x0000000000000000, 0x0000000000000000], // Yi
+    [0x0200000000000000, 0x0000000000000000, 0x0000000000000000], // Lisu
+    [0x0400000000000000, 0x0000000000000000, 0x0000000000000000], // Vai
+    [0x0800000000000000, 0x0000000000000000, 0x0000000000000000], // Bamum
+    [0x1000000000000000, 0x0000000000000000, 0x0000000000000000], // Syloti_Nagri
+    [0x2000000000000000, 0x0000000000000000, 0x0000000000000000], // Phags_Pa
+    [0x4000000000000000, 0x0000000000000000, 0x0000000000000000], // Saurashtra
+    [0x0000000000006000, 0x1000000000000000, 0x0000000000000000], // Bengali Devanagari Tulu_Tigalari
+    [0x0000000000042000, 0x0000000000000000, 0x0000000000000000], // Devanagari Tamil
+    [0x8000000000000000, 0x0000000000000000, 0x0000000000000000], // Kayah_Li
+    [0x0000000000000000, 0x0000000000000001, 0x0000000000000000], // Rejang
+    [0x0000000000000000, 0x0000000000000002, 0x0000000000000000], // Javanese
+    [0x0000080000000000, 0x0000000000000002, 0x0000000000000000], // Buginese Javanese
+    [0x0000000000000000, 0x0000000000000004, 0x0000000000000000], // Cham
+    [0x0000000000000000, 0x0000000000000008, 0x0000000000000000], // Tai_Viet
+    [0x0000000000000000, 0x0000000000000010, 0x0000000000000000], // Meetei_Mayek
+    [0x0000000000000280, 0x0000000000000000, 0x0000000000000000], // Arabic Thaana
+    [0x0000000000000000, 0x0000000000000020, 0x0000000000000000], // Linear_B
+    [0x0000000000000000, 0x0000000000000040, 0x0000000000000000], // Lycian
+    [0x0000000000000000, 0x0000000000000080, 0x0000000000000000], // Carian
+    [0x0000000000000088, 0x0000000000000000, 0x0000000000000000], // Arabic Coptic
+    [0x0000000000000000, 0x0000000000000100, 0x0000000000000000], // Old_Italic
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000000000], // Gothic
+    [0x0000000000000000, 0x0000000000000400, 0x0000000000000000], // Old_Permic
+    [0x0000000000000000, 0x0000000000000800, 0x0000000000000000], // Ugaritic
+    [0x0000000000000000, 0x0000000000001000, 0x0000000000000000], // Old_Persian
+    [0x0000000000000000, 0x0000000000002000, 0x0000000000000000], // Deseret
+    [0x0000000000000000, 0x0000000000004000, 0x0000000000000000], // Shavian
+    [0x0000000000000000, 0x0000000000008000, 0x0000000000000000], // Osmanya
+    [0x0000000000000000, 0x0000000000010000, 0x0000000000000000], // Osage
+    [0x0000000000000000, 0x0000000000020000, 0x0000000000000000], // Elbasan
+    [0x0000000000000000, 0x0000000000040000, 0x0000000000000000], // Caucasian_Albanian
+    [0x0000000000000000, 0x0000000000080000, 0x0000000000000000], // Vithkuqi
+    [0x0000000000000000, 0x0000000000100000, 0x0000000000000000], // Todhri
+    [0x0000000000000000, 0x0000000000200000, 0x0000000000000000], // Linear_A
+    [0x0000000000000000, 0x0000000000400000, 0x0000000000000000], // Cypriot
+    [0x0000000000000000, 0x0000000000800000, 0x0000000000000000], // Imperial_Aramaic
+    [0x0000000000000000, 0x0000000001000000, 0x0000000000000000], // Palmyrene
+    [0x0000000000000000, 0x0000000002000000, 0x0000000000000000], // Nabataean
+    [0x0000000000000000, 0x0000000004000000, 0x0000000000000000], // Hatran
+    [0x0000000000000000, 0x0000000008000000, 0x0000000000000000], // Phoenician
+    [0x0000000000000000, 0x0000000010000000, 0x0000000000000000], // Lydian
+    [0x0000000000000000, 0x0000000020000000, 0x0000000000000000], // Sidetic
+    [0x0000000000000000, 0x0000000040000000, 0x0000000000000000], // Meroitic_Hieroglyphs
+    [0x0000000000000000, 0x0000000080000000, 0x0000000000000000], // Meroitic_Cursive
+    [0x0000000000000000, 0x0000000100000000, 0x0000000000000000], // Kharoshthi
+    [0x0000000000000000, 0x0000000200000000, 0x0000000000000000], // Old_South_Arabian
+    [0x0000000000000000, 0x0000000400000000, 0x0000000000000000], // Old_North_Arabian
+    [0x0000000000000000, 0x0000000800000000, 0x0000000000000000], // Manichaean
+    [0x0000000000000000, 0x0000001000000000, 0x0000000000000000], // Avestan
+    [0x0000000000000000, 0x0000002000000000, 0x0000000000000000], // Inscriptional_Parthian
+    [0x0000000000000000, 0x0000004000000000, 0x0000000000000000], // Inscriptional_Pahlavi
+    [0x0000000000000000, 0x0000008000000000, 0x0000000000000000], // Psalter_Pahlavi
+    [0x0000000000000000, 0x0000010000000000, 0x0000000000000000], // Old_Turkic
+    [0x0000000000000000, 0x0000020000000000, 0x0000000000000000], // Old_Hungarian
+    [0x0000000000000000, 0x0000040000000000, 0x0000000000000000], // Hanifi_Rohingya
+    [0x0000000000000000, 0x0000080000000000, 0x0000000000000000], // Garay
+    [0x0000000000000000, 0x0000100000000000, 0x0000000000000000], // Yezidi
+    [0x0000000000000000, 0x0000200000000000, 0x0000000000000000], // Old_Sogdian
+    [0x0000000000000000, 0x0000400000000000, 0x0000000000000000], // Sogdian
+    [0x0000000000000000, 0x0000800000000000, 0x0000000000000000], // Old_Uyghur
+    [0x0000000000000000, 0x0001000000000000, 0x0000000000000000], // Chorasmian
+    [0x0000000000000000, 0x0002000000000000, 0x0000000000000000], // Elymaic
+    [0x0000000000000000, 0x0004000000000000, 0x0000000000000000], // Brahmi
+    [0x0000000000000000, 0x0008000000000000, 0x0000000000000000], // Kaithi
+    [0x0000000000000000, 0x0010000000000000, 0x0000000000000000], // Sora_Sompeng
+    [0x0000000000000000, 0x0020000000000000, 0x0000000000000000], // Chakma
+    [0x0000000000000000, 0x0040000000000000, 0x0000000000000000], // Mahajani
+    [0x0000000000000000, 0x0080000000000000, 0x0000000000000000], // Sharada
+    [0x0000000000000000, 0x0100000000000000, 0x0000000000000000], // Khojki
+    [0x0000000000000000, 0x0200000000000000, 0x0000000000000000], // Multani
+    [0x0000000000000000, 0x0400000000000000, 0x0000000000000000], // Khudawadi
+    [0x0000000000000000, 0x0800000000000000, 0x0000000000000000], // Grantha
+    [0x0000000000000000, 0x1000000000000000, 0x0000000000000000], // Tulu_Tigalari
+    [0x0000000000000000, 0x2000000000000000, 0x0000000000000000], // Newa
+    [0x0000000000000000, 0x4000000000000000, 0x0000000000000000], // Tirhuta
+    [0x0000000000000000, 0x8000000000000000, 0x0000000000000000], // Siddham
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000001], // Modi
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000002], // Takri
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000004], // Ahom
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000008], // Dogra
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000010], // Warang_Citi
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000020], // Dives_Akuru
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000080], // Zanabazar_Square
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000100], // Soyombo
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000200], // Pau_Cin_Hau
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000400], // Sunuwar
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000800], // Bhaiksuki
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000001000], // Marchen
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000002000], // Masaram_Gondi
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000004000], // Gunjala_Gondi
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000008000], // Tolong_Siki
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000010000], // Makasar
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000020000], // Kawi
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000040000], // Cuneiform
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000080000], // Cypro_Minoan
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000100000], // Egyptian_Hieroglyphs
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000200000], // Anatolian_Hieroglyphs
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000400000], // Gurung_Khema
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000800000], // Mro
+    [0x0000000000000000, 0x0000000000000000, 0x0000000001000000], // Tangsa
+    [0x0000000000000000, 0x0000000000000000, 0x0000000002000000], // Bassa_Vah
+    [0x0000000000000000, 0x0000000000000000, 0x0000000004000000], // Pahawh_Hmong
+    [0x0000000000000000, 0x0000000000000000, 0x0000000008000000], // Kirat_Rai
+    [0x0000000000000000, 0x0000000000000000, 0x0000000010000000], // Medefaidrin
+    [0x0000000000000000, 0x0000000000000000, 0x0000000020000000], // Beria_Erfe
+    [0x0000000000000000, 0x0000000000000000, 0x0000000040000000], // Miao
+    [0x0000000000000000, 0x0000000000000000, 0x0000000080000000], // Tangut
+    [0x0000000000000000, 0x0000000000000000, 0x0000000100000000], // Nushu
+    [0x0000000000000000, 0x0000000000000000, 0x0000000200000000], // Khitan_Small_Script
+    [0x0000000000000000, 0x0000000000000000, 0x0000000400000000], // Duployan
+    [0x0000000000000000, 0x0000000000000000, 0x0000000800000000], // SignWriting
+    [0x0000000000000000, 0x0000000000000000, 0x0000001000000000], // Nyiakeng_Puachue_Hmong
+    [0x0000000000000000, 0x0000000000000000, 0x0000002000000000], // Toto
+    [0x0000000000000000, 0x0000000000000000, 0x0000004000000000], // Wancho
+    [0x0000000000000000, 0x0000000000000000, 0x0000008000000000], // Nag_Mundari
+    [0x0000000000000000, 0x0000000000000000, 0x0000010000000000], // Ol_Onal
+    [0x0000000000000000, 0x0000000000000000, 0x0000020000000000], // Tai_Yo
+    [0x0000000000000000, 0x0000000000000000, 0x0000040000000000], // Mende_Kikakui
+    [0x0000000000000000, 0x0000000000000000, 0x0000080000000000], // Adlam
+];
+
+// Sorted, non-overlapping (first, last, SCRIPT_EXTENSION_SETS index) tuples.
+//
+// Identifier characters not listed here have a Script_Extensions value of
+// Common or Inherited, so they do not affect mixed-script detection.
+#[rustfmt::skip]
+pub const SCRIPT_EXTENSION_RANGES: [(u32, u32, u16); 887] = [
+    (0x0041, 0x005a, 0),
+    (0x0061, 0x007a, 0),
+    (0x00aa, 0x00aa, 0),
+    (0x00b7, 0x00b7, 1),
+    (0x00ba, 0x00ba, 0),
+    (0x00c0, 0x00d6, 0),
+    (0x00d8, 0x00f6, 0),
+    (0x00f8, 0x02b8, 0),
+    (0x02bc, 0x02bc, 2),
+    (0x02c7, 0x02c7, 3),
+    (0x02c9, 0x02cb, 3),
+    (0x02cd, 0x02cd, 4),
+    (0x02e0, 0x02e4, 0),
+    (0x0300, 0x0300, 5),
+    (0x0301, 0x0301, 6),
+    (0x0302, 0x0302, 7),
+    (0x0303, 0x0303, 8),
+    (0x0304, 0x0304, 9),
+    (0x0305, 0x0305, 10),
+    (0x0306, 0x0306, 11),
+    (0x0307, 0x0307, 12),
+    (0x0308, 0x0308, 13),
+    (0x0309, 0x0309, 14),
+    (0x030a, 0x030a, 15),
+    (0x030b, 0x030b, 16),
+    (0x030c, 0x030c, 17),
+    (0x030d, 0x030d, 18),
+    (0x030e, 0x030e, 19),
+    (0x0310, 0x0310, 18),
+    (0x0311, 0x0311, 20),
+    (0x0313, 0x0313, 21),
+    (0x0323, 0x0323, 22),
+    (0x0324, 0x0324, 23),
+    (0x0325, 0x0325, 24),
+    (0x032d, 0x032d, 25),
+    (0x032e, 0x032e, 24),
+    (0x0330, 0x0330, 26),
+    (0x0331, 0x0331, 27),
+    (0x0342, 0x0342, 28),
+    (0x0345, 0x0345, 28),
+    (0x0358, 0x0358, 29),
+    (0x035e, 0x035e, 30),
+    (0x0363, 0x036f, 0),
+    (0x0370, 0x0373, 28),
+    (0x0374, 0x0374, 31),
+    (0x0376, 0x0377, 28),
+    (0x037a, 0x037d, 28),
+    (0x037f, 0x037f, 28),
+    (0x0386, 0x0386, 28),
+    (0x0388, 0x038a, 28),
+    (0x038c, 0x038c, 28),
+    (0x038e, 0x03a1, 28),
+    (0x03a3, 0x03e1, 28),
+    (0x03e2, 0x03ef, 32),
+    (0x03f0, 0x03f5, 28),
+    (0x03f7, 0x03ff, 28),
+    (0x0400, 0x0481, 33),
+    (0x0483, 0x0483, 34),
+    (0x0484, 0x0484, 35),
+    (0x0485, 0x0486, 36),
+    (0x0487, 0x0487, 35),
+    (0x048a, 0x052f, 33),
+    (0x0531, 0x0556, 37),
+    (0x0559, 0x0559, 37),
+    (0x0560, 0x0588, 37),
+    (0x0591, 0x05bd, 38),
+    (0x05bf, 0x05bf, 38),
+    (0x05c1, 0x05c2, 38),
+    (0x05c4, 0x05c5, 38),
+    (0x05c7, 0x05c7, 38),
+    (0x05d0, 0x05ea, 38),
+    (0x05ef, 0x05f2, 38),
+    (0x0610, 0x061a, 39),
+    (0x0620, 0x063f, 39),
+    (0x0640, 0x0640, 40),
+    (0x0641, 0x064a, 39),
+    (0x064b, 0x0655, 41),
+    (0x0656, 0x065f, 39),
+    (0x0660, 0x0669, 42),
+    (0x066e, 0x066f, 39),
+    (0x0670, 0x0670, 41),
+    (0x0671, 0x06d3, 39),
+    (0x06d5, 0x06dc, 39),
+    (0x06df, 0x06e8, 39),
+    (0x06ea, 0x06fc, 39),
+    (0x06ff, 0x06ff, 39),
+    (0x0710, 0x074a, 43),
+    (0x074d, 0x074f, 43),
+    (0x0750, 0x077f, 39),
+    (0x0780, 0x07b1, 44),
+    (0x07c0, 0x07f5, 45),
+    (0x07fa, 0x07fa, 45),
+    (0x07fd, 0x07fd, 45),
+    (0x0800, 0x082d, 46),
+    (0x0840, 0x085b, 47),
+    (0x0860, 0x086a, 43),
+    (0x0870, 0x0887, 39),
+    (0x0889, 0x088f, 39),
+    (0x0897, 0x08e1, 39),
+    (0x08e3, 0x08ff, 39),
+    (0x0900, 0x0950, 48),
+    (0x0951, 0x0951, 49),
+    (0x0952, 0x0952, 50),
+    (0x0955, 0x0963, 48),
+    (0x0966, 0x096f, 51),
+    (0x0971, 0x097f, 48),
+    (0x0980, 0x0983, 52),
+    (0x0985, 0x098c, 52),
+    (0x098f, 0x0990, 52),
+    (0x0993, 0x09a8, 52),
+    (0x09aa, 0x09b0, 52),
+    (0x09b2, 0x09b2, 52),
+    (0x09b6, 0x09b9, 52),
+    (0x09bc, 0x09c4, 52),
+    (0x09c7, 0x09c8, 52),
+    (0x09cb, 0x09ce, 52),
+    (0x09d7, 0x09d7, 52),
+    (0x09dc, 0x09dd, 52),
+    (0x09df, 0x09e3, 52),
+    (0x09e6, 0x09ef, 53),
+    (0x09f0, 0x09f1, 52),
+    (0x09fc, 0x09fc, 52),
+    (0x09fe, 0x09fe, 52),
+    (0x0a01, 0x0a03, 54),
+    (0x0a05, 0x0a0a, 54),
+    (0x0a0f, 0x0a10, 54),
+    (0x0a13, 0x0a28, 54),
+    (0x0a2a, 0x0a30, 54),
+    (0x0a32, 0x0a33, 54),
+    (0x0a35, 0x0a36, 54),
+    (0x0a38, 0x0a39, 54),
+    (0x0a3c, 0x0a3c, 54),
+    (0x0a3e, 0x0a42, 54),
+    (0x0a47, 0x0a48, 54),
+    (0x0a4b, 0x0a4d, 54),
+    (0x0a51, 0x0a51, 54),
+    (0x0a59, 0x0a5c, 54),
+    (0x0a5e, 0x0a5e, 54),
+    (0x0a66, 0x0a6f, 55),
+    (0x0a70, 0x0a75, 54),
+    (0x0a81, 0x0a83, 56),
+    (0x0a85, 0x0a8d, 56),
+    (0x0a8f, 0x0a91, 56),
+    (0x0a93, 0x0aa8, 56),
+    (0x0aaa, 0x0ab0, 56),
+    (0x0ab2, 0x0ab3, 56),
+    (0x0ab5, 0x0ab9, 56),
+    (0x0abc, 0x0ac5, 56),
+    (0x0ac7, 0x0ac9, 56),
+    (0x0acb, 0x0acd, 56),
+    (0x0ad0, 0x0ad0, 56),
+    (0x0ae0, 0x0ae3, 56),
+    (0x0ae6, 0x0aef, 57),
+    (0x0af9, 0x0aff, 56),
+    (0x0b01, 0x0b03, 58),
+    (0x0b05, 0x0b0c, 58),
+    (0x0b0f, 0x0b10, 58),
+    (0x0b13, 0x0b28, 58),
+    (0x0b2a, 0x0b30, 58),
+    (0x0b32, 0x0b33, 58),
+    (0x0b35, 0x0b39, 58),
+    (0x0b3c, 0x0b44, 58),
+    (0x0b47, 0x0b48, 58),
+    (0x0b4b, 0x0b4d, 58),
+    (0x0b55, 0x0b57, 58),
+    (0x0b5c, 0x0b5d, 58),
+    (0x0b5f, 0x0b63, 58),
+    (0x0b66, 0x0b6f, 58),
+    (0x0b71, 0x0b71, 58),
+    (0x0b82, 0x0b83, 59),
+    (0x0b85, 0x0b8a, 59),
+    (0x0b8e, 0x0b90, 59),
+    (0x0b92, 0x0b95, 59),
+    (0x0b99, 0x0b9a, 59),
+    (0x0b9c, 0x0b9c, 59),
+    (0x0b9e, 0x0b9f, 59),
+    (0x0ba3, 0x0ba4, 59),
+    (0x0ba8, 0x0baa, 59),
+    (0x0bae, 0x0bb9, 59),
+    (0x0bbe, 0x0bc2, 59),
+    (0x0bc6, 0x0bc8, 59),
+    (0x0bca, 0x0bcd, 59),
+    (0x0bd0, 0x0bd0, 59),
+    (0x0bd7, 0x0bd7, 59),
+    (0x0be6, 0x0bef, 60),
+    (0x0c00, 0x0c0c, 61),
+    (0x0c0e, 0x0c10, 61),
+    (0x0c12, 0x0c28, 61),
+    (0x0c2a, 0x0c39, 61),
+    (0x0c3c, 0x0c44, 61),
+    (0x0c46, 0x0c48, 61),
+    (0x0c4a, 0x0c4d, 61),
+    (0x0c55, 0x0c56, 61),
+    (0x0c58, 0x0c5a, 61),
+    (0x0c5c, 0x0c5d, 61),
+    (0x0c60, 0x0c63, 61),
+    (0x0c66, 0x0c6f, 61),
+    (0x0c80, 0x0c83, 62),
+    (0x0c85, 0x0c8c, 62),
+    (0x0c8e, 0x0c90, 62),
+    (0x0c92, 0x0ca8, 62),
+    (0x0caa, 0x0cb3, 62),
+    (0x0cb5, 0x0cb9, 62),
+    (0x0cbc, 0x0cc4, 62),
+    (0x0cc6, 0x0cc8, 62),
+    (0x0cca, 0x0ccd, 62),
+    (0x0cd5, 0x0cd6, 62),
+    (0x0cdc, 0x0cde, 62),
+    (0x0ce0, 0x0ce3, 62),
+    (0x0ce6, 0x0cef, 63),
+    (0x0cf1, 0x0cf3, 62),
+    (0x0d00, 0x0d0c, 64),
+    (0x0d0e, 0x0d10, 64),
+    (0x0d12, 0x0d44, 64),
+    (0x0d46, 0x0d48, 64),
+    (0x0d4a, 0x0d4e, 64),
+    (0x0d54, 0x0d57, 64),
+    (0x0d5f, 0x0d63, 64),
+    (0x0d66, 0x0d6f, 64),
+    (0x0d7a, 0x0d7f, 64),
+    (0x0d81, 0x0d83, 65),
+    (0x0d85, 0x0d96, 65),
+    (0x0d9a, 0x0db1, 65),
+    (0x0db3, 0x0dbb, 65),
+    (0x0dbd, 0x0dbd, 65),
+    (0x0dc0, 0x0dc6, 65),
+    (0x0dca, 0x0dca, 65),
+    (0x0dcf, 0x0dd4, 65),
+    (0x0dd6, 0x0dd6, 65),
+    (0x0dd8, 0x0ddf, 65),
+    (0x0de6, 0x0def, 65),
+    (0x0df2, 0x0df3, 65),
+    (0x0e01, 0x0e3a, 66),
+    (0x0e40, 0x0e4e, 66),
+    (0x0e50, 0x0e59, 66),
+    (0x0e81, 0x0e82, 67),
+    (0x0e84, 0x0e84, 67),
+    (0x0e86, 0x0e8a, 67),
+    (0x0e8c, 0x0ea3, 67),
+    (0x0ea5, 0x0ea5, 67),
+    (0x0ea7, 0x0ebd, 67),
+    (0x0ec0, 0x0ec4, 67),
+    (0x0ec6, 0x0ec6, 67),
+    (0x0ec8, 0x0ece, 67),
+    (0x0ed0, 0x0ed9, 67),
+    (0x0edc, 0x0edf, 67),
+    (0x0f00, 0x0f00, 68),
+    (0x0f18, 0x0f19, 68),
+    (0x0f20, 0x0f29, 68),
+    (0x0f35, 0x0f35, 68),
+    (0x0f37, 0x0f37, 68),
+    (0x0f39, 0x0f39, 68),
+    (0x0f3e, 0x0f47, 68),
+    (0x0f49, 0x0f6c, 68),
+    (0x0f71, 0x0f84, 68),
+    (0x0f86, 0x0f97, 68),
+    (0x0f99, 0x0fbc, 68),
+    (0x0fc6, 0x0fc6, 68),
+    (0x1000, 0x103f, 69),
+    (0x1040, 0x1049, 70),
+    (0x1050, 0x109d, 69),
+    (0x10a0, 0x10c5, 71),
+    (0x10c7, 0x10c7, 71),
+    (0x10cd, 0x10cd, 71),
+    (0x10d0, 0x10fa, 71),
+    (0x10fc, 0x10ff, 71),
+    (0x1100, 0x11ff, 72),
+    (0x1200, 0x1248, 73),
+    (0x124a, 0x124d, 73),
+    (0x1250, 0x1256, 73),
+    (0x1258, 0x1258, 73),
+    (0x125a, 0x125d, 73),
+    (0x1260, 0x1288, 73),
+    (0x128a, 0x128d, 73),
+    (0x1290, 0x12b0, 73),
+    (0x12b2, 0x12b5, 73),
+    (0x12b8, 0x12be, 73),
+    (0x12c0, 0x12c0, 73),
+    (0x12c2, 0x12c5, 73),
+    (0x12c8, 0x12d6, 73),
+    (0x12d8, 0x1310, 73),
+    (0x1312, 0x1315, 73),
+    (0x1318, 0x135a, 73),
+    (0x135d, 0x135f, 73),
+    (0x1369, 0x1371, 73),
+    (0x1380, 0x138f, 73),
+    (0x13a0, 0x13f5, 74),
+    (0x13f8, 0x13fd, 74),
+    (0x1401, 0x166c, 75),
+    (0x166f, 0x167f, 75),
+    (0x1681, 0x169a, 76),
+    (0x16a0, 0x16ea, 77),
+    (0x16ee, 0x16f8, 77),
+    (0x1700, 0x1715, 78),
+    (0x171f, 0x171f, 78),
+    (0x1720, 0x1734, 79),
+    (0x1740, 0x1753, 80),
+    (0x1760, 0x176c, 81),
+    (0x176e, 0x1770, 81),
+    (0x1772, 0x1773, 81),
+    (0x1780, 0x17d3, 82),
+    (0x17d7, 0x17d7, 82),
+    (0x17dc, 0x17dd, 82),
+    (0x17e0, 0x17e9, 82),
+    (0x180b, 0x180d, 83),
+    (0x180f, 0x1819, 83),
+    (0x1820, 0x1878, 83),
+    (0x1880, 0x18aa, 83),
+    (0x18b0, 0x18f5, 75),
+    (0x1900, 0x191e, 84),
+    (0x1920, 0x192b, 84),
+    (0x1930, 0x193b, 84),
+    (0x1946, 0x194f, 84),
+    (0x1950, 0x196d, 85),
+    (0x1970, 0x1974, 85),
+    (0x1980, 0x19ab, 86),
+    (0x19b0, 0x19c9, 86),
+    (0x19d0, 0x19da, 86),
+    (0x1a00, 0x1a1b, 87),
+    (0x1a20, 0x1a5e, 88),
+    (0x1a60, 0x1a7c, 88),
+    (0x1a7f, 0x1a89, 88),
+    (0x1a90, 0x1a99, 88),
+    (0x1aa7, 0x1aa7, 88),
+    (0x1b00, 0x1b4c, 89),
+    (0x1b50, 0x1b59, 89),
+    (0x1b6b, 0x1b73, 89),
+    (0x1b80, 0x1bbf, 90),
+    (0x1bc0, 0x1bf3, 91),
+    (0x1c00, 0x1c37, 92),
+    (0x1c40, 0x1c49, 92),
+    (0x1c4d, 0x1c4f, 92),
+    (0x1c50, 0x1c7d, 93),
+    (0x1c80, 0x1c8a, 33),
+    (0x1c90, 0x1cba, 71),
+    (0x1cbd, 0x1cbf, 71),
+    (0x1cd0, 0x1cd0, 94),
+    (0x1cd1, 0x1cd1, 48),
+    (0x1cd2, 0x1cd2, 94),
+    (0x1cd4, 0x1cd4, 48),
+    (0x1cd5, 0x1cd5, 95),
+    (0x1cd6, 0x1cd6, 96),
+    (0x1cd7, 0x1cd7, 97),
+    (0x1cd8, 0x1cd8, 98),
+    (0x1cd9, 0x1cd9, 99),
+    (0x1cda, 0x1cda, 100),
+    (0x1cdb, 0x1cdb, 48),
+    (0x1cdc, 0x1cdd, 99),
+    (0x1cde, 0x1cdf, 48),
+    (0x1ce0, 0x1ce0, 99),
+    (0x1ce1, 0x1ce1, 101),
+    (0x1ce2, 0x1ce2, 102),
+    (0x1ce3, 0x1ce8, 48),
+    (0x1ce9, 0x1ce9, 103),
+    (0x1cea, 0x1cea, 104),
+    (0x1ceb, 0x1ceb, 105),
+    (0x1cec, 0x1cec, 48),
+    (0x1ced, 0x1ced, 106),
+    (0x1cee, 0x1cf1, 48),
+    (0x1cf2, 0x1cf2, 107),
+    (0x1cf3, 0x1cf3, 108),
+    (0x1cf4, 0x1cf4, 109),
+    (0x1cf5, 0x1cf6, 101),
+    (0x1cf7, 0x1cf7, 52),
+    (0x1cf8, 0x1cf9, 108),
+    (0x1cfa, 0x1cfa, 110),
+    (0x1d00, 0x1d25, 0),
+    (0x1d26, 0x1d2a, 28),
+    (0x1d2b, 0x1d2b, 33),
+    (0x1d2c, 0x1d5c, 0),
+    (0x1d5d, 0x1d61, 28),
+    (0x1d62, 0x1d65, 0),
+    (0x1d66, 0x1d6a, 28),
+    (0x1d6b, 0x1d77, 0),
+    (0x1d78, 0x1d78, 33),
+    (0x1d79, 0x1dbe, 0),
+    (0x1dbf, 0x1dc1, 28),
+    (0x1df8, 0x1df8, 111),
+    (0x1dfa, 0x1dfa, 43),
+    (0x1e00, 0x1eff, 0),
+    (0x1f00, 0x1f15, 28),
+    (0x1f18, 0x1f1d, 28),
+    (0x1f20, 0x1f45, 28),
+    (0x1f48, 0x1f4d, 28),
+    (0x1f50, 0x1f57, 28),
+    (0x1f59, 0x1f59, 28),
+    (0x1f5b, 0x1f5b, 28),
+    (0x1f5d, 0x1f5d, 28),
+    (0x1f5f, 0x1f7d, 28),
+    (0x1f80, 0x1fb4, 28),
+    (0x1fb6, 0x1fbc, 28),
+    (0x1fbe, 0x1fbe, 28),
+    (0x1fc2, 0x1fc4, 28),
+    (0x1fc6, 0x1fcc, 28),
+    (0x1fd0, 0x1fd3, 28),
+    (0x1fd6, 0x1fdb, 28),
+    (0x1fe0, 0x1fec, 28),
+    (0x1ff2, 0x1ff4, 28),
+    (0x1ff6, 0x1ffc, 28),
+    (0x2071, 0x2071, 0),
+    (0x207f, 0x207f, 0),
+    (0x2090, 0x209c, 0),
+    (0x20f0, 0x20f0, 112),
+    (0x2126, 0x2126, 28),
+    (0x212a, 0x212b, 0),
+    (0x2132, 0x2132, 0),
+    (0x214e, 0x214e, 0),
+    (0x2160, 0x2188, 0),
+    (0x2c00, 0x2c5f, 113),
+    (0x2c60, 0x2c7f, 0),
+    (0x2c80, 0x2ce4, 32),
+    (0x2ceb, 0x2cf3, 32),
+    (0x2d00, 0x2d25, 71),
+    (0x2d27, 0x2d27, 71),
+    (0x2d2d, 0x2d2d, 71),
+    (0x2d30, 0x2d67, 114),
+    (0x2d6f, 0x2d6f, 114),
+    (0x2d7f, 0x2d7f, 114),
+    (0x2d80, 0x2d96, 73),
+    (0x2da0, 0x2da6, 73),
+    (0x2da8, 0x2dae, 73),
+    (0x2db0, 0x2db6, 73),
+    (0x2db8, 0x2dbe, 73),
+    (0x2dc0, 0x2dc6, 73),
+    (0x2dc8, 0x2dce, 73),
+    (0x2dd0, 0x2dd6, 73),
+    (0x2dd8, 0x2dde, 73),
+    (0x2de0, 0x2dff, 33),
+    (0x3005, 0x3007, 115),
+    (0x3021, 0x3029, 115),
+    (0x302a, 0x302d, 116),
+    (0x302e, 0x302f, 72),
+    (0x3031, 0x3035, 117),
+    (0x3038, 0x303b, 115),
+    (0x303c, 0x303c, 118),
+    (0x3041, 0x3096, 119),
+    (0x3099, 0x309c, 117),
+    (0x309d, 0x309f, 119),
+    (0x30a1, 0x30fa, 120),
+    (0x30fb, 0x30fb, 121),
+    (0x30fc, 0x30fc, 117),
+    (0x30fd, 0x30ff, 120),
+    (0x3105, 0x312f, 122),
+    (0x3131, 0x318e, 72),
+    (0x31a0, 0x31bf, 122),
+    (0x31f0, 0x31ff, 120),
+    (0x3400, 0x4dbf, 115),
+    (0x4e00, 0x9fff, 115),
+    (0xa000, 0xa48c, 123),
+    (0xa4d0, 0xa4fd, 124),
+    (0xa500, 0xa60c, 125),
+    (0xa610, 0xa62b, 125),
+    (0xa640, 0xa66e, 33),
+    (0xa66f, 0xa66f, 35),
+    (0xa674, 0xa67d, 33),
+    (0xa67f, 0xa69f, 33),
+    (0xa6a0, 0xa6f1, 126),
+    (0xa722, 0xa787, 0),
+    (0xa78b, 0xa7dc, 0),
+    (0xa7f1, 0xa7ff, 0),
+    (0xa800, 0xa827, 127),
+    (0xa82c, 0xa82c, 127),
+    (0xa840, 0xa873, 128),
+    (0xa880, 0xa8c5, 129),
+    (0xa8d0, 0xa8d9, 129),
+    (0xa8e0, 0xa8f0, 48),
+    (0xa8f1, 0xa8f1, 130),
+    (0xa8f2, 0xa8f2, 48),
+    (0xa8f3, 0xa8f3, 131),
+    (0xa8f4, 0xa8f7, 48),
+    (0xa8fb, 0xa8fb, 48),
+    (0xa8fd, 0xa8ff, 48),
+    (0xa900, 0xa92d, 132),
+    (0xa930, 0xa953, 133),
+    (0xa960, 0xa97c, 72),
+    (0xa980, 0xa9c0, 134),
+    (0xa9cf, 0xa9cf, 135),
+    (0xa9d0, 0xa9d9, 134),
+    (0xa9e0, 0xa9fe, 69),
+    (0xaa00, 0xaa36, 136),
+    (0xaa40, 0xaa4d, 136),
+    (0xaa50, 0xaa59, 136),
+    (0xaa60, 0xaa76, 69),
+    (0xaa7a, 0xaa7f, 69),
+    (0xaa80, 0xaac2, 137),
+    (0xaadb, 0xaadd, 137),
+    (0xaae0, 0xaaef, 138),
+    (0xaaf2, 0xaaf6, 138),
+    (0xab01, 0xab06, 73),
+    (0xab09, 0xab0e, 73),
+    (0xab11, 0xab16, 73),
+    (0xab20, 0xab26, 73),
+    (0xab28, 0xab2e, 73),
+    (0xab30, 0xab5a, 0),
+    (0xab5c, 0xab64, 0),
+    (0xab65, 0xab65, 28),
+    (0xab66, 0xab69, 0),
+    (0xab70, 0xabbf, 74),
+    (0xabc0, 0xabea, 138),
+    (0xabec, 0xabed, 138),
+    (0xabf0, 0xabf9, 138),
+    (0xac00, 0xd7a3, 72),
+    (0xd7b0, 0xd7c6, 72),
+    (0xd7cb, 0xd7fb, 72),
+    (0xf900, 0xfa6d, 115),
+    (0xfa70, 0xfad9, 115),
+    (0xfb00, 0xfb06, 0),
+    (0xfb13, 0xfb17, 37),
+    (0xfb1d, 0xfb28, 38),
+    (0xfb2a, 0xfb36, 38),
+    (0xfb38, 0xfb3c, 38),
+    (0xfb3e, 0xfb3e, 38),
+    (0xfb40, 0xfb41, 38),
+    (0xfb43, 0xfb44, 38),
+    (0xfb46, 0xfb4f, 38),
+    (0xfb50, 0xfbb1, 39),
+    (0xfbd3, 0xfd3d, 39),
+    (0xfd50, 0xfd8f, 39),
+    (0xfd92, 0xfdc7, 39),
+    (0xfdf0, 0xfdf1, 39),
+    (0xfdf2, 0xfdf2, 139),
+    (0xfdf3, 0xfdfb, 39),
+    (0xfe2e, 0xfe2f, 33),
+    (0xfe70, 0xfe74, 39),
+    (0xfe76, 0xfefc, 39),
+    (0xff21, 0xff3a, 0),
+    (0xff41, 0xff5a, 0),
+    (0xff65, 0xff65, 121),
+    (0xff66, 0xff6f, 120),
+    (0xff70, 0xff70, 117),
+    (0xff71, 0xff9d, 120),
+    (0xff9e, 0xff9f, 117),
+    (0xffa0, 0xffbe, 72),
+    (0xffc2, 0xffc7, 72),
+    (0xffca, 0xffcf, 72),
+    (0xffd2, 0xffd7, 72),
+    (0xffda, 0xffdc, 72),
+    (0x10000, 0x1000b, 140),
+    (0x1000d, 0x10026, 140),
+    (0x10028, 0x1003a, 140),
+    (0x1003c, 0x1003d, 140),
+    (0x1003f, 0x1004d, 140),
+    (0x10050, 0x1005d, 140),
+    (0x10080, 0x100fa, 140),
+    (0x10140, 0x10174, 28),
+    (0x10280, 0x1029c, 141),
+    (0x102a0, 0x102d0, 142),
+    (0x102e0, 0x102e0, 143),
+    (0x10300, 0x1031f, 144),
+    (0x1032d, 0x1032f, 144),
+    (0x10330, 0x1034a, 145),
+    (0x10350, 0x1037a, 146),
+    (0x10380, 0x1039d, 147),
+    (0x103a0, 0x103c3, 148),
+    (0x103c8, 0x103cf, 148),
+    (0x103d1, 0x103d5, 148),
+    (0x10400, 0x1044f, 149),
+    (0x10450, 0x1047f, 150),
+    (0x10480, 0x1049d, 151),
+    (0x104a0, 0x104a9, 151),
+    (0x104b0, 0x104d3, 152),
+    (0x104d8, 0x104fb, 152),
+    (0x10500, 0x10527, 153),
+    (0x10530, 0x10563, 154),
+    (0x10570, 0x1057a, 155),
+    (0x1057c, 0x1058a, 155),
+    (0x1058c, 0x10592, 155),
+    (0x10594, 0x10595, 155),
+    (0x10597, 0x105a1, 155),
+    (0x105a3, 0x105b1, 155),
+    (0x105b3, 0x105b9, 155),
+    (0x105bb, 0x105bc, 155),
+    (0x105c0, 0x105f3, 156),
+    (0x10600, 0x10736, 157),
+    (0x10740, 0x10755, 157),
+    (0x10760, 0x10767, 157),
+    (0x10780, 0x10785, 0),
+    (0x10787, 0x107b0, 0),
+    (0x107b2, 0x107ba, 0),
+    (0x10800, 0x10805, 158),
+    (0x10808, 0x10808, 158),
+    (0x1080a, 0x10835, 158),
+    (0x10837, 0x10838, 158),
+    (0x1083c, 0x1083c, 158),
+    (0x1083f, 0x1083f, 158),
+    (0x10840, 0x10855, 159),
+    (0x10860, 0x10876, 160),
+    (0x10880, 0x1089e, 161),
+    (0x108e0, 0x108f2, 162),
+    (0x108f4, 0x108f5, 162),
+    (0x10900, 0x10915, 163),
+    (0x10920, 0x10939, 164),
+    (0x10940, 0x10959, 165),
+    (0x10980, 0x1099f, 166),
+    (0x109a0, 0x109b7, 167),
+    (0x109be, 0x109bf, 167),
+    (0x10a00, 0x10a03, 168),
+    (0x10a05, 0x10a06, 168),
+    (0x10a0c, 0x10a13, 168),
+    (0x10a15, 0x10a17, 168),
+    (0x10a19, 0x10a35, 168),
+    (0x10a38, 0x10a3a, 168),
+    (0x10a3f, 0x10a3f, 168),
+    (0x10a60, 0x10a7c, 169),
+    (0x10a80, 0x10a9c, 170),
+    (0x10ac0, 0x10ac7, 171),
+    (0x10ac9, 0x10ae6, 171),
+    (0x10b00, 0x10b35, 172),
+    (0x10b40, 0x10b55, 173),
+    (0x10b60, 0x10b72, 174),
+    (0x10b80, 0x10b91, 175),
+    (0x10c00, 0x10c48, 176),
+    (0x10c80, 0x10cb2, 177),
+    (0x10cc0, 0x10cf2, 177),
+    (0x10d00, 0x10d27, 178),
+    (0x10d30, 0x10d39, 178),
+    (0x10d40, 0x10d65, 179),
+    (0x10d69, 0x10d6d, 179),
+    (0x10d6f, 0x10d85, 179),
+    (0x10e80, 0x10ea9, 180),
+    (0x10eab, 0x10eac, 180),
+    (0x10eb0, 0x10eb1, 180),
+    (0x10ec2, 0x10ec7, 39),
+    (0x10efa, 0x10eff, 39),
+    (0x10f00, 0x10f1c, 181),
+    (0x10f27, 0x10f27, 181),
+    (0x10f30, 0x10f50, 182),
+    (0x10f70, 0x10f85, 183),
+    (0x10fb0, 0x10fc4, 184),
+    (0x10fe0, 0x10ff6, 185),
+    (0x11000, 0x11046, 186),
+    (0x11066, 0x11075, 186),
+    (0x1107f, 0x1107f, 186),
+    (0x11080, 0x110ba, 187),
+    (0x110c2, 0x110c2, 187),
+    (0x110d0, 0x110e8, 188),
+    (0x110f0, 0x110f9, 188),
+    (0x11100, 0x11134, 189),
+    (0x11136, 0x1113f, 189),
+    (0x11144, 0x11147, 189),
+    (0x11150, 0x11173, 190),
+    (0x11176, 0x11176, 190),
+    (0x11180, 0x111c4, 191),
+    (0x111c9, 0x111cc, 191),
+    (0x111ce, 0x111da, 191),
+    (0x111dc, 0x111dc, 191),
+    (0x11200, 0x11211, 192),
+    (0x11213, 0x11237, 192),
+    (0x1123e, 0x11241, 192),
+    (0x11280, 0x11286, 193),
+    (0x11288, 0x11288, 193),
+    (0x1128a, 0x1128d, 193),
+    (0x1128f, 0x1129d, 193),
+    (0x1129f, 0x112a8, 193),
+    (0x112b0, 0x112ea, 194),
+    (0x112f0, 0x112f9, 194),
+    (0x11300, 0x11300, 195),
+    (0x11301, 0x11301, 60),
+    (0x11302, 0x11302, 195),
+    (0x11303, 0x11303, 60),
+    (0x11305, 0x1130c, 195),
+    (0x1130f, 0x11310, 195),
+    (0x11313, 0x11328, 195),
+    (0x1132a, 0x11330, 195),
+    (0x11332, 0x11333, 195),
+    (0x11335, 0x11339, 195),
+    (0x1133b, 0x1133c, 60),
+    (0x1133d, 0x11344, 195),
+    (0x11347, 0x11348, 195),
+    (0x1134b, 0x1134d, 195),
+    (0x11350, 0x11350, 195),
+    (0x11357, 0x11357, 195),
+    (0x1135d, 0x11363, 195),
+    (0x11366, 0x1136c, 195),
+    (0x11370, 0x11374, 195),
+    (0x11380, 0x11389, 196),
+    (0x1138b, 0x1138b, 196),
+    (0x1138e, 0x1138e, 196),
+    (0x11390, 0x113b5, 196),
+    (0x113b7, 0x113c0, 196),
+    (0x113c2, 0x113c2, 196),
+    (0x113c5, 0x113c5, 196),
+    (0x113c7, 0x113ca, 196),
+    (0x113cc, 0x113d3, 196),
+    (0x113e1, 0x113e2, 196),
+    (0x11400, 0x1144a, 197),
+    (0x11450, 0x11459, 197),
+    (0x1145e, 0x11461, 197),
+    (0x11480, 0x114c5, 198),
+    (0x114c7, 0x114c7, 198),
+    (0x114d0, 0x114d9, 198),
+    (0x11580, 0x115b5, 199),
+    (0x115b8, 0x115c0, 199),
+    (0x115d8, 0x115dd, 199),
+    (0x11600, 0x11640, 200),
+    (0x11644, 0x11644, 200),
+    (0x11650, 0x11659, 200),
+    (0x11680, 0x116b8, 201),
+    (0x116c0, 0x116c9, 201),
+    (0x116d0, 0x116e3, 69),
+    (0x11700, 0x1171a, 202),
+    (0x1171d, 0x1172b, 202),
+    (0x11730, 0x11739, 202),
+    (0x11740, 0x11746, 202),
+    (0x11800, 0x1183a, 203),
+    (0x118a0, 0x118e9, 204),
+    (0x118ff, 0x118ff, 204),
+    (0x11900, 0x11906, 205),
+    (0x11909, 0x11909, 205),
+    (0x1190c, 0x11913, 205),
+    (0x11915, 0x11916, 205),
+    (0x11918, 0x11935, 205),
+    (0x11937, 0x11938, 205),
+    (0x1193b, 0x11943, 205),
+    (0x11950, 0x11959, 205),
+    (0x119a0, 0x119a7, 110),
+    (0x119aa, 0x119d7, 110),
+    (0x119da, 0x119e1, 110),
+    (0x119e3, 0x119e4, 110),
+    (0x11a00, 0x11a3e, 206),
+    (0x11a47, 0x11a47, 206),
+    (0x11a50, 0x11a99, 207),
+    (0x11a9d, 0x11a9d, 207),
+    (0x11ab0, 0x11abf, 75),
+    (0x11ac0, 0x11af8, 208),
+    (0x11b60, 0x11b67, 191),
+    (0x11bc0, 0x11be0, 209),
+    (0x11bf0, 0x11bf9, 209),
+    (0x11c00, 0x11c08, 210),
+    (0x11c0a, 0x11c36, 210),
+    (0x11c38, 0x11c40, 210),
+    (0x11c50, 0x11c59, 210),
+    (0x11c72, 0x11c8f, 211),
+    (0x11c92, 0x11ca7, 211),
+    (0x11ca9, 0x11cb6, 211),
+    (0x11d00, 0x11d06, 212),
+    (0x11d08, 0x11d09, 212),
+    (0x11d0b, 0x11d36, 212),
+    (0x11d3a, 0x11d3a, 212),
+    (0x11d3c, 0x11d3d, 212),
+    (0x11d3f, 0x11d47, 212),
+    (0x11d50, 0x11d59, 212),
+    (0x11d60, 0x11d65, 213),
+    (0x11d67, 0x11d68, 213),
+    (0x11d6a, 0x11d8e, 213),
+    (0x11d90, 0x11d91, 213),
+    (0x11d93, 0x11d98, 213),
+    (0x11da0, 0x11da9, 213),
+    (0x11db0, 0x11ddb, 214),
+    (0x11de0, 0x11de9, 214),
+    (0x11ee0, 0x11ef6, 215),
+    (0x11f00, 0x11f10, 216),
+    (0x11f12, 0x11f3a, 216),
+    (0x11f3e, 0x11f42, 216),
+    (0x11f50, 0x11f5a, 216),
+    (0x11fb0, 0x11fb0, 124),
+    (0x12000, 0x12399, 217),
+    (0x12400, 0x1246e, 217),
+    (0x12480, 0x12543, 217),
+    (0x12f90, 0x12ff0, 218),
+    (0x13000, 0x1342f, 219),
+    (0x13440, 0x13455, 219),
+    (0x13460, 0x143fa, 219),
+    (0x14400, 0x14646, 220),
+    (0x16100, 0x16139, 221),
+    (0x16800, 0x16a38, 126),
+    (0x16a40, 0x16a5e, 222),
+    (0x16a60, 0x16a69, 222),
+    (0x16a70, 0x16abe, 223),
+    (0x16ac0, 0x16ac9, 223),
+    (0x16ad0, 0x16aed, 224),
+    (0x16af0, 0x16af4, 224),
+    (0x16b00, 0x16b36, 225),
+    (0x16b40, 0x16b43, 225),
+    (0x16b50, 0x16b59, 225),
+    (0x16b63, 0x16b77, 225),
+    (0x16b7d, 0x16b8f, 225),
+    (0x16d40, 0x16d6c, 226),
+    (0x16d70, 0x16d79, 226),
+    (0x16e40, 0x16e7f, 227),
+    (0x16ea0, 0x16eb8, 228),
+    (0x16ebb, 0x16ed3, 228),
+    (0x16f00, 0x16f4a, 229),
+    (0x16f4f, 0x16f87, 229),
+    (0x16f8f, 0x16f9f, 229),
+    (0x16fe0, 0x16fe0, 230),
+    (0x16fe1, 0x16fe1, 231),
+    (0x16fe3, 0x16fe3, 115),
+    (0x16fe4, 0x16fe4, 232),
+    (0x16ff0, 0x16ff6, 115),
+    (0x17000, 0x18aff, 230),
+    (0x18b00, 0x18cd5, 232),
+    (0x18cff, 0x18cff, 232),
+    (0x18d00, 0x18d1e, 230),
+    (0x18d80, 0x18df2, 230),
+    (0x1aff0, 0x1aff3, 120),
+    (0x1aff5, 0x1affb, 120),
+    (0x1affd, 0x1affe, 120),
+    (0x1b000, 0x1b000, 120),
+    (0x1b001, 0x1b11f, 119),
+    (0x1b120, 0x1b122, 120),
+    (0x1b132, 0x1b132, 119),
+    (0x1b150, 0x1b152, 119),
+    (0x1b155, 0x1b155, 120),
+    (0x1b164, 0x1b167, 120),
+    (0x1b170, 0x1b2fb, 231),
+    (0x1bc00, 0x1bc6a, 233),
+    (0x1bc70, 0x1bc7c, 233),
+    (0x1bc80, 0x1bc88, 233),
+    (0x1bc90, 0x1bc99, 233),
+    (0x1bc9d, 0x1bc9e, 233),
+    (0x1d242, 0x1d244, 28),
+    (0x1da00, 0x1da36, 234),
+    (0x1da3b, 0x1da6c, 234),
+    (0x1da75, 0x1da75, 234),
+    (0x1da84, 0x1da84, 234),
+    (0x1da9b, 0x1da9f, 234),
+    (0x1daa1, 0x1daaf, 234),
+    (0x1df00, 0x1df1e, 0),
+    (0x1df25, 0x1df2a, 0),
+    (0x1e000, 0x1e006, 113),
+    (0x1e008, 0x1e018, 113),
+    (0x1e01b, 0x1e021, 113),
+    (0x1e023, 0x1e024, 113),
+    (0x1e026, 0x1e02a, 113),
+    (0x1e030, 0x1e06d, 33),
+    (0x1e08f, 0x1e08f, 33),
+    (0x1e100, 0x1e12c, 235),
+    (0x1e130, 0x1e13d, 235),
+    (0x1e140, 0x1e149, 235),
+    (0x1e14e, 0x1e14e, 235),
+    (0x1e290, 0x1e2ae, 236),
+    (0x1e2c0, 0x1e2f9, 237),
+    (0x1e4d0, 0x1e4f9, 238),
+    (0x1e5d0, 0x1e5fa, 239),
+    (0x1e6c0, 0x1e6de, 240),
+    (0x1e6e0, 0x1e6f5, 240),
+    (0x1e6fe, 0x1e6ff, 240),
+    (0x1e7e0, 0x1e7e6, 73),
+    (0x1e7e8, 0x1e7eb, 73),
+    (0x1e7ed, 0x1e7ee, 73),
+    (0x1e7f0, 0x1e7fe, 73),
+    (0x1e800, 0x1e8c4, 241),
+    (0x1e8d0, 0x1e8d6, 241),
+    (0x1e900, 0x1e94b, 242),
+    (0x1e950, 0x1e959, 242),
+    (0x1ee00, 0x1ee03, 39),
+    (0x1ee05, 0x1ee1f, 39),
+    (0x1ee21, 0x1ee22, 39),
+    (0x1ee24, 0x1ee24, 39),
+    (0x1ee27, 0x1ee27, 39),
+    (0x1ee29, 0x1ee32, 39),
+    (0x1ee34, 0x1ee37, 39),
+    (0x1ee39, 0x1ee39, 39),
+    (0x1ee3b, 0x1ee3b, 39),
+    (0x1ee42, 0x1ee42, 39),
+    (0x1ee47, 0x1ee47, 39),
+    (0x1ee49, 0x1ee49, 39),
+    (0x1ee4b, 0x1ee4b, 39),
+    (0x1ee4d, 0x1ee4f, 39),
+    (0x1ee51, 0x1ee52, 39),
+    (0x1ee54, 0x1ee54, 39),
+    (0x1ee57, 0x1ee57, 39),
+    (0x1ee59, 0x1ee59, 39),
+    (0x1ee5b, 0x1ee5b, 39),
+    (0x1ee5d, 0x1ee5d, 39),
+    (0x1ee5f, 0x1ee5f, 39),
+    (0x1ee61, 0x1ee62, 39),
+    (0x1ee64, 0x1ee64, 39),
+    (0x1ee67, 0x1ee6a, 39),
+    (0x1ee6c, 0x1ee72, 39),
+    (0x1ee74, 0x1ee77, 39),
+    (0x1ee79, 0x1ee7c, 39),
+    (0x1ee7e, 0x1ee7e, 39),
+    (0x1ee80, 0x1ee89, 39),
+    (0x1ee8b, 0x1ee9b, 39),
+    (0x1eea1, 0x1eea3, 39),
+    (0x1eea5, 0x1eea9, 39),
+    (0x1eeab, 0x1eebb, 39),
+    (0x20000, 0x2a6df, 115),
+    (0x2a700, 0x2b81d, 115),
+    (0x2b820, 0x2cead, 115),
+    (0x2ceb0, 0x2ebe0, 115),
+    (0x2ebf0, 0x2ee5d, 115),
+    (0x2f800, 0x2fa1d, 115),
+    (0x30000, 0x3134a, 115),
+    (0x31350, 0x33479, 115),
+];
+
+// Sorted (code point, UTF-8 prototype) pairs from UTS #39's confusables.txt
+// whose prototypes are ASCII identifier characters.
+#[rustfmt::skip]
+pub const CONFUSABLE_PROTOTYPES: [(u32, &[u8]); 59] = [
+    (0x0131, b"i"),
+    (0x0251, b"a"),
+    (0x0261, b"g"),
+    (0x0391, b"A"),
+    (0x0392, b"B"),
+    (0x0395, b"E"),
+    (0x0396, b"Z"),
+    (0x0397, b"H"),
+    (0x0399, b"l"),
+    (0x039a, b"K"),
+    (0x039c, b"M"),
+    (0x039d, b"N"),
+    (0x039f, b"O"),
+    (0x03a1, b"P"),
+    (0x03a4, b"T"),
+    (0x03a5, b"Y"),
+    (0x03a7, b"X"),
+    (0x03b1, b"a"),
+    (0x03b9, b"i"),
+    (0x03bd, b"v"),
+    (0x03bf, b"o"),
+    (0x03c1, b"p"),
+    (0x03c5, b"u"),
+    (0x0405, b"S"),
+    (0x0406, b"l"),
+    (0x0408, b"J"),
+    (0x0410, b"A"),
+    (0x0412, b"B"),
+    (0x0415, b"E"),
+    (0x0417, b"3"),
+    (0x041a, b"K"),
+    (0x041c, b"M"),
+    (0x041d, b"H"),
+    (0x041e, b"O"),
+    (0x0420, b"P"),
+    (0x0421, b"C"),
+    (0x0422, b"T"),
+    (0x0425, b"X"),
+    (0x0430, b"a"),
+    (0x0435, b"e"),
+    (0x043e, b"o"),
+    (0x0440, b"p"),
+    (0x0441, b"c"),
+    (0x0443, b"y"),
+    (0x0445, b"x"),
+    (0x0455, b"s"),
+    (0x0456, b"i"),
+    (0x0458, b"j"),
+    (0x04ae, b"Y"),
+    (0x04bb, b"h"),
+    (0x04c0, b"l"),
+    (0x0501, b"d"),
+    (0x051b, b"q"),
+    (0x051d, b"w"),
+    (0x0570, b"h"),
+    (0x0578, b"n"),
+    (0x057d, b"u"),
+    (0x0581, b"g"),
+    (0x0585, b"o"),
+];
+
+// quick-lint-js finds bugs in JavaScript programs.
+// Copyright (C) 2020  Matthew "strager" Glazar
+//
+// This file is part of quick-lint-js.
+//
+// quick-lint-js is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// quick-lint-js is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with quick-lint-js.  If not, see <https://www.gnu.org/licenses/>.
@@ -1,7 +1,7 @@
 // Copyright (C) 2020  Matthew "strager" Glazar
 // See end of file for extended copyright information.
 
-// This file was generated by tools/generate-lex-unicode/index.js.
+// This file was generated by rust/tools/generate_lex_unicode from Unicode 15.0.0.
 
 type UnicodeTableChunkIndexType = u8;
 
@@ -1337,6 +1337,678 @@ pub const IDENTIFIER_PART_CHUNK_INDEXES: [UnicodeTableChunkIndexType; 3586] = [
     0x1f, 0xbf,
 ];
 
+pub type UTF8TrieLeafIndexType = u16;
+
+pub const UTF8_TRIE_LEAF_SIZE: usize = 64;
+
+#[rustfmt::skip]
+pub const UTF8_TRIE_LEAVES: [[u64; 2]; 306] = [
+    [0x0000001000000000, 0x03ff001000000000],
+    [0x07fffffe87fffffe, 0x07fffffe87fffffe],
+    [0x0420040000000000, 0x04a0040000000000],
+    [0xff7fffffff7fffff, 0xff7fffffff7fffff],
+    [0xffffffffffffffff, 0xffffffffffffffff],
+    [0x0000501f0003ffc3, 0x0000501f0003ffc3],
+    [0x0000000000000000, 0xffffffffffffffff],
+    [0xbcdf000000000000, 0xbcdfffffffffffff],
+    [0xfffffffbffffd740, 0xfffffffbffffd7c0],
+    [0xffbfffffffffffff, 0xffbfffffffffffff],
+    [0xfffffffffffffc03, 0xfffffffffffffcfb],
+    [0xfffeffffffffffff, 0xfffeffffffffffff],
+    [0xffffffff027fffff, 0xffffffff027fffff],
+    [0x00000000000001ff, 0xbffffffffffe01ff],
+    [0x000787ffffff0000, 0x000787ffffff00b6],
+    [0xffffffff00000000, 0xffffffff07ff0000],
+    [0xfffec000000007ff, 0xffffc3ffffffffff],
+    [0x9c00c060002fffff, 0x9ffffdff9fefffff],
+    [0x0000fffffffd0000, 0xffffffffffff0000],
+    [0xffffffffffffe000, 0xffffffffffffe7ff],
+    [0x0002003fffffffff, 0x0003ffffffffffff],
+    [0x043007fffffffc00, 0x243fffffffffffff],
+    [0x00000110043fffff, 0x00003fffffffffff],
+    [0xffff07ff01ffffff, 0xffff07ff0fffffff],
+    [0xffffffff00007eff, 0xffffffffff007eff],
+    [0x00000000000003ff, 0xfffffffbffffffff],
+    [0x23fffffffffffff0, 0xffffffffffffffff],
+    [0xfffe0003ff010000, 0xfffeffcfffffffff],
+    [0x23c5fdfffff99fe1, 0xf3c5fdfffff99fef],
+    [0x10030003b0004000, 0x5003ffcfb080799f],
+    [0x036dfdfffff987e0, 0xd36dfdfffff987ee],
+    [0x001c00005e000000, 0x003fffc05e023987],
+    [0x23edfdfffffbbfe0, 0xf3edfdfffffbbfee],
+    [0x0200000300010000, 0xfe00ffcf00013bbf],
+    [0x23edfdfffff99fe0, 0xf3edfdfffff99fee],
+    [0x00020003b0000000, 0x0002ffcfb0e0399f],
+    [0x03ffc718d63dc7e8, 0xc3ffc718d63dc7ec],
+    [0x0000000000010000, 0x0000ffc000813dc7],
+    [0x23fffdfffffddfe0, 0xf3fffdfffffddfff],
+    [0x0000000327000000, 0x0000ffcf27603ddf],
+    [0x23effdfffffddfe1, 0xf3effdfffffddfef],
+    [0x0006000360000000, 0x000effcf60603ddf],
+    [0x27fffffffffddff0, 0xfffffffffffddfff],
+    [0xfc00000380704000, 0xfc00ffcf80f07ddf],
+    [0x2ffbfffffc7fffe0, 0x2ffbfffffc7fffee],
+    [0x000000000000007f, 0x000cffc0ff5f847f],
+    [0x000dfffffffffffe, 0x07fffffffffffffe],
+    [0x000000000000007f, 0x0000000003ff7fff],
+    [0x200dffaffffff7d6, 0x3fffffaffffff7d6],
+    [0x00000000f000005f, 0x00000000f3ff7f5f],
+    [0x0000000000000001, 0xc2a003ff03000001],
+    [0x00001ffffffffeff, 0xfffe1ffffffffeff],
+    [0x0000000000001f00, 0x1ffffffffeffffdf],
+    [0x0000000000000000, 0x0000000000000040],
+    [0x800007ffffffffff, 0xffffffffffffffff],
+    [0xffe1c0623c3f0000, 0xffffffffffff03ff],
+    [0xffffffff00004003, 0xffffffff3fffffff],
+    [0xf7ffffffffff20bf, 0xf7ffffffffff20bf],
+    [0xffffffff3d7f3dff, 0xffffffff3d7f3dff],
+    [0x7f3dffffffff3dff, 0x7f3dffffffff3dff],
+    [0xffffffffff7fff3d, 0xffffffffff7fff3d],
+    [0xffffffffff3dffff, 0xffffffffff3dffff],
+    [0x0000000007ffffff, 0x0003fe00e7ffffff],
+    [0xffffffff0000ffff, 0xffffffff0000ffff],
+    [0x3f3fffffffffffff, 0x3f3fffffffffffff],
+    [0xfffffffffffffffe, 0xfffffffffffffffe],
+    [0xffff9fffffffffff, 0xffff9fffffffffff],
+    [0xffffffff07fffffe, 0xffffffff07fffffe],
+    [0x01ffc7ffffffffff, 0x01ffc7ffffffffff],
+    [0x0003ffff8003ffff, 0x001fffff803fffff],
+    [0x0001dfff0003ffff, 0x000ddfff000fffff],
+    [0x000fffffffffffff, 0xffffffffffffffff],
+    [0x0000000010800000, 0x000003ff308fffff],
+    [0xffffffff00000000, 0xffffffff03ffb800],
+    [0x01ffffffffffffff, 0x01ffffffffffffff],
+    [0xffff05ffffffffff, 0xffff07ffffffffff],
+    [0x003fffffffffffff, 0x003fffffffffffff],
+    [0x000000007fffffff, 0x0fff0fff7fffffff],
+    [0x001f3fffffff0000, 0x001f3fffffffffc0],
+    [0xffff0fffffffffff, 0xffff0fffffffffff],
+    [0x00000000000003ff, 0x0000000007ff03ff],
+    [0xffffffff007fffff, 0xffffffff0fffffff],
+    [0x00000000001fffff, 0x9fffffff7fffffff],
+    [0x0000008000000000, 0xbfff008003ff03ff],
+    [0x0000000000000000, 0x0000000000007fff],
+    [0x000fffffffffffe0, 0xffffffffffffffff],
+    [0x0000000000001fe0, 0x000ff80003ff1fff],
+    [0xfc00c001fffffff8, 0xffffffffffffffff],
+    [0x0000003fffffffff, 0x000fffffffffffff],
+    [0x0000000fffffffff, 0x00ffffffffffffff],
+    [0x3ffffffffc00e000, 0x3fffffffffffe3ff],
+    [0xe7ffffffffff01ff, 0xe7ffffffffff01ff],
+    [0x046fde0000000000, 0x07fffffffff70000],
+    [0xffffffff3f3fffff, 0xffffffff3f3fffff],
+    [0x3fffffffaaff3f3f, 0x3fffffffaaff3f3f],
+    [0x5fdfffffffffffff, 0x5fdfffffffffffff],
+    [0x1fdc1fff0fcf1fdc, 0x1fdc1fff0fcf1fdc],
+    [0x0000000000000000, 0x8000000000003000],
+    [0x8002000000000000, 0x8002000000100001],
+    [0x000000001fff0000, 0x000000001fff0000],
+    [0x0000000000000000, 0x0001ffe21fff0000],
+    [0xf3fffd503f2ffc84, 0xf3fffd503f2ffc84],
+    [0xffffffff000043e0, 0xffffffff000043e0],
+    [0x00000000000001ff, 0x00000000000001ff],
+    [0x0000000000000000, 0x0000000000000000],
+    [0x000c781fffffffff, 0x000ff81fffffffff],
+    [0xffff20bfffffffff, 0xffff20bfffffffff],
+    [0x000080ffffffffff, 0x800080ffffffffff],
+    [0x7f7f7f7f007fffff, 0x7f7f7f7f007fffff],
+    [0x000000007f7f7f7f, 0xffffffff7f7f7f7f],
+    [0x1f3e03fe000000e0, 0x1f3efffe000000e0],
+    [0xfffffffef87fffff, 0xfffffffefe7fffff],
+    [0xf7ffffffffffffff, 0xf7ffffffffffffff],
+    [0xfffeffffffffffe0, 0xfffeffffffffffe0],
+    [0xffffffff00007fff, 0xffffffff00007fff],
+    [0xffff000000000000, 0xffff000000000000],
+    [0x0000000000001fff, 0x0000000000001fff],
+    [0x3fffffffffff0000, 0x3fffffffffff0000],
+    [0x00000c00ffff1fff, 0x00000fffffff1fff],
+    [0x80007fffffffffff, 0xbff0ffffffffffff],
+    [0xffffffff3fffffff, 0xffffffffffffffff],
+    [0x0000ffffffffffff, 0x0003ffffffffffff],
+    [0xfffffffcff800000, 0xfffffffcff800000],
+    [0xfffffffffffff9ff, 0xfffffffffffff9ff],
+    [0xfffc000003eb07ff, 0xfffc000003eb07ff],
+    [0x00000007fffff7bb, 0x000010ffffffffff],
+    [0x000fffffffffffff, 0x000fffffffffffff],
+    [0x000ffffffffffffc, 0xffffffffffffffff],
+    [0x68fc000000000000, 0xe8ffffff03ff003f],
+    [0xffff003ffffffc00, 0xffff3fffffffffff],
+    [0x1fffffff0000007f, 0x1fffffff000fffff],
+    [0x0007fffffffffff0, 0xffffffffffffffff],
+    [0x7c00ffdf00008000, 0x7fffffff03ff8001],
+    [0x000001ffffffffff, 0x007fffffffffffff],
+    [0xc47fffff00000ff7, 0xfc7fffff03ff3fff],
+    [0x3e62ffffffffffff, 0xffffffffffffffff],
+    [0x001c07ff38000005, 0x007cffff38000007],
+    [0xffff7f7f007e7e7e, 0xffff7f7f007e7e7e],
+    [0xffff03fff7ffffff, 0xffff03fff7ffffff],
+    [0x00000007ffffffff, 0x03ff37ffffffffff],
+    [0xffff000fffffffff, 0xffff000fffffffff],
+    [0x0ffffffffffff87f, 0x0ffffffffffff87f],
+    [0xffff3fffffffffff, 0xffff3fffffffffff],
+    [0x0000000003ffffff, 0x0000000003ffffff],
+    [0x5f7ffdffa0f8007f, 0x5f7ffdffe0f8007f],
+    [0xffffffffffffffdb, 0xffffffffffffffdb],
+    [0x0003ffffffffffff, 0x0003ffffffffffff],
+    [0xfffffffffff80000, 0xfffffffffff80000],
+    [0x3fffffffffffffff, 0x3fffffffffffffff],
+    [0xffffffffffff0000, 0xffffffffffff0000],
+    [0xfffffffffffcffff, 0xfffffffffffcffff],
+    [0x0fff0000000000ff, 0x0fff0000000000ff],
+    [0x0000000000000000, 0x0018ffff0000ffff],
+    [0xffdf000000000000, 0xffdf00000000e000],
+    [0x1fffffffffffffff, 0x1fffffffffffffff],
+    [0x07fffffe00000000, 0x87fffffe03ff0000],
+    [0xffffffc007fffffe, 0xffffffc007fffffe],
+    [0x7fffffffffffffff, 0x7fffffffffffffff],
+    [0x000000001cfcfcfc, 0x000000001cfcfcfc],
+    [0xb7ffff7fffffefff, 0xb7ffff7fffffefff],
+    [0x000000003fff3fff, 0x000000003fff3fff],
+    [0x07ffffffffffffff, 0x07ffffffffffffff],
+    [0x001fffffffffffff, 0x001fffffffffffff],
+    [0x0000000000000000, 0x2000000000000000],
+    [0xffffffff1fffffff, 0xffffffff1fffffff],
+    [0x000000000001ffff, 0x000000010001ffff],
+    [0xffffe000ffffffff, 0xffffe000ffffffff],
+    [0x003fffffffff07ff, 0x07ffffffffff07ff],
+    [0xffffffff3fffffff, 0xffffffff3fffffff],
+    [0x00000000003eff0f, 0x00000000003eff0f],
+    [0xffff00003fffffff, 0xffff03ff3fffffff],
+    [0x0fffffffff0fffff, 0x0fffffffff0fffff],
+    [0xffff00ffffffffff, 0xffff00ffffffffff],
+    [0xf7ff000fffffffff, 0xf7ff000fffffffff],
+    [0x1bfbfffbffb7f7ff, 0x1bfbfffbffb7f7ff],
+    [0x007fffffffffffff, 0x007fffffffffffff],
+    [0x000000ff003fffff, 0x000000ff003fffff],
+    [0x07fdffffffffffbf, 0x07fdffffffffffbf],
+    [0x91bffffffffffd3f, 0x91bffffffffffd3f],
+    [0x007fffff003fffff, 0x007fffff003fffff],
+    [0x000000007fffffff, 0x000000007fffffff],
+    [0x0037ffff00000000, 0x0037ffff00000000],
+    [0x03ffffff003fffff, 0x03ffffff003fffff],
+    [0xc0ffffffffffffff, 0xc0ffffffffffffff],
+    [0x003ffffffeef0001, 0x873ffffffeeff06f],
+    [0x1fffffff00000000, 0x1fffffff00000000],
+    [0x000000001fffffff, 0x000000001fffffff],
+    [0x0000001ffffffeff, 0x0000007ffffffeff],
+    [0x0007ffff003fffff, 0x0007ffff003fffff],
+    [0x000000000003ffff, 0x000000000003ffff],
+    [0x0007ffffffffffff, 0x0007ffffffffffff],
+    [0x0000000fffffffff, 0x03ff00ffffffffff],
+    [0x000303ffffffffff, 0x00031bffffffffff],
+    [0x0000000000000000, 0xe000000000000000],
+    [0xffff00801fffffff, 0xffff00801fffffff],
+    [0xffff00000000003f, 0xffff00000001ffff],
+    [0xffff000000000003, 0xffff00000000003f],
+    [0x007fffff0000001f, 0x007fffff0000001f],
+    [0x00fffffffffffff8, 0xffffffffffffffff],
+    [0x0026000000000000, 0x803fffc00000007f],
+    [0x0000fffffffffff8, 0x07ffffffffffffff],
+    [0x000001ffffff0000, 0x03ff01ffffff0004],
+    [0x0000007ffffffff8, 0xffdfffffffffffff],
+    [0x0047ffffffff0090, 0x004fffffffff00f0],
+    [0x0007fffffffffff8, 0xffffffffffffffff],
+    [0x000000001400001e, 0x0000000017ffde1f],
+    [0x80000ffffffbffff, 0xc0fffffffffbffff],
+    [0x0000000000000001, 0x0000000000000003],
+    [0xffff01ffbfffbd7f, 0xffff01ffbfffbd7f],
+    [0x000000007fffffff, 0x03ff07ffffffffff],
+    [0x23edfdfffff99fe0, 0xfbedfdfffff99fef],
+    [0x00000003e0010000, 0x001f1fcfe081399f],
+    [0x001fffffffffffff, 0xffffffffffffffff],
+    [0x0000000380000780, 0x00000003c3ff07ff],
+    [0x0000ffffffffffff, 0xffffffffffffffff],
+    [0x00000000000000b0, 0x0000000003ff00bf],
+    [0x00007fffffffffff, 0xff3fffffffffffff],
+    [0x000000000f000000, 0x000000003f000001],
+    [0x0000000000000010, 0x0000000003ff0011],
+    [0x010007ffffffffff, 0x01ffffffffffffff],
+    [0x0000000000000000, 0x00000000000003ff],
+    [0x0000000007ffffff, 0x03ff0fffe7ffffff],
+    [0x000000000000007f, 0x000000000000007f],
+    [0x00000fffffffffff, 0x07ffffffffffffff],
+    [0xffffffff00000000, 0xffffffff00000000],
+    [0x80000000ffffffff, 0x800003ffffffffff],
+    [0x8000ffffff6ff27f, 0xf9bfffffff6ff27f],
+    [0x0000000000000002, 0x0000000003ff000f],
+    [0xfffffcff00000000, 0xfffffcff00000000],
+    [0x0000000a0001ffff, 0x0000001bfcffffff],
+    [0x0407fffffffff801, 0x7fffffffffffffff],
+    [0xfffffffff0010000, 0xffffffffffff0080],
+    [0xffff0000200003ff, 0xffff000023ffffff],
+    [0x00007ffffffffdff, 0xff7ffffffffffdff],
+    [0xfffc000000000001, 0xfffc000003ff0001],
+    [0x000000000000ffff, 0x007ffefffffcffff],
+    [0x0001fffffffffb7f, 0xb47ffffffffffb7f],
+    [0xfffffdbf00000040, 0xfffffdbf03ff00ff],
+    [0x00000000010003ff, 0x000003ff01fb7fff],
+    [0x0007ffff00000000, 0x007fffff00000000],
+    [0x000ffffffffdfff4, 0xc7fffffffffdffff],
+    [0x0000000000000000, 0x0000000003ff0007],
+    [0x0001000000000000, 0x0001000000000000],
+    [0x00007fffffffffff, 0x00007fffffffffff],
+    [0x000000000000000f, 0x000000000000000f],
+    [0x0001ffffffffffff, 0x0001ffffffffffff],
+    [0x0000ffffffffffff, 0x0000ffffffffffff],
+    [0x000000000000007e, 0x00000000003fffff],
+    [0xffff00007fffffff, 0xffff03ff7fffffff],
+    [0x00003fffffff0000, 0x001f3fffffff03ff],
+    [0x0000ffffffffffff, 0x007fffffffffffff],
+    [0xe0fffff80000000f, 0xe0fffff803ff000f],
+    [0x000000000000ffff, 0x000000000000ffff],
+    [0x00000000000107ff, 0xffffffffffff87ff],
+    [0x00000000fff80000, 0x00000000ffff80ff],
+    [0x0000000b00000000, 0x0003001b00000000],
+    [0x00ffffffffffffff, 0x00ffffffffffffff],
+    [0x00000000003fffff, 0x00000000003fffff],
+    [0x6fef000000000000, 0x6fef000000000000],
+    [0x00040007ffffffff, 0x00040007ffffffff],
+    [0xffff00f000270000, 0xffff00f000270000],
+    [0x0fffffffffffffff, 0x0fffffffffffffff],
+    [0x1fff07ffffffffff, 0x1fff07ffffffffff],
+    [0x0000000003ff01ff, 0x0000000063ff01ff],
+    [0x0000000000000000, 0xffff3fffffffffff],
+    [0x0000000000000000, 0x000000000000007f],
+    [0x0000000000000000, 0xf807e3e000000000],
+    [0x0000000000000000, 0x00003c0000000fe7],
+    [0x0000000000000000, 0x000000000000001c],
+    [0xffffffffffdfffff, 0xffffffffffdfffff],
+    [0xebffde64dfffffff, 0xebffde64dfffffff],
+    [0xffffffffffffffef, 0xffffffffffffffef],
+    [0x7bffffffdfdfe7bf, 0x7bffffffdfdfe7bf],
+    [0xfffffffffffdfc5f, 0xfffffffffffdfc5f],
+    [0xffffff3fffffffff, 0xffffff3fffffffff],
+    [0xf7fffffff7fffffd, 0xf7fffffff7fffffd],
+    [0xffdfffffffdfffff, 0xffdfffffffdfffff],
+    [0xffff7fffffff7fff, 0xffff7fffffff7fff],
+    [0xfffffdfffffffdff, 0xfffffdfffffffdff],
+    [0x0000000000000ff7, 0xffffffffffffcff7],
+    [0x0000000000000000, 0xf87fffffffffffff],
+    [0x0000000000000000, 0x00201fffffffffff],
+    [0x0000000000000000, 0x0000fffef8000010],
+    [0x000007e07fffffff, 0x000007e07fffffff],
+    [0xffff000000000000, 0xffff07dbf9ffff7f],
+    [0x00003fffffffffff, 0x00003fffffffffff],
+    [0x0000000000000000, 0x0000000000008000],
+    [0x3f801fffffffffff, 0x3fff1fffffffffff],
+    [0x0000000000004000, 0x00000000000043ff],
+    [0x00003fffffff0000, 0x00007fffffff0000],
+    [0x00000fffffffffff, 0x03ffffffffffffff],
+    [0x00000fffffff0000, 0x03ffffffffff0000],
+    [0x7fff6f7f00000000, 0x7fff6f7f00000000],
+    [0x000000000000001f, 0x00000000007f001f],
+    [0x000000000000080f, 0x0000000003ff0fff],
+    [0x0af7fe96ffffffef, 0x0af7fe96ffffffef],
+    [0x5ef7f796aa96ea84, 0x5ef7f796aa96ea84],
+    [0x0ffffbee0ffffbff, 0x0ffffbee0ffffbff],
+    [0x0000000000000000, 0x03ff000000000000],
+    [0x00000000ffffffff, 0x00000000ffffffff],
+    [0x03ffffffffffffff, 0x03ffffffffffffff],
+    [0xffff0003ffffffff, 0xffff0003ffffffff],
+    [0x00000001ffffffff, 0x00000001ffffffff],
+    [0x000000003fffffff, 0x000000003fffffff],
+    [0xffffffffffff07ff, 0xffffffffffff07ff],
+    [0x0000000000000000, 0x0000ffffffffffff],
+];
+
+#[rustfmt::skip]
+pub const UTF8_TRIE_BMP_LEAF_INDEXES: [UTF8TrieLeafIndexType; 1024] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0005, 0x0006, 0x0007, 0x0008, 0x0009,
+    0x0004, 0x0004, 0x000a, 0x0004, 0x000b, 0x000c, 0x000d, 0x000e,
+    0x000f, 0x0010, 0x0004, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015,
+    0x0016, 0x0017, 0x0018, 0x0019, 0x001a, 0x001b, 0x001c, 0x001d,
+    0x001e, 0x001f, 0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025,
+    0x0026, 0x0027, 0x0028, 0x0029, 0x002a, 0x002b, 0x002c, 0x002d,
+    0x002e, 0x002f, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035,
+    0x0036, 0x0037, 0x0038, 0x0039, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x003a, 0x003b, 0x003c, 0x003d, 0x003e, 0x003f, 0x0040,
+    0x0041, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048,
+    0x0049, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005a, 0x005b, 0x005c, 0x0004, 0x0004, 0x0004, 0x0006,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x005d, 0x005e, 0x005f, 0x0060,
+    0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0069, 0x006a, 0x006b, 0x006c, 0x006d,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x006e, 0x0041, 0x006f, 0x0070, 0x0071, 0x0004, 0x0072, 0x0073,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0074, 0x0075, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0004, 0x007b, 0x007c,
+    0x007d, 0x007e, 0x007f, 0x0080, 0x0081, 0x0082, 0x0083, 0x0084,
+    0x0085, 0x0086, 0x0087, 0x0088, 0x0089, 0x008a, 0x0004, 0x008b,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x008c, 0x008d,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x008e, 0x0004, 0x008f, 0x0090, 0x0091, 0x0092, 0x0093,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x0004, 0x009a, 0x009b, 0x009c, 0x009d, 0x009e,
+];
+
+#[rustfmt::skip]
+pub const UTF8_TRIE_SUPPLEMENTARY_BLOCK_INDEXES: [u8; 272] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+    0x08, 0x05, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+    0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07,
+    0x07, 0x07, 0x0f, 0x10, 0x11, 0x07, 0x12, 0x13,
+    0x07, 0x14, 0x15, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x16, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+    0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05, 0x05,
+];
+
+#[rustfmt::skip]
+pub const UTF8_TRIE_SUPPLEMENTARY_LEAF_INDEXES: [UTF8TrieLeafIndexType; 1472] = [
+    0x009f, 0x00a0, 0x0004, 0x00a1, 0x0068, 0x00a2, 0x0068, 0x00a3,
+    0x0068, 0x0068, 0x00a4, 0x00a5, 0x00a6, 0x00a7, 0x00a8, 0x00a9,
+    0x0004, 0x0004, 0x00aa, 0x00ab, 0x00ac, 0x00ad, 0x00ae, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x00af, 0x00b0, 0x00b1, 0x0068,
+    0x00b2, 0x00b3, 0x00b4, 0x00b5, 0x00b6, 0x0068, 0x00b7, 0x0068,
+    0x00b8, 0x00b9, 0x00ba, 0x00bb, 0x004c, 0x00bc, 0x00bd, 0x0068,
+    0x0004, 0x0067, 0x00be, 0x00be, 0x00bf, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x00c0, 0x00c1, 0x00c2, 0x00c3, 0x00c4, 0x00c5,
+    0x00c6, 0x00c7, 0x00c8, 0x00c9, 0x00ca, 0x00cb, 0x00cc, 0x00cd,
+    0x00ce, 0x00cf, 0x00d0, 0x00d1, 0x00d2, 0x00d3, 0x0068, 0x0068,
+    0x00d4, 0x00d5, 0x00d6, 0x00d7, 0x0068, 0x0068, 0x00d8, 0x00d9,
+    0x00d6, 0x00da, 0x00db, 0x00dc, 0x00dd, 0x00de, 0x0068, 0x0068,
+    0x00df, 0x0068, 0x00e0, 0x00e1, 0x00e2, 0x00e3, 0x00e4, 0x00e5,
+    0x00e6, 0x00e7, 0x00e8, 0x004a, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x00e9, 0x00ea, 0x00eb, 0x0068, 0x00ec, 0x00ed, 0x00ee, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x00ef, 0x00f0, 0x00f1, 0x00f2, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x008f, 0x0068,
+    0x0004, 0x00f3, 0x0004, 0x0004, 0x0004, 0x00f4, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0095, 0x00f5,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x00f6, 0x00f7, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x00de, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x004a, 0x00f8, 0x009d, 0x00f9, 0x00fa, 0x00fb, 0x00fc, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0004, 0x0068, 0x0068, 0x0004, 0x00fd, 0x00fe, 0x00ff,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0100,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0101, 0x0067, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0102,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0103, 0x0104, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0105, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0106, 0x0107, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0108, 0x0109, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x010a, 0x010b, 0x0068,
+    0x0068, 0x010c, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x010d, 0x010e, 0x010f, 0x0110, 0x0111, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0112, 0x0113, 0x0114, 0x0115, 0x0116, 0x0117,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0118, 0x0119, 0x011a, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x011b, 0x0068, 0x0068, 0x0068,
+    0x011c, 0x011d, 0x011e, 0x0068, 0x011f, 0x0120, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0121, 0x0122, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0123, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0124,
+    0x0004, 0x0004, 0x0004, 0x0125, 0x0004, 0x0126, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0127, 0x0128, 0x0129, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x012a,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x012b, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x012c, 0x0004, 0x0004, 0x0004,
+    0x00a8, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x012d, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x012e,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x012f, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0130, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x0004, 0x00f6, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0006, 0x0006, 0x0006, 0x0131,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+    0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068, 0x0068,
+];
+
 // quick-lint-js finds bugs in JavaScript programs.
 // Copyright (C) 2020  Matthew "strager" Glazar
 //
@@ -1,14 +1,28 @@
 pub mod buffering_diag_reporter;
 pub mod diag_reporter;
+#[cfg(feature = "serde")]
+pub mod diag_serde;
 pub mod diagnostic;
 pub mod diagnostic_formatter;
 pub mod diagnostic_types;
 pub mod document;
+pub mod highlight;
 pub mod identifier;
+pub mod jsx_entity;
 pub mod language;
 pub mod lex;
+#[cfg(not(feature = "unicode_17"))]
+pub mod lex_confusables_generated;
+#[cfg(feature = "unicode_17")]
+#[path = "lex_confusables_17_0_0_generated.rs"]
+pub mod lex_confusables_generated;
 pub mod lex_keyword;
+#[cfg(not(feature = "unicode_17"))]
+pub mod lex_unicode_generated;
+#[cfg(feature = "unicode_17")]
+#[path = "lex_unicode_17_0_0_generated.rs"]
 pub mod lex_unicode_generated;
 pub mod linter;
 pub mod source_code_span;
 pub mod token;
+pub mod vec_diag_reporter;
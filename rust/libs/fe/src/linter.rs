@@ -15,6 +15,17 @@ pub struct LinterOptions {
 
     // If true, print a human-readable representation of parser visits to stderr.
     pub print_parser_visits: bool,
+
+    // If true, report identifiers which mix scripts or which contain characters
+    // which look like ASCII characters.
+    pub confusable_identifiers: bool,
+
+    // If true, report non-ASCII whitespace between tokens and zero-width
+    // characters in identifiers.
+    pub irregular_whitespace: bool,
+
+    // If true, treat the input as strict mode code (e.g. an ES module).
+    pub strict_mode: bool,
 }
 
 impl Default for LinterOptions {
@@ -23,6 +34,9 @@ impl Default for LinterOptions {
             jsx: true,
             typescript: true,
             print_parser_visits: true,
+            confusable_identifiers: false,
+            irregular_whitespace: false,
+            strict_mode: false,
         }
     }
 }
@@ -30,13 +44,36 @@ impl Default for LinterOptions {
 pub fn parse_and_lint(
     code: PaddedStringView<'_>,
     reporter: &'_ dyn DiagReporter,
-    _linter_options: LinterOptions,
+    linter_options: LinterOptions,
+) {
+    parse_and_lint_with_allocator(code, reporter, linter_options, &mut LexerAllocator::new());
+}
+
+// Like parse_and_lint, but reuse allocator's memory instead of allocating new
+// memory. Useful when linting many files in a row.
+//
+// allocator is reset before linting, so diagnostics reported by an earlier call
+// must not be used after this call begins.
+pub fn parse_and_lint_with_allocator(
+    code: PaddedStringView<'_>,
+    reporter: &'_ dyn DiagReporter,
+    linter_options: LinterOptions,
+    allocator: &mut LexerAllocator,
 ) {
+    allocator.reset();
     // NOTE(port): This is trimmed down because we aren't porting the parser or
     // the variable analyzer. Just lex the whole document. This won't work if
     // there are regexp literals or template literals, but whatever.
-    let allocator = LexerAllocator::new();
-    let mut l: Lexer = Lexer::new(code, reporter, &allocator);
+    let mut l: Lexer = Lexer::new_with_options(
+        code,
+        reporter,
+        allocator,
+        LexerOptions {
+            confusable_identifiers: linter_options.confusable_identifiers,
+            irregular_whitespace: linter_options.irregular_whitespace,
+            strict_mode: linter_options.strict_mode,
+        },
+    );
     while l.peek().type_ != TokenType::EndOfFile {
         l.skip();
     }
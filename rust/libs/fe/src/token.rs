@@ -261,6 +261,7 @@ macro_rules! qljs_case_conditional_assignment_operator {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenType {
     // Single-character symbols:
     Ampersand = '&' as isize,
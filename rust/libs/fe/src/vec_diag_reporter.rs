@@ -0,0 +1,221 @@
+use crate::diag_reporter::*;
+use crate::diagnostic::*;
+use crate::diagnostic_formatter::*;
+use crate::diagnostic_types::*;
+use crate::source_code_span::*;
+use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_util::narrow_cast::*;
+use cpp_vs_rust_util::padded_string::*;
+
+// A reported diagnostic with its messages already formatted.
+//
+// Unlike the raw pointer given to DiagReporter::report_impl, a Diagnostic can
+// be stored and inspected without unsafe code. To read a diagnostic's fields,
+// match on diag():
+//
+//   if let AnyDiag::DiagUnclosedStringLiteral(d) = diagnostic.diag() {
+//       /* use d.string_literal */
+//   }
+#[derive(Debug)]
+pub struct Diagnostic<'code> {
+    diag: AnyDiag<'code>,
+    // Copies of diag's &[u8] fields, which diag's fields point to. The lexer
+    // allocates some strings (such as DiagConfusableIdentifier::skeleton) in
+    // memory which is freed after linting.
+    _strings: Vec<Vec<u8>>,
+    code: [u8; 5],
+    // The diagnostic's main message, followed by its notes (if any).
+    messages: Vec<DiagnosticMessage>,
+}
+
+// One message of a Diagnostic. begin and end are byte offsets into the linted
+// code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosticMessage {
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+    pub begin: PaddedStringSizeType,
+    pub end: PaddedStringSizeType,
+}
+
+impl<'code> Diagnostic<'code> {
+    fn new(
+        mut diag: AnyDiag<'code>,
+        code: [u8; 5],
+        messages: Vec<DiagnosticMessage>,
+    ) -> Diagnostic<'code> {
+        let mut strings: Vec<Vec<u8>> = vec![];
+        diag.visit_string_fields_mut(&mut |string: &mut &'code [u8]| {
+            let copy: Vec<u8> = string.to_vec();
+            // A Vec's elements do not move when the Vec moves, and _strings is
+            // never modified after this function, so the copy lives as long as
+            // the Diagnostic.
+            *string = unsafe { std::slice::from_raw_parts(copy.as_ptr(), copy.len()) };
+            strings.push(copy);
+        });
+        Diagnostic {
+            diag,
+            _strings: strings,
+            code,
+            messages,
+        }
+    }
+
+    pub fn diag_type(&self) -> DiagType {
+        self.diag.diag_type()
+    }
+
+    // For example, "E0001".
+    pub fn code(&self) -> &str {
+        std::str::from_utf8(&self.code).unwrap()
+    }
+
+    pub fn severity(&self) -> DiagnosticSeverity {
+        self.messages[0].severity
+    }
+
+    pub fn message(&self) -> &str {
+        &self.messages[0].message
+    }
+
+    pub fn begin(&self) -> PaddedStringSizeType {
+        self.messages[0].begin
+    }
+
+    pub fn end(&self) -> PaddedStringSizeType {
+        self.messages[0].end
+    }
+
+    // Extra messages pointing at related code. Each note's severity is
+    // DiagnosticSeverity::Note.
+    pub fn notes(&self) -> &[DiagnosticMessage] {
+        &self.messages[1..]
+    }
+
+    // The main message followed by the notes.
+    pub fn messages(&self) -> &[DiagnosticMessage] {
+        &self.messages
+    }
+
+    // The returned diag's &[u8] fields point into this Diagnostic, so they
+    // have a shorter lifetime than 'code.
+    pub fn diag<'a>(&'a self) -> &'a AnyDiag<'a> {
+        &self.diag
+    }
+}
+
+impl<'code> Clone for Diagnostic<'code> {
+    fn clone(&self) -> Self {
+        Diagnostic::new(self.diag.clone(), self.code, self.messages.clone())
+    }
+}
+
+// Collects reported diagnostics into a Vec<Diagnostic>.
+pub struct VecDiagReporter<'code> {
+    input: PaddedStringView<'code>,
+    translator: Translator,
+    diagnostics: std::cell::RefCell<Vec<Diagnostic<'code>>>,
+}
+
+impl<'code> VecDiagReporter<'code> {
+    // input must be the code given to the lexer. Messages are formatted in
+    // English until set_translator is called.
+    pub fn new(input: PaddedStringView<'code>) -> VecDiagReporter<'code> {
+        VecDiagReporter {
+            input,
+            translator: Translator::new_using_messages_from_source_code(),
+            diagnostics: std::cell::RefCell::new(vec![]),
+        }
+    }
+
+    // Affects diagnostics reported after this call.
+    pub fn set_translator(&mut self, translator: Translator) {
+        self.translator = translator;
+    }
+
+    pub fn len(&self) -> usize {
+        self.diagnostics.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.borrow().is_empty()
+    }
+
+    pub fn clone_diagnostics(&self) -> Vec<Diagnostic<'code>> {
+        self.diagnostics.borrow().clone()
+    }
+
+    // Remove and return the diagnostics reported so far.
+    pub fn take_diagnostics(&self) -> Vec<Diagnostic<'code>> {
+        self.diagnostics.take()
+    }
+
+    pub fn into_diagnostics(self) -> Vec<Diagnostic<'code>> {
+        self.diagnostics.into_inner()
+    }
+}
+
+impl<'code> DiagReporter for VecDiagReporter<'code> {
+    fn report_impl(&self, type_: DiagType, diag: *const u8) {
+        let info: &DiagnosticInfo = get_diagnostic_info(type_);
+        let mut formatter: VecDiagFormatter = VecDiagFormatter {
+            input: self.input,
+            translator: &self.translator,
+            current_message: vec![],
+            messages: vec![],
+        };
+        formatter.format(info, diag);
+        self.diagnostics.borrow_mut().push(Diagnostic::new(
+            unsafe { AnyDiag::from_raw_parts(type_, diag) },
+            info.code_string(),
+            formatter.messages,
+        ));
+    }
+}
+
+struct VecDiagFormatter<'code, 'reporter> {
+    input: PaddedStringView<'code>,
+    translator: &'reporter Translator,
+    current_message: Vec<u8>,
+    messages: Vec<DiagnosticMessage>,
+}
+
+impl<'code, 'reporter> DiagnosticFormatter for VecDiagFormatter<'code, 'reporter> {
+    fn write_before_message(
+        &mut self,
+        _code: &str,
+        _severity: DiagnosticSeverity,
+        _origin: SourceCodeSpan<'_>,
+    ) {
+    }
+
+    fn write_message_part(&mut self, _code: &str, _severity: DiagnosticSeverity, message: &[u8]) {
+        self.current_message.extend_from_slice(message);
+    }
+
+    fn write_after_message(
+        &mut self,
+        _code: &str,
+        severity: DiagnosticSeverity,
+        origin: SourceCodeSpan<'_>,
+    ) {
+        let offset = |c: *const u8| -> PaddedStringSizeType {
+            narrow_cast(unsafe { c.offset_from(self.input.c_str()) })
+        };
+        let message: Vec<u8> = std::mem::take(&mut self.current_message);
+        self.messages.push(DiagnosticMessage {
+            severity,
+            // Messages can quote source code, which might not be UTF-8.
+            message: match String::from_utf8(message) {
+                Ok(message) => message,
+                Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+            },
+            begin: offset(origin.begin_ptr()),
+            end: offset(origin.end_ptr()),
+        });
+    }
+
+    fn translator(&self) -> Translator {
+        self.translator.clone()
+    }
+}
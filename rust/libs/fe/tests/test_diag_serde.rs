@@ -0,0 +1,166 @@
+use cpp_vs_rust_container::monotonic_allocator::*;
+use cpp_vs_rust_fe::diag_serde::*;
+use cpp_vs_rust_fe::diagnostic::*;
+use cpp_vs_rust_fe::diagnostic_types::*;
+use cpp_vs_rust_fe::lex::*;
+use cpp_vs_rust_fe::linter::*;
+use cpp_vs_rust_fe::source_code_span::*;
+use cpp_vs_rust_fe::token::*;
+use cpp_vs_rust_port::assert_matches;
+use cpp_vs_rust_test::diag_collector::*;
+use cpp_vs_rust_util::padded_string::*;
+use serde::de::DeserializeSeed;
+
+fn deserialize_json<'code, Diag: DiagSerde<'code>>(
+    json: &str,
+    input: PaddedStringView<'code>,
+    allocator: &'code MonotonicAllocator,
+) -> Result<Diag, serde_json::Error> {
+    DiagDeserializer::<Diag>::new(input, allocator)
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn enums_round_trip() {
+    for diag_type in [
+        DiagType::DiagUnclosedStringLiteral,
+        DiagType::DiagMultipleMessageTest,
+    ] {
+        let json: String = serde_json::to_string(&diag_type).unwrap();
+        assert_eq!(serde_json::from_str::<DiagType>(&json).unwrap(), diag_type);
+    }
+    assert_eq!(
+        serde_json::to_string(&DiagType::DiagUnclosedStringLiteral).unwrap(),
+        r#""DiagUnclosedStringLiteral""#
+    );
+
+    for severity in [
+        DiagnosticSeverity::Error,
+        DiagnosticSeverity::Note,
+        DiagnosticSeverity::Warning,
+    ] {
+        let json: String = serde_json::to_string(&severity).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DiagnosticSeverity>(&json).unwrap(),
+            severity
+        );
+    }
+
+    for token_type in [TokenType::Identifier, TokenType::KWIf, TokenType::EndOfFile] {
+        let json: String = serde_json::to_string(&token_type).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TokenType>(&json).unwrap(),
+            token_type
+        );
+    }
+}
+
+#[test]
+fn diag_spans_are_offsets_into_input() {
+    let input: PaddedString = PaddedString::from_slice(b"x = \"hello\";");
+    let diag: DiagInvalidQuotesAroundStringLiteral = DiagInvalidQuotesAroundStringLiteral {
+        opening_quote: SourceCodeSpan::from_slice(&input.as_slice()[4..5]),
+        suggested_quote: b'\'',
+    };
+    let json: String = serde_json::to_string(&DiagSerializer::new(&diag, input.view())).unwrap();
+    assert_eq!(
+        json,
+        r#"{"opening_quote":{"begin":4,"end":5},"suggested_quote":39}"#
+    );
+
+    // Spans point into the input given to the deserializer, even if it is a
+    // different copy of the code.
+    let other_input: PaddedString = PaddedString::from_slice(input.as_slice());
+    let allocator: MonotonicAllocator = MonotonicAllocator::new("test");
+    let deserialized: DiagInvalidQuotesAroundStringLiteral =
+        deserialize_json(&json, other_input.view(), &allocator).unwrap();
+    assert!(same_pointers(
+        deserialized.opening_quote,
+        SourceCodeSpan::from_slice(&other_input.as_slice()[4..5])
+    ));
+    assert_eq!(deserialized.suggested_quote, b'\'');
+}
+
+#[test]
+fn diag_strings_are_copied() {
+    let input: PaddedString = PaddedString::from_slice(b"9007199254740993");
+    let diag: DiagIntegerLiteralWillLosePrecision = DiagIntegerLiteralWillLosePrecision {
+        characters: SourceCodeSpan::from_slice(input.as_slice()),
+        rounded_val: b"9007199254740992",
+    };
+    let json: String = serde_json::to_string(&DiagSerializer::new(&diag, input.view())).unwrap();
+
+    let allocator: MonotonicAllocator = MonotonicAllocator::new("test");
+    let deserialized: DiagIntegerLiteralWillLosePrecision =
+        deserialize_json(&json, input.view(), &allocator).unwrap();
+    assert!(same_pointers(deserialized.characters, diag.characters));
+    assert_eq!(deserialized.rounded_val, b"9007199254740992");
+}
+
+#[test]
+fn linted_diags_round_trip_as_any_diag() {
+    let input: PaddedString = PaddedString::from_slice(b"9007199254740993; 'unclosed");
+    let collector: DiagCollector = DiagCollector::new();
+    let mut lexer_allocator: LexerAllocator = LexerAllocator::new();
+    parse_and_lint_with_allocator(
+        input.view(),
+        &collector,
+        LinterOptions::default(),
+        &mut lexer_allocator,
+    );
+    let diags: Vec<AnyDiag> = collector.clone_errors();
+    assert_eq!(diags.len(), 2);
+
+    let allocator: MonotonicAllocator = MonotonicAllocator::new("test");
+    for diag in &diags {
+        let json: String = serde_json::to_string(&DiagSerializer::new(diag, input.view())).unwrap();
+        let deserialized: AnyDiag = deserialize_json(&json, input.view(), &allocator).unwrap();
+        assert_eq!(deserialized.diag_type(), diag.diag_type());
+        assert_eq!(
+            serde_json::to_string(&DiagSerializer::new(&deserialized, input.view())).unwrap(),
+            json
+        );
+    }
+    assert_matches!(
+        deserialize_json::<AnyDiag>(
+            r#"{"DiagUnclosedStringLiteral":{"string_literal":{"begin":18,"end":27}}}"#,
+            input.view(),
+            &allocator
+        ),
+        Ok(AnyDiag::DiagUnclosedStringLiteral(diag))
+            if diag.string_literal.as_slice() == b"'unclosed",
+    );
+}
+
+#[test]
+fn span_outside_input_fails_to_serialize() {
+    let input: PaddedString = PaddedString::from_slice(b"hello");
+    let other_code: PaddedString = PaddedString::from_slice(b"'");
+    let diag: DiagUnclosedStringLiteral = DiagUnclosedStringLiteral {
+        string_literal: SourceCodeSpan::from_slice(other_code.as_slice()),
+    };
+    let error: serde_json::Error =
+        serde_json::to_string(&DiagSerializer::new(&diag, input.view())).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        DiagSerdeError::SpanOutsideInput.to_string()
+    );
+}
+
+#[test]
+fn invalid_offsets_fail_to_deserialize() {
+    let input: PaddedString = PaddedString::from_slice(b"hello");
+    let allocator: MonotonicAllocator = MonotonicAllocator::new("test");
+    for (begin, end) in [(0, 6), (3, 2), (-1, 2)] {
+        let json: String = format!(r#"{{"string_literal":{{"begin":{begin},"end":{end}}}}}"#);
+        let error: serde_json::Error =
+            deserialize_json::<DiagUnclosedStringLiteral>(&json, input.view(), &allocator)
+                .unwrap_err();
+        assert!(
+            error.to_string().starts_with(
+                &DiagSerdeError::InvalidSpan(SourceCodeSpanOffsets { begin, end }).to_string()
+            ),
+            "{error}"
+        );
+    }
+}
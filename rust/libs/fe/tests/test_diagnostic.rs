@@ -91,12 +91,13 @@ fn diagnostic_message_arg_info_type() {
         DiagnosticArgType::Char8,
         DiagnosticArgType::EnumKind,
         DiagnosticArgType::Identifier,
+        DiagnosticArgType::Integer,
         DiagnosticArgType::SourceCodeSpan,
         DiagnosticArgType::StatementKind,
         DiagnosticArgType::String8View,
         DiagnosticArgType::VariableKind,
     ] {
-        for offset in [0, 2, 4, 6, 8, 10, 12, 14, 16, 24, 32] {
+        for offset in [0, 2, 4, 6, 8, 10, 12, 14, 16, 24, 32, 64, 128, 256, 510] {
             assert_eq!(
                 DiagnosticMessageArgInfo::new(offset, arg_type).type_(),
                 arg_type,
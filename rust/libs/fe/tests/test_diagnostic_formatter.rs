@@ -7,7 +7,9 @@ use cpp_vs_rust_fe::language::*;
 use cpp_vs_rust_fe::source_code_span::*;
 use cpp_vs_rust_i18n::qljs_translatable;
 use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_i18n::translation_catalog::*;
 use cpp_vs_rust_port::qljs_offset_of;
+use std::sync::Arc;
 
 fn empty_span() -> SourceCodeSpan<'static> {
     unsafe { SourceCodeSpan::new(std::ptr::null(), std::ptr::null()) }
@@ -15,12 +17,20 @@ fn empty_span() -> SourceCodeSpan<'static> {
 
 struct StringDiagnosticFormatter {
     message: Vec<u8>,
+    translator: Translator,
 }
 
 impl StringDiagnosticFormatter {
     fn new() -> StringDiagnosticFormatter {
+        StringDiagnosticFormatter::new_with_translator(
+            Translator::new_using_messages_from_source_code(),
+        )
+    }
+
+    fn new_with_translator(translator: Translator) -> StringDiagnosticFormatter {
         StringDiagnosticFormatter {
             message: Vec::<u8>::new(),
+            translator,
         }
     }
 }
@@ -53,7 +63,7 @@ impl DiagnosticFormatter for StringDiagnosticFormatter {
     }
 
     fn translator(&self) -> Translator {
-        Translator::new_using_messages_from_source_code()
+        self.translator.clone()
     }
 }
 
@@ -369,6 +379,38 @@ fn enum_kind_placeholder() {
         );
         assert_eq!(formatter.message, b"expected enum\n");
     }
+
+    {
+        let diag = TestDiag {
+            empty_span: empty_span(),
+            kind: EnumKind::Normal,
+        };
+        let mut formatter = StringDiagnosticFormatter::new();
+        formatter.format_message(
+            "E9999",
+            DiagnosticSeverity::Error,
+            qljs_translatable!("expected {1:singular}"),
+            &message_args,
+            &diag as *const _ as *const u8,
+        );
+        assert_eq!(formatter.message, b"expected an enum\n");
+    }
+
+    {
+        let diag = TestDiag {
+            empty_span: empty_span(),
+            kind: EnumKind::DeclareConstEnum,
+        };
+        let mut formatter = StringDiagnosticFormatter::new();
+        formatter.format_message(
+            "E9999",
+            DiagnosticSeverity::Error,
+            qljs_translatable!("expected {1:singular}"),
+            &message_args,
+            &diag as *const _ as *const u8,
+        );
+        assert_eq!(formatter.message, b"expected a declare const enum\n");
+    }
 }
 
 #[test]
@@ -581,3 +623,139 @@ fn statement_kind_placeholder() {
         assert_eq!(formatter.message, b"expected a labelled statement\n");
     }
 }
+
+#[test]
+fn variable_kind_placeholder() {
+    struct TestDiag {
+        empty_span: SourceCodeSpan<'static>,
+        kind: VariableKind,
+    }
+    let message_args: DiagnosticMessageArgs = [
+        DiagnosticMessageArgInfo::new(
+            qljs_offset_of!(TestDiag, empty_span),
+            DiagnosticArgType::SourceCodeSpan,
+        ),
+        DiagnosticMessageArgInfo::new(
+            qljs_offset_of!(TestDiag, kind),
+            DiagnosticArgType::VariableKind,
+        ),
+        DiagnosticMessageArgInfo::empty(),
+    ];
+
+    for (kind, expected_headlinese, expected_singular) in [
+        (
+            VariableKind::Const,
+            &b"cannot assign to 'const' variable\n"[..],
+            &b"cannot assign to a 'const' variable\n"[..],
+        ),
+        (
+            VariableKind::ArrowParameter,
+            &b"cannot assign to parameter\n"[..],
+            &b"cannot assign to a parameter\n"[..],
+        ),
+        (
+            VariableKind::Import,
+            &b"cannot assign to import\n"[..],
+            &b"cannot assign to an import\n"[..],
+        ),
+    ] {
+        let diag = TestDiag {
+            empty_span: empty_span(),
+            kind,
+        };
+
+        let mut formatter = StringDiagnosticFormatter::new();
+        formatter.format_message(
+            "E9999",
+            DiagnosticSeverity::Error,
+            qljs_translatable!("cannot assign to {1:headlinese}"),
+            &message_args,
+            &diag as *const _ as *const u8,
+        );
+        assert_eq!(formatter.message, expected_headlinese);
+
+        let mut formatter = StringDiagnosticFormatter::new();
+        formatter.format_message(
+            "E9999",
+            DiagnosticSeverity::Error,
+            qljs_translatable!("cannot assign to {1:singular}"),
+            &message_args,
+            &diag as *const _ as *const u8,
+        );
+        assert_eq!(formatter.message, expected_singular);
+    }
+}
+
+struct IntegerTestDiag {
+    empty_span: SourceCodeSpan<'static>,
+    count: usize,
+}
+
+const INTEGER_TEST_DIAG_MESSAGE_ARGS: DiagnosticMessageArgs = [
+    DiagnosticMessageArgInfo::new(
+        qljs_offset_of!(IntegerTestDiag, empty_span),
+        DiagnosticArgType::SourceCodeSpan,
+    ),
+    DiagnosticMessageArgInfo::new(
+        qljs_offset_of!(IntegerTestDiag, count),
+        DiagnosticArgType::Integer,
+    ),
+    DiagnosticMessageArgInfo::empty(),
+];
+
+fn format_integer_test_diag(translator: Translator, count: usize) -> Vec<u8> {
+    let diag = IntegerTestDiag {
+        empty_span: empty_span(),
+        count,
+    };
+    let mut formatter = StringDiagnosticFormatter::new_with_translator(translator);
+    formatter.format_message(
+        "E9999",
+        DiagnosticSeverity::Error,
+        qljs_translatable!("expected {1} {1:plural:argument|arguments}"),
+        &INTEGER_TEST_DIAG_MESSAGE_ARGS,
+        &diag as *const _ as *const u8,
+    );
+    formatter.message
+}
+
+#[test]
+fn integer_plural_placeholder_uses_source_code_plural_rule() {
+    let t = Translator::new_using_messages_from_source_code();
+    assert_eq!(
+        format_integer_test_diag(t.clone(), 0),
+        b"expected 0 arguments\n"
+    );
+    assert_eq!(
+        format_integer_test_diag(t.clone(), 1),
+        b"expected 1 argument\n"
+    );
+    assert_eq!(format_integer_test_diag(t, 42), b"expected 42 arguments\n");
+}
+
+#[test]
+fn integer_plural_placeholder_uses_translation_plural_rule() {
+    // Polish has three plural forms.
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"\"\n\
+         msgstr \"Plural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\\n\"\n\
+         \n\
+         msgid \"expected {1} {1:plural:argument|arguments}\"\n\
+         msgstr \"oczekiwano {1} {1:plural:argument|argumenty|argument\u{f3}w}\"\n",
+    )
+    .unwrap();
+    let mut t = Translator::new_using_messages_from_source_code();
+    t.use_catalog(Arc::new(catalog));
+    for (count, expected) in [
+        (1, "oczekiwano 1 argument\n"),
+        (2, "oczekiwano 2 argumenty\n"),
+        (5, "oczekiwano 5 argument\u{f3}w\n"),
+        (12, "oczekiwano 12 argument\u{f3}w\n"),
+        (22, "oczekiwano 22 argumenty\n"),
+    ] {
+        assert_eq!(
+            String::from_utf8(format_integer_test_diag(t.clone(), count)).unwrap(),
+            expected
+        );
+    }
+}
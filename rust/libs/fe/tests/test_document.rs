@@ -19,3 +19,61 @@ fn set_text_multiple_times() {
     doc.set_text(b"finally");
     assert_eq!(doc.string().slice(), b"finally");
 }
+
+#[test]
+fn replace_text_in_middle() {
+    let mut doc: Document<WebDemoLocator> = Document::new();
+    doc.set_text(b"content goes here");
+    doc.replace_text(WebDemoSourceRange { begin: 8, end: 12 }, b"went");
+    assert_eq!(doc.string().slice(), b"content went here");
+}
+
+#[test]
+fn replace_text_inserting_and_deleting() {
+    let mut doc: Document<WebDemoLocator> = Document::new();
+    doc.set_text(b"content");
+    doc.replace_text(WebDemoSourceRange { begin: 0, end: 0 }, b"new ");
+    assert_eq!(doc.string().slice(), b"new content");
+    doc.replace_text(WebDemoSourceRange { begin: 11, end: 11 }, b" here");
+    assert_eq!(doc.string().slice(), b"new content here");
+    doc.replace_text(WebDemoSourceRange { begin: 3, end: 11 }, b"");
+    assert_eq!(doc.string().slice(), b"new here");
+    doc.replace_text(WebDemoSourceRange { begin: 0, end: 8 }, b"");
+    assert_eq!(doc.string().slice(), b"");
+}
+
+#[test]
+fn replace_text_after_end_appends() {
+    let mut doc: Document<WebDemoLocator> = Document::new();
+    doc.set_text(b"hello");
+    doc.replace_text(
+        WebDemoSourceRange {
+            begin: 100,
+            end: 200,
+        },
+        b" world",
+    );
+    assert_eq!(doc.string().slice(), b"hello world");
+}
+
+#[test]
+fn replace_text_uses_utf_16_offsets() {
+    let mut doc: Document<WebDemoLocator> = Document::new();
+    // U+2603 has one UTF-16 code unit. U+1f496 has two UTF-16 code units.
+    doc.set_text("\u{2603}\u{01f496}x".as_bytes());
+    doc.replace_text(WebDemoSourceRange { begin: 3, end: 4 }, b"y");
+    assert_eq!(doc.string().slice(), "\u{2603}\u{01f496}y".as_bytes());
+    doc.replace_text(WebDemoSourceRange { begin: 1, end: 3 }, b"z");
+    assert_eq!(doc.string().slice(), "\u{2603}zy".as_bytes());
+}
+
+#[test]
+fn set_text_after_replace_text() {
+    let mut doc: Document<WebDemoLocator> = Document::new();
+    doc.set_text(b"content goes here");
+    doc.replace_text(WebDemoSourceRange { begin: 0, end: 7 }, b"text");
+    doc.set_text(b"finally");
+    assert_eq!(doc.string().slice(), b"finally");
+    doc.replace_text(WebDemoSourceRange { begin: 5, end: 7 }, b"ity");
+    assert_eq!(doc.string().slice(), b"finality");
+}
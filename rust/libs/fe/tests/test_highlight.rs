@@ -0,0 +1,296 @@
+use cpp_vs_rust_fe::highlight::*;
+use cpp_vs_rust_fe::linter::*;
+use cpp_vs_rust_fe::token::*;
+use cpp_vs_rust_util::padded_string::*;
+
+use HighlightClass::*;
+
+fn highlight_with_options(
+    code: &str,
+    linter_options: LinterOptions,
+) -> Vec<(std::string::String, HighlightClass)> {
+    let input: PaddedString = PaddedString::from_slice(code.as_bytes());
+    highlight(input.view(), linter_options)
+        .iter()
+        .map(|token: &HighlightToken| {
+            (
+                code[token.begin as usize..token.end as usize].to_string(),
+                token.class,
+            )
+        })
+        .collect()
+}
+
+fn highlight_js(code: &str) -> Vec<(std::string::String, HighlightClass)> {
+    highlight_with_options(
+        code,
+        LinterOptions {
+            jsx: false,
+            ..LinterOptions::default()
+        },
+    )
+}
+
+fn highlight_jsx(code: &str) -> Vec<(std::string::String, HighlightClass)> {
+    highlight_with_options(
+        code,
+        LinterOptions {
+            jsx: true,
+            ..LinterOptions::default()
+        },
+    )
+}
+
+fn t(text: &str, class: HighlightClass) -> (std::string::String, HighlightClass) {
+    (text.to_string(), class)
+}
+
+#[test]
+fn empty_code_has_no_tokens() {
+    assert_eq!(highlight_js(""), vec![]);
+    assert_eq!(highlight_js(" \n\t"), vec![]);
+}
+
+#[test]
+fn keywords_identifiers_and_literals() {
+    assert_eq!(
+        highlight_js("for (let x of #y) return 'hi' + 4.2;"),
+        vec![
+            t("for", Keyword),
+            t("(", Punctuator),
+            t("let", ContextualKeyword),
+            t("x", Identifier),
+            t("of", ContextualKeyword),
+            t("#y", PrivateIdentifier),
+            t(")", Punctuator),
+            t("return", Keyword),
+            t("'hi'", String),
+            t("+", Punctuator),
+            t("4.2", Number),
+            t(";", Punctuator),
+        ]
+    );
+}
+
+#[test]
+fn comments() {
+    assert_eq!(
+        highlight_js("#!/usr/bin/env node\na /* b */ // c\r\n/** d\n*/"),
+        vec![
+            t("#!/usr/bin/env node", Comment),
+            t("a", Identifier),
+            t("/* b */", Comment),
+            t("// c", Comment),
+            t("/** d\n*/", Comment),
+        ]
+    );
+    assert_eq!(
+        highlight_js("x /* unterminated"),
+        vec![t("x", Identifier), t("/* unterminated", Comment)]
+    );
+}
+
+#[test]
+fn slash_after_operand_is_division() {
+    assert_eq!(
+        highlight_js("a / b / c"),
+        vec![
+            t("a", Identifier),
+            t("/", Punctuator),
+            t("b", Identifier),
+            t("/", Punctuator),
+            t("c", Identifier),
+        ]
+    );
+    assert_eq!(
+        highlight_js("(x) /= 2"),
+        vec![
+            t("(", Punctuator),
+            t("x", Identifier),
+            t(")", Punctuator),
+            t("/=", Punctuator),
+            t("2", Number),
+        ]
+    );
+}
+
+#[test]
+fn slash_before_operand_is_regexp() {
+    assert_eq!(
+        highlight_js("x = /a[/]b/g.test(y)"),
+        vec![
+            t("x", Identifier),
+            t("=", Punctuator),
+            t("/a[/]b/g", Regexp),
+            t(".", Punctuator),
+            t("test", Identifier),
+            t("(", Punctuator),
+            t("y", Identifier),
+            t(")", Punctuator),
+        ]
+    );
+    assert_eq!(
+        highlight_js("return /=/;"),
+        vec![t("return", Keyword), t("/=/", Regexp), t(";", Punctuator)]
+    );
+    assert_eq!(highlight_js("/x/"), vec![t("/x/", Regexp)]);
+}
+
+#[test]
+fn templates_with_substitutions() {
+    assert_eq!(
+        highlight_js("`a${b}c${ {d} }e` / 2"),
+        vec![
+            t("`a${", Template),
+            t("b", Identifier),
+            t("}", Template),
+            t("c${", Template),
+            t("{", Punctuator),
+            t("d", Identifier),
+            t("}", Punctuator),
+            t("}", Template),
+            t("e`", Template),
+            t("/", Punctuator),
+            t("2", Number),
+        ]
+    );
+    assert_eq!(
+        highlight_js("`${`${/re/}`}`"),
+        vec![
+            t("`${", Template),
+            t("`${", Template),
+            t("/re/", Regexp),
+            t("}", Template),
+            t("`", Template),
+            t("}", Template),
+            t("`", Template),
+        ]
+    );
+}
+
+#[test]
+fn less_is_jsx_only_if_jsx_is_enabled() {
+    assert_eq!(
+        highlight_js("x = <div>"),
+        vec![
+            t("x", Identifier),
+            t("=", Punctuator),
+            t("<", Punctuator),
+            t("div", Identifier),
+            t(">", Punctuator),
+        ]
+    );
+    assert_eq!(
+        highlight_jsx("a < b"),
+        vec![t("a", Identifier), t("<", Punctuator), t("b", Identifier)]
+    );
+}
+
+#[test]
+fn jsx_element_with_attributes_and_children() {
+    assert_eq!(
+        highlight_jsx("x = <my-div class=\"c\" id={y}>Hello, {name}! <br/></my-div> / 2"),
+        vec![
+            t("x", Identifier),
+            t("=", Punctuator),
+            t("<", JSXTag),
+            t("my-div", JSXTag),
+            t("class", Identifier),
+            t("=", Punctuator),
+            t("\"c\"", String),
+            t("id", Identifier),
+            t("=", Punctuator),
+            t("{", Punctuator),
+            t("y", Identifier),
+            t("}", Punctuator),
+            t(">", JSXTag),
+            t("Hello,", JSXText),
+            t("{", Punctuator),
+            t("name", Identifier),
+            t("}", Punctuator),
+            t("!", JSXText),
+            t("<", JSXTag),
+            t("br", JSXTag),
+            t("/", JSXTag),
+            t(">", JSXTag),
+            t("<", JSXTag),
+            t("/", JSXTag),
+            t("my-div", JSXTag),
+            t(">", JSXTag),
+            t("/", Punctuator),
+            t("2", Number),
+        ]
+    );
+}
+
+#[test]
+fn jsx_fragments_member_names_and_nested_expressions() {
+    assert_eq!(
+        highlight_jsx("<><a.b>{c ? <d /> : 'e'}</a.b></>"),
+        vec![
+            t("<", JSXTag),
+            t(">", JSXTag),
+            t("<", JSXTag),
+            t("a", JSXTag),
+            t(".", JSXTag),
+            t("b", JSXTag),
+            t(">", JSXTag),
+            t("{", Punctuator),
+            t("c", Identifier),
+            t("?", Punctuator),
+            t("<", JSXTag),
+            t("d", JSXTag),
+            t("/", JSXTag),
+            t(">", JSXTag),
+            t(":", Punctuator),
+            t("'e'", String),
+            t("}", Punctuator),
+            t("<", JSXTag),
+            t("/", JSXTag),
+            t("a", JSXTag),
+            t(".", JSXTag),
+            t("b", JSXTag),
+            t(">", JSXTag),
+            t("<", JSXTag),
+            t("/", JSXTag),
+            t(">", JSXTag),
+        ]
+    );
+}
+
+#[test]
+fn unterminated_jsx_does_not_hang() {
+    for code in ["<div", "<div>text", "<div>{", "<div attr=", "`${<a>"] {
+        let _ = highlight_jsx(code);
+    }
+}
+
+#[test]
+fn tokens_are_sorted_and_do_not_overlap() {
+    let code: &str = "/* c */ let x = `a${<b c={/d/}>e{f}</b>}g` // h\n";
+    let input: PaddedString = PaddedString::from_slice(code.as_bytes());
+    let tokens: Vec<HighlightToken> = highlight(input.view(), LinterOptions::default());
+    assert!(!tokens.is_empty());
+    for pair in tokens.windows(2) {
+        assert!(pair[0].begin < pair[0].end, "{pair:?}");
+        assert!(pair[0].end <= pair[1].begin, "{pair:?}");
+    }
+}
+
+#[test]
+fn token_types_classify_like_lexer_keyword_categories() {
+    assert_eq!(classify_token_type(TokenType::KWIf), Keyword);
+    assert_eq!(classify_token_type(TokenType::KWAwait), Keyword);
+    assert_eq!(classify_token_type(TokenType::KWAsync), ContextualKeyword);
+    assert_eq!(classify_token_type(TokenType::KWType), ContextualKeyword);
+    assert_eq!(classify_token_type(TokenType::Identifier), Identifier);
+    assert_eq!(classify_token_type(TokenType::EqualGreater), Punctuator);
+}
+
+#[test]
+fn lsp_semantic_token_types() {
+    assert_eq!(Keyword.lsp_semantic_token_type(), "keyword");
+    assert_eq!(ContextualKeyword.lsp_semantic_token_type(), "keyword");
+    assert_eq!(Regexp.lsp_semantic_token_type(), "regexp");
+    assert_eq!(JSXText.lsp_semantic_token_type(), "string");
+}
@@ -0,0 +1,160 @@
+use cpp_vs_rust_fe::jsx_entity::*;
+
+#[test]
+fn named_entities() {
+    assert_eq!(
+        parse_jsx_entity(b"&amp;"),
+        JSXEntity::Valid {
+            code_point: '&' as u32,
+            size: 5
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&nbsp; rest"),
+        JSXEntity::Valid {
+            code_point: 0xa0,
+            size: 6
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&apos;"),
+        JSXEntity::Valid {
+            code_point: '\'' as u32,
+            size: 6
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&AElig;"),
+        JSXEntity::Valid {
+            code_point: 0xc6,
+            size: 7
+        }
+    );
+}
+
+#[test]
+fn named_entities_are_case_sensitive() {
+    assert_eq!(parse_jsx_entity(b"&AMP;"), JSXEntity::Unknown { size: 5 });
+    assert_eq!(parse_jsx_entity(b"&Nbsp;"), JSXEntity::Unknown { size: 6 });
+}
+
+#[test]
+fn unknown_named_entities() {
+    assert_eq!(parse_jsx_entity(b"&nbps;"), JSXEntity::Unknown { size: 6 });
+    // HTML5-only entities are not recognized by JSX compilers.
+    assert_eq!(
+        parse_jsx_entity(b"&NewLine;"),
+        JSXEntity::Unknown { size: 9 }
+    );
+}
+
+#[test]
+fn unterminated_named_entities() {
+    assert_eq!(
+        parse_jsx_entity(b"&copy 2020"),
+        JSXEntity::Unterminated { size: 5 }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&amp"),
+        JSXEntity::Unterminated { size: 4 }
+    );
+}
+
+#[test]
+fn ampersand_without_entity() {
+    assert_eq!(parse_jsx_entity(b"&"), JSXEntity::NotEntity);
+    assert_eq!(parse_jsx_entity(b"& Jerry"), JSXEntity::NotEntity);
+    assert_eq!(parse_jsx_entity(b"&D"), JSXEntity::NotEntity);
+    assert_eq!(parse_jsx_entity(b"&;"), JSXEntity::NotEntity);
+    assert_eq!(parse_jsx_entity(b"&#"), JSXEntity::NotEntity);
+    assert_eq!(parse_jsx_entity(b"&#x"), JSXEntity::NotEntity);
+}
+
+#[test]
+fn decimal_entities() {
+    assert_eq!(
+        parse_jsx_entity(b"&#65;"),
+        JSXEntity::Valid {
+            code_point: 65,
+            size: 5
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&#128512;"),
+        JSXEntity::Valid {
+            code_point: 0x1f600,
+            size: 9
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&#65 "),
+        JSXEntity::Unterminated { size: 4 }
+    );
+}
+
+#[test]
+fn hex_entities() {
+    assert_eq!(
+        parse_jsx_entity(b"&#x41;"),
+        JSXEntity::Valid {
+            code_point: 0x41,
+            size: 6
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&#XfF;"),
+        JSXEntity::Valid {
+            code_point: 0xff,
+            size: 6
+        }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&#x41"),
+        JSXEntity::Unterminated { size: 5 }
+    );
+}
+
+#[test]
+fn invalid_numeric_entities() {
+    assert_eq!(parse_jsx_entity(b"&#;"), JSXEntity::Unknown { size: 3 });
+    assert_eq!(parse_jsx_entity(b"&#x;"), JSXEntity::Unknown { size: 4 });
+    assert_eq!(parse_jsx_entity(b"&#0;"), JSXEntity::Unknown { size: 4 });
+    assert_eq!(
+        parse_jsx_entity(b"&#xd800;"),
+        JSXEntity::Unknown { size: 8 }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&#x110000;"),
+        JSXEntity::Unknown { size: 10 }
+    );
+    assert_eq!(
+        parse_jsx_entity(b"&#99999999999999999999;"),
+        JSXEntity::Unknown { size: 23 }
+    );
+}
+
+#[test]
+fn look_up_named_entities() {
+    assert_eq!(look_up_jsx_entity(b"lt"), Some('<' as u32));
+    assert_eq!(look_up_jsx_entity(b"zwnj"), Some(0x200c));
+    assert_eq!(look_up_jsx_entity(b"nbps"), None);
+    assert_eq!(look_up_jsx_entity(b""), None);
+}
+
+#[test]
+fn decode_entities() {
+    assert_eq!(decode_jsx_entities(b"hello"), b"hello");
+    assert_eq!(decode_jsx_entities(b"Tom &amp; Jerry"), b"Tom & Jerry");
+    assert_eq!(decode_jsx_entities(b"&lt;div&gt;"), b"<div>");
+    assert_eq!(decode_jsx_entities(b"a&nbsp;b"), "a\u{a0}b".as_bytes());
+    assert_eq!(decode_jsx_entities(b"&#x1F600;"), "\u{1f600}".as_bytes());
+}
+
+#[test]
+fn decode_keeps_invalid_entities() {
+    assert_eq!(decode_jsx_entities(b"Tom & Jerry"), b"Tom & Jerry");
+    assert_eq!(decode_jsx_entities(b"&nbps;"), b"&nbps;");
+    assert_eq!(decode_jsx_entities(b"&copy 2020"), b"&copy 2020");
+    assert_eq!(decode_jsx_entities(b"&#xd800;"), b"&#xd800;");
+    assert_eq!(decode_jsx_entities(b"&&amp;"), b"&&");
+}
@@ -45,6 +45,25 @@ fn lex_block_comments() {
     }
 }
 
+#[test]
+fn reset_allocator_can_be_reused_by_another_lexer() {
+    let mut allocator = LexerAllocator::new();
+    for (input, expected_name) in [
+        (&b"\\u{61}bc"[..], &b"abc"[..]),
+        (&b"d\\u0065f"[..], &b"def"[..]),
+    ] {
+        allocator.reset();
+        let v = DiagCollector::new();
+        let code = PaddedString::from_slice(input);
+        let mut l = Lexer::new(code.view(), &v, &allocator);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        assert_eq!(l.peek().identifier_name().normalized_name(), expected_name);
+        l.skip();
+        assert_eq!(l.peek().type_, TokenType::EndOfFile);
+        qljs_assert_no_diags!(v.clone_errors(), code.view());
+    }
+}
+
 #[test]
 fn lex_unopened_block_comment() {
     {
@@ -1407,9 +1426,195 @@ fn lex_strings() {
             );
         },
     );
+}
+
+#[test]
+fn lex_string_with_digit_escapes() {
+    let mut f = Fixture::new();
+
+    f.check_tokens(br#"'\0'"#, &[TokenType::String]);
+    f.check_tokens(br#"'hello\0world'"#, &[TokenType::String]);
+
+    for (input, escape_sequence) in [
+        (r#"'\00'"#, r#"\00"#),
+        (r#"'\08'"#, r#"\0"#),
+        (r#"'\1'"#, r#"\1"#),
+        (r#"'\12'"#, r#"\12"#),
+        (r#"'\123'"#, r#"\123"#),
+        (r#"'\3777'"#, r#"\377"#),
+        (r#"'\477'"#, r#"\47"#),
+        (r#"'\7a'"#, r#"\7"#),
+    ] {
+        scoped_trace!(input);
+        let input = PaddedString::from_slice(input.as_bytes());
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new(input.view(), &errors, &allocator);
+        assert_eq!(l.peek().type_, TokenType::String);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            input.view(),
+            DiagLegacyOctalEscapeSequence {
+                escape_sequence: b"'"..(escape_sequence.as_bytes()),
+            },
+        );
+    }
+
+    f.check_tokens_with_errors(
+        br#"'\8\9'"#,
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagNonOctalDecimalEscapeSequence {
+                    escape_sequence: b"'"..b"\\8",
+                },
+                DiagNonOctalDecimalEscapeSequence {
+                    escape_sequence: b"'\\8"..b"\\9",
+                },
+            );
+        },
+    );
+
+    // Escaped backslashes are not escape sequences.
+    f.check_tokens(br#"'\\1'"#, &[TokenType::String]);
+}
+
+#[test]
+fn lex_string_with_digit_escapes_in_strict_mode() {
+    let options = LexerOptions {
+        strict_mode: true,
+        ..LexerOptions::default()
+    };
+
+    {
+        let input = PaddedString::from_slice(br#"'\0'"#);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &errors, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::String);
+        qljs_assert_no_diags!(errors.clone_errors(), input.view());
+    }
+
+    {
+        let input = PaddedString::from_slice(br#"'\012'"#);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &errors, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::String);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            input.view(),
+            DiagLegacyOctalEscapeSequenceNotAllowed {
+                escape_sequence: b"'"..b"\\012",
+            },
+        );
+    }
+
+    {
+        let input = PaddedString::from_slice(br#"'\9'"#);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &errors, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::String);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            input.view(),
+            DiagNonOctalDecimalEscapeSequenceNotAllowed {
+                escape_sequence: b"'"..b"\\9",
+            },
+        );
+    }
+}
+
+#[test]
+fn lex_string_with_unpaired_surrogate_escapes() {
+    let mut f = Fixture::new();
+
+    f.check_tokens(br#"'\uD83D\uDE00'"#, &[TokenType::String]);
+    f.check_tokens(br#"'\u{D83D}\u{DE00}'"#, &[TokenType::String]);
+    f.check_tokens(br#"'\uD83D\u{de00}'"#, &[TokenType::String]);
+    f.check_tokens(br#"'\u{1F600}'"#, &[TokenType::String]);
+
+    f.check_tokens_with_errors(
+        br#"'\uD800'"#,
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'"..b"\\uD800",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        br#"'\u{dfff}x'"#,
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'"..b"\\u{dfff}",
+                },
+            );
+        },
+    );
+
+    // Two high surrogates in a row.
+    f.check_tokens_with_errors(
+        br#"'\uD83D\uD83D'"#,
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'"..b"\\uD83D",
+                },
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'\\uD83D"..b"\\uD83D",
+                },
+            );
+        },
+    );
+
+    // Low surrogate before high surrogate.
+    f.check_tokens_with_errors(
+        br#"'\uDE00\uD83D'"#,
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'"..b"\\uDE00",
+                },
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'\\uDE00"..b"\\uD83D",
+                },
+            );
+        },
+    );
 
-    // TODO(#187): Report octal escape sequences in strict mode.
-    // TODO(#187): Report invalid octal escape sequences in non-strict mode.
+    // A literal non-BMP character does not complete an escaped high surrogate.
+    f.check_tokens_with_errors(
+        "'\\uD83D\u{1f600}'".as_bytes(),
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnpairedSurrogateEscapeSequence {
+                    escape_sequence: b"'"..b"\\uD83D",
+                },
+            );
+        },
+    );
 }
 
 #[test]
@@ -1804,6 +2009,71 @@ fn templates_buffer_unicode_escape_errors() {
     }
 }
 
+#[test]
+fn templates_buffer_digit_escape_errors() {
+    for (input, escape_sequence) in [
+        (r#"`\01`"#, r#"\01"#),
+        (r#"`\00`"#, r#"\00"#),
+        (r#"`\7`"#, r#"\7"#),
+    ] {
+        scoped_trace!(input);
+        let input = PaddedString::from_slice(input.as_bytes());
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new(input.view(), &errors, &allocator);
+
+        assert_eq!(l.peek().type_, TokenType::CompleteTemplate);
+        qljs_assert_no_diags!(errors.clone_errors(), input.view());
+        l.peek()
+            .report_errors_for_escape_sequences_in_template(&errors);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            input.view(),
+            DiagLegacyOctalEscapeSequenceNotAllowed {
+                escape_sequence: b"`"..(escape_sequence.as_bytes()),
+            },
+        );
+    }
+
+    {
+        let input = PaddedString::from_slice(br#"`\8${expr}`"#);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new(input.view(), &errors, &allocator);
+
+        assert_eq!(l.peek().type_, TokenType::IncompleteTemplate);
+        qljs_assert_no_diags!(errors.clone_errors(), input.view());
+        l.peek()
+            .report_errors_for_escape_sequences_in_template(&errors);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            input.view(),
+            DiagNonOctalDecimalEscapeSequenceNotAllowed {
+                escape_sequence: b"`"..b"\\8",
+            },
+        );
+    }
+
+    {
+        let input = PaddedString::from_slice(br#"`\uD800`"#);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new(input.view(), &errors, &allocator);
+
+        assert_eq!(l.peek().type_, TokenType::CompleteTemplate);
+        qljs_assert_no_diags!(errors.clone_errors(), input.view());
+        l.peek()
+            .report_errors_for_escape_sequences_in_template(&errors);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            input.view(),
+            DiagUnpairedSurrogateEscapeSequence {
+                escape_sequence: b"`"..b"\\uD800",
+            },
+        );
+    }
+}
+
 #[test]
 fn templates_do_not_buffer_valid_unicode_escapes() {
     {
@@ -1862,6 +2132,255 @@ fn lex_template_literal_with_ascii_control_characters() {
     }
 }
 
+// U+202E Right-to-Left Override (RLO) is 0xe2 0x80 0xae in UTF-8.
+// U+202C Pop Directional Formatting (PDF) is 0xe2 0x80 0xac in UTF-8.
+// U+2067 Right-to-Left Isolate (RLI) is 0xe2 0x81 0xa7 in UTF-8.
+// U+2069 Pop Directional Isolate (PDI) is 0xe2 0x81 0xa9 in UTF-8.
+
+#[test]
+fn lex_comments_with_bidi_control_characters() {
+    let mut f = Fixture::new();
+
+    assert_eq!(
+        f.lex_to_eof_types(b"/* \xe2\x80\xaeevil\xe2\x80\xac */"),
+        vec![]
+    );
+    assert_eq!(
+        f.lex_to_eof_types(b"// \xe2\x81\xa7evil\xe2\x81\xa9"),
+        vec![]
+    );
+    assert_eq!(
+        f.lex_to_eof_types(b"/*\n \xe2\x81\xa7\xe2\x80\xaeevil\xe2\x80\xac\xe2\x81\xa9 */"),
+        vec![]
+    );
+    // A PDI also closes embeddings opened inside the isolate.
+    assert_eq!(
+        f.lex_to_eof_types(b"// \xe2\x81\xa7\xe2\x80\xaeevil\xe2\x81\xa9"),
+        vec![]
+    );
+
+    f.check_tokens_with_errors(
+        b"/* \xe2\x80\xae } \xe2\x81\xa7if (isAdmin)\xe2\x81\xa9 \xe2\x81\xa7 begin admins only */ x",
+        &[TokenType::Identifier],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"/* "..b"\xe2\x80\xae",
+                },
+                DiagUnclosedBidiControlCharacter {
+                    character: b"/* \xe2\x80\xae } \xe2\x81\xa7if (isAdmin)\xe2\x81\xa9 "..b"\xe2\x81\xa7",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"/*\n \xe2\x80\xaeevil */ x",
+        &[TokenType::Identifier],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"/*\n "..b"\xe2\x80\xae",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"/* \xe2\x80\xaeevil",
+        &[],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"/* "..b"\xe2\x80\xae",
+                },
+                DiagUnclosedBlockComment {
+                    comment_open: 0..b"/*",
+                },
+            );
+        },
+    );
+
+    for line_terminator in LINE_TERMINATORS {
+        let input: String = format!("// \u{202e}evil{line_terminator}x");
+        scoped_trace!(input);
+        f.check_tokens_with_errors(
+            input.as_bytes(),
+            &[TokenType::Identifier],
+            |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+                qljs_assert_diags!(
+                    errors,
+                    input,
+                    DiagUnclosedBidiControlCharacter {
+                        character: b"// "..b"\xe2\x80\xae",
+                    },
+                );
+            },
+        );
+    }
+
+    f.check_tokens_with_errors(
+        b"// evil\xe2\x81\xa9\nx",
+        &[TokenType::Identifier],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnmatchedBidiControlCharacter {
+                    character: b"// evil"..b"\xe2\x81\xa9",
+                },
+            );
+        },
+    );
+
+    // A PDF cannot close an embedding opened outside of the innermost isolate.
+    f.check_tokens_with_errors(
+        b"// \xe2\x80\xae\xe2\x81\xa7\xe2\x80\xac\xe2\x81\xa9",
+        &[],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnmatchedBidiControlCharacter {
+                    character: b"// \xe2\x80\xae\xe2\x81\xa7"..b"\xe2\x80\xac",
+                },
+                DiagUnclosedBidiControlCharacter {
+                    character: b"// "..b"\xe2\x80\xae",
+                },
+            );
+        },
+    );
+}
+
+#[test]
+fn lex_strings_with_bidi_control_characters() {
+    let mut f = Fixture::new();
+
+    f.check_tokens(b"'\xe2\x80\xaeevil\xe2\x80\xac'", &[TokenType::String]);
+    f.check_tokens(b"\"\xe2\x81\xa7evil\xe2\x81\xa9\"", &[TokenType::String]);
+
+    f.check_tokens_with_errors(
+        b"'\xe2\x80\xaeevil' x",
+        &[TokenType::String, TokenType::Identifier],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"'"..b"\xe2\x80\xae",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"'\\\xe2\x80\xaeevil'",
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"'\\"..b"\xe2\x80\xae",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"\"evil\xe2\x80\xac\"",
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnmatchedBidiControlCharacter {
+                    character: b"\"evil"..b"\xe2\x80\xac",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"'\xe2\x80\xaeevil",
+        &[TokenType::String],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedStringLiteral {
+                    string_literal: 0..b"'\xe2\x80\xaeevil",
+                },
+                DiagUnclosedBidiControlCharacter {
+                    character: b"'"..b"\xe2\x80\xae",
+                },
+            );
+        },
+    );
+}
+
+#[test]
+fn lex_templates_with_bidi_control_characters() {
+    let mut f = Fixture::new();
+
+    f.check_tokens(
+        b"`\xe2\x80\xaeevil\xe2\x80\xac`",
+        &[TokenType::CompleteTemplate],
+    );
+    f.check_tokens(
+        b"`\n\xe2\x81\xa7evil\xe2\x81\xa9`",
+        &[TokenType::CompleteTemplate],
+    );
+
+    f.check_tokens_with_errors(
+        b"`\xe2\x80\xaeevil`",
+        &[TokenType::CompleteTemplate],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"`"..b"\xe2\x80\xae",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"`\xe2\x81\xa7${",
+        &[TokenType::IncompleteTemplate],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnclosedBidiControlCharacter {
+                    character: b"`"..b"\xe2\x81\xa7",
+                },
+            );
+        },
+    );
+
+    f.check_tokens_with_errors(
+        b"`evil\xe2\x81\xa9`",
+        &[TokenType::CompleteTemplate],
+        |input: PaddedStringView, errors: &Vec<AnyDiag>| {
+            qljs_assert_diags!(
+                errors,
+                input,
+                DiagUnmatchedBidiControlCharacter {
+                    character: b"`evil"..b"\xe2\x81\xa9",
+                },
+            );
+        },
+    );
+}
+
 #[test]
 fn lex_regular_expression_literals() {
     fn check_regexp(raw_code: &[u8]) {
@@ -2720,6 +3239,110 @@ fn lex_identifier_with_disallowed_initial_character_as_subsequent_character() {
     f.check_single_token("legal\u{0816}".as_bytes(), "legal\u{0816}".as_bytes());
 }
 
+#[test]
+fn lex_confusable_identifiers() {
+    let options = LexerOptions {
+        confusable_identifiers: true,
+        ..LexerOptions::default()
+    };
+
+    {
+        // U+0430 is CYRILLIC SMALL LETTER A.
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice("p\u{0430}ypal = 1".as_bytes());
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagConfusableIdentifier {
+                identifier: 0..("p\u{0430}ypal".as_bytes()),
+                skeleton: b"paypal",
+            },
+        );
+    }
+
+    {
+        // U+0441 is CYRILLIC SMALL LETTER ES.
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice(b"\\u0441ount");
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().normalized_identifier, "\u{0441}ount".as_bytes());
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagConfusableIdentifier {
+                identifier: 0..b"\\u0441ount",
+                skeleton: b"count",
+            },
+        );
+    }
+
+    {
+        // U+0434 is CYRILLIC SMALL LETTER DE, which is not confusable.
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice("get\u{0434}ata".as_bytes());
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagMixedScriptIdentifier {
+                identifier: 0..("get\u{0434}ata".as_bytes()),
+            },
+        );
+    }
+
+    for identifier in [
+        // Cyrillic "данные" (contains confusable characters, but doesn't look
+        // like ASCII).
+        "\u{0434}\u{0430}\u{043d}\u{043d}\u{044b}\u{0435}",
+        // Japanese "日本語のテキスト".
+        "\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{30c6}\u{30ad}\u{30b9}\u{30c8}",
+        // Cyrillic "длина_1" (contains Common characters).
+        "\u{0434}\u{043b}\u{0438}\u{043d}\u{0430}_1",
+        // Latin "café".
+        "caf\u{00e9}",
+        // Korean "한글漢字".
+        "\u{d55c}\u{ae00}\u{6f22}\u{5b57}",
+    ] {
+        scoped_trace!(identifier);
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice(identifier.as_bytes());
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        assert_matches!(v.clone_errors(), e if e.is_empty());
+    }
+
+    {
+        // Cyrillic "соре" looks like ASCII "cope".
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice("\u{0441}\u{043e}\u{0440}\u{0435}".as_bytes());
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagConfusableIdentifier {
+                identifier: 0..("\u{0441}\u{043e}\u{0440}\u{0435}".as_bytes()),
+                skeleton: b"cope",
+            },
+        );
+    }
+
+    {
+        let mut f = Fixture::new();
+        // Without the option, confusable identifiers are not reported.
+        f.check_single_token("p\u{0430}ypal".as_bytes(), "p\u{0430}ypal".as_bytes());
+        f.check_single_token("get\u{0434}ata".as_bytes(), "get\u{0434}ata".as_bytes());
+    }
+}
+
 #[test]
 fn lex_identifiers_which_look_like_keywords() {
     let mut f = Fixture::new();
@@ -3138,6 +3761,130 @@ fn lex_whitespace() {
     }
 }
 
+#[test]
+fn lex_irregular_whitespace() {
+    let options = LexerOptions {
+        irregular_whitespace: true,
+        ..LexerOptions::default()
+    };
+
+    for whitespace in &[
+        "\u{00a0}", // 0xc2 0xa0      No-Break Space (NBSP)
+        "\u{1680}", // 0xe1 0x9a 0x80 Ogham Space Mark
+        "\u{2000}", // 0xe2 0x80 0x80 En Quad
+        "\u{200a}", // 0xe2 0x80 0x8a Hair Space
+        "\u{202f}", // 0xe2 0x80 0xaf Narrow No-Break Space (NNBSP)
+        "\u{205f}", // 0xe2 0x81 0x9f Medium Mathematical Space (MMSP)
+        "\u{3000}", // 0xe3 0x80 0x80 Ideographic Space
+    ] {
+        let input_string: String = format!("a{whitespace}b");
+        scoped_trace!(input_string);
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice(input_string.as_bytes());
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        l.skip();
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagIrregularWhitespace {
+                whitespace: b"a"..(whitespace.as_bytes()),
+                replacement: b' ',
+            },
+        );
+    }
+
+    {
+        // Zero-width whitespace should be deleted, not replaced with a space.
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice("a\u{feff}b".as_bytes());
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        l.skip();
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagZeroWidthWhitespace {
+                character: b"a"..("\u{feff}".as_bytes()),
+            },
+        );
+    }
+
+    for input in [
+        "a \t\u{000b}\u{000c}b",
+        "a\n\r\n\u{2028}\u{2029}b",
+        "'\u{00a0}' /*\u{00a0}*/ //\u{3000}",
+    ] {
+        scoped_trace!(input);
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice(input.as_bytes());
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        while l.peek().type_ != TokenType::EndOfFile {
+            l.skip();
+        }
+        qljs_assert_no_diags!(v.clone_errors(), input.view());
+    }
+
+    {
+        let mut f = Fixture::new();
+        // Without the option, irregular whitespace is not reported.
+        f.check_tokens(
+            "a\u{00a0}b".as_bytes(),
+            &[TokenType::Identifier, TokenType::Identifier],
+        );
+    }
+}
+
+#[test]
+fn lex_zero_width_characters_in_identifiers() {
+    let options = LexerOptions {
+        irregular_whitespace: true,
+        ..LexerOptions::default()
+    };
+
+    for zero_width_character in [
+        "\u{200c}", // Zero Width Non-Joiner (ZWNJ)
+        "\u{200d}", // Zero Width Joiner (ZWJ)
+    ] {
+        let input_string: String = format!("user{zero_width_character}Id");
+        scoped_trace!(input_string);
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice(input_string.as_bytes());
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        assert_eq!(l.peek().normalized_identifier, input_string.as_bytes());
+        qljs_assert_diags!(
+            v.clone_errors(),
+            input.view(),
+            DiagZeroWidthCharacterInIdentifier {
+                character: b"user"..(zero_width_character.as_bytes()),
+            },
+        );
+    }
+
+    {
+        // Escape sequences are visible, so they are not reported.
+        let v = DiagCollector::new();
+        let input = PaddedString::from_slice(b"user\\u200dId");
+        let allocator = LexerAllocator::new();
+        let l = Lexer::new_with_options(input.view(), &v, &allocator, options);
+        assert_eq!(l.peek().type_, TokenType::Identifier);
+        qljs_assert_no_diags!(v.clone_errors(), input.view());
+    }
+
+    {
+        let mut f = Fixture::new();
+        // Without the option, zero-width characters are not reported.
+        f.check_single_token("user\u{200d}Id".as_bytes(), "user\u{200d}Id".as_bytes());
+    }
+}
+
 #[test]
 fn lex_shebang() {
     let mut f = Fixture::new();
@@ -3744,6 +4491,98 @@ fn is_identifier_byte_agrees_with_is_identifier_character() {
     }
 }
 
+#[test]
+fn utf_8_identifier_lookup_matches_code_point_lookup() {
+    for c in '\u{80}'..=char::MAX {
+        let mut utf_8: [u8; 4] = [0; 4];
+        let size: usize = encode_utf_8(c as u32, &mut utf_8);
+        let padded: PaddedString = PaddedString::from_slice(&utf_8[..size]);
+        assert_eq!(
+            look_up_identifier_character_utf_8(padded.view()),
+            Some(UTF8IdentifierCharacter {
+                size,
+                is_initial_identifier_character: is_initial_identifier_character(c as u32),
+                is_identifier_character: is_identifier_character(
+                    c as u32,
+                    IdentifierKind::JavaScript
+                ),
+            }),
+            "code point = U+{:04X}",
+            c as u32,
+        );
+    }
+}
+
+#[test]
+fn utf_8_identifier_lookup_rejects_ascii_and_invalid_sequences() {
+    for input in [
+        &b""[..],
+        b"a",
+        b"\x80",             // Lone continuation byte.
+        b"\xc0\x80",         // Overlong.
+        b"\xc3",             // Truncated.
+        b"\xc3a",            // Missing continuation byte.
+        b"\xe0\x80\x80",     // Overlong.
+        b"\xe3\x81",         // Truncated.
+        b"\xed\xa0\x80",     // U+D800 (surrogate).
+        b"\xf0\x80\x80\x80", // Overlong.
+        b"\xf0\x9f\x98",     // Truncated.
+        b"\xf4\x90\x80\x80", // U+110000 (out of range).
+        b"\xf8\x88\x80\x80\x80",
+        b"\xff",
+    ] {
+        let padded: PaddedString = PaddedString::from_slice(input);
+        assert_eq!(
+            look_up_identifier_character_utf_8(padded.view()),
+            None,
+            "input = {input:?}",
+        );
+    }
+}
+
+#[test]
+fn lex_identifiers_with_multi_byte_characters() {
+    let mut f = Fixture::new();
+    // U+00E9 LATIN SMALL LETTER E WITH ACUTE (2 bytes)
+    // U+5909 CJK UNIFIED IDEOGRAPH-5909 (3 bytes)
+    // U+0301 COMBINING ACUTE ACCENT (2 bytes, ID_Continue only)
+    // U+1D465 MATHEMATICAL ITALIC SMALL X (4 bytes)
+    // U+20000 CJK UNIFIED IDEOGRAPH-20000 (4 bytes)
+    // U+1D7CE MATHEMATICAL BOLD DIGIT ZERO (4 bytes, ID_Continue only)
+    for identifier in [
+        "\u{e9}t\u{e9}",
+        "\u{5909}\u{6570}",
+        "e\u{301}",
+        "\u{1d465}\u{1d7ce}",
+        "\u{20000}_\u{20001}",
+        "a\u{5909}b\u{1d465}c",
+    ] {
+        f.check_single_token(identifier.as_bytes(), identifier.as_bytes());
+    }
+}
+
+#[test]
+fn identifier_tables_match_selected_unicode_version() {
+    // U+10940 SIDETIC LETTER N01 was added in Unicode 17.0.
+    let sidetic_letter: u32 = 0x10940;
+    assert_eq!(
+        is_initial_identifier_character(sidetic_letter),
+        cfg!(feature = "unicode_17")
+    );
+    assert_eq!(
+        is_identifier_character(sidetic_letter, IdentifierKind::JavaScript),
+        cfg!(feature = "unicode_17")
+    );
+
+    // U+30FB KATAKANA MIDDLE DOT became ID_Continue in Unicode 15.1.
+    let katakana_middle_dot: u32 = 0x30fb;
+    assert!(!is_initial_identifier_character(katakana_middle_dot));
+    assert_eq!(
+        is_identifier_character(katakana_middle_dot, IdentifierKind::JavaScript),
+        cfg!(feature = "unicode_17")
+    );
+}
+
 #[test]
 fn jsx_identifier() {
     fn check_identifier(tag_code: &[u8], expected_normalized: &[u8]) {
@@ -4093,6 +4932,138 @@ fn jsx_illegal_text_children() {
     }
 }
 
+#[test]
+fn jsx_text_children_with_entities() {
+    for input in [
+        "<>&amp;</>",
+        "<>Tom &amp; Jerry</>",
+        "<>Tom & Jerry</>",
+        "<>R&D</>",
+        "<>&#65;&#x41;&#X41;</>",
+        "<>&nbsp;&apos;&hellip;</>",
+    ] {
+        let code = PaddedString::from_slice(input.as_bytes());
+        scoped_trace!(code);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '<'.
+
+        l.skip_in_jsx_children(); // Skip '>'.
+        assert_eq!(l.peek().type_, TokenType::Less);
+        qljs_assert_no_diags!(errors.clone_errors(), code.view());
+    }
+
+    {
+        let code = PaddedString::from_slice(b"<>a&nbps;b</>");
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '<'.
+
+        l.skip_in_jsx_children(); // Skip '>'.
+        assert_eq!(l.peek().type_, TokenType::Less);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            code.view(),
+            DiagUnknownJSXEntity {
+                entity: b"<>a"..b"&nbps;",
+            },
+        );
+    }
+
+    {
+        let code = PaddedString::from_slice(b"<>&#xd800;</>");
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '<'.
+
+        l.skip_in_jsx_children(); // Skip '>'.
+        assert_eq!(l.peek().type_, TokenType::Less);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            code.view(),
+            DiagUnknownJSXEntity {
+                entity: b"<>"..b"&#xd800;",
+            },
+        );
+    }
+
+    {
+        let code = PaddedString::from_slice(b"<>&copy 2020</>");
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '<'.
+
+        l.skip_in_jsx_children(); // Skip '>'.
+        assert_eq!(l.peek().type_, TokenType::Less);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            code.view(),
+            DiagUnterminatedJSXEntity {
+                entity: b"<>"..b"&copy",
+            },
+        );
+    }
+
+    {
+        let code = PaddedString::from_slice(b"<>&#65");
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '<'.
+
+        l.skip_in_jsx_children(); // Skip '>'.
+        assert_eq!(l.peek().type_, TokenType::EndOfFile);
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            code.view(),
+            DiagUnterminatedJSXEntity {
+                entity: b"<>"..b"&#65",
+            },
+        );
+    }
+}
+
+#[test]
+fn jsx_string_with_entities() {
+    for input in [r#"!"&amp;""#, r#"!'Tom & Jerry'"#, r#"!"&#x41;&quot;""#] {
+        let code = PaddedString::from_slice(input.as_bytes());
+        scoped_trace!(code);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '!'.
+
+        assert_eq!(l.peek().type_, TokenType::String);
+        assert_eq!(l.peek().end, code.null_terminator());
+        qljs_assert_no_diags!(errors.clone_errors(), code.view());
+    }
+
+    {
+        let code = PaddedString::from_slice(br#"!"&nbps;&amp""#);
+        let errors = DiagCollector::new();
+        let allocator = LexerAllocator::new();
+        let mut l = Lexer::new(code.view(), &errors, &allocator);
+        l.skip_in_jsx(); // Ignore '!'.
+
+        assert_eq!(l.peek().type_, TokenType::String);
+        assert_eq!(l.peek().end, code.null_terminator());
+        qljs_assert_diags!(
+            errors.clone_errors(),
+            code.view(),
+            DiagUnknownJSXEntity {
+                entity: b"!\""..b"&nbps;",
+            },
+            DiagUnterminatedJSXEntity {
+                entity: b"!\"&nbps;"..b"&amp",
+            },
+        );
+    }
+}
+
 #[test]
 fn jsx_expression_children() {
     let mut f = Fixture::new();
@@ -0,0 +1,131 @@
+use cpp_vs_rust_fe::diag_reporter::*;
+use cpp_vs_rust_fe::diagnostic::*;
+use cpp_vs_rust_fe::diagnostic_types::*;
+use cpp_vs_rust_fe::linter::*;
+use cpp_vs_rust_fe::source_code_span::*;
+use cpp_vs_rust_fe::vec_diag_reporter::*;
+use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_port::assert_matches;
+use cpp_vs_rust_util::padded_string::*;
+
+#[test]
+fn collects_linter_diagnostics_with_byte_offsets() {
+    let input: PaddedString = PaddedString::from_slice(b"let x = 'hello;\nx;");
+    let reporter: VecDiagReporter = VecDiagReporter::new(input.view());
+    parse_and_lint(input.view(), &reporter, LinterOptions::default());
+
+    let diagnostics: Vec<Diagnostic> = reporter.into_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic: &Diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.diag_type(), DiagType::DiagUnclosedStringLiteral);
+    assert_eq!(diagnostic.code(), "E0040");
+    assert_eq!(diagnostic.severity(), DiagnosticSeverity::Error);
+    assert_eq!(diagnostic.message(), "unclosed string literal");
+    assert_eq!((diagnostic.begin(), diagnostic.end()), (8, 15));
+    assert!(diagnostic.notes().is_empty());
+    assert_matches!(
+        diagnostic.diag(),
+        AnyDiag::DiagUnclosedStringLiteral(diag)
+            if diag.string_literal.as_slice() == b"'hello;",
+    );
+}
+
+#[test]
+fn diag_strings_outlive_lexer_allocator() {
+    let input: PaddedString = PaddedString::from_slice(b"9007199254740993");
+    let reporter: VecDiagReporter = VecDiagReporter::new(input.view());
+    // parse_and_lint frees the memory which the lexer allocated rounded_val in.
+    parse_and_lint(input.view(), &reporter, LinterOptions::default());
+
+    let diagnostics: Vec<Diagnostic> = reporter.into_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    let copy: Diagnostic = diagnostics[0].clone();
+    drop(diagnostics);
+    assert_eq!(
+        copy.message(),
+        "integer cannot be represented and will be rounded to '9007199254740992'"
+    );
+    assert_matches!(
+        copy.diag(),
+        AnyDiag::DiagIntegerLiteralWillLosePrecision(diag)
+            if diag.rounded_val == b"9007199254740992",
+    );
+}
+
+#[test]
+fn second_message_is_a_note() {
+    let input: PaddedString = PaddedString::from_slice(b"hello world");
+    let reporter: VecDiagReporter = VecDiagReporter::new(input.view());
+    report(
+        &reporter,
+        DiagMultipleMessageTest {
+            a: SourceCodeSpan::from_slice(&input.as_slice()[0..5]),
+            b: SourceCodeSpan::from_slice(&input.as_slice()[6..11]),
+        },
+    );
+
+    let diagnostics: Vec<Diagnostic> = reporter.into_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code(), "E6969");
+    assert_eq!(
+        diagnostics[0].messages(),
+        &[
+            DiagnosticMessage {
+                severity: DiagnosticSeverity::Error,
+                message: "test for multiple messages".to_string(),
+                begin: 0,
+                end: 5,
+            },
+            DiagnosticMessage {
+                severity: DiagnosticSeverity::Note,
+                message: "second message here".to_string(),
+                begin: 6,
+                end: 11,
+            },
+        ]
+    );
+    assert_eq!(diagnostics[0].notes(), &diagnostics[0].messages()[1..]);
+}
+
+#[test]
+fn messages_use_translator() {
+    let input: PaddedString = PaddedString::from_slice(b"'hello");
+    let mut translator: Translator = Translator::new_using_messages_from_source_code();
+    assert!(translator.use_messages_from_locale("en_US@snarky"));
+    let mut reporter: VecDiagReporter = VecDiagReporter::new(input.view());
+    reporter.set_translator(translator.clone());
+    parse_and_lint(input.view(), &reporter, LinterOptions::default());
+
+    let diagnostics: Vec<Diagnostic> = reporter.into_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message(),
+        translator.translate(unclosed_string_literal_message())
+    );
+    assert_ne!(diagnostics[0].message(), "unclosed string literal");
+}
+
+#[test]
+fn take_diagnostics_empties_reporter() {
+    let input: PaddedString = PaddedString::from_slice(b"'a\n'b\n");
+    let reporter: VecDiagReporter = VecDiagReporter::new(input.view());
+    assert!(reporter.is_empty());
+    for (begin, end) in [(0, 2), (3, 5)] {
+        report(
+            &reporter,
+            DiagUnclosedStringLiteral {
+                string_literal: SourceCodeSpan::from_slice(&input.as_slice()[begin..end]),
+            },
+        );
+    }
+    assert_eq!(reporter.len(), 2);
+
+    let diagnostics: Vec<Diagnostic> = reporter.take_diagnostics();
+    assert_eq!(diagnostics.len(), 2);
+    assert!(reporter.is_empty());
+    assert_eq!((diagnostics[1].begin(), diagnostics[1].end()), (3, 5));
+}
+
+fn unclosed_string_literal_message() -> TranslatableMessage {
+    get_diagnostic_info(DiagType::DiagUnclosedStringLiteral).message_formats[0]
+}
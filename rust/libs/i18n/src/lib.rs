@@ -1,4 +1,7 @@
 pub mod locale;
+pub mod plural_forms;
+pub mod pseudo_locale;
 pub mod translation;
+pub mod translation_catalog;
 pub mod translation_table;
 pub mod translation_table_generated;
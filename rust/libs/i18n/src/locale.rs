@@ -147,3 +147,289 @@ fn iterate_locale_name_combinations<Func: FnMut(&str) -> bool>(
         }
     }
 }
+
+// Returns the index of the locale which best matches locale_name.
+//
+// locale_name can be a POSIX locale name (e.g. "pt_BR.UTF-8") or a BCP 47
+// language tag (e.g. "pt-BR"). If no locale matches exactly (see
+// find_locale), then a locale with the same language and no modifier is
+// picked, so "sv" matches "sv_SE" (but not "sv_SE@slang").
+pub fn find_best_locale(locales: &[u8], locale_name: &str) -> Option<i32> {
+    let posix_locale_name: String = match LanguageTag::parse(locale_name) {
+        Some(tag) => tag.to_posix_locale_name(),
+        None => String::from(locale_name),
+    };
+    if let Some(locale_index) = find_locale(locales, &posix_locale_name) {
+        return Some(locale_index);
+    }
+
+    let language: &str = parse_locale(&posix_locale_name).language();
+    if language.is_empty() {
+        return None;
+    }
+    locales
+        .split(|c| *c == 0)
+        .take_while(|l| !l.is_empty())
+        .position(|l: &[u8]| {
+            let l: &str = std::str::from_utf8(l).unwrap_or("");
+            let parts: LocaleParts = parse_locale(l);
+            parts.language() == language && parts.parts[MODIFIER_INDEX].is_empty()
+        })
+        .map(|i: usize| i as i32)
+}
+
+// Returns true if locale_name asks for untranslated messages: "C", "POSIX", or
+// a BCP 47 tag with the POSIX variant (e.g. "en-US-u-va-posix").
+pub fn is_posix_locale(locale_name: &str) -> bool {
+    locale_name == "C"
+        || locale_name == "POSIX"
+        || LanguageTag::parse(locale_name).is_some_and(|tag| tag.is_posix())
+}
+
+// Returns the user's preferred locales for messages, from most preferred to
+// least preferred. Pass the result to Translator::use_messages_from_locales.
+pub fn get_user_locale_preferences() -> Vec<String> {
+    get_user_locale_preferences_from_environment(|name: &str| std::env::var(name).ok())
+}
+
+// Like get_user_locale_preferences, but reads environment variables using
+// get_env instead of from the process environment.
+//
+// This lookup order mimics GNU gettext:
+//
+// 1. The message locale is the first non-empty variable of LC_ALL,
+//    LC_MESSAGES, and LANG, or "C" if they are all unset.
+// 2. If the message locale is C or POSIX, then LANGUAGE is ignored.
+// 3. Otherwise, LANGUAGE (if non-empty) is a colon-separated list of locales
+//    which overrides the message locale (e.g. "sv:pt_BR:de").
+pub fn get_user_locale_preferences_from_environment<GetEnv: Fn(&str) -> Option<String>>(
+    get_env: GetEnv,
+) -> Vec<String> {
+    let get_non_empty_env =
+        |name: &str| -> Option<String> { get_env(name).filter(|value: &String| !value.is_empty()) };
+
+    let locale: String = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|name: &&str| get_non_empty_env(name))
+        .unwrap_or_else(|| String::from("C"));
+    if is_posix_locale(&locale) {
+        return vec![locale];
+    }
+
+    if let Some(language) = get_non_empty_env("LANGUAGE") {
+        let locales: Vec<String> = language
+            .split(':')
+            .filter(|l: &&str| !l.is_empty())
+            .map(String::from)
+            .collect();
+        if !locales.is_empty() {
+            return locales;
+        }
+    }
+    vec![locale]
+}
+
+// A BCP 47 (RFC 5646) language tag, such as "zh-Hant-TW" or "de-CH-1996".
+//
+// Each subtag is normalized to its canonical case.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LanguageTag {
+    // Lowercase, e.g. "pt".
+    pub language: String,
+    // Titlecase, e.g. "Latn".
+    pub script: Option<String>,
+    // Uppercase, e.g. "BR" or "419".
+    pub region: Option<String>,
+    // Lowercase, e.g. ["1996"].
+    pub variants: Vec<String>,
+    // Lowercase singleton followed by its subtags, e.g. ["u-va-posix"].
+    pub extensions: Vec<String>,
+}
+
+// Deprecated language subtags from the IANA Language Subtag Registry.
+const DEPRECATED_LANGUAGES: [(&str, &str); 4] =
+    [("in", "id"), ("iw", "he"), ("ji", "yi"), ("jw", "jv")];
+
+impl LanguageTag {
+    // Returns None if tag is not well-formed. Private use subtags (after "x-")
+    // are ignored. Grandfathered tags (e.g. "i-klingon") are not supported.
+    pub fn parse(tag: &str) -> Option<LanguageTag> {
+        let mut subtags = tag.split('-').peekable();
+        let is_alpha = |s: &str| s.bytes().all(|c| c.is_ascii_alphabetic());
+        let is_digit = |s: &str| s.bytes().all(|c| c.is_ascii_digit());
+        let is_alphanumeric = |s: &str| s.bytes().all(|c| c.is_ascii_alphanumeric());
+
+        let language: &str = subtags.next()?;
+        if !(matches!(language.len(), 2..=3 | 5..=8) && is_alpha(language)) {
+            return None;
+        }
+        let mut language: String = language.to_ascii_lowercase();
+        if let Some((_, preferred)) = DEPRECATED_LANGUAGES.iter().find(|(l, _)| *l == language) {
+            language = String::from(*preferred);
+        }
+        let mut result: LanguageTag = LanguageTag {
+            language,
+            script: None,
+            region: None,
+            variants: vec![],
+            extensions: vec![],
+        };
+
+        // Extended language subtags (e.g. "yue" in "zh-yue") are ignored.
+        while let Some(&s) = subtags.peek() {
+            if s.len() == 3 && is_alpha(s) {
+                subtags.next();
+            } else {
+                break;
+            }
+        }
+        if let Some(&s) = subtags.peek() {
+            if s.len() == 4 && is_alpha(s) {
+                subtags.next();
+                result.script = Some(s[..1].to_ascii_uppercase() + &s[1..].to_ascii_lowercase());
+            }
+        }
+        if let Some(&s) = subtags.peek() {
+            if (s.len() == 2 && is_alpha(s)) || (s.len() == 3 && is_digit(s)) {
+                subtags.next();
+                result.region = Some(s.to_ascii_uppercase());
+            }
+        }
+        while let Some(&s) = subtags.peek() {
+            let is_variant: bool = is_alphanumeric(s)
+                && (matches!(s.len(), 5..=8) || (s.len() == 4 && s.as_bytes()[0].is_ascii_digit()));
+            if !is_variant {
+                break;
+            }
+            subtags.next();
+            result.variants.push(s.to_ascii_lowercase());
+        }
+        while let Some(singleton) = subtags.next() {
+            if singleton.len() != 1 || !is_alphanumeric(singleton) {
+                return None;
+            }
+            let mut extension: String = singleton.to_ascii_lowercase();
+            if extension == "x" {
+                // Private use subtags continue until the end of the tag.
+                let mut private_use_subtag_count: usize = 0;
+                for s in subtags.by_ref() {
+                    if !(1..=8).contains(&s.len()) || !is_alphanumeric(s) {
+                        return None;
+                    }
+                    private_use_subtag_count += 1;
+                }
+                if private_use_subtag_count == 0 {
+                    return None;
+                }
+                break;
+            }
+            while let Some(&s) = subtags.peek() {
+                if s.len() == 1 {
+                    break;
+                }
+                if !(2..=8).contains(&s.len()) || !is_alphanumeric(s) {
+                    return None;
+                }
+                subtags.next();
+                extension.push('-');
+                extension.push_str(&s.to_ascii_lowercase());
+            }
+            if extension.len() == 1 {
+                return None;
+            }
+            result.extensions.push(extension);
+        }
+        Some(result)
+    }
+
+    // Returns the POSIX locale name for this tag, e.g. "pt_BR" for "pt-BR" or
+    // "sr_RS@latin" for "sr-Latn-RS".
+    pub fn to_posix_locale_name(&self) -> String {
+        let mut name: String = self.language.clone();
+        if let Some(region) = &self.region {
+            name.push('_');
+            name.push_str(region);
+        }
+        match self.script.as_deref() {
+            Some("Latn") => name.push_str("@latin"),
+            Some("Cyrl") => name.push_str("@cyrillic"),
+            _ => {}
+        }
+        name
+    }
+
+    // Returns true if this tag has the POSIX variant of the Unicode locale
+    // extension ("-u-va-posix").
+    pub fn is_posix(&self) -> bool {
+        self.extensions.iter().any(|extension: &String| {
+            let mut subtags = extension.split('-');
+            subtags.next() == Some("u")
+                && subtags
+                    .collect::<Vec<&str>>()
+                    .windows(2)
+                    .any(|keyword| keyword == ["va", "posix"])
+        })
+    }
+}
+
+impl std::fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.language)?;
+        for subtag in self
+            .script
+            .iter()
+            .chain(self.region.iter())
+            .chain(self.variants.iter())
+            .chain(self.extensions.iter())
+        {
+            write!(f, "-{subtag}")?;
+        }
+        Ok(())
+    }
+}
+
+// Parses an HTTP Accept-Language header value (RFC 9110), such as
+// "de-CH, de;q=0.9, en;q=0.5". Returns the language ranges from most preferred
+// to least preferred. Ranges with equal weights keep their order.
+//
+// "*" and ranges with q=0 are dropped, as are malformed entries.
+pub fn parse_accept_language(header: &str) -> Vec<&str> {
+    let mut ranges: Vec<(&str, u32)> = vec![];
+    for entry in header.split(',') {
+        let mut parameters = entry.split(';');
+        let range: &str = parameters.next().unwrap_or("").trim();
+        if range.is_empty() || range == "*" {
+            continue;
+        }
+        let mut weight: Option<u32> = Some(1000);
+        for parameter in parameters {
+            let Some((name, value)) = parameter.split_once('=') else {
+                weight = None;
+                break;
+            };
+            if name.trim().eq_ignore_ascii_case("q") {
+                weight = parse_q_value(value.trim());
+            }
+        }
+        match weight {
+            Some(0) | None => {}
+            Some(weight) => ranges.push((range, weight)),
+        }
+    }
+    ranges.sort_by_key(|&(_, weight)| std::cmp::Reverse(weight));
+    ranges.into_iter().map(|(range, _)| range).collect()
+}
+
+// Parses "0.5" into 500. Returns None if the weight is malformed.
+fn parse_q_value(value: &str) -> Option<u32> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let fraction_value: u32 = format!("{fraction:0<3}").parse().ok()?;
+    match whole {
+        "0" => Some(fraction_value),
+        "1" if fraction_value == 0 => Some(1000),
+        _ => None,
+    }
+}
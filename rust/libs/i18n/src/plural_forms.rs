@@ -0,0 +1,235 @@
+// A GNU gettext plural rule, from a .po file's Plural-Forms header, such as
+// "nplurals=2; plural=(n != 1);".
+//
+// The plural expression is C-like (see GNU gettext's plural.y). It is
+// evaluated with unsigned arithmetic each time a form is selected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PluralForms<'a> {
+    count: usize,
+    expression: &'a str,
+}
+
+impl PluralForms<'static> {
+    // The rule for untranslated messages, which are English. GNU gettext uses
+    // this rule if a catalog has no Plural-Forms header.
+    pub const SOURCE_CODE: PluralForms<'static> = PluralForms {
+        count: 2,
+        expression: "n != 1",
+    };
+}
+
+impl<'a> PluralForms<'a> {
+    // Parses a Plural-Forms header value. Returns None if the value is
+    // malformed.
+    pub fn parse(header_value: &'a str) -> Option<PluralForms<'a>> {
+        let mut count: Option<usize> = None;
+        let mut expression: Option<&str> = None;
+        for field in header_value.split(';') {
+            let field: &str = field.trim();
+            if field.is_empty() {
+                continue;
+            }
+            let (name, value) = field.split_once('=')?;
+            match name.trim() {
+                "nplurals" => count = Some(value.trim().parse().ok()?),
+                "plural" => expression = Some(value.trim()),
+                _ => return None,
+            }
+        }
+        let plural_forms: PluralForms = PluralForms {
+            count: count.filter(|&count| count > 0)?,
+            expression: expression?,
+        };
+        // Check the expression's syntax.
+        evaluate_plural_expression(plural_forms.expression, 0)?;
+        Some(plural_forms)
+    }
+
+    // The number of plural forms (nplurals).
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    // Returns the index of the plural form to use for n.
+    pub fn select(&self, n: u64) -> usize {
+        let index: u64 = evaluate_plural_expression(self.expression, n).unwrap_or(0);
+        std::cmp::min(index, (self.count - 1) as u64) as usize
+    }
+}
+
+// Returns None if expression is malformed. Division by zero results in zero.
+pub fn evaluate_plural_expression(expression: &str, n: u64) -> Option<u64> {
+    let mut evaluator: PluralExpressionEvaluator = PluralExpressionEvaluator {
+        input: expression.as_bytes(),
+        n,
+    };
+    let result: u64 = evaluator.conditional()?;
+    evaluator.skip_whitespace();
+    if !evaluator.input.is_empty() {
+        return None;
+    }
+    Some(result)
+}
+
+// A recursive descent parser which evaluates as it parses. Both branches of
+// '?:', '&&', and '||' are always evaluated, so syntax errors are found
+// regardless of n.
+struct PluralExpressionEvaluator<'a> {
+    input: &'a [u8],
+    n: u64,
+}
+
+impl<'a> PluralExpressionEvaluator<'a> {
+    // conditional = or ("?" conditional ":" conditional)?
+    fn conditional(&mut self) -> Option<u64> {
+        let condition: u64 = self.or()?;
+        if !self.try_parse(b"?") {
+            return Some(condition);
+        }
+        let if_true: u64 = self.conditional()?;
+        if !self.try_parse(b":") {
+            return None;
+        }
+        let if_false: u64 = self.conditional()?;
+        Some(if condition != 0 { if_true } else { if_false })
+    }
+
+    // or = and ("||" and)*
+    fn or(&mut self) -> Option<u64> {
+        let mut result: u64 = self.and()?;
+        while self.try_parse(b"||") {
+            let rhs: u64 = self.and()?;
+            result = ((result != 0) || (rhs != 0)) as u64;
+        }
+        Some(result)
+    }
+
+    // and = equality ("&&" equality)*
+    fn and(&mut self) -> Option<u64> {
+        let mut result: u64 = self.equality()?;
+        while self.try_parse(b"&&") {
+            let rhs: u64 = self.equality()?;
+            result = ((result != 0) && (rhs != 0)) as u64;
+        }
+        Some(result)
+    }
+
+    // equality = relational (("==" | "!=") relational)*
+    fn equality(&mut self) -> Option<u64> {
+        let mut result: u64 = self.relational()?;
+        loop {
+            if self.try_parse(b"==") {
+                result = (result == self.relational()?) as u64;
+            } else if self.try_parse(b"!=") {
+                result = (result != self.relational()?) as u64;
+            } else {
+                return Some(result);
+            }
+        }
+    }
+
+    // relational = additive (("<=" | ">=" | "<" | ">") additive)*
+    fn relational(&mut self) -> Option<u64> {
+        let mut result: u64 = self.additive()?;
+        loop {
+            if self.try_parse(b"<=") {
+                result = (result <= self.additive()?) as u64;
+            } else if self.try_parse(b">=") {
+                result = (result >= self.additive()?) as u64;
+            } else if self.try_parse(b"<") {
+                result = (result < self.additive()?) as u64;
+            } else if self.try_parse(b">") {
+                result = (result > self.additive()?) as u64;
+            } else {
+                return Some(result);
+            }
+        }
+    }
+
+    // additive = multiplicative (("+" | "-") multiplicative)*
+    fn additive(&mut self) -> Option<u64> {
+        let mut result: u64 = self.multiplicative()?;
+        loop {
+            if self.try_parse(b"+") {
+                result = result.wrapping_add(self.multiplicative()?);
+            } else if self.try_parse(b"-") {
+                result = result.wrapping_sub(self.multiplicative()?);
+            } else {
+                return Some(result);
+            }
+        }
+    }
+
+    // multiplicative = unary (("*" | "/" | "%") unary)*
+    fn multiplicative(&mut self) -> Option<u64> {
+        let mut result: u64 = self.unary()?;
+        loop {
+            if self.try_parse(b"*") {
+                result = result.wrapping_mul(self.unary()?);
+            } else if self.try_parse(b"/") {
+                result = result.checked_div(self.unary()?).unwrap_or(0);
+            } else if self.try_parse(b"%") {
+                result = result.checked_rem(self.unary()?).unwrap_or(0);
+            } else {
+                return Some(result);
+            }
+        }
+    }
+
+    // unary = "!" unary | primary
+    fn unary(&mut self) -> Option<u64> {
+        // Don't confuse "!=" with "!".
+        self.skip_whitespace();
+        if self.input.starts_with(b"!") && !self.input.starts_with(b"!=") {
+            self.input = &self.input[1..];
+            return Some((self.unary()? == 0) as u64);
+        }
+        self.primary()
+    }
+
+    // primary = "n" | number | "(" conditional ")"
+    fn primary(&mut self) -> Option<u64> {
+        self.skip_whitespace();
+        if self.try_parse(b"n") {
+            return Some(self.n);
+        }
+        if self.try_parse(b"(") {
+            let result: u64 = self.conditional()?;
+            if !self.try_parse(b")") {
+                return None;
+            }
+            return Some(result);
+        }
+        let digit_count: usize = self
+            .input
+            .iter()
+            .take_while(|c: &&u8| c.is_ascii_digit())
+            .count();
+        if digit_count == 0 {
+            return None;
+        }
+        let (digits, rest) = self.input.split_at(digit_count);
+        self.input = rest;
+        std::str::from_utf8(digits).ok()?.parse().ok()
+    }
+
+    fn try_parse(&mut self, token: &[u8]) -> bool {
+        self.skip_whitespace();
+        match self.input.strip_prefix(token) {
+            Some(rest) => {
+                self.input = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some((c, rest)) = self.input.split_first() {
+            if !c.is_ascii_whitespace() {
+                break;
+            }
+            self.input = rest;
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::locale::*;
+use cpp_vs_rust_message_format::*;
+
+// A fake locale for testing how user interfaces handle translated messages.
+// Its translations are generated from the untranslated messages (see
+// pseudo_localize) rather than loaded from a .po file.
+//
+// en-XA is the pseudo-locale tag used by Android and Chromium.
+pub const PSEUDO_LOCALE_NAME: &str = "en_XA";
+
+// Returns true if locale_name refers to the pseudo-locale. locale_name can be a
+// POSIX locale name (e.g. "en_XA.UTF-8") or a BCP 47 language tag (e.g.
+// "en-XA").
+pub fn is_pseudo_locale(locale_name: &str) -> bool {
+    let posix_locale_name: String = match LanguageTag::parse(locale_name) {
+        Some(tag) => tag.to_posix_locale_name(),
+        None => String::from(locale_name),
+    };
+    let locales: String = format!("{PSEUDO_LOCALE_NAME}\0\0");
+    find_locale(locales.as_bytes(), &posix_locale_name).is_some()
+}
+
+// Transforms an untranslated message format string for the pseudo-locale:
+//
+// * vowels are accented ("unexpected" becomes "ûnéxpéctéd"),
+// * the text is padded with '~' to be about 40% longer, and
+// * the message is surrounded by '[' and ']', so truncation is visible.
+//
+// Placeholders such as "{0}" and "{1:headlinese}" are kept intact, so the
+// result is a valid translation of message (see
+// check_translation_placeholders). The forms of plural placeholders are
+// accented too.
+//
+// If message is not a valid message format string, it is returned with only
+// the brackets added.
+pub fn pseudo_localize(message: &str) -> String {
+    let mut result: String = String::from("[");
+    let mut text_char_count: usize = 0;
+    for part in parse_message_format(message) {
+        match part {
+            Ok(MessageFormatPart::Text(text)) => {
+                for c in text.chars() {
+                    if c == '{' {
+                        result.push_str("{{");
+                    } else {
+                        result.push(accent(c));
+                    }
+                }
+                text_char_count += text.chars().count();
+            }
+            Ok(MessageFormatPart::Placeholder(placeholder)) => match placeholder.form {
+                PlaceholderForm::Plain => {
+                    result.push_str(&format!("{{{}}}", placeholder.arg_index));
+                }
+                PlaceholderForm::Headlinese => {
+                    result.push_str(&format!("{{{}:headlinese}}", placeholder.arg_index));
+                }
+                PlaceholderForm::Singular => {
+                    result.push_str(&format!("{{{}:singular}}", placeholder.arg_index));
+                }
+                PlaceholderForm::Plural(forms) => {
+                    let accented_forms: String = forms.chars().map(accent).collect();
+                    result.push_str(&format!(
+                        "{{{}:plural:{accented_forms}}}",
+                        placeholder.arg_index
+                    ));
+                }
+            },
+            Err(_) => return format!("[{message}]"),
+        }
+    }
+    let padding_length: usize = (text_char_count * 2).div_ceil(5);
+    if padding_length > 0 {
+        result.push(' ');
+        result.push_str(&"~".repeat(padding_length));
+    }
+    result.push(']');
+    result
+}
+
+fn accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'e' => 'é',
+        'i' => 'î',
+        'o' => 'ö',
+        'u' => 'û',
+        'y' => 'ý',
+        'A' => 'Å',
+        'E' => 'É',
+        'I' => 'Î',
+        'O' => 'Ö',
+        'U' => 'Û',
+        'Y' => 'Ý',
+        _ => c,
+    }
+}
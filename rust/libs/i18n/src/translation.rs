@@ -1,4 +1,7 @@
 use crate::locale::*;
+use crate::plural_forms::*;
+use crate::pseudo_locale::*;
+use crate::translation_catalog::*;
 use crate::translation_table::*;
 use crate::translation_table_generated::*;
 use cpp_vs_rust_util::c_string::*;
@@ -31,9 +34,29 @@ pub fn initialize_translations_from_locale(locale_name: &str) {
     }
 }
 
+// Picks the user's language from the LANGUAGE, LC_ALL, LC_MESSAGES, and LANG
+// environment variables. See get_user_locale_preferences.
+pub fn initialize_translations_from_environment() {
+    initialize_locale();
+    let locales: Vec<String> = get_user_locale_preferences();
+    let locales: Vec<&str> = locales.iter().map(|l: &String| l.as_str()).collect();
+    let messages: *mut Translator = std::ptr::addr_of_mut!(QLJS_MESSAGES);
+    unsafe {
+        if !(*messages).use_messages_from_locales(&locales) {
+            (*messages).use_messages_from_source_code();
+        }
+    }
+}
+
+// Translator::locale_index for the pseudo-locale. See PSEUDO_LOCALE_NAME.
+const PSEUDO_LOCALE_INDEX: i32 = TRANSLATION_TABLE_LOCALE_COUNT as i32 + 1;
+
 #[derive(Clone)]
 pub struct Translator {
     locale_index: i32,
+    // Translations loaded at run time. Messages missing from the catalog fall
+    // back to locale_index's compiled-in translations.
+    catalog: Option<std::sync::Arc<TranslationCatalog>>,
 }
 
 impl Translator {
@@ -41,15 +64,32 @@ impl Translator {
     pub const fn new_using_messages_from_source_code() -> Translator {
         Translator {
             locale_index: TRANSLATION_TABLE_LOCALE_COUNT as i32,
+            catalog: None,
         }
     }
 
+    // Prefer the catalog's translations over the compiled-in translations of
+    // the current locale. The catalog is kept if the locale changes.
+    pub fn use_catalog(&mut self, catalog: std::sync::Arc<TranslationCatalog>) {
+        self.catalog = Some(catalog);
+    }
+
+    pub fn clear_catalog(&mut self) {
+        self.catalog = None;
+    }
+
     pub fn use_messages_from_source_code(&mut self) {
         self.locale_index = TRANSLATION_TABLE_LOCALE_COUNT as i32;
     }
 
+    // locale_name can be a POSIX locale name (e.g. "pt_BR.UTF-8") or a BCP 47
+    // language tag (e.g. "pt-BR"). See find_best_locale and is_pseudo_locale.
     pub fn use_messages_from_locale(&mut self, locale_name: &str) -> bool {
-        match find_locale(TRANSLATION_DATA_LOCALE_TABLE.as_bytes(), locale_name) {
+        if is_pseudo_locale(locale_name) {
+            self.locale_index = PSEUDO_LOCALE_INDEX;
+            return true;
+        }
+        match find_best_locale(TRANSLATION_DATA_LOCALE_TABLE.as_bytes(), locale_name) {
             Some(locale_index) => {
                 self.locale_index = locale_index;
                 true
@@ -58,9 +98,12 @@ impl Translator {
         }
     }
 
+    // Uses the first of locale_names which has translations. locale_names is
+    // ordered from most preferred to least preferred, such as the result of
+    // parse_accept_language.
     pub fn use_messages_from_locales(&mut self, locale_names: &[&str]) -> bool {
         for locale in locale_names {
-            if *locale == "C" || *locale == "POSIX" {
+            if is_posix_locale(locale) {
                 // Stop seaching. C/POSIX locale takes priority. See GNU gettext.
                 break;
             }
@@ -72,27 +115,99 @@ impl Translator {
         false
     }
 
-    pub fn translate(&self, message: TranslatableMessage) -> &'static str {
+    pub fn translate(&self, message: TranslatableMessage) -> &str {
+        self.look_up(message).0
+    }
+
+    // Like translate, but also returns the plural rule of the language which
+    // the message was translated into. Use the rule to pick between the
+    // forms of a plural placeholder (e.g. "{1:plural:argument|arguments}").
+    pub fn translate_with_plural_forms(
+        &self,
+        message: TranslatableMessage,
+    ) -> (&str, PluralForms<'_>) {
+        let (translated, source) = self.look_up(message);
+        let plural_forms: Option<PluralForms> = match source {
+            TranslationSource::Catalog(catalog) => catalog.plural_forms(),
+            TranslationSource::Locale(locale_index) => {
+                PluralForms::parse(TRANSLATION_DATA_PLURAL_FORMS_TABLE[locale_index])
+            }
+            TranslationSource::PseudoLocale => None,
+        };
+        (translated, plural_forms.unwrap_or(PluralForms::SOURCE_CODE))
+    }
+
+    fn look_up(&self, message: TranslatableMessage) -> (&str, TranslationSource<'_>) {
         // If the following assertion fails, it's likely that
         // translation-table-generated.h is out of date. Run
         // tools/update-translator-sources to rebuild that file.
         qljs_assert!(message.valid());
 
+        if let Some(catalog) = &self.catalog {
+            if let Some(translated) = catalog.translate(message) {
+                return (translated, TranslationSource::Catalog(catalog));
+            }
+        }
+
         let mapping_index: u16 = message.translation_table_mapping_index();
+        if self.locale_index == PSEUDO_LOCALE_INDEX {
+            return (
+                &pseudo_locale_translations()[mapping_index as usize],
+                TranslationSource::PseudoLocale,
+            );
+        }
         let mapping: &TranslationTableMappingEntry =
             &TRANSLATION_DATA_MAPPING_TABLE[mapping_index as usize];
-        let mut string_offset: u32 = mapping.0[self.locale_index as usize];
+        let mut locale_index: usize = self.locale_index as usize;
+        let mut string_offset: u32 = mapping.0[locale_index];
         if string_offset == 0 {
             // The string has no translation.
-            string_offset = mapping.0[TRANSLATION_TABLE_LOCALE_COUNT as usize];
+            locale_index = TRANSLATION_TABLE_LOCALE_COUNT as usize;
+            string_offset = mapping.0[locale_index];
             qljs_assert!(string_offset != 0);
         }
         let string_and_other_stuff: &[u8] =
             &TRANSLATION_DATA_STRING_TABLE[string_offset as usize..];
-        unsafe { read_utf8_c_string_from_slice(string_and_other_stuff) }
+        (
+            unsafe { read_utf8_c_string_from_slice(string_and_other_stuff) },
+            TranslationSource::Locale(locale_index),
+        )
     }
 }
 
+enum TranslationSource<'a> {
+    Catalog(&'a TranslationCatalog),
+    // Index into the compiled-in translation tables.
+    Locale(usize),
+    PseudoLocale,
+}
+
+// Returns the pseudo-locale's translation of every message, indexed by
+// TranslatableMessage::translation_table_mapping_index. The translations are
+// generated the first time they are needed.
+fn pseudo_locale_translations() -> &'static [Box<str>] {
+    static TRANSLATIONS: std::sync::OnceLock<Box<[Box<str>]>> = std::sync::OnceLock::new();
+    TRANSLATIONS.get_or_init(|| {
+        let source_code_locale_index: usize = TRANSLATION_TABLE_LOCALE_COUNT as usize;
+        TRANSLATION_DATA_MAPPING_TABLE
+            .iter()
+            .map(|mapping: &TranslationTableMappingEntry| {
+                let string_offset: u32 = mapping.0[source_code_locale_index];
+                if string_offset == 0 {
+                    // TRANSLATION_TABLE_UNALLOCATED_MAPPING_INDEX.
+                    return Box::from("");
+                }
+                let untranslated: &str = unsafe {
+                    read_utf8_c_string_from_slice(
+                        &TRANSLATION_DATA_STRING_TABLE[string_offset as usize..],
+                    )
+                };
+                pseudo_localize(untranslated).into_boxed_str()
+            })
+            .collect()
+    })
+}
+
 // An un-translated message.
 #[derive(Clone, Copy)]
 pub struct TranslatableMessage(pub u16);
@@ -0,0 +1,419 @@
+use crate::plural_forms::*;
+use crate::translation::*;
+use crate::translation_table::*;
+use crate::translation_table_generated::*;
+use cpp_vs_rust_message_format::*;
+
+// Translations loaded at run time from a GNU gettext .po or .mo file.
+//
+// A TranslationCatalog only holds translations for messages in the compiled-in
+// translation table (i.e. messages which some qljs_translatable! refers to).
+// Other entries in the file are ignored.
+//
+// Translations whose placeholders differ from their untranslated message's are
+// skipped, like msgfmt does, and reported by TranslationCatalog::warnings. One
+// bad translation does not prevent the rest of the catalog from loading.
+//
+// See Translator::use_catalog.
+pub struct TranslationCatalog {
+    // Indexed by TranslatableMessage::translation_table_mapping_index.
+    translations: Vec<Option<Box<str>>>,
+    // The header's Plural-Forms field, if any.
+    plural_forms: Option<Box<str>>,
+    warnings: Vec<TranslationCatalogWarning>,
+}
+
+#[derive(Debug)]
+pub enum TranslationCatalogError {
+    IO(std::io::Error),
+    InvalidMO(&'static str),
+    InvalidPO { line: usize, message: &'static str },
+    UnsupportedCharset(String),
+    InvalidPluralForms(String),
+}
+
+impl std::fmt::Display for TranslationCatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            TranslationCatalogError::IO(error) => write!(f, "{error}"),
+            TranslationCatalogError::InvalidMO(message) => write!(f, "invalid .mo file: {message}"),
+            TranslationCatalogError::InvalidPO { line, message } => {
+                write!(f, "invalid .po file: line {line}: {message}")
+            }
+            TranslationCatalogError::UnsupportedCharset(charset) => {
+                write!(f, "unsupported charset: {charset} (expected UTF-8)")
+            }
+            TranslationCatalogError::InvalidPluralForms(plural_forms) => {
+                write!(f, "invalid Plural-Forms: {plural_forms}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslationCatalogError {}
+
+// A translation which was skipped because its placeholders (e.g. "{0}" or
+// "{1:headlinese}") differ from the untranslated message's placeholders. See
+// check_translation_placeholders.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranslationCatalogWarning {
+    pub untranslated: String,
+    pub error: TranslationPlaceholderError,
+}
+
+impl std::fmt::Display for TranslationCatalogWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "skipped translation of {:?}: {}",
+            self.untranslated, self.error
+        )
+    }
+}
+
+const MO_MAGIC: u32 = 0x950412de;
+
+impl TranslationCatalog {
+    pub fn new() -> TranslationCatalog {
+        TranslationCatalog {
+            translations: vec![None; TRANSLATION_TABLE_MAPPING_TABLE_SIZE as usize],
+            plural_forms: None,
+            warnings: vec![],
+        }
+    }
+
+    // Reads a .mo or .po file. The format is detected from the file's
+    // contents, not from its name.
+    pub fn load_file(
+        path: &std::path::Path,
+    ) -> Result<TranslationCatalog, TranslationCatalogError> {
+        let data: Vec<u8> = std::fs::read(path).map_err(TranslationCatalogError::IO)?;
+        TranslationCatalog::from_bytes(&data)
+    }
+
+    // Parses the contents of a .mo or .po file.
+    pub fn from_bytes(data: &[u8]) -> Result<TranslationCatalog, TranslationCatalogError> {
+        let is_mo: bool = data.len() >= 4
+            && [u32::from_le_bytes, u32::from_be_bytes]
+                .iter()
+                .any(|from_bytes| from_bytes(data[0..4].try_into().unwrap()) == MO_MAGIC);
+        if is_mo {
+            TranslationCatalog::from_mo(data)
+        } else {
+            match std::str::from_utf8(data) {
+                Ok(text) => TranslationCatalog::from_po(text),
+                Err(error) => Err(TranslationCatalogError::InvalidPO {
+                    line: data[..error.valid_up_to()]
+                        .iter()
+                        .filter(|&&c| c == b'\n')
+                        .count()
+                        + 1,
+                    message: "invalid UTF-8",
+                }),
+            }
+        }
+    }
+
+    // Parses a compiled GNU gettext message catalog (.mo file) of either byte
+    // order.
+    pub fn from_mo(data: &[u8]) -> Result<TranslationCatalog, TranslationCatalogError> {
+        let read_u32 = |offset: usize| -> Result<u32, TranslationCatalogError> {
+            let bytes: &[u8] = offset
+                .checked_add(4)
+                .and_then(|end: usize| data.get(offset..end))
+                .ok_or(TranslationCatalogError::InvalidMO("file is truncated"))?;
+            Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+        };
+        let is_big_endian: bool = match read_u32(0)? {
+            MO_MAGIC => false,
+            magic if magic.swap_bytes() == MO_MAGIC => true,
+            _ => return Err(TranslationCatalogError::InvalidMO("bad magic number")),
+        };
+        let read_u32 = |offset: usize| -> Result<usize, TranslationCatalogError> {
+            let value: u32 = read_u32(offset)?;
+            Ok((if is_big_endian {
+                value.swap_bytes()
+            } else {
+                value
+            }) as usize)
+        };
+        let read_string =
+            |table_offset: usize, index: usize| -> Result<&str, TranslationCatalogError> {
+                let out_of_bounds =
+                    || TranslationCatalogError::InvalidMO("string table is out of bounds");
+                let descriptor_offset: usize = index
+                    .checked_mul(8)
+                    .and_then(|relative_offset: usize| table_offset.checked_add(relative_offset))
+                    .ok_or_else(out_of_bounds)?;
+                let length: usize = read_u32(descriptor_offset)?;
+                let offset: usize =
+                    read_u32(descriptor_offset.checked_add(4).ok_or_else(out_of_bounds)?)?;
+                let bytes: &[u8] = data.get(offset..offset.saturating_add(length)).ok_or(
+                    TranslationCatalogError::InvalidMO("string is out of bounds"),
+                )?;
+                // Plural forms are separated by '\0'. Only the first form is used.
+                let bytes: &[u8] = bytes.split(|&c| c == 0).next().unwrap();
+                std::str::from_utf8(bytes)
+                    .map_err(|_| TranslationCatalogError::InvalidMO("string is not UTF-8"))
+            };
+
+        let revision: usize = read_u32(4)?;
+        if revision >> 16 > 1 {
+            return Err(TranslationCatalogError::InvalidMO("unsupported revision"));
+        }
+        let string_count: usize = read_u32(8)?;
+        let untranslated_table_offset: usize = read_u32(12)?;
+        let translated_table_offset: usize = read_u32(16)?;
+
+        let mut catalog: TranslationCatalog = TranslationCatalog::new();
+        for i in 0..string_count {
+            let untranslated: &str = read_string(untranslated_table_offset, i)?;
+            let translated: &str = read_string(translated_table_offset, i)?;
+            if untranslated.is_empty() {
+                catalog.plural_forms = check_header(translated)?;
+            } else {
+                catalog.add(untranslated, translated);
+            }
+        }
+        Ok(catalog)
+    }
+
+    // Parses a GNU gettext portable object (.po file).
+    //
+    // Fuzzy entries, obsolete entries, and entries with plural forms are
+    // ignored, matching what msgfmt compiles by default.
+    pub fn from_po(text: &str) -> Result<TranslationCatalog, TranslationCatalogError> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Field {
+            None,
+            Context,
+            Msgid,
+            MsgidPlural,
+            Msgstr,
+        }
+
+        #[derive(Default)]
+        struct Entry {
+            context: Option<String>,
+            msgid: Option<String>,
+            msgstr: String,
+            is_fuzzy: bool,
+            is_plural: bool,
+        }
+
+        let mut catalog: TranslationCatalog = TranslationCatalog::new();
+        let mut finish = |entry: Entry| -> Result<(), TranslationCatalogError> {
+            let Some(msgid) = entry.msgid else {
+                return Ok(());
+            };
+            if entry.context.is_none() && msgid.is_empty() {
+                catalog.plural_forms = check_header(&entry.msgstr)?;
+                return Ok(());
+            }
+            if entry.is_fuzzy || entry.is_plural {
+                return Ok(());
+            }
+            let untranslated: String = match entry.context {
+                Some(context) => format!("{context}\u{4}{msgid}"),
+                None => msgid,
+            };
+            catalog.add(&untranslated, &entry.msgstr);
+            Ok(())
+        };
+
+        let mut entry: Entry = Entry::default();
+        let mut field: Field = Field::None;
+        for (line_index, line) in text.lines().enumerate() {
+            let error = |message: &'static str| TranslationCatalogError::InvalidPO {
+                line: line_index + 1,
+                message,
+            };
+            let line: &str = line.trim();
+            if line.starts_with('"') {
+                let s: String = parse_po_string(line).ok_or_else(|| error("malformed string"))?;
+                match field {
+                    Field::None => return Err(error("string without keyword")),
+                    Field::Context => entry.context.get_or_insert_with(String::new).push_str(&s),
+                    Field::Msgid => entry.msgid.get_or_insert_with(String::new).push_str(&s),
+                    Field::MsgidPlural => {}
+                    Field::Msgstr => entry.msgstr.push_str(&s),
+                }
+                continue;
+            }
+
+            // A comment, msgctxt, or msgid after a msgstr begins the next
+            // entry.
+            let begins_entry: bool = line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("msgctxt")
+                || line.starts_with("msgid ");
+            if begins_entry && field == Field::Msgstr {
+                finish(std::mem::take(&mut entry))?;
+                field = Field::None;
+            }
+            if line.is_empty() || line.starts_with("#~") {
+                continue;
+            }
+            if let Some(flags) = line.strip_prefix("#,") {
+                if flags.split(',').any(|flag| flag.trim() == "fuzzy") {
+                    entry.is_fuzzy = true;
+                }
+                continue;
+            }
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let (keyword, rest) = line
+                .split_once(|c: char| c.is_ascii_whitespace())
+                .ok_or_else(|| error("expected keyword and string"))?;
+            let s: String =
+                parse_po_string(rest.trim_start()).ok_or_else(|| error("malformed string"))?;
+            match keyword {
+                "msgctxt" => {
+                    field = Field::Context;
+                    entry.context = Some(s);
+                }
+                "msgid" => {
+                    field = Field::Msgid;
+                    entry.msgid = Some(s);
+                }
+                "msgid_plural" => {
+                    field = Field::MsgidPlural;
+                    entry.is_plural = true;
+                }
+                "msgstr" | "msgstr[0]" => {
+                    if entry.msgid.is_none() {
+                        return Err(error("msgstr without msgid"));
+                    }
+                    field = Field::Msgstr;
+                    entry.msgstr = s;
+                }
+                _ if keyword.starts_with("msgstr[") => {
+                    // Only the first plural form is used.
+                    field = Field::MsgidPlural;
+                }
+                _ => return Err(error("unknown keyword")),
+            }
+        }
+        if field == Field::Msgstr {
+            finish(entry)?;
+        }
+        Ok(catalog)
+    }
+
+    // Adds or replaces a translation. Empty translations and messages which
+    // are not in the compiled-in translation table are ignored. Translations
+    // with different placeholders than untranslated are skipped and added to
+    // warnings.
+    pub fn add(&mut self, untranslated: &str, translated: &str) {
+        if translated.is_empty() {
+            return;
+        }
+        let Some(mapping_index) = translation_table_look_up(untranslated) else {
+            return;
+        };
+        if let Err(error) = check_translation_placeholders(untranslated, translated) {
+            self.warnings.push(TranslationCatalogWarning {
+                untranslated: untranslated.to_string(),
+                error,
+            });
+            return;
+        }
+        self.translations[mapping_index as usize] = Some(translated.into());
+    }
+
+    // Returns the translations which were skipped, in the order they were
+    // added.
+    pub fn warnings(&self) -> &[TranslationCatalogWarning] {
+        &self.warnings
+    }
+
+    // Returns None if this catalog has no translation for the message.
+    pub fn translate(&self, message: TranslatableMessage) -> Option<&str> {
+        self.translations
+            .get(message.translation_table_mapping_index() as usize)?
+            .as_deref()
+    }
+
+    // Returns the rule for choosing plural forms in this catalog's
+    // translations, or None if the catalog has no Plural-Forms header.
+    pub fn plural_forms(&self) -> Option<PluralForms<'_>> {
+        PluralForms::parse(self.plural_forms.as_deref()?)
+    }
+
+    // Returns the number of translated messages.
+    pub fn len(&self) -> usize {
+        self.translations.iter().filter(|t| t.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for TranslationCatalog {
+    fn default() -> Self {
+        TranslationCatalog::new()
+    }
+}
+
+// Rejects catalogs which are not encoded in UTF-8 or which have a malformed
+// Plural-Forms field. Returns the Plural-Forms field, if any.
+fn check_header(header: &str) -> Result<Option<Box<str>>, TranslationCatalogError> {
+    let mut plural_forms: Option<Box<str>> = None;
+    for line in header.lines() {
+        if let Some(value) = line.strip_prefix("Plural-Forms:") {
+            let value: &str = value.trim();
+            if PluralForms::parse(value).is_none() {
+                return Err(TranslationCatalogError::InvalidPluralForms(
+                    value.to_string(),
+                ));
+            }
+            plural_forms = Some(value.into());
+            continue;
+        }
+        let Some(content_type) = line.strip_prefix("Content-Type:") else {
+            continue;
+        };
+        let Some((_, charset)) = content_type.split_once("charset=") else {
+            continue;
+        };
+        let charset: &str = charset.trim();
+        // msginit writes "CHARSET" as a placeholder.
+        if !(charset.eq_ignore_ascii_case("UTF-8")
+            || charset.eq_ignore_ascii_case("UTF8")
+            || charset == "CHARSET")
+        {
+            return Err(TranslationCatalogError::UnsupportedCharset(
+                charset.to_string(),
+            ));
+        }
+    }
+    Ok(plural_forms)
+}
+
+// Parses a C-style quoted string such as "hello\n".
+fn parse_po_string(quoted: &str) -> Option<String> {
+    let body: &str = quoted.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result: String = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                'a' => '\u{7}',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'v' => '\u{b}',
+                c @ ('"' | '\\') => c,
+                _ => return None,
+            }),
+            '"' => return None,
+            _ => result.push(c),
+        }
+    }
+    Some(result)
+}
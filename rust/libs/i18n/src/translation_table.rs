@@ -9,11 +9,18 @@ pub struct TranslationTableMappingEntry(
 );
 
 pub const fn translation_table_const_look_up(untranslated: &str) -> u16 {
-    let index: Option<usize> = sorted_search(&UNTRANSLATED_STRINGS, untranslated);
-    match index {
-        Some(index) => (index + 1) as u16,
+    match translation_table_look_up(untranslated) {
+        Some(mapping_index) => mapping_index,
         None => {
             panic!("translation_table_generated.rs is out of date. Run tools/update-translator-sources to rebuild this file.");
         }
     }
 }
+
+// Returns None if untranslated is not in the compiled-in translation table.
+pub const fn translation_table_look_up(untranslated: &str) -> Option<u16> {
+    match sorted_search(&UNTRANSLATED_STRINGS, untranslated) {
+        Some(index) => Some((index + 1) as u16),
+        None => None,
+    }
+}
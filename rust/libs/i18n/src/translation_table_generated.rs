@@ -1,4 +1,4 @@
-// Code generated by tools/compile-translations.go. DO NOT EDIT.
+// Code generated by rust/tools/compile_translations. DO NOT EDIT.
 // source: po/*.po
 
 // Copyright (C) 2020  Matthew "strager" Glazar
@@ -7,56 +7,105 @@
 use crate::translation_table::*;
 
 pub const TRANSLATION_TABLE_LOCALE_COUNT: u32 = 5;
-pub const TRANSLATION_TABLE_MAPPING_TABLE_SIZE: u16 = 64;
+pub const TRANSLATION_TABLE_MAPPING_TABLE_SIZE: u16 = 117;
 
-pub const UNTRANSLATED_STRINGS: [&str; 63] = [
+pub const UNTRANSLATED_STRINGS: [&str; 116] = [
     "'>' is not allowed directly in JSX text; write {{'>'} or &gt; instead",
+    "'catch' variable",
+    "'const' variable",
     "'do-while' loop",
     "'for' loop",
     "'if' statement",
+    "'let' variable",
+    "'var' variable",
     "'while' loop",
     "'with' statement",
     "'{0}' is not allowed for strings; use {1} instead",
     "'}' is not allowed directly in JSX text; write {{'}'} instead",
     "BigInt literal contains decimal point",
     "BigInt literal contains exponent",
+    "HTML entity is missing ';': {0}",
     "RegExp literal flags cannot contain Unicode escapes",
+    "Unicode bidirectional control character has no matching opening character",
+    "a 'catch' variable",
+    "a 'const' variable",
     "a 'do-while' loop",
     "a 'for' loop",
+    "a 'let' variable",
+    "a 'var' variable",
     "a 'while' loop",
     "a 'with' statement",
+    "a class",
+    "a const enum",
+    "a declare const enum",
+    "a declare enum",
+    "a function",
+    "a generic parameter",
     "a labelled statement",
+    "a namespace",
+    "a parameter",
+    "a type alias",
+    "a type import",
     "a {{0} b }} c",
     "an 'if' statement",
+    "an enum",
+    "an import",
+    "an import alias",
+    "an index signature parameter",
+    "an interface",
     "binary number literal has no digits",
+    "cannot assign to {1:headlinese}",
+    "cannot assign to {1:singular}",
     "character is not allowed in identifiers",
+    "class",
     "code point in Unicode escape sequence must not be greater than U+10FFFF",
     "code point out of range",
+    "enum",
+    "escape sequence {0} is not allowed in template literals or strict mode code",
+    "escape sequence {0} produces an unpaired UTF-16 surrogate",
     "escaped character is not allowed in identifiers",
     "escaping '-' is not allowed in tag names; write '-' instead",
     "expected hexadecimal digits in Unicode escape sequence",
     "expected {1:headlinese}",
     "expected {1:singular}",
+    "expected {1} {1:plural:argument|arguments}",
     "free {1} and {0} {1} {2}",
+    "function",
+    "generic parameter",
     "hex number literal has no digits",
+    "identifier contains characters which look like ASCII characters",
+    "identifier looks like '{1}'",
+    "identifier mixes characters from different scripts",
+    "import",
+    "import alias",
+    "index signature parameter",
     "integer cannot be represented and will be rounded to '{1}'",
+    "interface",
     "invalid UTF-8 sequence",
     "invalid hex escape sequence: {0}",
+    "irregular whitespace character; replace it with '{1}'",
     "keywords cannot contain escape sequences",
     "labelled statement",
+    "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
     "legacy octal literal may not be BigInt",
     "legacy octal literals may not contain underscores",
+    "namespace",
     "number literal contains consecutive underscores",
     "number literal contains trailing underscore(s)",
+    "octal escape sequence {0} is not allowed in template literals or strict mode code",
     "octal literal may not have decimal",
     "octal literal may not have exponent",
     "octal number literal has no digits",
+    "parameter",
     "second message here",
     "see here",
     "something happened",
     "test for multiple messages",
     "this {0} looks fishy",
     "this {1} looks fishy",
+    "type alias",
+    "type import",
+    "unclosed Unicode bidirectional control character",
     "unclosed block comment",
     "unclosed identifier escape sequence",
     "unclosed regexp literal",
@@ -71,76 +120,133 @@ pub const UNTRANSLATED_STRINGS: [&str; 63] = [
     "unexpected characters in octal literal",
     "unexpected control character",
     "unicode byte order mark (BOM) cannot appear before #! at beginning of script",
+    "unknown HTML entity: {0}",
+    "unnecessary escape sequence {0}; remove the backslash",
     "unopened block comment",
     "what is this '{1}' nonsense?",
+    "zero-width character in identifier; delete it",
+    "zero-width whitespace character; delete it",
 ];
 
 pub const TRANSLATION_DATA_MAPPING_TABLE: [TranslationTableMappingEntry;
     TRANSLATION_TABLE_MAPPING_TABLE_SIZE as usize] = [
     TranslationTableMappingEntry([0, 0, 0, 0, 0, 0]), //
-    TranslationTableMappingEntry([1, 2177, 0, 5627, 0, 8936]), //
-    TranslationTableMappingEntry([94, 2240, 0, 5697, 0, 9006]), //
-    TranslationTableMappingEntry([112, 2253, 0, 5713, 0, 9022]), //
-    TranslationTableMappingEntry([125, 2269, 0, 5724, 0, 9033]), //
-    TranslationTableMappingEntry([138, 2293, 0, 5741, 0, 9048]), //
-    TranslationTableMappingEntry([153, 2307, 0, 5754, 0, 9061]), //
-    TranslationTableMappingEntry([168, 2345, 0, 5773, 0, 9078]), //
-    TranslationTableMappingEntry([237, 2393, 0, 5819, 0, 9128]), //
-    TranslationTableMappingEntry([320, 2448, 3949, 5881, 7694, 9190]), //
-    TranslationTableMappingEntry([352, 2480, 4006, 5915, 7739, 9228]), //
-    TranslationTableMappingEntry([382, 2513, 4046, 5945, 0, 9261]), //
-    TranslationTableMappingEntry([438, 2551, 0, 6009, 0, 9313]), //
-    TranslationTableMappingEntry([461, 2566, 0, 6028, 0, 9331]), //
-    TranslationTableMappingEntry([479, 2584, 0, 6042, 0, 9344]), //
-    TranslationTableMappingEntry([499, 2600, 0, 6058, 0, 9359]), //
-    TranslationTableMappingEntry([0, 0, 0, 6081, 0, 9378]), //
-    TranslationTableMappingEntry([519, 2640, 0, 0, 0, 9399]), //
-    TranslationTableMappingEntry([533, 2654, 0, 6107, 0, 9413]), //
-    TranslationTableMappingEntry([551, 2680, 4109, 6128, 7783, 9431]), //
-    TranslationTableMappingEntry([587, 2712, 4161, 6163, 7825, 9467]), //
-    TranslationTableMappingEntry([621, 2742, 4208, 6209, 0, 9507]), //
-    TranslationTableMappingEntry([705, 2800, 4304, 6286, 7869, 9579]), //
-    TranslationTableMappingEntry([751, 2818, 4330, 6325, 7887, 9603]), //
-    TranslationTableMappingEntry([815, 2871, 0, 6379, 0, 9651]), //
-    TranslationTableMappingEntry([889, 2907, 4384, 6435, 7935, 9711]), //
-    TranslationTableMappingEntry([944, 2988, 0, 6508, 0, 9766]), //
-    TranslationTableMappingEntry([968, 3012, 0, 6532, 0, 9790]), //
-    TranslationTableMappingEntry([990, 3034, 0, 0, 0, 9812]), //
-    TranslationTableMappingEntry([1017, 3059, 4456, 6554, 7992, 9837]), //
-    TranslationTableMappingEntry([0, 3092, 0, 6592, 0, 9870]), //
-    TranslationTableMappingEntry([1058, 3160, 4504, 6660, 8028, 9929]), //
-    TranslationTableMappingEntry([1083, 3206, 4529, 6687, 8050, 9952]), //
-    TranslationTableMappingEntry([1117, 3221, 4572, 6727, 8087, 9985]), //
-    TranslationTableMappingEntry([0, 0, 0, 6782, 0, 10026]), //
-    TranslationTableMappingEntry([1174, 3293, 4639, 6804, 8133, 10045]), //
-    TranslationTableMappingEntry([1228, 3298, 4701, 6846, 8185, 10084]), //
-    TranslationTableMappingEntry([1289, 3348, 4776, 6895, 8249, 10134]), //
-    TranslationTableMappingEntry([1358, 3371, 4855, 6936, 8300, 10182]), //
-    TranslationTableMappingEntry([1398, 3397, 4916, 6975, 8356, 10229]), //
-    TranslationTableMappingEntry([1448, 3418, 4969, 7016, 8400, 10264]), //
-    TranslationTableMappingEntry([1496, 3445, 5013, 7053, 8443, 10300]), //
-    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10335]), //
-    TranslationTableMappingEntry([1531, 3482, 0, 7085, 0, 10355]), //
-    TranslationTableMappingEntry([1542, 3491, 0, 7095, 0, 10364]), //
+    TranslationTableMappingEntry([1, 2177, 0, 5534, 0, 8843]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 8913]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 8930]), //
+    TranslationTableMappingEntry([94, 2240, 0, 5604, 0, 8947]), //
+    TranslationTableMappingEntry([112, 2253, 0, 5620, 0, 8963]), //
+    TranslationTableMappingEntry([125, 2269, 0, 5631, 0, 8974]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 8989]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9004]), //
+    TranslationTableMappingEntry([138, 2293, 0, 5648, 0, 9019]), //
+    TranslationTableMappingEntry([153, 2307, 0, 5661, 0, 9032]), //
+    TranslationTableMappingEntry([168, 0, 0, 5680, 0, 9049]), //
+    TranslationTableMappingEntry([237, 2345, 0, 5726, 0, 9099]), //
+    TranslationTableMappingEntry([320, 2400, 3856, 5788, 7601, 9161]), //
+    TranslationTableMappingEntry([352, 2432, 3913, 5822, 7646, 9199]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9232]), //
+    TranslationTableMappingEntry([382, 2465, 3953, 5852, 0, 9264]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9316]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9390]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9409]), //
+    TranslationTableMappingEntry([438, 2503, 0, 5916, 0, 9428]), //
+    TranslationTableMappingEntry([461, 2518, 0, 5935, 0, 9446]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9459]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9476]), //
+    TranslationTableMappingEntry([479, 2536, 0, 5949, 0, 9493]), //
+    TranslationTableMappingEntry([499, 2552, 0, 5965, 0, 9508]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9527]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9535]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9548]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9569]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9584]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9595]), //
+    TranslationTableMappingEntry([0, 0, 0, 5988, 0, 9615]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9636]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9648]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9660]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9673]), //
+    TranslationTableMappingEntry([519, 2592, 0, 0, 0, 9687]), //
+    TranslationTableMappingEntry([533, 2606, 0, 6014, 0, 9701]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9719]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9727]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9737]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9753]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9782]), //
+    TranslationTableMappingEntry([551, 2632, 4016, 6035, 7690, 9795]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9831]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9863]), //
+    TranslationTableMappingEntry([587, 0, 4068, 6070, 7732, 9893]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 9933]), //
+    TranslationTableMappingEntry([621, 2664, 4115, 6116, 0, 9939]), //
+    TranslationTableMappingEntry([705, 2722, 4211, 6193, 7776, 10011]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10035]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10040]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10116]), //
+    TranslationTableMappingEntry([751, 2740, 4237, 6232, 7794, 10174]), //
+    TranslationTableMappingEntry([815, 2793, 0, 6286, 0, 10222]), //
+    TranslationTableMappingEntry([889, 2829, 4291, 6342, 7842, 10282]), //
+    TranslationTableMappingEntry([944, 2910, 0, 6415, 0, 10337]), //
+    TranslationTableMappingEntry([968, 2934, 0, 6439, 0, 10361]), //
     TranslationTableMappingEntry([0, 0, 0, 0, 0, 10383]), //
-    TranslationTableMappingEntry([1556, 3517, 0, 7110, 0, 10410]), //
-    TranslationTableMappingEntry([1589, 3538, 0, 7135, 0, 10431]), //
-    TranslationTableMappingEntry([1622, 3559, 5063, 7160, 8481, 10452]), //
-    TranslationTableMappingEntry([1647, 3609, 5094, 7196, 8511, 10475]), //
-    TranslationTableMappingEntry([1683, 3621, 5145, 7234, 8548, 10511]), //
-    TranslationTableMappingEntry([1710, 3646, 5173, 7256, 8573, 10535]), //
-    TranslationTableMappingEntry([1733, 3671, 5201, 7278, 8599, 10559]), //
-    TranslationTableMappingEntry([1752, 3690, 5221, 7304, 8614, 10577]), //
-    TranslationTableMappingEntry([1768, 3702, 5235, 7319, 8631, 10592]), //
-    TranslationTableMappingEntry([1785, 3713, 5249, 7334, 8648, 10607]), //
-    TranslationTableMappingEntry([1816, 3725, 5283, 7369, 8681, 10636]), //
-    TranslationTableMappingEntry([1862, 3765, 5332, 7415, 8718, 10676]), //
-    TranslationTableMappingEntry([1913, 3802, 5377, 7464, 8752, 10713]), //
-    TranslationTableMappingEntry([1950, 3824, 5429, 7501, 8789, 10753]), //
-    TranslationTableMappingEntry([1995, 3850, 5476, 7544, 8825, 10792]), //
-    TranslationTableMappingEntry([2022, 3880, 5510, 7575, 8853, 10821]), //
-    TranslationTableMappingEntry([2120, 3917, 5596, 7657, 0, 10898]), //
-    TranslationTableMappingEntry([2147, 3920, 0, 0, 0, 10921]), //
+    TranslationTableMappingEntry([990, 2956, 0, 0, 0, 10426]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10451]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10460]), //
+    TranslationTableMappingEntry([1017, 2981, 4363, 6461, 7899, 10478]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10511]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10575]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10603]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10654]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10661]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10674]), //
+    TranslationTableMappingEntry([0, 3014, 0, 6499, 0, 10700]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10759]), //
+    TranslationTableMappingEntry([1058, 3082, 4411, 6567, 7935, 10769]), //
+    TranslationTableMappingEntry([1083, 0, 4436, 6594, 7957, 10792]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10825]), //
+    TranslationTableMappingEntry([1117, 3128, 4479, 6634, 7994, 10879]), //
+    TranslationTableMappingEntry([0, 0, 0, 6689, 0, 10920]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 10939]), //
+    TranslationTableMappingEntry([1174, 3200, 4546, 6711, 8040, 11021]), //
+    TranslationTableMappingEntry([1228, 3205, 4608, 6753, 8092, 11060]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11110]), //
+    TranslationTableMappingEntry([1289, 3255, 4683, 6802, 8156, 11120]), //
+    TranslationTableMappingEntry([1358, 3278, 4762, 6843, 8207, 11168]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11215]), //
+    TranslationTableMappingEntry([1398, 3304, 4823, 6882, 8263, 11297]), //
+    TranslationTableMappingEntry([1448, 3325, 4876, 6923, 8307, 11332]), //
+    TranslationTableMappingEntry([1496, 3352, 4920, 6960, 8350, 11368]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11403]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11413]), //
+    TranslationTableMappingEntry([1531, 3389, 0, 6992, 0, 11433]), //
+    TranslationTableMappingEntry([1542, 3398, 0, 7002, 0, 11442]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11461]), //
+    TranslationTableMappingEntry([1556, 3424, 0, 7017, 0, 11488]), //
+    TranslationTableMappingEntry([1589, 3445, 0, 7042, 0, 11509]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11530]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11541]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 11553]), //
+    TranslationTableMappingEntry([1622, 3466, 4970, 7067, 8388, 11602]), //
+    TranslationTableMappingEntry([1647, 3516, 5001, 7103, 8418, 11625]), //
+    TranslationTableMappingEntry([1683, 3528, 5052, 7141, 8455, 11661]), //
+    TranslationTableMappingEntry([1710, 3553, 5080, 7163, 8480, 11685]), //
+    TranslationTableMappingEntry([1733, 3578, 5108, 7185, 8506, 11709]), //
+    TranslationTableMappingEntry([1752, 3597, 5128, 7211, 8521, 11727]), //
+    TranslationTableMappingEntry([1768, 3609, 5142, 7226, 8538, 11742]), //
+    TranslationTableMappingEntry([1785, 3620, 5156, 7241, 8555, 11757]), //
+    TranslationTableMappingEntry([1816, 3632, 5190, 7276, 8588, 11786]), //
+    TranslationTableMappingEntry([1862, 3672, 5239, 7322, 8625, 11826]), //
+    TranslationTableMappingEntry([1913, 3709, 5284, 7371, 8659, 11863]), //
+    TranslationTableMappingEntry([1950, 3731, 5336, 7408, 8696, 11903]), //
+    TranslationTableMappingEntry([1995, 3757, 5383, 7451, 8732, 11942]), //
+    TranslationTableMappingEntry([2022, 3787, 5417, 7482, 8760, 11971]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 12048]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 12073]), //
+    TranslationTableMappingEntry([2120, 3824, 5503, 7564, 0, 12127]), //
+    TranslationTableMappingEntry([2147, 3827, 0, 0, 0, 12150]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 12179]), //
+    TranslationTableMappingEntry([0, 0, 0, 0, 0, 12225]), //
 ];
 
 pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = "\
@@ -209,7 +315,6 @@ pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = "\
         when (not if) statement\0\
         whenever loop\0\
         what-the-heck-is-wrong-with statement\0\
-        smart quotes \u{1f9e0} require the SmartyPantsJS DLC\0\
         Facebook says '}' is not allowed; write {{'}'} instead\0\
         it's Big*Int*, not Big*Decimal*\0\
         BigExponInt is an ES2069 feature\0\
@@ -221,7 +326,6 @@ pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = "\
         a {{0} b }} c\0\
         a when (not if) statement\0\
         binary number lost its genitals\0\
-        hold up! \u{270b} no '{0}' allowed\0\
         U+10FFFF is the limit. what are you trying to accomplish?\0\
         it won't fit \u{1f930}\0\
         fugitive \u{1f9b9}\u{200d}\u{2642}\u{fe0f} is not allowed in identifiers\0\
@@ -233,7 +337,6 @@ pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = "\
         hex number literal has no digits\0\
         this number's too thicc for JavaScript; '{1}' would be used instead\0\
         quick-lint-js only works with nonbinary files\0\
-        this ain't hex\0\
         that sequence should escape from this keyword cuz it's not allowed here\0\
         0Ops\0\
         legacy_octal_literals_may_not_contain_underscores\0\
@@ -388,52 +491,101 @@ pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = "\
         of\u{00f6}rv\u{00e4}ntat kontrolltecken\0\
         unicode byte ordningsm\u{00e4}rke (BOM) kan inte f\u{00f6}rekomma f\u{00f6}re #! i b\u{00f6}rjan av skript\0\
         '>' is not allowed directly in JSX text; write {{'>'} or &gt; instead\0\
+        'catch' variable\0\
+        'const' variable\0\
         'do-while' loop\0\
         'for' loop\0\
         'if' statement\0\
+        'let' variable\0\
+        'var' variable\0\
         'while' loop\0\
         'with' statement\0\
         '{0}' is not allowed for strings; use {1} instead\0\
         '}' is not allowed directly in JSX text; write {{'}'} instead\0\
         BigInt literal contains decimal point\0\
         BigInt literal contains exponent\0\
+        HTML entity is missing ';': {0}\0\
         RegExp literal flags cannot contain Unicode escapes\0\
+        Unicode bidirectional control character has no matching opening character\0\
+        a 'catch' variable\0\
+        a 'const' variable\0\
         a 'do-while' loop\0\
         a 'for' loop\0\
+        a 'let' variable\0\
+        a 'var' variable\0\
         a 'while' loop\0\
         a 'with' statement\0\
+        a class\0\
+        a const enum\0\
+        a declare const enum\0\
+        a declare enum\0\
+        a function\0\
+        a generic parameter\0\
         a labelled statement\0\
+        a namespace\0\
+        a parameter\0\
+        a type alias\0\
+        a type import\0\
         a {{0} b }} c\0\
         an 'if' statement\0\
+        an enum\0\
+        an import\0\
+        an import alias\0\
+        an index signature parameter\0\
+        an interface\0\
         binary number literal has no digits\0\
+        cannot assign to {1:headlinese}\0\
+        cannot assign to {1:singular}\0\
         character is not allowed in identifiers\0\
+        class\0\
         code point in Unicode escape sequence must not be greater than U+10FFFF\0\
         code point out of range\0\
+        enum\0\
+        escape sequence {0} is not allowed in template literals or strict mode code\0\
+        escape sequence {0} produces an unpaired UTF-16 surrogate\0\
         escaped character is not allowed in identifiers\0\
         escaping '-' is not allowed in tag names; write '-' instead\0\
         expected hexadecimal digits in Unicode escape sequence\0\
         expected {1:headlinese}\0\
         expected {1:singular}\0\
+        expected {1} {1:plural:argument|arguments}\0\
         free {1} and {0} {1} {2}\0\
+        function\0\
+        generic parameter\0\
         hex number literal has no digits\0\
+        identifier contains characters which look like ASCII characters\0\
+        identifier looks like '{1}'\0\
+        identifier mixes characters from different scripts\0\
+        import\0\
+        import alias\0\
+        index signature parameter\0\
         integer cannot be represented and will be rounded to '{1}'\0\
+        interface\0\
         invalid UTF-8 sequence\0\
         invalid hex escape sequence: {0}\0\
+        irregular whitespace character; replace it with '{1}'\0\
         keywords cannot contain escape sequences\0\
         labelled statement\0\
+        legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead\0\
         legacy octal literal may not be BigInt\0\
         legacy octal literals may not contain underscores\0\
+        namespace\0\
         number literal contains consecutive underscores\0\
         number literal contains trailing underscore(s)\0\
+        octal escape sequence {0} is not allowed in template literals or strict mode code\0\
         octal literal may not have decimal\0\
         octal literal may not have exponent\0\
         octal number literal has no digits\0\
+        parameter\0\
         second message here\0\
         see here\0\
         something happened\0\
         test for multiple messages\0\
         this {0} looks fishy\0\
         this {1} looks fishy\0\
+        type alias\0\
+        type import\0\
+        unclosed Unicode bidirectional control character\0\
         unclosed block comment\0\
         unclosed identifier escape sequence\0\
         unclosed regexp literal\0\
@@ -448,8 +600,12 @@ pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = "\
         unexpected characters in octal literal\0\
         unexpected control character\0\
         unicode byte order mark (BOM) cannot appear before #! at beginning of script\0\
+        unknown HTML entity: {0}\0\
+        unnecessary escape sequence {0}; remove the backslash\0\
         unopened block comment\0\
         what is this '{1}' nonsense?\0\
+        zero-width character in identifier; delete it\0\
+        zero-width whitespace character; delete it\0\
 ".as_bytes();
 
 pub const TRANSLATION_DATA_LOCALE_TABLE: &str = "\
@@ -461,6 +617,22 @@ pub const TRANSLATION_DATA_LOCALE_TABLE: &str = "\
         \0\
 ";
 
+pub const TRANSLATION_DATA_PLURAL_FORMS_TABLE: [&str;
+    (TRANSLATION_TABLE_LOCALE_COUNT + 1) as usize] = [
+    // de
+    "nplurals=2; plural=(n != 1);",
+    // en_US@snarky
+    "nplurals=2; plural=(n != 1);",
+    // fr_FR
+    "nplurals=2; plural=(n > 1);",
+    // pt_BR
+    "nplurals=2; plural=(n > 1);",
+    // sv_SE
+    "nplurals=2; plural=(n != 1);",
+    // (untranslated)
+    "nplurals=2; plural=(n != 1);",
+];
+
 // quick-lint-js finds bugs in JavaScript programs.
 // Copyright (C) 2020  Matthew "strager" Glazar
 //
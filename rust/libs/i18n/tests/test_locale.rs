@@ -87,6 +87,192 @@ fn match_subset_of_locale_name() {
     });
 }
 
+#[test]
+fn bcp_47_tags_are_normalized() {
+    let normalize = |tag: &str| -> String { LanguageTag::parse(tag).unwrap().to_string() };
+    assert_eq!(normalize("sv"), "sv");
+    assert_eq!(normalize("pt-br"), "pt-BR");
+    assert_eq!(normalize("ZH-hant-tw"), "zh-Hant-TW");
+    assert_eq!(normalize("es-419"), "es-419");
+    assert_eq!(normalize("de-CH-1996"), "de-CH-1996");
+    assert_eq!(normalize("sl-rozaj-biske"), "sl-rozaj-biske");
+    assert_eq!(normalize("en-US-U-VA-POSIX"), "en-US-u-va-posix");
+    assert_eq!(normalize("zh-yue-HK"), "zh-HK");
+    assert_eq!(normalize("en-x-private-use"), "en");
+    assert_eq!(normalize("iw-IL"), "he-IL");
+}
+
+#[test]
+fn malformed_bcp_47_tags_are_rejected() {
+    for tag in [
+        "",
+        "e",
+        "en_US",
+        "fr_FR.utf8@bon",
+        "en--US",
+        "en-",
+        "123",
+        "en-US-a",
+        "en-US-x",
+        "en-US-u-toolongsubtag",
+        "en-US-!",
+        "C",
+    ] {
+        assert_eq!(LanguageTag::parse(tag), None, "{tag:?}");
+    }
+}
+
+#[test]
+fn bcp_47_tag_to_posix_locale_name() {
+    let posix = |tag: &str| -> String { LanguageTag::parse(tag).unwrap().to_posix_locale_name() };
+    assert_eq!(posix("sv"), "sv");
+    assert_eq!(posix("pt-BR"), "pt_BR");
+    assert_eq!(posix("zh-Hant-TW"), "zh_TW");
+    assert_eq!(posix("sr-Latn-RS"), "sr_RS@latin");
+    assert_eq!(posix("de-CH-1996"), "de_CH");
+}
+
+#[test]
+fn posix_locales() {
+    assert!(is_posix_locale("C"));
+    assert!(is_posix_locale("POSIX"));
+    assert!(is_posix_locale("en-US-u-va-posix"));
+    assert!(is_posix_locale("en-u-ca-gregory-va-posix"));
+    assert!(!is_posix_locale("en-US"));
+    assert!(!is_posix_locale("en_US"));
+    assert!(!is_posix_locale("c"));
+}
+
+fn user_locale_preferences(environment: &[(&str, &str)]) -> Vec<String> {
+    get_user_locale_preferences_from_environment(|name: &str| -> Option<String> {
+        environment
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| String::from(*value))
+    })
+}
+
+#[test]
+fn user_locale_preferences_follow_gnu_gettext_variable_order() {
+    assert_eq!(
+        user_locale_preferences(&[
+            ("LC_ALL", "de_DE"),
+            ("LC_MESSAGES", "fr_FR"),
+            ("LANG", "sv_SE")
+        ]),
+        vec!["de_DE"]
+    );
+    assert_eq!(
+        user_locale_preferences(&[("LC_MESSAGES", "fr_FR"), ("LANG", "sv_SE")]),
+        vec!["fr_FR"]
+    );
+    assert_eq!(
+        user_locale_preferences(&[("LANG", "sv_SE.UTF-8")]),
+        vec!["sv_SE.UTF-8"]
+    );
+    assert_eq!(
+        user_locale_preferences(&[("LC_ALL", ""), ("LC_MESSAGES", ""), ("LANG", "pt_BR")]),
+        vec!["pt_BR"]
+    );
+    assert_eq!(user_locale_preferences(&[]), vec!["C"]);
+}
+
+#[test]
+fn user_locale_preferences_use_language_priority_list() {
+    assert_eq!(
+        user_locale_preferences(&[("LANGUAGE", "sv:pt_BR::de"), ("LANG", "en_US.UTF-8")]),
+        vec!["sv", "pt_BR", "de"]
+    );
+    assert_eq!(
+        user_locale_preferences(&[("LANGUAGE", ""), ("LANG", "de_DE")]),
+        vec!["de_DE"]
+    );
+    assert_eq!(
+        user_locale_preferences(&[("LANGUAGE", ":"), ("LANG", "de_DE")]),
+        vec!["de_DE"]
+    );
+}
+
+#[test]
+fn c_or_posix_locale_overrides_language_priority_list() {
+    assert_eq!(
+        user_locale_preferences(&[("LANGUAGE", "sv:de"), ("LC_ALL", "C"), ("LANG", "de_DE")]),
+        vec!["C"]
+    );
+    assert_eq!(
+        user_locale_preferences(&[("LANGUAGE", "sv:de"), ("LC_MESSAGES", "POSIX")]),
+        vec!["POSIX"]
+    );
+    // With no locale variables, the locale is C.
+    assert_eq!(user_locale_preferences(&[("LANGUAGE", "sv:de")]), vec!["C"]);
+}
+
+#[test]
+fn accept_language_is_ordered_by_weight() {
+    assert_eq!(
+        parse_accept_language("de-CH, de;q=0.9, en;q=0.5, *;q=0.1"),
+        vec!["de-CH", "de", "en"]
+    );
+    assert_eq!(
+        parse_accept_language("fr;q=0.5, pt;q=0.5,sv"),
+        vec!["sv", "fr", "pt"]
+    );
+    assert_eq!(parse_accept_language("pt-BR"), vec!["pt-BR"]);
+    assert_eq!(parse_accept_language(""), Vec::<&str>::new());
+}
+
+#[test]
+fn accept_language_drops_rejected_and_malformed_ranges() {
+    assert_eq!(
+        parse_accept_language("en;q=0, de;q=2, fr;q=abc, sv;q=0.1234, pt;q=0.001, es;q"),
+        vec!["pt"]
+    );
+    assert_eq!(parse_accept_language("en;Q=1.000, de"), vec!["en", "de"]);
+}
+
+#[test]
+fn best_locale_matches_bcp_47_tags() {
+    iterate_permutations(
+        &["de", "en_US@snarky", "pt_BR", "sv_SE"],
+        |locales: &[&str]| {
+            let locales_string: Vec<u8> = make_locales_string(locales);
+            let find = |locale_name: &str| -> Option<&str> {
+                find_best_locale(locales_string.as_slice(), locale_name)
+                    .map(|index: i32| locales[index as usize])
+            };
+            assert_eq!(find("pt-BR"), Some("pt_BR"));
+            assert_eq!(find("PT-br"), Some("pt_BR"));
+            assert_eq!(find("pt_BR.UTF-8"), Some("pt_BR"));
+            assert_eq!(find("de-CH"), Some("de"));
+            assert_eq!(find("de_AT@euro"), Some("de"));
+            assert_eq!(find("en_US@snarky"), Some("en_US@snarky"));
+        },
+    );
+}
+
+#[test]
+fn best_locale_matches_same_language_without_modifier() {
+    iterate_permutations(
+        &["sv_SE", "pt_BR", "pt_PT", "en_US@snarky"],
+        |locales: &[&str]| {
+            let locales_string: Vec<u8> = make_locales_string(locales);
+            let find = |locale_name: &str| -> Option<&str> {
+                find_best_locale(locales_string.as_slice(), locale_name)
+                    .map(|index: i32| locales[index as usize])
+            };
+            assert_eq!(find("sv"), Some("sv_SE"));
+            assert_eq!(find("sv-FI"), Some("sv_SE"));
+            let pt: &str = find("pt").unwrap();
+            assert!(pt == "pt_BR" || pt == "pt_PT", "{pt}");
+            assert_eq!(find("pt-PT"), Some("pt_PT"));
+            assert_eq!(find("en"), None);
+            assert_eq!(find("en-US"), None);
+            assert_eq!(find("fr"), None);
+            assert_eq!(find(""), None);
+        },
+    );
+}
+
 fn make_locales_string(locales: &[&str]) -> Vec<u8> {
     let mut locales_string: Vec<u8> = vec![];
     for locale in locales {
@@ -0,0 +1,102 @@
+use cpp_vs_rust_i18n::plural_forms::*;
+
+fn select_all(plural_forms: &PluralForms, ns: &[u64]) -> Vec<usize> {
+    ns.iter().map(|&n: &u64| plural_forms.select(n)).collect()
+}
+
+#[test]
+fn source_code_rule_is_english() {
+    let rule: PluralForms = PluralForms::SOURCE_CODE;
+    assert_eq!(rule.count(), 2);
+    assert_eq!(select_all(&rule, &[0, 1, 2, 100]), vec![1, 0, 1, 1]);
+    let parsed: PluralForms = PluralForms::parse("nplurals=2; plural=(n != 1);").unwrap();
+    assert_eq!(parsed.count(), 2);
+    assert_eq!(select_all(&parsed, &[0, 1, 2, 100]), vec![1, 0, 1, 1]);
+}
+
+#[test]
+fn french_rule() {
+    let rule: PluralForms = PluralForms::parse("nplurals=2; plural=(n > 1);").unwrap();
+    assert_eq!(select_all(&rule, &[0, 1, 2, 10]), vec![0, 0, 1, 1]);
+}
+
+#[test]
+fn single_form_rule() {
+    let rule: PluralForms = PluralForms::parse("nplurals=1; plural=0;").unwrap();
+    assert_eq!(rule.count(), 1);
+    assert_eq!(select_all(&rule, &[0, 1, 2]), vec![0, 0, 0]);
+}
+
+#[test]
+fn polish_rule() {
+    let rule: PluralForms = PluralForms::parse(
+        "nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);",
+    )
+    .unwrap();
+    assert_eq!(
+        select_all(&rule, &[0, 1, 2, 4, 5, 12, 14, 21, 22, 112, 122]),
+        vec![2, 0, 1, 1, 2, 2, 2, 2, 1, 2, 1]
+    );
+}
+
+#[test]
+fn russian_rule() {
+    let rule: PluralForms = PluralForms::parse(
+        "nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);",
+    )
+    .unwrap();
+    assert_eq!(
+        select_all(&rule, &[1, 2, 5, 11, 21, 22, 111]),
+        vec![0, 1, 2, 2, 0, 1, 2]
+    );
+}
+
+#[test]
+fn selected_form_is_clamped_to_form_count() {
+    let rule: PluralForms = PluralForms::parse("nplurals=2; plural=n;").unwrap();
+    assert_eq!(select_all(&rule, &[0, 1, 2, 99]), vec![0, 1, 1, 1]);
+}
+
+#[test]
+fn expression_operators() {
+    for (expression, expected) in [
+        ("n", 7),
+        ("n + 1 - 3", 5),
+        ("n * 2 % 5", 4),
+        ("n / 2", 3),
+        ("n / 0", 0),
+        ("n % 0", 0),
+        ("!n", 0),
+        ("!!n", 1),
+        ("n != 7", 0),
+        ("n == 7 && n >= 7 && n <= 7", 1),
+        ("n < 7 || n > 7", 0),
+        ("n > 5 ? n < 10 ? 1 : 2 : 3", 1),
+        ("((n))", 7),
+    ] {
+        assert_eq!(
+            evaluate_plural_expression(expression, 7),
+            Some(expected),
+            "{expression:?}"
+        );
+    }
+}
+
+#[test]
+fn malformed_headers_are_rejected() {
+    for header in [
+        "",
+        "nplurals=2;",
+        "plural=(n != 1);",
+        "nplurals=0; plural=0;",
+        "nplurals=x; plural=0;",
+        "nplurals=2; plural=(n != 1",
+        "nplurals=2; plural=n ? 1;",
+        "nplurals=2; plural=m;",
+        "nplurals=2; plural=n 1;",
+        "nplurals=2; plural=(n != 1); extra=1;",
+        "nplurals 2; plural=n;",
+    ] {
+        assert_eq!(PluralForms::parse(header), None, "{header:?}");
+    }
+}
@@ -0,0 +1,85 @@
+use cpp_vs_rust_i18n::pseudo_locale::*;
+use cpp_vs_rust_i18n::qljs_translatable;
+use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_i18n::translation_table_generated::*;
+use cpp_vs_rust_message_format::*;
+
+#[test]
+fn vowels_are_accented_and_text_is_padded() {
+    assert_eq!(pseudo_localize("unexpected '#'"), "[ûnéxpéctéd '#' ~~~~~~]");
+    assert_eq!(
+        pseudo_localize("Invalid UTF-8"),
+        "[Învál\u{ee}d ÛTF-8 ~~~~~~]"
+    );
+    assert_eq!(pseudo_localize(""), "[]");
+}
+
+#[test]
+fn placeholders_are_kept() {
+    assert_eq!(
+        pseudo_localize("{0} is {1:headlinese}, not {2:singular}"),
+        "[{0} îs {1:headlinese}, nöt {2:singular} ~~~~]"
+    );
+    assert_eq!(
+        pseudo_localize("expected {1} {1:plural:argument|arguments}"),
+        "[éxpéctéd {1} {1:plural:árgûmént|árgûménts} ~~~~]"
+    );
+    assert_eq!(pseudo_localize("expected '{{'"), "[éxpéctéd '{{' ~~~~~]");
+}
+
+#[test]
+fn malformed_message_is_only_bracketed() {
+    assert_eq!(pseudo_localize("unexpected {"), "[unexpected {]");
+}
+
+#[test]
+fn every_message_keeps_its_placeholders() {
+    let keys = |message: &str| -> Vec<String> {
+        let mut keys: Vec<String> = parse_message_format_placeholders(message)
+            .unwrap()
+            .iter()
+            .map(Placeholder::key)
+            .collect();
+        keys.sort();
+        keys
+    };
+    for untranslated in UNTRANSLATED_STRINGS {
+        assert_eq!(
+            keys(&pseudo_localize(untranslated)),
+            keys(untranslated),
+            "{untranslated:?}"
+        );
+    }
+}
+
+#[test]
+fn pseudo_locale_names() {
+    assert!(is_pseudo_locale("en_XA"));
+    assert!(is_pseudo_locale("en_XA.UTF-8"));
+    assert!(is_pseudo_locale("en-XA"));
+    assert!(is_pseudo_locale("EN-xa"));
+    assert!(!is_pseudo_locale("en"));
+    assert!(!is_pseudo_locale("en_US"));
+    assert!(!is_pseudo_locale("xa"));
+    assert!(!is_pseudo_locale(""));
+}
+
+#[test]
+fn translator_uses_pseudo_locale() {
+    let mut t = Translator::new_using_messages_from_source_code();
+    assert!(t.use_messages_from_locales(&["xx-YY", "en-XA", "de"]));
+    assert_eq!(
+        t.translate(qljs_translatable!("unexpected '#'")),
+        "[ûnéxpéctéd '#' ~~~~~~]"
+    );
+
+    let (_, plural_forms) = t.translate_with_plural_forms(qljs_translatable!("unexpected '#'"));
+    assert_eq!(plural_forms.select(1), 0);
+    assert_eq!(plural_forms.select(2), 1);
+
+    t.use_messages_from_source_code();
+    assert_eq!(
+        t.translate(qljs_translatable!("unexpected '#'")),
+        "unexpected '#'"
+    );
+}
@@ -5,7 +5,10 @@ use cpp_vs_rust_fe::diagnostic::*;
 use cpp_vs_rust_fe::diagnostic_formatter::*;
 use cpp_vs_rust_fe::diagnostic_types::*;
 use cpp_vs_rust_fe::source_code_span::*;
+use cpp_vs_rust_i18n::plural_forms::*;
+use cpp_vs_rust_i18n::qljs_translatable;
 use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_i18n::translation_table_generated::*;
 
 struct BasicTextDiagReporter {
     translator: Translator,
@@ -104,6 +107,46 @@ fn english_snarky_translates() {
     assert_eq!(reporter.get_messages(), vec![b"#unexpected"],);
 }
 
+#[test]
+fn bcp_47_tag_selects_locale() {
+    let mut t = Translator::new_using_messages_from_source_code();
+    assert!(!t.use_messages_from_locales(&["xx-YY", "en-US-u-va-posix", "de"]));
+    assert!(t.use_messages_from_locales(&["xx-YY", "en_US@snarky", "de"]));
+    let reporter = BasicTextDiagReporter::new(t);
+    report(
+        &reporter,
+        DiagUnexpectedHashCharacter {
+            where_: dummy_span(),
+        },
+    );
+    assert_eq!(reporter.get_messages(), vec![b"#unexpected"],);
+}
+
+#[test]
+fn compiled_in_locales_have_valid_plural_forms() {
+    for plural_forms in TRANSLATION_DATA_PLURAL_FORMS_TABLE {
+        assert!(
+            PluralForms::parse(plural_forms).is_some(),
+            "{plural_forms:?}"
+        );
+    }
+}
+
+#[test]
+fn plural_forms_follow_translated_locale() {
+    let mut t = Translator::new_using_messages_from_source_code();
+    let (_, plural_forms) = t.translate_with_plural_forms(qljs_translatable!("unexpected '#'"));
+    assert_eq!(plural_forms.select(0), 1);
+    assert_eq!(plural_forms.select(1), 0);
+    assert_eq!(plural_forms.select(2), 1);
+
+    assert!(t.use_messages_from_locale("fr_FR"));
+    let (_, plural_forms) = t.translate_with_plural_forms(qljs_translatable!("unexpected '#'"));
+    assert_eq!(plural_forms.select(0), 0);
+    assert_eq!(plural_forms.select(1), 0);
+    assert_eq!(plural_forms.select(2), 1);
+}
+
 fn dummy_span() -> SourceCodeSpan<'static> {
     const HELLO: &'static [u8] = b"hello";
     SourceCodeSpan::from_slice(&HELLO[0..5])
@@ -0,0 +1,423 @@
+use cpp_vs_rust_i18n::plural_forms::*;
+use cpp_vs_rust_i18n::qljs_translatable;
+use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_i18n::translation_catalog::*;
+use cpp_vs_rust_message_format::*;
+use std::sync::Arc;
+
+// Writes a .mo file like msgfmt, with the given byte order.
+fn make_mo(entries: &[(&str, &str)], big_endian: bool) -> Vec<u8> {
+    let mut entries: Vec<(&str, &str)> = entries.to_vec();
+    entries.sort();
+    let u32_bytes = |value: usize| -> [u8; 4] {
+        if big_endian {
+            (value as u32).to_be_bytes()
+        } else {
+            (value as u32).to_le_bytes()
+        }
+    };
+    let header_size: usize = 28;
+    let untranslated_table_offset: usize = header_size;
+    let translated_table_offset: usize = untranslated_table_offset + entries.len() * 8;
+    let mut strings_offset: usize = translated_table_offset + entries.len() * 8;
+
+    let mut out: Vec<u8> = vec![];
+    for value in [
+        0x950412de,
+        0,
+        entries.len(),
+        untranslated_table_offset,
+        translated_table_offset,
+        0,
+        0,
+    ] {
+        out.extend_from_slice(&u32_bytes(value));
+    }
+    let mut strings: Vec<u8> = vec![];
+    let mut untranslated_table: Vec<u8> = vec![];
+    let mut translated_table: Vec<u8> = vec![];
+    for (table, index) in [(&mut untranslated_table, 0), (&mut translated_table, 1)] {
+        for entry in &entries {
+            let s: &str = if index == 0 { entry.0 } else { entry.1 };
+            table.extend_from_slice(&u32_bytes(s.len()));
+            table.extend_from_slice(&u32_bytes(strings_offset));
+            strings.extend_from_slice(s.as_bytes());
+            strings.push(0);
+            strings_offset += s.len() + 1;
+        }
+    }
+    out.extend_from_slice(&untranslated_table);
+    out.extend_from_slice(&translated_table);
+    out.extend_from_slice(&strings);
+    out
+}
+
+fn translator_with_catalog(catalog: TranslationCatalog) -> Translator {
+    let mut t: Translator = Translator::new_using_messages_from_source_code();
+    t.use_catalog(Arc::new(catalog));
+    t
+}
+
+#[test]
+fn po_catalog_overrides_source_code_messages() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"\"\n\
+         msgstr \"\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n\
+         \n\
+         # translator comment\n\
+         #: cpp/src/quick-lint-js/fe/diagnostic-types.h\n\
+         msgid \"unexpected '#'\"\n\
+         msgstr \"\"\n\
+         \"unerwartetes \"\n\
+         \"'#'\"\n",
+    )
+    .unwrap();
+    assert_eq!(catalog.len(), 1);
+    let t: Translator = translator_with_catalog(catalog);
+    assert_eq!(
+        t.translate(qljs_translatable!("unexpected '#'")),
+        "unerwartetes '#'"
+    );
+    assert_eq!(
+        t.translate(qljs_translatable!("unclosed template")),
+        "unclosed template"
+    );
+}
+
+#[test]
+fn catalog_falls_back_to_compiled_in_locale() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"unexpected '#'\"\n\
+         msgstr \"hash?\"\n",
+    )
+    .unwrap();
+    let mut t: Translator = Translator::new_using_messages_from_source_code();
+    assert!(t.use_messages_from_locale("en_US@snarky"));
+    t.use_catalog(Arc::new(catalog));
+    assert_eq!(t.translate(qljs_translatable!("unexpected '#'")), "hash?");
+    assert_eq!(
+        t.translate(qljs_translatable!("unclosed template")),
+        "`unclosed template"
+    );
+
+    t.clear_catalog();
+    assert_eq!(
+        t.translate(qljs_translatable!("unexpected '#'")),
+        "#unexpected"
+    );
+}
+
+#[test]
+fn po_catalog_ignores_fuzzy_obsolete_empty_and_unknown_entries() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "#, fuzzy\n\
+         msgid \"unexpected '#'\"\n\
+         msgstr \"fuzzy\"\n\
+         \n\
+         msgid \"unclosed template\"\n\
+         msgstr \"\"\n\
+         \n\
+         #~ msgid \"unclosed regexp literal\"\n\
+         #~ msgstr \"obsolete\"\n\
+         \n\
+         msgid \"message which is not in the translation table\"\n\
+         msgstr \"unknown\"\n\
+         msgid \"unclosed block comment\"\n\
+         msgstr \"translated\"\n",
+    )
+    .unwrap();
+    assert_eq!(catalog.len(), 1);
+    assert_eq!(
+        catalog.translate(qljs_translatable!("unclosed block comment")),
+        Some("translated")
+    );
+    assert_eq!(
+        catalog.translate(qljs_translatable!("unexpected '#'")),
+        None
+    );
+    assert_eq!(
+        catalog.translate(qljs_translatable!("unclosed template")),
+        None
+    );
+    assert_eq!(
+        catalog.translate(qljs_translatable!("unclosed regexp literal")),
+        None
+    );
+}
+
+#[test]
+fn po_catalog_decodes_escape_sequences() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"unexpected '\\\\' in identifier\"\n\
+         msgstr \"\\\"\\\\\\\" nicht erlaubt\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        catalog.translate(qljs_translatable!("unexpected '\\' in identifier")),
+        Some("\"\\\" nicht erlaubt")
+    );
+}
+
+#[test]
+fn malformed_po_catalog_is_rejected() {
+    for (po, expected_line) in [
+        ("msgid \"unexpected '#'\"\nmsgstr unquoted\n", 2),
+        (
+            "msgid \"unexpected '#'\"\n\"continued\" \n\"unterminated\n",
+            3,
+        ),
+        ("\n\nmsgstr \"no msgid\"\n", 3),
+        ("msgid \"x\"\nmsgfoo \"y\"\n", 2),
+    ] {
+        match TranslationCatalog::from_po(po) {
+            Err(TranslationCatalogError::InvalidPO { line, .. }) => {
+                assert_eq!(line, expected_line, "{po:?}")
+            }
+            Err(error) => panic!("unexpected error for {po:?}: {error}"),
+            Ok(_) => panic!("expected error for {po:?}"),
+        }
+    }
+}
+
+#[test]
+fn translation_with_different_placeholders_is_skipped() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"invalid hex escape sequence: {0}\"\n\
+         msgstr \"ungültige Escape-Sequenz: {1}\"\n\
+         \n\
+         msgid \"unclosed string literal\"\n\
+         msgstr \"nicht geschlossenes String-Literal\"\n",
+    )
+    .unwrap();
+    assert_eq!(catalog.len(), 1);
+    assert_eq!(
+        catalog.translate(qljs_translatable!("invalid hex escape sequence: {0}")),
+        None
+    );
+    assert_eq!(
+        catalog.warnings(),
+        &[TranslationCatalogWarning {
+            untranslated: "invalid hex escape sequence: {0}".to_string(),
+            error: TranslationPlaceholderError::Mismatch {
+                untranslated: vec!["0".to_string()],
+                translated: vec!["1".to_string()],
+            },
+        }]
+    );
+
+    // Omitting a placeholder is a mismatch too.
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"invalid hex escape sequence: {0}\"\n\
+         msgstr \"ungültige Escape-Sequenz\"\n",
+    )
+    .unwrap();
+    assert_eq!(catalog.len(), 0);
+    assert_eq!(catalog.warnings().len(), 1);
+
+    // Placeholders may be reordered, and "{{" is not a placeholder.
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"free {1} and {0} {1} {2}\"\n\
+         msgstr \"{{ {2} {1} {1} {0}\"\n",
+    )
+    .unwrap();
+    assert_eq!(catalog.len(), 1);
+    assert!(catalog.warnings().is_empty());
+}
+
+#[test]
+fn mo_translation_with_different_placeholders_is_skipped() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_mo(&make_mo(
+        &[
+            ("invalid hex escape sequence: {0}", "ungültig"),
+            ("unclosed string literal", "nicht geschlossen"),
+        ],
+        false,
+    ))
+    .unwrap();
+    assert_eq!(catalog.len(), 1);
+    assert_eq!(catalog.warnings().len(), 1);
+    assert_eq!(
+        catalog.warnings()[0].to_string(),
+        "skipped translation of \"invalid hex escape sequence: {0}\": translation has placeholders none but msgid has {0}"
+    );
+}
+
+#[test]
+fn translation_may_have_different_number_of_plural_forms() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"expected {1} {1:plural:argument|arguments}\"\n\
+         msgstr \"{1:plural:Argument|Argumente|Argumenten} erwartet: {1}\"\n",
+    )
+    .unwrap();
+    assert_eq!(catalog.len(), 1);
+    for msgstr in ["{1} erwartet", "{1} {1:singular} erwartet"] {
+        let catalog: TranslationCatalog = TranslationCatalog::from_po(&format!(
+            "msgid \"expected {{1}} {{1:plural:argument|arguments}}\"\n\
+             msgstr \"{msgstr}\"\n",
+        ))
+        .unwrap();
+        assert_eq!(catalog.len(), 0, "{msgstr:?} should be skipped");
+        assert_eq!(catalog.warnings().len(), 1, "{msgstr:?} should be skipped");
+    }
+}
+
+#[test]
+fn po_catalog_plural_forms_header() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"\"\n\
+         msgstr \"\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n\
+         \"Plural-Forms: nplurals=2; plural=(n > 1);\\n\"\n",
+    )
+    .unwrap();
+    let plural_forms: PluralForms = catalog.plural_forms().unwrap();
+    assert_eq!(plural_forms.count(), 2);
+    assert_eq!(plural_forms.select(0), 0);
+    assert_eq!(plural_forms.select(1), 0);
+    assert_eq!(plural_forms.select(2), 1);
+
+    let catalog: TranslationCatalog =
+        TranslationCatalog::from_po("msgid \"unexpected '#'\"\nmsgstr \"#\"\n").unwrap();
+    assert_eq!(catalog.plural_forms(), None);
+}
+
+#[test]
+fn mo_catalog_plural_forms_header() {
+    let mo: Vec<u8> = make_mo(&[("", "Plural-Forms: nplurals=1; plural=0;\n")], false);
+    let catalog: TranslationCatalog = TranslationCatalog::from_mo(&mo).unwrap();
+    assert_eq!(catalog.plural_forms().unwrap().count(), 1);
+}
+
+#[test]
+fn malformed_plural_forms_header_is_rejected() {
+    assert!(matches!(
+        TranslationCatalog::from_po(
+            "msgid \"\"\n\
+             msgstr \"Plural-Forms: nplurals=2; plural=(n > 1;\\n\"\n",
+        ),
+        Err(TranslationCatalogError::InvalidPluralForms(plural_forms))
+            if plural_forms == "nplurals=2; plural=(n > 1;"
+    ));
+}
+
+#[test]
+fn non_utf_8_catalog_is_rejected() {
+    assert!(matches!(
+        TranslationCatalog::from_po(
+            "msgid \"\"\n\
+             msgstr \"Content-Type: text/plain; charset=ISO-8859-1\\n\"\n",
+        ),
+        Err(TranslationCatalogError::UnsupportedCharset(charset)) if charset == "ISO-8859-1"
+    ));
+    assert!(matches!(
+        TranslationCatalog::from_bytes(b"msgid \"unexpected '#'\"\nmsgstr \"\xe9\"\n"),
+        Err(TranslationCatalogError::InvalidPO { line: 2, .. })
+    ));
+}
+
+#[test]
+fn mo_catalog_of_either_byte_order() {
+    let entries: &[(&str, &str)] = &[
+        ("", "Content-Type: text/plain; charset=UTF-8\n"),
+        ("unexpected '#'", "inattendu '#'"),
+        ("unclosed template", "modèle non fermé"),
+        ("message which is not in the translation table", "ignored"),
+    ];
+    for big_endian in [false, true] {
+        let catalog: TranslationCatalog =
+            TranslationCatalog::from_bytes(&make_mo(entries, big_endian)).unwrap();
+        assert_eq!(catalog.len(), 2);
+        let t: Translator = translator_with_catalog(catalog);
+        assert_eq!(
+            t.translate(qljs_translatable!("unexpected '#'")),
+            "inattendu '#'"
+        );
+        assert_eq!(
+            t.translate(qljs_translatable!("unclosed template")),
+            "modèle non fermé"
+        );
+    }
+}
+
+#[test]
+fn malformed_mo_catalog_is_rejected() {
+    let mo: Vec<u8> = make_mo(&[("unexpected '#'", "inattendu '#'")], false);
+    assert!(matches!(
+        TranslationCatalog::from_mo(&mo[..mo.len() - 20]),
+        Err(TranslationCatalogError::InvalidMO(_))
+    ));
+    assert!(matches!(
+        TranslationCatalog::from_mo(&mo[..10]),
+        Err(TranslationCatalogError::InvalidMO(_))
+    ));
+    assert!(matches!(
+        TranslationCatalog::from_mo(b"not a .mo file"),
+        Err(TranslationCatalogError::InvalidMO(_))
+    ));
+}
+
+#[test]
+fn mo_catalog_with_huge_offsets_is_rejected() {
+    let mo: Vec<u8> = make_mo(&[("unexpected '#'", "inattendu '#'")], false);
+    for (field_offset, value) in [
+        // Untranslated string table offset.
+        (12, u32::MAX),
+        (12, u32::MAX - 4),
+        // Translated string table offset.
+        (16, u32::MAX - 7),
+        // String count. Descriptors after the first are past the end of the
+        // file.
+        (8, u32::MAX),
+    ] {
+        let mut mo: Vec<u8> = mo.clone();
+        mo[field_offset..field_offset + 4].copy_from_slice(&value.to_le_bytes());
+        assert!(
+            matches!(
+                TranslationCatalog::from_mo(&mo),
+                Err(TranslationCatalogError::InvalidMO(_))
+            ),
+            "field at {field_offset} = {value:#x}"
+        );
+    }
+}
+
+#[test]
+fn load_catalog_from_file() {
+    let path: std::path::PathBuf = std::env::temp_dir().join(format!(
+        "cpp_vs_rust_i18n_test_translation_catalog_{}.mo",
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        make_mo(&[("unexpected '#'", "inattendu '#'")], false),
+    )
+    .unwrap();
+    let catalog: Result<TranslationCatalog, TranslationCatalogError> =
+        TranslationCatalog::load_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        catalog
+            .unwrap()
+            .translate(qljs_translatable!("unexpected '#'")),
+        Some("inattendu '#'")
+    );
+
+    assert!(matches!(
+        TranslationCatalog::load_file(&path),
+        Err(TranslationCatalogError::IO(_))
+    ));
+}
+
+#[test]
+fn cloned_translator_shares_catalog() {
+    let catalog: TranslationCatalog = TranslationCatalog::from_po(
+        "msgid \"unexpected '#'\"\n\
+         msgstr \"hash?\"\n",
+    )
+    .unwrap();
+    let t: Translator = translator_with_catalog(catalog);
+    let t2: Translator = t.clone();
+    drop(t);
+    assert_eq!(t2.translate(qljs_translatable!("unexpected '#'")), "hash?");
+}
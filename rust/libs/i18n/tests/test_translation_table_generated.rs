@@ -1,4 +1,4 @@
-// Code generated by tools/compile-translations.go. DO NOT EDIT.
+// Code generated by rust/tools/compile_translations. DO NOT EDIT.
 // source: po/*.po
 
 // Copyright (C) 2020  Matthew "strager" Glazar
@@ -25,7 +25,7 @@ pub struct TranslatedString {
     pub expected_per_locale: [&'static str; 6],
 }
 
-pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
+pub const TEST_TRANSLATION_TABLE: [TranslatedString; 116] = [
     TranslatedString{
         translatable: qljs_translatable!("'>' is not allowed directly in JSX text; write {{'>'} or &gt; instead"),
         expected_per_locale: [
@@ -37,6 +37,28 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "'>' is not allowed directly in JSX text; write {{'>'} or &gt; instead",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("'catch' variable"),
+        expected_per_locale: [
+            "'catch' variable",
+            "'catch' variable",
+            "'catch' variable",
+            "'catch' variable",
+            "'catch' variable",
+            "'catch' variable",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("'const' variable"),
+        expected_per_locale: [
+            "'const' variable",
+            "'const' variable",
+            "'const' variable",
+            "'const' variable",
+            "'const' variable",
+            "'const' variable",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("'do-while' loop"),
         expected_per_locale: [
@@ -70,6 +92,28 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "'if' statement",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("'let' variable"),
+        expected_per_locale: [
+            "'let' variable",
+            "'let' variable",
+            "'let' variable",
+            "'let' variable",
+            "'let' variable",
+            "'let' variable",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("'var' variable"),
+        expected_per_locale: [
+            "'var' variable",
+            "'var' variable",
+            "'var' variable",
+            "'var' variable",
+            "'var' variable",
+            "'var' variable",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("'while' loop"),
         expected_per_locale: [
@@ -97,7 +141,7 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
         expected_per_locale: [
             "'{0}' is not allowed for strings; use {1} instead",
             "'{0}' ist f\u{00fc}r Strings nicht erlaubt. '{1}' anstattdessen verwenden.",
-            "smart quotes \u{1f9e0} require the SmartyPantsJS DLC",
+            "'{0}' is not allowed for strings; use {1} instead",
             "'{0}' is not allowed for strings; use {1} instead",
             "'{0}' n\u{00e3}o \u{00e9} permitido para strings; use {1}",
             "'{0}' is not allowed for strings; use {1} instead",
@@ -136,6 +180,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "BigInt heltallitter\u{00e4}r inneh\u{00e5}ller exponent",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("HTML entity is missing ';': {0}"),
+        expected_per_locale: [
+            "HTML entity is missing ';': {0}",
+            "HTML entity is missing ';': {0}",
+            "HTML entity is missing ';': {0}",
+            "HTML entity is missing ';': {0}",
+            "HTML entity is missing ';': {0}",
+            "HTML entity is missing ';': {0}",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("RegExp literal flags cannot contain Unicode escapes"),
         expected_per_locale: [
@@ -147,6 +202,39 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "RegExp literal flags cannot contain Unicode escapes",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("Unicode bidirectional control character has no matching opening character"),
+        expected_per_locale: [
+            "Unicode bidirectional control character has no matching opening character",
+            "Unicode bidirectional control character has no matching opening character",
+            "Unicode bidirectional control character has no matching opening character",
+            "Unicode bidirectional control character has no matching opening character",
+            "Unicode bidirectional control character has no matching opening character",
+            "Unicode bidirectional control character has no matching opening character",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a 'catch' variable"),
+        expected_per_locale: [
+            "a 'catch' variable",
+            "a 'catch' variable",
+            "a 'catch' variable",
+            "a 'catch' variable",
+            "a 'catch' variable",
+            "a 'catch' variable",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a 'const' variable"),
+        expected_per_locale: [
+            "a 'const' variable",
+            "a 'const' variable",
+            "a 'const' variable",
+            "a 'const' variable",
+            "a 'const' variable",
+            "a 'const' variable",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("a 'do-while' loop"),
         expected_per_locale: [
@@ -169,6 +257,28 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "a 'for' loop",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("a 'let' variable"),
+        expected_per_locale: [
+            "a 'let' variable",
+            "a 'let' variable",
+            "a 'let' variable",
+            "a 'let' variable",
+            "a 'let' variable",
+            "a 'let' variable",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a 'var' variable"),
+        expected_per_locale: [
+            "a 'var' variable",
+            "a 'var' variable",
+            "a 'var' variable",
+            "a 'var' variable",
+            "a 'var' variable",
+            "a 'var' variable",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("a 'while' loop"),
         expected_per_locale: [
@@ -191,6 +301,72 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "a 'with' statement",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("a class"),
+        expected_per_locale: [
+            "a class",
+            "a class",
+            "a class",
+            "a class",
+            "a class",
+            "a class",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a const enum"),
+        expected_per_locale: [
+            "a const enum",
+            "a const enum",
+            "a const enum",
+            "a const enum",
+            "a const enum",
+            "a const enum",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a declare const enum"),
+        expected_per_locale: [
+            "a declare const enum",
+            "a declare const enum",
+            "a declare const enum",
+            "a declare const enum",
+            "a declare const enum",
+            "a declare const enum",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a declare enum"),
+        expected_per_locale: [
+            "a declare enum",
+            "a declare enum",
+            "a declare enum",
+            "a declare enum",
+            "a declare enum",
+            "a declare enum",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a function"),
+        expected_per_locale: [
+            "a function",
+            "a function",
+            "a function",
+            "a function",
+            "a function",
+            "a function",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a generic parameter"),
+        expected_per_locale: [
+            "a generic parameter",
+            "a generic parameter",
+            "a generic parameter",
+            "a generic parameter",
+            "a generic parameter",
+            "a generic parameter",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("a labelled statement"),
         expected_per_locale: [
@@ -202,6 +378,50 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "a labelled statement",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("a namespace"),
+        expected_per_locale: [
+            "a namespace",
+            "a namespace",
+            "a namespace",
+            "a namespace",
+            "a namespace",
+            "a namespace",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a parameter"),
+        expected_per_locale: [
+            "a parameter",
+            "a parameter",
+            "a parameter",
+            "a parameter",
+            "a parameter",
+            "a parameter",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a type alias"),
+        expected_per_locale: [
+            "a type alias",
+            "a type alias",
+            "a type alias",
+            "a type alias",
+            "a type alias",
+            "a type alias",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("a type import"),
+        expected_per_locale: [
+            "a type import",
+            "a type import",
+            "a type import",
+            "a type import",
+            "a type import",
+            "a type import",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("a {{0} b }} c"),
         expected_per_locale: [
@@ -224,6 +444,61 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "an 'if' statement",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("an enum"),
+        expected_per_locale: [
+            "an enum",
+            "an enum",
+            "an enum",
+            "an enum",
+            "an enum",
+            "an enum",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("an import"),
+        expected_per_locale: [
+            "an import",
+            "an import",
+            "an import",
+            "an import",
+            "an import",
+            "an import",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("an import alias"),
+        expected_per_locale: [
+            "an import alias",
+            "an import alias",
+            "an import alias",
+            "an import alias",
+            "an import alias",
+            "an import alias",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("an index signature parameter"),
+        expected_per_locale: [
+            "an index signature parameter",
+            "an index signature parameter",
+            "an index signature parameter",
+            "an index signature parameter",
+            "an index signature parameter",
+            "an index signature parameter",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("an interface"),
+        expected_per_locale: [
+            "an interface",
+            "an interface",
+            "an interface",
+            "an interface",
+            "an interface",
+            "an interface",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("binary number literal has no digits"),
         expected_per_locale: [
@@ -235,17 +510,50 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "bin\u{00e4}ra nummerlitteraler has inga siffror",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("cannot assign to {1:headlinese}"),
+        expected_per_locale: [
+            "cannot assign to {1:headlinese}",
+            "cannot assign to {1:headlinese}",
+            "cannot assign to {1:headlinese}",
+            "cannot assign to {1:headlinese}",
+            "cannot assign to {1:headlinese}",
+            "cannot assign to {1:headlinese}",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("cannot assign to {1:singular}"),
+        expected_per_locale: [
+            "cannot assign to {1:singular}",
+            "cannot assign to {1:singular}",
+            "cannot assign to {1:singular}",
+            "cannot assign to {1:singular}",
+            "cannot assign to {1:singular}",
+            "cannot assign to {1:singular}",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("character is not allowed in identifiers"),
         expected_per_locale: [
             "character is not allowed in identifiers",
             "Ung\u{00fc}ltiges Zeichen in Bezeichner",
-            "hold up! \u{270b} no '{0}' allowed",
+            "character is not allowed in identifiers",
             "caract\u{00e8}re non autoris\u{00e9} dans les identifiants",
             "caracter n\u{00e3}o \u{00e9} permitido em identificadores",
             "tecknet \u{00e4}r inte till\u{00e5}tet i indentifierare",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("class"),
+        expected_per_locale: [
+            "class",
+            "class",
+            "class",
+            "class",
+            "class",
+            "class",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("code point in Unicode escape sequence must not be greater than U+10FFFF"),
         expected_per_locale: [
@@ -268,6 +576,39 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "kod punkt ur span",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("enum"),
+        expected_per_locale: [
+            "enum",
+            "enum",
+            "enum",
+            "enum",
+            "enum",
+            "enum",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("escape sequence {0} is not allowed in template literals or strict mode code"),
+        expected_per_locale: [
+            "escape sequence {0} is not allowed in template literals or strict mode code",
+            "escape sequence {0} is not allowed in template literals or strict mode code",
+            "escape sequence {0} is not allowed in template literals or strict mode code",
+            "escape sequence {0} is not allowed in template literals or strict mode code",
+            "escape sequence {0} is not allowed in template literals or strict mode code",
+            "escape sequence {0} is not allowed in template literals or strict mode code",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("escape sequence {0} produces an unpaired UTF-16 surrogate"),
+        expected_per_locale: [
+            "escape sequence {0} produces an unpaired UTF-16 surrogate",
+            "escape sequence {0} produces an unpaired UTF-16 surrogate",
+            "escape sequence {0} produces an unpaired UTF-16 surrogate",
+            "escape sequence {0} produces an unpaired UTF-16 surrogate",
+            "escape sequence {0} produces an unpaired UTF-16 surrogate",
+            "escape sequence {0} produces an unpaired UTF-16 surrogate",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("escaped character is not allowed in identifiers"),
         expected_per_locale: [
@@ -323,6 +664,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "expected {1:singular}",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("expected {1} {1:plural:argument|arguments}"),
+        expected_per_locale: [
+            "expected {1} {1:plural:argument|arguments}",
+            "expected {1} {1:plural:argument|arguments}",
+            "expected {1} {1:plural:argument|arguments}",
+            "expected {1} {1:plural:argument|arguments}",
+            "expected {1} {1:plural:argument|arguments}",
+            "expected {1} {1:plural:argument|arguments}",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("free {1} and {0} {1} {2}"),
         expected_per_locale: [
@@ -334,6 +686,28 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "free {1} and {0} {1} {2}",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("function"),
+        expected_per_locale: [
+            "function",
+            "function",
+            "function",
+            "function",
+            "function",
+            "function",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("generic parameter"),
+        expected_per_locale: [
+            "generic parameter",
+            "generic parameter",
+            "generic parameter",
+            "generic parameter",
+            "generic parameter",
+            "generic parameter",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("hex number literal has no digits"),
         expected_per_locale: [
@@ -345,6 +719,72 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "hex nummerlitteral har inga siffror",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("identifier contains characters which look like ASCII characters"),
+        expected_per_locale: [
+            "identifier contains characters which look like ASCII characters",
+            "identifier contains characters which look like ASCII characters",
+            "identifier contains characters which look like ASCII characters",
+            "identifier contains characters which look like ASCII characters",
+            "identifier contains characters which look like ASCII characters",
+            "identifier contains characters which look like ASCII characters",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("identifier looks like '{1}'"),
+        expected_per_locale: [
+            "identifier looks like '{1}'",
+            "identifier looks like '{1}'",
+            "identifier looks like '{1}'",
+            "identifier looks like '{1}'",
+            "identifier looks like '{1}'",
+            "identifier looks like '{1}'",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("identifier mixes characters from different scripts"),
+        expected_per_locale: [
+            "identifier mixes characters from different scripts",
+            "identifier mixes characters from different scripts",
+            "identifier mixes characters from different scripts",
+            "identifier mixes characters from different scripts",
+            "identifier mixes characters from different scripts",
+            "identifier mixes characters from different scripts",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("import"),
+        expected_per_locale: [
+            "import",
+            "import",
+            "import",
+            "import",
+            "import",
+            "import",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("import alias"),
+        expected_per_locale: [
+            "import alias",
+            "import alias",
+            "import alias",
+            "import alias",
+            "import alias",
+            "import alias",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("index signature parameter"),
+        expected_per_locale: [
+            "index signature parameter",
+            "index signature parameter",
+            "index signature parameter",
+            "index signature parameter",
+            "index signature parameter",
+            "index signature parameter",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("integer cannot be represented and will be rounded to '{1}'"),
         expected_per_locale: [
@@ -356,6 +796,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "integer cannot be represented and will be rounded to '{1}'",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("interface"),
+        expected_per_locale: [
+            "interface",
+            "interface",
+            "interface",
+            "interface",
+            "interface",
+            "interface",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("invalid UTF-8 sequence"),
         expected_per_locale: [
@@ -372,12 +823,23 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
         expected_per_locale: [
             "invalid hex escape sequence: {0}",
             "Ung\u{00fc}ltige Hex-Escapesequenz: {0}",
-            "this ain't hex",
+            "invalid hex escape sequence: {0}",
             "s\u{00e9}quence d'\u{00e9}chappement hex invalide: {0}",
             "sequ\u{00ea}ncia de escape hex inv\u{00e1}lida: {0}",
             "ogiltig kring\u{00e5}ende hex sekvens: {0}",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("irregular whitespace character; replace it with '{1}'"),
+        expected_per_locale: [
+            "irregular whitespace character; replace it with '{1}'",
+            "irregular whitespace character; replace it with '{1}'",
+            "irregular whitespace character; replace it with '{1}'",
+            "irregular whitespace character; replace it with '{1}'",
+            "irregular whitespace character; replace it with '{1}'",
+            "irregular whitespace character; replace it with '{1}'",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("keywords cannot contain escape sequences"),
         expected_per_locale: [
@@ -400,6 +862,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "labelled statement",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead"),
+        expected_per_locale: [
+            "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+            "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+            "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+            "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+            "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+            "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("legacy octal literal may not be BigInt"),
         expected_per_locale: [
@@ -422,6 +895,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "\u{00e4}rftligt octal nummerlitteral kan inte inneh\u{00e5}lla understr\u{00e4}ck",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("namespace"),
+        expected_per_locale: [
+            "namespace",
+            "namespace",
+            "namespace",
+            "namespace",
+            "namespace",
+            "namespace",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("number literal contains consecutive underscores"),
         expected_per_locale: [
@@ -444,6 +928,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "nummerlitter\u{00e4}r inneh\u{00e5}ller efterf\u{00f6}ljande understr\u{00e4}ck",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("octal escape sequence {0} is not allowed in template literals or strict mode code"),
+        expected_per_locale: [
+            "octal escape sequence {0} is not allowed in template literals or strict mode code",
+            "octal escape sequence {0} is not allowed in template literals or strict mode code",
+            "octal escape sequence {0} is not allowed in template literals or strict mode code",
+            "octal escape sequence {0} is not allowed in template literals or strict mode code",
+            "octal escape sequence {0} is not allowed in template literals or strict mode code",
+            "octal escape sequence {0} is not allowed in template literals or strict mode code",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("octal literal may not have decimal"),
         expected_per_locale: [
@@ -477,6 +972,17 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "oktal nummerlitteral har inga siffror",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("parameter"),
+        expected_per_locale: [
+            "parameter",
+            "parameter",
+            "parameter",
+            "parameter",
+            "parameter",
+            "parameter",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("second message here"),
         expected_per_locale: [
@@ -543,6 +1049,39 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "this {1} looks fishy",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("type alias"),
+        expected_per_locale: [
+            "type alias",
+            "type alias",
+            "type alias",
+            "type alias",
+            "type alias",
+            "type alias",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("type import"),
+        expected_per_locale: [
+            "type import",
+            "type import",
+            "type import",
+            "type import",
+            "type import",
+            "type import",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("unclosed Unicode bidirectional control character"),
+        expected_per_locale: [
+            "unclosed Unicode bidirectional control character",
+            "unclosed Unicode bidirectional control character",
+            "unclosed Unicode bidirectional control character",
+            "unclosed Unicode bidirectional control character",
+            "unclosed Unicode bidirectional control character",
+            "unclosed Unicode bidirectional control character",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("unclosed block comment"),
         expected_per_locale: [
@@ -697,6 +1236,28 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "unicode byte ordningsm\u{00e4}rke (BOM) kan inte f\u{00f6}rekomma f\u{00f6}re #! i b\u{00f6}rjan av skript",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("unknown HTML entity: {0}"),
+        expected_per_locale: [
+            "unknown HTML entity: {0}",
+            "unknown HTML entity: {0}",
+            "unknown HTML entity: {0}",
+            "unknown HTML entity: {0}",
+            "unknown HTML entity: {0}",
+            "unknown HTML entity: {0}",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("unnecessary escape sequence {0}; remove the backslash"),
+        expected_per_locale: [
+            "unnecessary escape sequence {0}; remove the backslash",
+            "unnecessary escape sequence {0}; remove the backslash",
+            "unnecessary escape sequence {0}; remove the backslash",
+            "unnecessary escape sequence {0}; remove the backslash",
+            "unnecessary escape sequence {0}; remove the backslash",
+            "unnecessary escape sequence {0}; remove the backslash",
+        ],
+    },
     TranslatedString{
         translatable: qljs_translatable!("unopened block comment"),
         expected_per_locale: [
@@ -719,6 +1280,28 @@ pub const TEST_TRANSLATION_TABLE: [TranslatedString; 63] = [
             "what is this '{1}' nonsense?",
         ],
     },
+    TranslatedString{
+        translatable: qljs_translatable!("zero-width character in identifier; delete it"),
+        expected_per_locale: [
+            "zero-width character in identifier; delete it",
+            "zero-width character in identifier; delete it",
+            "zero-width character in identifier; delete it",
+            "zero-width character in identifier; delete it",
+            "zero-width character in identifier; delete it",
+            "zero-width character in identifier; delete it",
+        ],
+    },
+    TranslatedString{
+        translatable: qljs_translatable!("zero-width whitespace character; delete it"),
+        expected_per_locale: [
+            "zero-width whitespace character; delete it",
+            "zero-width whitespace character; delete it",
+            "zero-width whitespace character; delete it",
+            "zero-width whitespace character; delete it",
+            "zero-width whitespace character; delete it",
+            "zero-width whitespace character; delete it",
+        ],
+    },
 ];
 
 #[test]
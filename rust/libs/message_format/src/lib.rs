@@ -0,0 +1,263 @@
+// Parser for diagnostic message format strings, such as
+// "expected {1} {1:plural:argument|arguments}, got {2}".
+//
+// A format string contains text and placeholders. Each placeholder refers to
+// one of the diagnostic's arguments by index:
+//
+// * {N}: argument N
+// * {N:headlinese}: argument N as a noun phrase without an article (e.g.
+//   "'for' loop")
+// * {N:singular}: argument N as a noun phrase with an article (e.g.
+//   "a 'for' loop")
+// * {N:plural:form0|form1|...}: the plural form for the number in argument N,
+//   chosen by the translation's Plural-Forms rule (see PluralForms). In the
+//   untranslated message, form0 is singular and form1 is plural.
+//
+// "{{" is an escaped '{'. '}' outside of a placeholder needs no escaping.
+//
+// Which placeholders are allowed for which kinds of arguments is checked by
+// the qljs_diagnostic proc macro. See DiagnosticFormatter::format_message.
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MessageFormatPart<'a> {
+    Text(&'a str),
+    Placeholder(Placeholder<'a>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Placeholder<'a> {
+    pub arg_index: usize,
+    pub form: PlaceholderForm<'a>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlaceholderForm<'a> {
+    Plain,
+    Headlinese,
+    Singular,
+    // The plural forms, separated by '|'.
+    Plural(&'a str),
+}
+
+impl<'a> PlaceholderForm<'a> {
+    // Returns the plural form with the given index, or the last plural form if
+    // there are not enough forms.
+    pub fn plural_form(&self, index: usize) -> &'a str {
+        match self {
+            PlaceholderForm::Plural(forms) => {
+                let mut last_form: &str = "";
+                for (i, form) in forms.split('|').enumerate() {
+                    if i == index {
+                        return form;
+                    }
+                    last_form = form;
+                }
+                last_form
+            }
+            _ => panic!("placeholder has no plural forms"),
+        }
+    }
+}
+
+impl<'a> Placeholder<'a> {
+    // A key which is the same for a message and for each of its translations:
+    // "1:plural" for "{1:plural:argument|arguments}", or the placeholder's
+    // text otherwise (e.g. "1:headlinese").
+    pub fn key(&self) -> String {
+        match self.form {
+            PlaceholderForm::Plain => format!("{}", self.arg_index),
+            PlaceholderForm::Headlinese => format!("{}:headlinese", self.arg_index),
+            PlaceholderForm::Singular => format!("{}:singular", self.arg_index),
+            PlaceholderForm::Plural(_) => format!("{}:plural", self.arg_index),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MessageFormatError {
+    MissingRightCurly,
+    UnrecognizedPlaceholder(String),
+}
+
+impl std::fmt::Display for MessageFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            MessageFormatError::MissingRightCurly => write!(f, "'{{' has no matching '}}'"),
+            MessageFormatError::UnrecognizedPlaceholder(placeholder) => {
+                write!(f, "unrecognized placeholder: {{{placeholder}}}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MessageFormatError {}
+
+pub fn parse_message_format(format: &str) -> MessageFormatParser<'_> {
+    MessageFormatParser { remaining: format }
+}
+
+// Returns the placeholders in a message format string. Text is skipped.
+pub fn parse_message_format_placeholders(
+    format: &str,
+) -> Result<Vec<Placeholder<'_>>, MessageFormatError> {
+    let mut placeholders: Vec<Placeholder> = vec![];
+    for part in parse_message_format(format) {
+        if let MessageFormatPart::Placeholder(placeholder) = part? {
+            placeholders.push(placeholder);
+        }
+    }
+    Ok(placeholders)
+}
+
+// Iterates over the parts of a message format string. Parsing stops after the
+// first error.
+pub struct MessageFormatParser<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> Iterator for MessageFormatParser<'a> {
+    type Item = Result<MessageFormatPart<'a>, MessageFormatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        let Some((before_left_curly, after_left_curly)) = self.remaining.split_once('{') else {
+            let text: &str = self.remaining;
+            self.remaining = "";
+            return Some(Ok(MessageFormatPart::Text(text)));
+        };
+
+        if let Some(after_escape) = after_left_curly.strip_prefix('{') {
+            // "{{"; the '{' is escaped.
+            let before_and_including_left_curly: &str =
+                &self.remaining[0..(before_left_curly.len() + 1)];
+            self.remaining = after_escape;
+            return Some(Ok(MessageFormatPart::Text(before_and_including_left_curly)));
+        }
+        if !before_left_curly.is_empty() {
+            self.remaining = &self.remaining[before_left_curly.len()..];
+            return Some(Ok(MessageFormatPart::Text(before_left_curly)));
+        }
+
+        let result: Result<MessageFormatPart, MessageFormatError> =
+            match after_left_curly.split_once('}') {
+                Some((curly_content, after_right_curly)) => {
+                    self.remaining = after_right_curly;
+                    parse_placeholder(curly_content).map(MessageFormatPart::Placeholder)
+                }
+                None => Err(MessageFormatError::MissingRightCurly),
+            };
+        if result.is_err() {
+            self.remaining = "";
+        }
+        Some(result)
+    }
+}
+
+fn parse_placeholder(curly_content: &str) -> Result<Placeholder<'_>, MessageFormatError> {
+    let unrecognized = || MessageFormatError::UnrecognizedPlaceholder(curly_content.to_string());
+    let (index, form) = match curly_content.split_once(':') {
+        Some((index, form)) => (index, Some(form)),
+        None => (curly_content, None),
+    };
+    if index.is_empty() || !index.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(unrecognized());
+    }
+    let arg_index: usize = index.parse().map_err(|_| unrecognized())?;
+    let form: PlaceholderForm = match form {
+        None => PlaceholderForm::Plain,
+        Some("headlinese") => PlaceholderForm::Headlinese,
+        Some("singular") => PlaceholderForm::Singular,
+        Some(form) => match form.strip_prefix("plural:") {
+            Some(forms) => PlaceholderForm::Plural(forms),
+            None => return Err(unrecognized()),
+        },
+    };
+    Ok(Placeholder { arg_index, form })
+}
+
+// Returns the sorted keys of the placeholders in a message format string, with
+// no duplicates. For example, "{1:headlinese} and {0} {0}" has keys
+// ["0", "1:headlinese"]. See Placeholder::key.
+pub fn placeholder_keys(format: &str) -> Result<Vec<String>, MessageFormatError> {
+    let mut keys: Vec<String> = parse_message_format_placeholders(format)?
+        .iter()
+        .map(Placeholder::key)
+        .collect();
+    keys.sort_unstable();
+    keys.dedup();
+    Ok(keys)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TranslationPlaceholderError {
+    InvalidUntranslated(MessageFormatError),
+    InvalidTranslated(MessageFormatError),
+    // Placeholder keys, as returned by placeholder_keys.
+    Mismatch {
+        untranslated: Vec<String>,
+        translated: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for TranslationPlaceholderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let write_keys =
+            |f: &mut std::fmt::Formatter<'_>, keys: &[String]| -> Result<(), std::fmt::Error> {
+                if keys.is_empty() {
+                    return write!(f, "none");
+                }
+                for (i, key) in keys.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{{{key}}}")?;
+                }
+                Ok(())
+            };
+        match self {
+            TranslationPlaceholderError::InvalidUntranslated(error) => {
+                write!(f, "invalid msgid: {error}")
+            }
+            TranslationPlaceholderError::InvalidTranslated(error) => {
+                write!(f, "invalid translation: {error}")
+            }
+            TranslationPlaceholderError::Mismatch {
+                untranslated,
+                translated,
+            } => {
+                write!(f, "translation has placeholders ")?;
+                write_keys(f, translated)?;
+                write!(f, " but msgid has ")?;
+                write_keys(f, untranslated)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslationPlaceholderError {}
+
+// Checks that a translation has the same placeholders as its untranslated
+// message. The placeholders may be in a different order or repeated, and plural
+// placeholders may have a different number of forms. Every placeholder must be
+// present, and no others may be added.
+//
+// This rule is used both when compiling translations into the program and when
+// loading translation catalogs at run time.
+pub fn check_translation_placeholders(
+    untranslated: &str,
+    translated: &str,
+) -> Result<(), TranslationPlaceholderError> {
+    let untranslated: Vec<String> =
+        placeholder_keys(untranslated).map_err(TranslationPlaceholderError::InvalidUntranslated)?;
+    let translated: Vec<String> =
+        placeholder_keys(translated).map_err(TranslationPlaceholderError::InvalidTranslated)?;
+    if untranslated != translated {
+        return Err(TranslationPlaceholderError::Mismatch {
+            untranslated,
+            translated,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use cpp_vs_rust_message_format::*;
+
+fn parse(format: &str) -> Vec<MessageFormatPart<'_>> {
+    parse_message_format(format)
+        .collect::<Result<Vec<MessageFormatPart>, MessageFormatError>>()
+        .unwrap()
+}
+
+fn placeholder(arg_index: usize, form: PlaceholderForm<'_>) -> MessageFormatPart<'_> {
+    MessageFormatPart::Placeholder(Placeholder { arg_index, form })
+}
+
+#[test]
+fn text_without_placeholders() {
+    assert_eq!(parse(""), vec![]);
+    assert_eq!(
+        parse("unexpected '#'"),
+        vec![MessageFormatPart::Text("unexpected '#'")]
+    );
+    assert_eq!(
+        parse("missing '}'"),
+        vec![MessageFormatPart::Text("missing '}'")]
+    );
+}
+
+#[test]
+fn escaped_left_curly_is_text() {
+    assert_eq!(
+        parse("expected '{{'"),
+        vec![
+            MessageFormatPart::Text("expected '{"),
+            MessageFormatPart::Text("'"),
+        ]
+    );
+    assert_eq!(
+        parse("{{{0}"),
+        vec![
+            MessageFormatPart::Text("{"),
+            placeholder(0, PlaceholderForm::Plain),
+        ]
+    );
+}
+
+#[test]
+fn placeholders_of_each_form() {
+    assert_eq!(
+        parse("{0} is {1:headlinese}, not {2:singular}"),
+        vec![
+            placeholder(0, PlaceholderForm::Plain),
+            MessageFormatPart::Text(" is "),
+            placeholder(1, PlaceholderForm::Headlinese),
+            MessageFormatPart::Text(", not "),
+            placeholder(2, PlaceholderForm::Singular),
+        ]
+    );
+    assert_eq!(
+        parse("expected {1} {1:plural:argument|arguments}"),
+        vec![
+            MessageFormatPart::Text("expected "),
+            placeholder(1, PlaceholderForm::Plain),
+            MessageFormatPart::Text(" "),
+            placeholder(1, PlaceholderForm::Plural("argument|arguments")),
+        ]
+    );
+}
+
+#[test]
+fn plural_form_falls_back_to_last_form() {
+    let form: PlaceholderForm = PlaceholderForm::Plural("argument|argumenty|argumentów");
+    assert_eq!(form.plural_form(0), "argument");
+    assert_eq!(form.plural_form(1), "argumenty");
+    assert_eq!(form.plural_form(2), "argumentów");
+    assert_eq!(form.plural_form(3), "argumentów");
+    assert_eq!(
+        PlaceholderForm::Plural("Argumente").plural_form(1),
+        "Argumente"
+    );
+}
+
+#[test]
+fn placeholder_keys_ignore_plural_form_text() {
+    let keys = |format: &str| -> Vec<String> {
+        parse_message_format_placeholders(format)
+            .unwrap()
+            .iter()
+            .map(Placeholder::key)
+            .collect()
+    };
+    assert_eq!(
+        keys("{0} {1:headlinese} {2:singular} {1:plural:a|b}"),
+        vec!["0", "1:headlinese", "2:singular", "1:plural"]
+    );
+    assert_eq!(keys("{1:plural:x}"), keys("{1:plural:y|z|w}"));
+}
+
+#[test]
+fn malformed_formats_are_rejected() {
+    for (format, expected_error) in [
+        ("expected {1", MessageFormatError::MissingRightCurly),
+        (
+            "{}",
+            MessageFormatError::UnrecognizedPlaceholder("".to_string()),
+        ),
+        (
+            "{x}",
+            MessageFormatError::UnrecognizedPlaceholder("x".to_string()),
+        ),
+        (
+            "{-1}",
+            MessageFormatError::UnrecognizedPlaceholder("-1".to_string()),
+        ),
+        (
+            "{1:plural}",
+            MessageFormatError::UnrecognizedPlaceholder("1:plural".to_string()),
+        ),
+        (
+            "{1:shouting}",
+            MessageFormatError::UnrecognizedPlaceholder("1:shouting".to_string()),
+        ),
+    ] {
+        assert_eq!(
+            parse_message_format_placeholders(format),
+            Err(expected_error),
+            "{format:?}"
+        );
+    }
+}
+
+#[test]
+fn parsing_stops_after_first_error() {
+    let parts: Vec<Result<MessageFormatPart, MessageFormatError>> =
+        parse_message_format("a {x} b {0}").collect();
+    assert_eq!(
+        parts,
+        vec![
+            Ok(MessageFormatPart::Text("a ")),
+            Err(MessageFormatError::UnrecognizedPlaceholder("x".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn translation_placeholders_may_be_reordered_or_repeated() {
+    for (untranslated, translated) in [
+        ("'{0}' in {1:singular}", "in {1:singular}: {0}"),
+        ("{0}", "{0} {0}"),
+        ("write {{'}'}", "schreib {{'}'}"),
+        (
+            "{1} {1:plural:argument|arguments}",
+            "{1} {1:plural:argument|argumenty|argumentów}",
+        ),
+    ] {
+        assert_eq!(
+            check_translation_placeholders(untranslated, translated),
+            Ok(()),
+            "{untranslated:?} -> {translated:?}"
+        );
+    }
+}
+
+#[test]
+fn translation_placeholders_must_match() {
+    for (untranslated, translated) in [
+        ("'{0}' is not allowed", "not allowed"),
+        ("character is not allowed", "no '{0}' allowed"),
+        ("{1:headlinese} starts here", "{1} starts here"),
+        ("{0} and {1}", "{0} and {2}"),
+        ("{1:plural:argument|arguments}", "{1:singular}"),
+    ] {
+        assert!(
+            matches!(
+                check_translation_placeholders(untranslated, translated),
+                Err(TranslationPlaceholderError::Mismatch { .. })
+            ),
+            "{untranslated:?} -> {translated:?} should be rejected"
+        );
+    }
+
+    assert_eq!(
+        check_translation_placeholders("{0} and {1}", "{1}")
+            .unwrap_err()
+            .to_string(),
+        "translation has placeholders {1} but msgid has {0}, {1}"
+    );
+    assert_eq!(
+        check_translation_placeholders("{0}", "{0"),
+        Err(TranslationPlaceholderError::InvalidTranslated(
+            MessageFormatError::MissingRightCurly
+        ))
+    );
+}
@@ -0,0 +1,201 @@
+use crate::token_stream_parser::*;
+use crate::token_writer::*;
+use cpp_vs_rust_util::perfect_hash::*;
+
+struct Keyword {
+    name: String,
+    token_type: proc_macro::TokenStream,
+}
+
+struct PerfectHash {
+    multiplier: u32,
+    table_bits: u32,
+}
+
+// See qljs_make_keyword_table in lib.rs.
+pub fn make_keyword_table(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut parser = TokenStreamParser::new(item);
+    parser.skip_keyword("not_keyword");
+    parser.skip_punct(":");
+    let not_keyword: proc_macro::TokenStream = parser.parse_until_comma();
+    parser.skip_comma();
+    parser.skip_keyword("keywords");
+    parser.skip_punct(":");
+    let keywords_stream: proc_macro::TokenStream = parser
+        .try_parse_brace()
+        .expect("expected { after keywords:");
+    parser.try_parse_comma();
+    parser.expect_eof();
+
+    let mut keywords: Vec<Keyword> = vec![];
+    let mut keywords_parser = TokenStreamParser::new(keywords_stream);
+    while !keywords_parser.is_eof() {
+        let name: String = keywords_parser
+            .try_parse_string()
+            .expect("expected keyword string");
+        keywords_parser.skip_punct("=>");
+        let token_type: proc_macro::TokenStream = keywords_parser.parse_until_comma();
+        keywords.push(Keyword {
+            name: name,
+            token_type: token_type,
+        });
+        if keywords_parser.try_parse_comma().is_none() {
+            break;
+        }
+    }
+    keywords_parser.expect_eof();
+
+    let names: Vec<&[u8]> = keywords.iter().map(|k| k.name.as_bytes()).collect();
+    check_keys_are_unique(&names);
+    let perfect_hash: PerfectHash = find_perfect_hash(&names);
+
+    let mut string_pool: Vec<u8> = vec![];
+    let mut table: Vec<Option<(usize, &Keyword)>> = vec![None; 1 << perfect_hash.table_bits];
+    for keyword in &keywords {
+        let index: usize = hash_short_string(
+            keyword.name.as_bytes(),
+            perfect_hash.multiplier,
+            perfect_hash.table_bits,
+        );
+        table[index] = Some((string_pool.len(), keyword));
+        string_pool.extend_from_slice(keyword.name.as_bytes());
+    }
+    let min_length: usize = names.iter().map(|name| name.len()).min().unwrap_or(0);
+    let max_length: usize = names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+    let mut out = TokenWriter::new();
+    let mut write_const = |name: &str, type_: &str, write_value: &dyn Fn(&mut TokenWriter)| {
+        out.ident("const");
+        out.ident(name);
+        out.punct(":");
+        out.ident(type_);
+        out.punct("=");
+        write_value(&mut out);
+        out.punct(";");
+    };
+    write_const("KEYWORD_HASH_MULTIPLIER", "u32", &|out| {
+        out.literal_u32(perfect_hash.multiplier)
+    });
+    write_const("KEYWORD_HASH_TABLE_BITS", "u32", &|out| {
+        out.literal_u32(perfect_hash.table_bits)
+    });
+    write_const("KEYWORD_MIN_LENGTH", "usize", &|out| {
+        out.literal_usize(min_length)
+    });
+    write_const("KEYWORD_MAX_LENGTH", "usize", &|out| {
+        out.literal_usize(max_length)
+    });
+
+    out.ident("static");
+    out.ident("KEYWORD_STRING_POOL");
+    out.punct(":");
+    out.punct("&");
+    out.build_bracket(|type_: &mut TokenWriter| {
+        type_.ident("u8");
+    });
+    out.punct("=");
+    out.byte_string(&string_pool);
+    out.punct(";");
+
+    out.ident("static");
+    out.ident("KEYWORD_TABLE");
+    out.punct(":");
+    out.build_bracket(|type_: &mut TokenWriter| {
+        type_.ident("KeywordEntry");
+        type_.punct(";");
+        type_.literal_usize(table.len());
+    });
+    out.punct("=");
+    out.build_bracket(|entries: &mut TokenWriter| {
+        for slot in &table {
+            let (string_offset, length, token_type): (usize, usize, &proc_macro::TokenStream) =
+                match slot {
+                    Some((string_offset, keyword)) => {
+                        (*string_offset, keyword.name.len(), &keyword.token_type)
+                    }
+                    // A length of 0 never matches.
+                    None => (0, 0, &not_keyword),
+                };
+            entries.ident("KeywordEntry");
+            entries.build_brace(|fields: &mut TokenWriter| {
+                fields.ident("string_offset");
+                fields.punct(":");
+                fields.literal_u16(
+                    u16::try_from(string_offset).expect("too many keywords for string pool"),
+                );
+                fields.punct(",");
+                fields.ident("length");
+                fields.punct(":");
+                fields.literal_u8(u8::try_from(length).expect("keyword is too long"));
+                fields.punct(",");
+                fields.ident("type_");
+                fields.punct(":");
+                fields.group(token_type.clone(), proc_macro::Delimiter::None);
+                fields.punct(",");
+            });
+            entries.punct(",");
+        }
+    });
+    out.punct(";");
+
+    out.to_token_stream()
+}
+
+// hash_short_string only looks at some bytes of each keyword. Make sure those
+// bytes are enough to tell keywords apart.
+fn check_keys_are_unique(names: &[&[u8]]) {
+    let key = |name: &[u8]| -> (u8, u8, u8, usize) {
+        if !(2..=255).contains(&name.len()) {
+            panic!(
+                "keyword {:?} must be between 2 and 255 bytes long",
+                String::from_utf8_lossy(name)
+            );
+        }
+        (name[0], name[1], name[name.len() - 1], name.len())
+    };
+    for (i, a) in names.iter().enumerate() {
+        for b in &names[..i] {
+            if key(a) == key(b) {
+                panic!(
+                    "keywords {:?} and {:?} have the same first two bytes, last byte, and length",
+                    String::from_utf8_lossy(b),
+                    String::from_utf8_lossy(a),
+                );
+            }
+        }
+    }
+}
+
+// Finds a multiplier for hash_short_string which gives every keyword its own
+// slot. Starts with a sparse table (at least four slots per keyword) so that a
+// multiplier is found quickly.
+fn find_perfect_hash(names: &[&[u8]]) -> PerfectHash {
+    const MAX_TABLE_BITS: u32 = 16;
+    const ATTEMPTS_PER_TABLE_SIZE: usize = 100_000;
+
+    let min_table_bits: u32 = (names.len() * 4)
+        .next_power_of_two()
+        .trailing_zeros()
+        .max(1);
+    // Deterministic pseudo-random multipliers (Numerical Recipes LCG).
+    let mut random: u32 = 1;
+    for table_bits in min_table_bits..=MAX_TABLE_BITS {
+        let mut used: Vec<bool> = vec![false; 1 << table_bits];
+        for _ in 0..ATTEMPTS_PER_TABLE_SIZE {
+            random = random.wrapping_mul(1664525).wrapping_add(1013904223);
+            let multiplier: u32 = random | 1;
+            used.fill(false);
+            let is_perfect: bool = names.iter().all(|name| {
+                let index: usize = hash_short_string(name, multiplier, table_bits);
+                !std::mem::replace(&mut used[index], true)
+            });
+            if is_perfect {
+                return PerfectHash {
+                    multiplier: multiplier,
+                    table_bits: table_bits,
+                };
+            }
+        }
+    }
+    panic!("could not find a perfect hash for keywords");
+}
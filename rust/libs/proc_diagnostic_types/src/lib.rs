@@ -8,9 +8,11 @@
 // Refactoring is easier if the shorthand syntax is avoided.
 #![allow(clippy::redundant_field_names)]
 
+mod keyword_table;
 mod token_stream_parser;
 mod token_writer;
 
+use cpp_vs_rust_message_format::*;
 use token_stream_parser::*;
 use token_writer::*;
 
@@ -43,6 +45,13 @@ const DIAGNOSTIC_MAX_ARG_COUNT: usize = 3;
 // * The tuple's second argument must be a field of the attributed struct
 //   (without "self.")
 // * The tuple's second argument must have type *Identifier* or *SourceCodeSpan*
+// * The tuple's other arguments are fields referred to by {1} and {2}
+// * Each placeholder in the format string must suit its field's type:
+//   * {N} needs a field which is not a kind (*EnumKind*, *StatementKind*, or
+//     *VariableKind*)
+//   * {N:headlinese} and {N:singular} work with any field
+//   * {N:plural:form0|form1} needs a *usize* field
+//   See cpp_vs_rust_message_format.
 //
 // Adding the qljs_diagnostic attribute will automatically derive Clone.
 //
@@ -100,12 +109,18 @@ pub fn qljs_diagnostic(
     struct_body_parser.expect_eof();
     parser.expect_eof();
 
+    let diag: RegisteredDiag = RegisteredDiag {
+        name: struct_name.to_string(),
+        fields: fields,
+        attribute: parse_qljs_diagnostic_attribute(attr),
+    };
+    for message in &diag.attribute.messages {
+        check_message_format(&diag, message);
+    }
+    let mut diag_serde = TokenWriter::new();
+    write_diag_struct_serde(&mut diag_serde, &diag);
     unsafe {
-        REGISTERED_DIAG_STRUCTS.push(RegisteredDiag {
-            name: struct_name.to_string(),
-            fields: fields,
-            attribute: parse_qljs_diagnostic_attribute(attr),
-        })
+        REGISTERED_DIAG_STRUCTS.push(diag);
     };
 
     let mut derive = TokenWriter::new();
@@ -115,9 +130,291 @@ pub fn qljs_diagnostic(
 
     let mut tokens: proc_macro::TokenStream = derive.to_token_stream();
     tokens.extend([item]);
+    tokens.extend(diag_serde.to_token_stream());
     tokens
 }
 
+// Write:
+//
+// #[cfg(feature = "serde")]
+// const _: () = {
+//     #[derive(serde::Serialize, serde::Deserialize)]
+//     #[serde(rename = "$diag")]
+//     pub struct SerdeValue<'code> {
+//         pub $field: <$field_type as crate::diag_serde::DiagSerde<'code>>::Serde,
+//         /* ... */
+//     }
+//
+//     impl<'code> crate::diag_serde::DiagSerde<'code> for $diag<'code> {
+//         type Serde = SerdeValue<'code>;
+//
+//         fn to_serde(&self, input: PaddedStringView<'code>)
+//             -> Result<SerdeValue<'code>, crate::diag_serde::DiagSerdeError> {
+//             Ok(SerdeValue {
+//                 $field: crate::diag_serde::DiagSerde::to_serde(&self.$field, input)?,
+//                 /* ... */
+//             })
+//         }
+//
+//         fn from_serde(value: SerdeValue<'code>, input: PaddedStringView<'code>,
+//                       allocator: &'code dyn Allocator)
+//             -> Result<Self, crate::diag_serde::DiagSerdeError> {
+//             Ok($diag {
+//                 $field: crate::diag_serde::DiagSerde::from_serde(value.$field, input, allocator)?,
+//                 /* ... */
+//             })
+//         }
+//     }
+// };
+fn write_diag_struct_serde(writer: &mut TokenWriter, diag: &RegisteredDiag) {
+    write_diag_serde_impl(
+        writer,
+        &diag.name,
+        |serde_value: &mut TokenWriter| {
+            serde_value.ident("pub");
+            serde_value.ident("struct");
+            serde_value.ident("SerdeValue");
+            write_code_lifetime_generics(serde_value);
+            serde_value.build_brace(|fields: &mut TokenWriter| {
+                for field in &diag.fields {
+                    fields.ident("pub");
+                    fields.ident(&field.name);
+                    fields.punct(":");
+                    write_diag_serde_type(fields, |field_type: &mut TokenWriter| {
+                        write_arg_type(field_type, field.type_);
+                    });
+                    fields.punct(",");
+                }
+            });
+        },
+        |to_serde: &mut TokenWriter| {
+            to_serde.ident("SerdeValue");
+            to_serde.build_brace(|fields: &mut TokenWriter| {
+                for field in &diag.fields {
+                    fields.ident(&field.name);
+                    fields.punct(":");
+                    fields.path(&["crate", "diag_serde", "DiagSerde", "to_serde"]);
+                    fields.build_paren(|args: &mut TokenWriter| {
+                        args.punct("&");
+                        args.ident("self");
+                        args.punct(".");
+                        args.ident(&field.name);
+                        args.punct(",");
+                        args.ident("input");
+                    });
+                    fields.punct("?");
+                    fields.punct(",");
+                }
+            });
+        },
+        |from_serde: &mut TokenWriter| {
+            from_serde.ident(&diag.name);
+            from_serde.build_brace(|fields: &mut TokenWriter| {
+                for field in &diag.fields {
+                    fields.ident(&field.name);
+                    fields.punct(":");
+                    fields.path(&["crate", "diag_serde", "DiagSerde", "from_serde"]);
+                    fields.build_paren(|args: &mut TokenWriter| {
+                        args.ident("value");
+                        args.punct(".");
+                        args.ident(&field.name);
+                        args.punct(",");
+                        args.ident("input");
+                        args.punct(",");
+                        args.ident("allocator");
+                    });
+                    fields.punct("?");
+                    fields.punct(",");
+                }
+            });
+        },
+    );
+}
+
+// Write:
+//
+// #[cfg(feature = "serde")]
+// const _: () = {
+//     #[derive(serde::Serialize, serde::Deserialize)]
+//     #[serde(rename = "$type_name")]
+//     $serde_value
+//
+//     impl<'code> crate::diag_serde::DiagSerde<'code> for $type_name<'code> {
+//         type Serde = SerdeValue<'code>;
+//
+//         fn to_serde(&self, input: PaddedStringView<'code>)
+//             -> Result<SerdeValue<'code>, crate::diag_serde::DiagSerdeError> {
+//             Ok($to_serde)
+//         }
+//
+//         fn from_serde(value: SerdeValue<'code>, input: PaddedStringView<'code>,
+//                       allocator: &'code dyn Allocator)
+//             -> Result<Self, crate::diag_serde::DiagSerdeError> {
+//             Ok($from_serde)
+//         }
+//     }
+// };
+fn write_diag_serde_impl(
+    writer: &mut TokenWriter,
+    type_name: &str,
+    write_serde_value: impl FnOnce(&mut TokenWriter),
+    write_to_serde: impl FnOnce(&mut TokenWriter),
+    write_from_serde: impl FnOnce(&mut TokenWriter),
+) {
+    writer.punct("#");
+    writer.build_bracket(|attribute: &mut TokenWriter| {
+        attribute.ident("cfg");
+        attribute.build_paren(|cfg: &mut TokenWriter| {
+            cfg.ident("feature");
+            cfg.punct("=");
+            cfg.string("serde");
+        });
+    });
+    writer.ident("const");
+    writer.ident("_");
+    writer.punct(":");
+    writer.empty_paren();
+    writer.punct("=");
+    writer.build_brace(|body: &mut TokenWriter| {
+        body.punct("#");
+        body.build_bracket(|attribute: &mut TokenWriter| {
+            attribute.ident("derive");
+            attribute.build_paren(|derives: &mut TokenWriter| {
+                derives.path(&["serde", "Serialize"]);
+                derives.punct(",");
+                derives.path(&["serde", "Deserialize"]);
+            });
+        });
+        body.punct("#");
+        body.build_bracket(|attribute: &mut TokenWriter| {
+            attribute.ident("serde");
+            attribute.build_paren(|serde: &mut TokenWriter| {
+                serde.ident("rename");
+                serde.punct("=");
+                serde.string(type_name);
+            });
+        });
+        write_serde_value(body);
+
+        body.ident("impl");
+        write_code_lifetime_generics(body);
+        body.path(&["crate", "diag_serde", "DiagSerde"]);
+        write_code_lifetime_generics(body);
+        body.ident("for");
+        body.ident(type_name);
+        write_code_lifetime_generics(body);
+        body.build_brace(|impl_body: &mut TokenWriter| {
+            impl_body.ident("type");
+            impl_body.ident("Serde");
+            impl_body.punct("=");
+            impl_body.ident("SerdeValue");
+            write_code_lifetime_generics(impl_body);
+            impl_body.punct(";");
+
+            impl_body.ident("fn");
+            impl_body.ident("to_serde");
+            impl_body.build_paren(|parameters: &mut TokenWriter| {
+                parameters.punct("&");
+                parameters.ident("self");
+                parameters.punct(",");
+                parameters.ident("input");
+                parameters.punct(":");
+                parameters.path(&["cpp_vs_rust_util", "padded_string", "PaddedStringView"]);
+                write_code_lifetime_generics(parameters);
+            });
+            impl_body.punct("->");
+            impl_body.ident("Result");
+            impl_body.punct("<");
+            impl_body.ident("SerdeValue");
+            write_code_lifetime_generics(impl_body);
+            impl_body.punct(",");
+            impl_body.path(&["crate", "diag_serde", "DiagSerdeError"]);
+            impl_body.punct(">");
+            impl_body.build_brace(|fn_body: &mut TokenWriter| {
+                fn_body.ident("Ok");
+                fn_body.build_paren(write_to_serde);
+            });
+
+            impl_body.ident("fn");
+            impl_body.ident("from_serde");
+            impl_body.build_paren(|parameters: &mut TokenWriter| {
+                parameters.ident("value");
+                parameters.punct(":");
+                parameters.ident("SerdeValue");
+                write_code_lifetime_generics(parameters);
+                parameters.punct(",");
+                parameters.ident("input");
+                parameters.punct(":");
+                parameters.path(&["cpp_vs_rust_util", "padded_string", "PaddedStringView"]);
+                write_code_lifetime_generics(parameters);
+                parameters.punct(",");
+                parameters.ident("allocator");
+                parameters.punct(":");
+                parameters.punct("&");
+                parameters.lifetime("code");
+                parameters.ident("dyn");
+                parameters.path(&["cpp_vs_rust_port", "allocator", "Allocator"]);
+            });
+            impl_body.punct("->");
+            impl_body.ident("Result");
+            impl_body.punct("<");
+            impl_body.ident("Self");
+            impl_body.punct(",");
+            impl_body.path(&["crate", "diag_serde", "DiagSerdeError"]);
+            impl_body.punct(">");
+            impl_body.build_brace(|fn_body: &mut TokenWriter| {
+                fn_body.ident("Ok");
+                fn_body.build_paren(write_from_serde);
+            });
+        });
+    });
+    writer.punct(";");
+}
+
+// Write: <$type as crate::diag_serde::DiagSerde<'code>>::Serde
+fn write_diag_serde_type(writer: &mut TokenWriter, write_type: impl FnOnce(&mut TokenWriter)) {
+    writer.punct("<");
+    write_type(writer);
+    writer.ident("as");
+    writer.path(&["crate", "diag_serde", "DiagSerde"]);
+    write_code_lifetime_generics(writer);
+    writer.punct(">");
+    writer.punct("::");
+    writer.ident("Serde");
+}
+
+// Write: <'code>
+fn write_code_lifetime_generics(writer: &mut TokenWriter) {
+    writer.punct("<");
+    writer.lifetime("code");
+    writer.punct(">");
+}
+
+fn write_arg_type(writer: &mut TokenWriter, arg_type: DiagnosticArgType) {
+    match arg_type {
+        DiagnosticArgType::Char8 => writer.ident("u8"),
+        DiagnosticArgType::EnumKind => writer.path(&["crate", "language", "EnumKind"]),
+        DiagnosticArgType::Identifier => {
+            writer.path(&["crate", "identifier", "Identifier"]);
+            write_code_lifetime_generics(writer);
+        }
+        DiagnosticArgType::Integer => writer.ident("usize"),
+        DiagnosticArgType::SourceCodeSpan => {
+            writer.path(&["crate", "source_code_span", "SourceCodeSpan"]);
+            write_code_lifetime_generics(writer);
+        }
+        DiagnosticArgType::StatementKind => writer.path(&["crate", "language", "StatementKind"]),
+        DiagnosticArgType::String8View => {
+            writer.punct("&");
+            writer.lifetime("code");
+            writer.build_bracket(|slice: &mut TokenWriter| {
+                slice.ident("u8");
+            });
+        }
+        DiagnosticArgType::VariableKind => writer.path(&["crate", "language", "VariableKind"]),
+    }
+}
+
 fn parse_qljs_diagnostic_attribute(stream: proc_macro::TokenStream) -> QLJSDiagnosticAttribute {
     let mut parser = TokenStreamParser::new(stream);
     let code: String = parser
@@ -183,6 +480,64 @@ fn parse_qljs_diagnostic_attribute(stream: proc_macro::TokenStream) -> QLJSDiagn
     }
 }
 
+// Panics (failing compilation) if the message's format string has a malformed
+// placeholder or a placeholder which doesn't suit its field.
+fn check_message_format(diag: &RegisteredDiag, message: &QLJSDiagnosticAttributeMessage) {
+    let fail = |reason: String| -> ! {
+        panic!(
+            "{}: invalid message format {:?}: {}",
+            diag.name, message.format, reason
+        );
+    };
+
+    match message.fields.first() {
+        Some(origin_field) => match diag.field_type(origin_field) {
+            DiagnosticArgType::Identifier | DiagnosticArgType::SourceCodeSpan => {}
+            origin_type => fail(format!(
+                "field {} has type {:?}, but the first field must be an Identifier or SourceCodeSpan",
+                origin_field, origin_type
+            )),
+        },
+        None => fail("expected field name".to_string()),
+    }
+
+    for part in parse_message_format(&message.format) {
+        let placeholder: Placeholder = match part {
+            Ok(MessageFormatPart::Text(_)) => continue,
+            Ok(MessageFormatPart::Placeholder(placeholder)) => placeholder,
+            Err(error) => fail(error.to_string()),
+        };
+        let Some(field_name) = message.fields.get(placeholder.arg_index) else {
+            fail(format!(
+                "placeholder {{{}}} refers to argument {}, but the message has {} arguments",
+                placeholder.key(),
+                placeholder.arg_index,
+                message.fields.len()
+            ));
+        };
+        let field_type: DiagnosticArgType = diag.field_type(field_name);
+        let is_kind: bool = matches!(
+            field_type,
+            DiagnosticArgType::EnumKind
+                | DiagnosticArgType::StatementKind
+                | DiagnosticArgType::VariableKind
+        );
+        let suits_field: bool = match placeholder.form {
+            PlaceholderForm::Plain => !is_kind,
+            PlaceholderForm::Headlinese | PlaceholderForm::Singular => true,
+            PlaceholderForm::Plural(_) => field_type == DiagnosticArgType::Integer,
+        };
+        if !suits_field {
+            fail(format!(
+                "placeholder {{{}}} cannot be used with field {} of type {:?}",
+                placeholder.key(),
+                field_name,
+                field_type
+            ));
+        }
+    }
+}
+
 fn parse_arg_type(parser: &mut TokenStreamParser) -> DiagnosticArgType {
     let skip_generic_args = |parser: &mut TokenStreamParser| {
         if parser
@@ -215,6 +570,10 @@ fn parse_arg_type(parser: &mut TokenStreamParser) -> DiagnosticArgType {
         return DiagnosticArgType::Char8;
     }
 
+    if parser.try_parse_keyword("usize").is_some() {
+        return DiagnosticArgType::Integer;
+    }
+
     if parser.try_parse_keyword("EnumKind").is_some() {
         return DiagnosticArgType::EnumKind;
     }
@@ -248,6 +607,7 @@ fn parse_arg_type(parser: &mut TokenStreamParser) -> DiagnosticArgType {
 // Write:
 //
 // #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // #[repr(u16)]
 // pub enum $name {
 //     Diag1,
@@ -265,6 +625,22 @@ pub fn qljs_make_diag_type_enum(item: proc_macro::TokenStream) -> proc_macro::To
     let mut enum_writer = TokenWriter::new();
     enum_writer.derive_attribute(&["Clone", "Copy", "Debug", "Eq", "PartialEq"]);
     enum_writer.punct("#");
+    enum_writer.build_bracket(|attribute: &mut TokenWriter| {
+        attribute.ident("cfg_attr");
+        attribute.build_paren(|cfg_attr: &mut TokenWriter| {
+            cfg_attr.ident("feature");
+            cfg_attr.punct("=");
+            cfg_attr.string("serde");
+            cfg_attr.punct(",");
+            cfg_attr.ident("derive");
+            cfg_attr.build_paren(|derives: &mut TokenWriter| {
+                derives.path(&["serde", "Serialize"]);
+                derives.punct(",");
+                derives.path(&["serde", "Deserialize"]);
+            });
+        });
+    });
+    enum_writer.punct("#");
     enum_writer.build_bracket(|attribute: &mut TokenWriter| {
         attribute.ident("repr");
         attribute.build_paren(|repr: &mut TokenWriter| {
@@ -300,6 +676,23 @@ pub fn qljs_make_diag_type_enum(item: proc_macro::TokenStream) -> proc_macro::To
 //             /* ... */
 //         }
 //     }
+//
+//     pub fn diag_type(&self) -> DiagType {
+//         match self {
+//             AnyDiag::Diag1(_) => DiagType::Diag1,
+//             AnyDiag::Diag2(_) => DiagType::Diag2,
+//             /* ... */
+//         }
+//     }
+//
+//     // Calls visit for each field with type &[u8].
+//     pub(crate) fn visit_string_fields_mut(&mut self, visit: &mut dyn FnMut(&mut &'code [u8])) {
+//         match self {
+//             AnyDiag::Diag1(_diag) => { visit(&mut _diag.string_field); }
+//             AnyDiag::Diag2(_diag) => {}
+//             /* ... */
+//         }
+//     }
 // }
 #[proc_macro]
 pub fn qljs_make_any_diag_enum(_args: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -388,11 +781,202 @@ pub fn qljs_make_any_diag_enum(_args: proc_macro::TokenStream) -> proc_macro::To
                 }
             });
         });
+
+        impl_body.ident("pub");
+        impl_body.ident("fn");
+        impl_body.ident("diag_type");
+        impl_body.build_paren(|parameters: &mut TokenWriter| {
+            parameters.punct("&");
+            parameters.ident("self");
+        });
+        impl_body.punct("->");
+        impl_body.ident("DiagType");
+        impl_body.build_brace(|fn_body: &mut TokenWriter| {
+            fn_body.ident("match");
+            fn_body.ident("self");
+            fn_body.build_brace(|match_body: &mut TokenWriter| {
+                for diag_struct in unsafe { &*std::ptr::addr_of!(REGISTERED_DIAG_STRUCTS) } {
+                    match_body.ident("AnyDiag");
+                    match_body.punct("::");
+                    match_body.ident(&diag_struct.name);
+                    match_body.build_paren(|pattern: &mut TokenWriter| {
+                        pattern.ident("_");
+                    });
+                    match_body.punct("=>");
+                    match_body.ident("DiagType");
+                    match_body.punct("::");
+                    match_body.ident(&diag_struct.name);
+                    match_body.punct(",");
+                }
+            });
+        });
+
+        impl_body.ident("pub");
+        impl_body.build_paren(|visibility: &mut TokenWriter| {
+            visibility.ident("crate");
+        });
+        impl_body.ident("fn");
+        impl_body.ident("visit_string_fields_mut");
+        impl_body.build_paren(|parameters: &mut TokenWriter| {
+            parameters.punct("&");
+            parameters.ident("mut");
+            parameters.ident("self");
+            parameters.punct(",");
+            parameters.ident("visit");
+            parameters.punct(":");
+            parameters.punct("&");
+            parameters.ident("mut");
+            parameters.ident("dyn");
+            parameters.ident("FnMut");
+            parameters.build_paren(|visit_parameters: &mut TokenWriter| {
+                visit_parameters.punct("&");
+                visit_parameters.ident("mut");
+                visit_parameters.punct("&");
+                visit_parameters.lifetime("code");
+                visit_parameters.build_bracket(|slice: &mut TokenWriter| {
+                    slice.ident("u8");
+                });
+            });
+        });
+        impl_body.build_brace(|fn_body: &mut TokenWriter| {
+            fn_body.ident("match");
+            fn_body.ident("self");
+            fn_body.build_brace(|match_body: &mut TokenWriter| {
+                for diag_struct in unsafe { &*std::ptr::addr_of!(REGISTERED_DIAG_STRUCTS) } {
+                    match_body.ident("AnyDiag");
+                    match_body.punct("::");
+                    match_body.ident(&diag_struct.name);
+                    match_body.build_paren(|pattern: &mut TokenWriter| {
+                        pattern.ident("_diag");
+                    });
+                    match_body.punct("=>");
+                    match_body.build_brace(|arm_body: &mut TokenWriter| {
+                        for field in &diag_struct.fields {
+                            if field.type_ != DiagnosticArgType::String8View {
+                                continue;
+                            }
+                            arm_body.ident("visit");
+                            arm_body.build_paren(|args: &mut TokenWriter| {
+                                args.punct("&");
+                                args.ident("mut");
+                                args.ident("_diag");
+                                args.punct(".");
+                                args.ident(&field.name);
+                            });
+                            arm_body.punct(";");
+                        }
+                    });
+                }
+            });
+        });
     });
 
+    write_any_diag_serde(&mut writer);
+
     writer.to_token_stream()
 }
 
+// Write:
+//
+// #[cfg(feature = "serde")]
+// const _: () = {
+//     #[derive(serde::Serialize, serde::Deserialize)]
+//     #[serde(rename = "AnyDiag")]
+//     pub enum SerdeValue<'code> {
+//         Diag1(<Diag1<'code> as crate::diag_serde::DiagSerde<'code>>::Serde),
+//         /* ... */
+//     }
+//
+//     impl<'code> crate::diag_serde::DiagSerde<'code> for AnyDiag<'code> {
+//         /* See write_diag_serde_impl. */
+//         // to_serde:
+//             Ok(match self {
+//                 AnyDiag::Diag1(diag) => SerdeValue::Diag1(crate::diag_serde::DiagSerde::to_serde(diag, input)?),
+//                 /* ... */
+//             })
+//         // from_serde:
+//             Ok(match value {
+//                 SerdeValue::Diag1(diag) => AnyDiag::Diag1(crate::diag_serde::DiagSerde::from_serde(diag, input, allocator)?),
+//                 /* ... */
+//             })
+//     }
+// };
+fn write_any_diag_serde(writer: &mut TokenWriter) {
+    let diag_structs: &[RegisteredDiag] = unsafe { &*std::ptr::addr_of!(REGISTERED_DIAG_STRUCTS) };
+    write_diag_serde_impl(
+        writer,
+        "AnyDiag",
+        |serde_value: &mut TokenWriter| {
+            serde_value.ident("pub");
+            serde_value.ident("enum");
+            serde_value.ident("SerdeValue");
+            write_code_lifetime_generics(serde_value);
+            serde_value.build_brace(|variants: &mut TokenWriter| {
+                for diag_struct in diag_structs {
+                    variants.ident(&diag_struct.name);
+                    variants.build_paren(|variant: &mut TokenWriter| {
+                        write_diag_serde_type(variant, |diag_type: &mut TokenWriter| {
+                            diag_type.ident(&diag_struct.name);
+                            write_code_lifetime_generics(diag_type);
+                        });
+                    });
+                    variants.punct(",");
+                }
+            });
+        },
+        |to_serde: &mut TokenWriter| {
+            to_serde.ident("match");
+            to_serde.ident("self");
+            to_serde.build_brace(|match_body: &mut TokenWriter| {
+                for diag_struct in diag_structs {
+                    match_body.path(&["AnyDiag", &diag_struct.name]);
+                    match_body.build_paren(|pattern: &mut TokenWriter| {
+                        pattern.ident("diag");
+                    });
+                    match_body.punct("=>");
+                    match_body.path(&["SerdeValue", &diag_struct.name]);
+                    match_body.build_paren(|variant: &mut TokenWriter| {
+                        variant.path(&["crate", "diag_serde", "DiagSerde", "to_serde"]);
+                        variant.build_paren(|args: &mut TokenWriter| {
+                            args.ident("diag");
+                            args.punct(",");
+                            args.ident("input");
+                        });
+                        variant.punct("?");
+                    });
+                    match_body.punct(",");
+                }
+            });
+        },
+        |from_serde: &mut TokenWriter| {
+            from_serde.ident("match");
+            from_serde.ident("value");
+            from_serde.build_brace(|match_body: &mut TokenWriter| {
+                for diag_struct in diag_structs {
+                    match_body.path(&["SerdeValue", &diag_struct.name]);
+                    match_body.build_paren(|pattern: &mut TokenWriter| {
+                        pattern.ident("diag");
+                    });
+                    match_body.punct("=>");
+                    match_body.path(&["AnyDiag", &diag_struct.name]);
+                    match_body.build_paren(|variant: &mut TokenWriter| {
+                        variant.path(&["crate", "diag_serde", "DiagSerde", "from_serde"]);
+                        variant.build_paren(|args: &mut TokenWriter| {
+                            args.ident("diag");
+                            args.punct(",");
+                            args.ident("input");
+                            args.punct(",");
+                            args.ident("allocator");
+                        });
+                        variant.punct("?");
+                    });
+                    match_body.punct(",");
+                }
+            });
+        },
+    );
+}
+
 // For each registered diagnostic struct, write the following:
 //
 // impl<'code> HasDiagType for $diag<'code> {
@@ -554,6 +1138,7 @@ enum DiagnosticArgType {
     Char8,          // u8
     EnumKind,       // EnumKind
     Identifier,     // Identifier<'code>
+    Integer,        // usize
     SourceCodeSpan, // SourceCodeSpan<'code>
     StatementKind,  // StatementKind
     String8View,    // &'code [u8]
@@ -562,9 +1147,13 @@ enum DiagnosticArgType {
 
 impl RegisteredDiag {
     fn arg_type_string_for_field(&self, field_name: &str) -> &'static str {
+        get_diagnostic_message_arg_type(self.field_type(field_name))
+    }
+
+    fn field_type(&self, field_name: &str) -> DiagnosticArgType {
         for field in &self.fields {
             if field.name == field_name {
-                return get_diagnostic_message_arg_type(field.type_);
+                return field.type_;
             }
         }
         panic!("could not find field {}::{}", self.name, field_name);
@@ -606,6 +1195,7 @@ fn get_diagnostic_message_arg_type(field_type: DiagnosticArgType) -> &'static st
         DiagnosticArgType::Char8 => "Char8",
         DiagnosticArgType::EnumKind => "EnumKind",
         DiagnosticArgType::Identifier => "Identifier",
+        DiagnosticArgType::Integer => "Integer",
         DiagnosticArgType::SourceCodeSpan => "SourceCodeSpan",
         DiagnosticArgType::StatementKind => "StatementKind",
         DiagnosticArgType::String8View => "String8View",
@@ -719,3 +1309,38 @@ fn write_translatable_message_unallocated(out: &mut TokenWriter) {
     out.ident("unallocated");
     out.empty_paren();
 }
+
+// Generates a perfect hash table for looking up keywords:
+//
+// qljs_make_keyword_table! {
+//     not_keyword: TokenType::Identifier,
+//     keywords: {
+//         "abstract" => TokenType::KWAbstract,
+//         "any" => TokenType::KWAny,
+//         // ...
+//     },
+// }
+//
+// The following items are generated:
+//
+// * KEYWORD_HASH_MULTIPLIER and KEYWORD_HASH_TABLE_BITS: arguments for
+//   cpp_vs_rust_util::perfect_hash::hash_short_string
+// * KEYWORD_MIN_LENGTH and KEYWORD_MAX_LENGTH: lengths of the shortest and
+//   longest keywords
+// * KEYWORD_STRING_POOL: every keyword's bytes, concatenated
+// * KEYWORD_TABLE: one KeywordEntry per hash value
+//
+// The caller must define KeywordEntry:
+//
+// struct KeywordEntry {
+//     string_offset: u16, // Index into KEYWORD_STRING_POOL.
+//     length: u8,         // 0 for unused entries.
+//     type_: TokenType,   // not_keyword for unused entries.
+// }
+//
+// Compilation fails if two keywords cannot be told apart by
+// hash_short_string.
+#[proc_macro]
+pub fn qljs_make_keyword_table(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    keyword_table::make_keyword_table(item)
+}
@@ -139,6 +139,27 @@ impl TokenStreamParser {
         }
     }
 
+    // Returns the tokens up to (but not including) the next top-level comma or
+    // the end of input.
+    pub fn parse_until_comma(&mut self) -> proc_macro::TokenStream {
+        let mut tokens: Vec<proc_macro::TokenTree> = vec![];
+        loop {
+            match &self.current {
+                None => break,
+                Some(proc_macro::TokenTree::Punct(punct))
+                    if punct.as_char() == ',' && punct.spacing() == proc_macro::Spacing::Alone =>
+                {
+                    break
+                }
+                Some(token) => {
+                    tokens.push(token.clone());
+                    self.skip();
+                }
+            }
+        }
+        tokens.into_iter().collect()
+    }
+
     pub fn is_eof(&mut self) -> bool {
         self.current.is_none()
     }
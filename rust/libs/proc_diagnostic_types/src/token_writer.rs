@@ -37,6 +37,16 @@ impl TokenWriter {
         self.ident(name);
     }
 
+    // Writes segments separated by '::' (e.g. crate::diag_serde::DiagSerde).
+    pub fn path(&mut self, segments: &[&str]) {
+        for (i, segment) in segments.iter().enumerate() {
+            if i != 0 {
+                self.punct("::");
+            }
+            self.ident(segment);
+        }
+    }
+
     pub fn string(&mut self, value: &str) {
         self.0
             .push(proc_macro::TokenTree::Literal(proc_macro::Literal::string(
@@ -60,12 +70,30 @@ impl TokenWriter {
             )));
     }
 
+    pub fn byte_string(&mut self, value: &[u8]) {
+        self.token(proc_macro::TokenTree::Literal(
+            proc_macro::Literal::byte_string(value),
+        ));
+    }
+
+    pub fn literal_u8(&mut self, value: u8) {
+        self.token(proc_macro::TokenTree::Literal(
+            proc_macro::Literal::u8_suffixed(value),
+        ));
+    }
+
     pub fn literal_u16(&mut self, value: u16) {
         self.token(proc_macro::TokenTree::Literal(
             proc_macro::Literal::u16_suffixed(value),
         ));
     }
 
+    pub fn literal_u32(&mut self, value: u32) {
+        self.token(proc_macro::TokenTree::Literal(
+            proc_macro::Literal::u32_suffixed(value),
+        ));
+    }
+
     pub fn literal_usize(&mut self, value: usize) {
         self.token(proc_macro::TokenTree::Literal(
             proc_macro::Literal::usize_suffixed(value),
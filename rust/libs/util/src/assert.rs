@@ -71,9 +71,24 @@ pub fn report_assertion_failure_c(file_name: *const u8, line: u32, message: *con
 }
 
 pub fn report_assertion_failure(file_name: &str, line: u32, message: &str) {
+    let failure: String = format!("{file_name}:{line}: internal check failed: {message}");
     eprintln!(
-        "{file_name}:{line}: internal check failed: {message}\n\
+        "{failure}\n\
                quick-lint-js crashed. Please report this bug here:\n\
                https://quick-lint-js.com/crash-report/\n"
     );
+    LAST_ASSERTION_FAILURE.with(|last| last.set(Some(failure)));
+}
+
+thread_local! {
+    static LAST_ASSERTION_FAILURE: std::cell::Cell<Option<String>> =
+        const { std::cell::Cell::new(None) };
+}
+
+// Returns the message of the most recent failed internal check on this thread,
+// then forgets it. Returns None if no check failed since the last call.
+//
+// Useful for describing a crash after catching its panic.
+pub fn take_last_assertion_failure() -> Option<String> {
+    LAST_ASSERTION_FAILURE.with(|last| last.take())
 }
@@ -5,6 +5,7 @@ pub mod c_string;
 pub mod const_assert;
 pub mod narrow_cast;
 pub mod padded_string;
+pub mod perfect_hash;
 pub mod permutations;
 pub mod simd;
 pub mod simd_neon_arm;
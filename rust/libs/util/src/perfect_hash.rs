@@ -0,0 +1,16 @@
+// Hash function for perfect hash tables of short strings, such as the keyword
+// table generated by qljs_make_keyword_table!.
+//
+// Like gperf's output, the hash only looks at the first two bytes, the last
+// byte, and the length of s. The multiplier is chosen at compile time so that
+// no two keys collide.
+//
+// s.len() must be at least 2 and at most 255. table_bits must be at least 1
+// and at most 32.
+#[inline(always)]
+pub fn hash_short_string(s: &[u8], multiplier: u32, table_bits: u32) -> usize {
+    let len: usize = s.len();
+    let key: u32 =
+        (s[0] as u32) | ((s[1] as u32) << 8) | ((s[len - 1] as u32) << 16) | ((len as u32) << 24);
+    (key.wrapping_mul(multiplier) >> (32 - table_bits)) as usize
+}
@@ -179,6 +179,39 @@ pub fn count_lsp_characters_in_utf_8(utf_8: PaddedStringView, offset: i32) -> is
     count
 }
 
+// The inverse of count_lsp_characters_in_utf_8: returns the byte offset after
+// character_count UTF-16 code units.
+//
+// If character_count is in the middle of a surrogate pair, returns the offset
+// of the pair's code point. If character_count is past the end of utf_8,
+// returns utf_8.len().
+pub fn advance_lsp_characters_in_utf_8(
+    utf_8: PaddedStringView,
+    character_count: isize,
+) -> PaddedStringSizeType {
+    let mut c: PaddedStringSizeType = 0;
+    let end: PaddedStringSizeType = utf_8.len();
+    let mut count: isize = 0;
+    while count < character_count && c < end {
+        let result: DecodeUTF8Result = decode_utf_8(utf_8.substr(c));
+        if let Some(code_point) = result.code_point {
+            if (code_point as u32) >= 0x10000 {
+                if count + 2 > character_count {
+                    break;
+                }
+                count += 2;
+            } else {
+                count += 1;
+            }
+            c += result.size;
+        } else {
+            c += 1;
+            count += 1;
+        }
+    }
+    c
+}
+
 pub fn count_utf_8_characters(utf_8: PaddedStringView, offset: usize) -> usize {
     let mut c: usize = 0;
     let stop: usize = offset;
@@ -201,3 +234,26 @@ pub fn count_utf_8_characters(utf_8: PaddedStringView, offset: usize) -> usize {
 
     count
 }
+
+// The inverse of count_utf_8_characters: returns the byte offset after
+// character_count characters.
+//
+// If character_count is past the end of utf_8, returns utf_8.len().
+pub fn advance_utf_8_characters(
+    utf_8: PaddedStringView,
+    character_count: usize,
+) -> PaddedStringSizeType {
+    let mut c: PaddedStringSizeType = 0;
+    let end: PaddedStringSizeType = utf_8.len();
+    let mut count: usize = 0;
+    while count < character_count && c < end {
+        let result = decode_utf_8(utf_8.substr(c));
+        if result.is_ok() {
+            c += result.size;
+        } else {
+            c += 1;
+        }
+        count += 1;
+    }
+    c
+}
@@ -1,3 +1,4 @@
+use cpp_vs_rust_util::assert::*;
 use cpp_vs_rust_util::qljs_always_assert;
 
 #[test]
@@ -11,6 +12,24 @@ fn failing_assert_crashes() {
     // TODO(port-later): Check the printed message.
 }
 
+#[test]
+#[cfg(panic = "unwind")]
+fn failing_assert_remembers_message() {
+    take_last_assertion_failure();
+    let result = std::panic::catch_unwind(|| {
+        let everything_is_okay = false;
+        qljs_always_assert!(everything_is_okay);
+    });
+    assert!(result.is_err());
+    let failure: String = take_last_assertion_failure().unwrap();
+    assert!(failure.contains("test_assert.rs:"), "{failure}");
+    assert!(
+        failure.ends_with(": internal check failed: everything_is_okay"),
+        "{failure}"
+    );
+    assert_eq!(take_last_assertion_failure(), None);
+}
+
 #[test]
 fn passing_assert_does_not_crash() {
     qljs_always_assert!(true);
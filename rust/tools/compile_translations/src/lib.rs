@@ -0,0 +1,733 @@
+// Compiles the translations in po/ into Rust source code for the
+// cpp_vs_rust_i18n crate. See tools/compile-translations.go for a description
+// of the table format.
+
+use cpp_vs_rust_message_format::*;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+// An entry from a .po or .pot file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct POEntry {
+    // 1-based line number of the entry's msgid.
+    pub line: usize,
+    pub context: Option<String>,
+    pub msgid: String,
+    pub msgstr: String,
+    pub is_fuzzy: bool,
+}
+
+impl POEntry {
+    // The header entry holds metadata (Content-Type, Language, etc.), not a
+    // translation.
+    pub fn is_header(&self) -> bool {
+        self.context.is_none() && self.msgid.is_empty()
+    }
+
+    // The string used to look up this entry, matching GNU gettext's MO files:
+    // msgctxt and msgid are joined with U+0004.
+    pub fn untranslated(&self) -> String {
+        match &self.context {
+            Some(context) => format!("{context}\u{4}{}", self.msgid),
+            None => self.msgid.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranslationEntry {
+    pub untranslated: String,
+    pub translated: String,
+}
+
+// Parses the contents of a .po or .pot file.
+//
+// Obsolete entries (#~) are skipped. Plural forms (msgid_plural) are not
+// supported.
+pub fn parse_po(text: &str) -> Result<Vec<POEntry>, String> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        None,
+        Context,
+        Msgid,
+        Msgstr,
+    }
+
+    #[derive(Default)]
+    struct PartialEntry {
+        line: usize,
+        context: Option<String>,
+        msgid: Option<String>,
+        msgstr: Option<String>,
+        is_fuzzy: bool,
+        is_obsolete: bool,
+    }
+
+    fn finish(entry: PartialEntry, entries: &mut Vec<POEntry>) -> Result<(), String> {
+        if entry.is_obsolete {
+            return Ok(());
+        }
+        match (entry.msgid, entry.msgstr) {
+            (None, None) if entry.context.is_none() => Ok(()),
+            (Some(msgid), Some(msgstr)) => {
+                entries.push(POEntry {
+                    line: entry.line,
+                    context: entry.context,
+                    msgid,
+                    msgstr,
+                    is_fuzzy: entry.is_fuzzy,
+                });
+                Ok(())
+            }
+            (None, _) => Err(format!("line {}: entry is missing msgid", entry.line)),
+            (Some(_), None) => Err(format!("line {}: entry is missing msgstr", entry.line)),
+        }
+    }
+
+    let mut entries: Vec<POEntry> = vec![];
+    let mut entry: PartialEntry = PartialEntry::default();
+    let mut field: Field = Field::None;
+    for (line_index, line) in text.lines().enumerate() {
+        let line_number: usize = line_index + 1;
+        let line: &str = line.trim();
+        let error = |message: &str| format!("line {line_number}: {message}");
+
+        if line.is_empty() {
+            finish(std::mem::take(&mut entry), &mut entries)?;
+            field = Field::None;
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if field == Field::Msgstr {
+                // A comment after msgstr starts the next entry.
+                finish(std::mem::take(&mut entry), &mut entries)?;
+                field = Field::None;
+            }
+            if comment.starts_with('~') {
+                entry.is_obsolete = true;
+            } else if let Some(flags) = comment.strip_prefix(',') {
+                if flags.split(',').any(|flag| flag.trim() == "fuzzy") {
+                    entry.is_fuzzy = true;
+                }
+            }
+            continue;
+        }
+        if line.starts_with('"') {
+            let string: String = parse_po_string(line).map_err(|message| error(&message))?;
+            match field {
+                Field::None => return Err(error("string without msgid or msgstr")),
+                Field::Context => entry.context.as_mut().unwrap().push_str(&string),
+                Field::Msgid => entry.msgid.as_mut().unwrap().push_str(&string),
+                Field::Msgstr => entry.msgstr.as_mut().unwrap().push_str(&string),
+            }
+            continue;
+        }
+
+        let (keyword, rest): (&str, &str) = line
+            .split_once(|c: char| c.is_ascii_whitespace())
+            .ok_or_else(|| error(&format!("malformed line: {line}")))?;
+        let string: String = parse_po_string(rest.trim()).map_err(|message| error(&message))?;
+        match keyword {
+            "msgctxt" | "msgid" => {
+                if field == Field::Msgstr {
+                    finish(std::mem::take(&mut entry), &mut entries)?;
+                }
+                if keyword == "msgctxt" {
+                    if entry.context.is_some() || entry.msgid.is_some() {
+                        return Err(error("unexpected msgctxt"));
+                    }
+                    entry.context = Some(string);
+                    field = Field::Context;
+                } else {
+                    if entry.msgid.is_some() {
+                        return Err(error("unexpected msgid"));
+                    }
+                    entry.line = line_number;
+                    entry.msgid = Some(string);
+                    field = Field::Msgid;
+                }
+            }
+            "msgstr" => {
+                if entry.msgid.is_none() || entry.msgstr.is_some() {
+                    return Err(error("unexpected msgstr"));
+                }
+                entry.msgstr = Some(string);
+                field = Field::Msgstr;
+            }
+            "msgid_plural" => return Err(error("plural forms are not supported")),
+            _ if keyword.starts_with("msgstr[") => {
+                return Err(error("plural forms are not supported"))
+            }
+            _ => return Err(error(&format!("unknown keyword: {keyword}"))),
+        }
+    }
+    finish(entry, &mut entries)?;
+    Ok(entries)
+}
+
+// Parses a C-style quoted string such as "hello\n".
+fn parse_po_string(quoted: &str) -> Result<String, String> {
+    let body: &str = quoted
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+        .filter(|_| quoted.len() >= 2)
+        .ok_or_else(|| format!("malformed string: {quoted}"))?;
+    let mut result: String = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.push(match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('a') => '\u{7}',
+                Some('b') => '\u{8}',
+                Some('f') => '\u{c}',
+                Some('v') => '\u{b}',
+                Some(c @ ('"' | '\\')) => c,
+                _ => return Err(format!("unsupported escape sequence in string: {quoted}")),
+            }),
+            '"' => return Err(format!("unescaped '\"' in string: {quoted}")),
+            _ => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+// Returns the translations which msgfmt would compile: entries which are
+// translated and not fuzzy, excluding the header. The result is sorted by
+// untranslated string.
+//
+// Each translation is checked with check_translation_placeholders. Like
+// msgfmt, translations which fail the check are skipped and reported in
+// warnings instead of failing the whole file.
+pub fn compile_po_entries(
+    entries: &[POEntry],
+    warnings: &mut Vec<String>,
+) -> Result<Vec<TranslationEntry>, String> {
+    let mut translations: BTreeMap<String, TranslationEntry> = BTreeMap::new();
+    let mut errors: Vec<String> = vec![];
+    for entry in entries {
+        if entry.is_header() || entry.is_fuzzy || entry.msgstr.is_empty() {
+            continue;
+        }
+        if let Err(error) = check_translation_placeholders(&entry.msgid, &entry.msgstr) {
+            warnings.push(format!(
+                "line {}: skipping translation of {:?}: {error}",
+                entry.line, entry.msgid
+            ));
+            continue;
+        }
+        let untranslated: String = entry.untranslated();
+        if translations.contains_key(&untranslated) {
+            errors.push(format!(
+                "line {}: duplicate message definition: {:?}",
+                entry.line, entry.msgid
+            ));
+            continue;
+        }
+        translations.insert(
+            untranslated.clone(),
+            TranslationEntry {
+                untranslated,
+                translated: entry.msgstr.clone(),
+            },
+        );
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+    Ok(translations.into_values().collect())
+}
+
+// Like compile_po_entries, but translates every entry of a .pot file to its
+// msgid (like msgen).
+pub fn compile_pot_entries(
+    entries: &[POEntry],
+    warnings: &mut Vec<String>,
+) -> Result<Vec<TranslationEntry>, String> {
+    let entries: Vec<POEntry> = entries
+        .iter()
+        .map(|entry| POEntry {
+            msgstr: entry.msgid.clone(),
+            ..entry.clone()
+        })
+        .collect();
+    compile_po_entries(&entries, warnings)
+}
+
+// Key: locale name, or "" for the untranslated messages in messages.pot.
+pub type Locales = BTreeMap<String, Vec<TranslationEntry>>;
+
+// Key: locale name. Value: the locale's Plural-Forms header, such as
+// "nplurals=2; plural=(n != 1);".
+pub type LocalePluralForms = BTreeMap<String, String>;
+
+// The plural rule of the untranslated (English) messages. GNU gettext uses this
+// rule if a .po file has no Plural-Forms header.
+pub const SOURCE_CODE_PLURAL_FORMS: &str = "nplurals=2; plural=(n != 1);";
+
+// Returns the value of the Plural-Forms field in the header entry, if any.
+pub fn parse_plural_forms_header(entries: &[POEntry]) -> Option<String> {
+    let header: &POEntry = entries.iter().find(|entry| entry.is_header())?;
+    header.msgstr.lines().find_map(|line| {
+        line.strip_prefix("Plural-Forms:")
+            .map(|plural_forms| plural_forms.trim().to_string())
+    })
+}
+
+// compile_po_entries or compile_pot_entries.
+type CompileEntriesFn = fn(&[POEntry], &mut Vec<String>) -> Result<Vec<TranslationEntry>, String>;
+
+// Reads po/*.po and po/messages.pot. Skipped translations are reported in
+// warnings; see compile_po_entries.
+pub fn load_locales(po_dir: &Path, warnings: &mut Vec<String>) -> Result<Locales, String> {
+    // Prefixes each line of message with path.
+    let in_file = |path: &Path, message: String| -> String {
+        message
+            .lines()
+            .map(|line| format!("{}: {line}", path.display()))
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+    let mut compile_file =
+        |path: &Path, compile_entries: CompileEntriesFn| -> Result<Vec<TranslationEntry>, String> {
+            let text: String = std::fs::read_to_string(path)
+                .map_err(|error| format!("{}: {error}", path.display()))?;
+            let mut file_warnings: Vec<String> = vec![];
+            let result: Result<Vec<TranslationEntry>, String> = parse_po(&text)
+                .and_then(|entries| compile_entries(&entries, &mut file_warnings))
+                .map_err(|message| in_file(path, message));
+            warnings.extend(
+                file_warnings
+                    .into_iter()
+                    .map(|message| in_file(path, message)),
+            );
+            result
+        };
+
+    let mut locales: Locales = BTreeMap::new();
+    let mut errors: Vec<String> = vec![];
+    let in_po_dir = |error: std::io::Error| format!("{}: {error}", po_dir.display());
+    for dir_entry in std::fs::read_dir(po_dir).map_err(in_po_dir)? {
+        let path: PathBuf = dir_entry.map_err(in_po_dir)?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("po") {
+            continue;
+        }
+        let Some(locale_name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            errors.push(format!("{}: invalid locale name", path.display()));
+            continue;
+        };
+        match compile_file(&path, compile_po_entries) {
+            Ok(translations) => {
+                locales.insert(locale_name.to_string(), translations);
+            }
+            Err(message) => errors.push(message),
+        }
+    }
+    match compile_file(&po_dir.join("messages.pot"), compile_pot_entries) {
+        Ok(translations) => {
+            locales.insert(String::new(), translations);
+        }
+        Err(message) => errors.push(message),
+    }
+    if !errors.is_empty() {
+        errors.sort();
+        return Err(errors.join("\n"));
+    }
+    Ok(locales)
+}
+
+// Reads the Plural-Forms header of each po/*.po file. Locales without a
+// Plural-Forms header are omitted.
+pub fn load_plural_forms(po_dir: &Path) -> Result<LocalePluralForms, String> {
+    let mut plural_forms: LocalePluralForms = LocalePluralForms::new();
+    let in_po_dir = |error: std::io::Error| format!("{}: {error}", po_dir.display());
+    for dir_entry in std::fs::read_dir(po_dir).map_err(in_po_dir)? {
+        let path: PathBuf = dir_entry.map_err(in_po_dir)?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("po") {
+            continue;
+        }
+        // load_locales reports invalid locale names.
+        let Some(locale_name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let in_file = |message: String| format!("{}: {message}", path.display());
+        let text: String =
+            std::fs::read_to_string(&path).map_err(|error| in_file(error.to_string()))?;
+        let entries: Vec<POEntry> = parse_po(&text).map_err(in_file)?;
+        if let Some(header) = parse_plural_forms_header(&entries) {
+            plural_forms.insert(locale_name.to_string(), header);
+        }
+    }
+    Ok(plural_forms)
+}
+
+// Returns every locale name, sorted. The untranslated locale ("") is first.
+pub fn locale_names(locales: &Locales) -> Vec<&str> {
+    let mut names: Vec<&str> = locales.keys().map(|name| name.as_str()).collect();
+    if !locales.contains_key("") {
+        names.insert(0, "");
+    }
+    names
+}
+
+// Returns every untranslated string, sorted with no duplicates.
+pub fn all_untranslated(locales: &Locales) -> Vec<&str> {
+    let all: BTreeSet<&str> = locales
+        .values()
+        .flatten()
+        .map(|translation| translation.untranslated.as_str())
+        .collect();
+    all.into_iter().collect()
+}
+
+pub struct TranslationTable {
+    // Sorted, except that the untranslated locale ("") is last.
+    pub locales: Vec<String>,
+    // Sorted. Index i corresponds to mapping table entry i+1.
+    pub untranslated: Vec<String>,
+    // Key: index in locales. Value: offset in string_table, or 0 if the
+    // string is not translated in that locale.
+    pub absolute_mapping_table: Vec<Vec<u32>>,
+    pub string_table: Vec<u8>,
+    pub locale_table: Vec<u8>,
+}
+
+pub fn create_translation_table(locales: &Locales) -> TranslationTable {
+    fn add_string(string: &str, table: &mut Vec<u8>) -> u32 {
+        let offset: u32 = table.len() as u32;
+        table.extend_from_slice(string.as_bytes());
+        table.push(0);
+        offset
+    }
+
+    let untranslated: Vec<String> = all_untranslated(locales)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let mut locale_names: Vec<String> = locale_names(locales)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    // Put the untranslated ("") locale last. This has two effects:
+    // * When writing locale_table, we'll add an empty locale at the end,
+    //   terminating the list.
+    // * Untranslated strings are placed in
+    //   TranslationTableMappingEntry[locale_count].
+    locale_names.rotate_left(1);
+
+    let mut locale_table: Vec<u8> = vec![];
+    for locale_name in &locale_names {
+        add_string(locale_name, &mut locale_table);
+    }
+
+    let mut string_table: Vec<u8> = vec![0];
+    let mut absolute_mapping_table: Vec<Vec<u32>> =
+        vec![vec![0; locale_names.len()]; untranslated.len() + 1];
+    for (locale_index, locale_name) in locale_names.iter().enumerate() {
+        for translation in locales.get(locale_name).into_iter().flatten() {
+            let index: usize = untranslated
+                .binary_search(&translation.untranslated)
+                .expect("untranslated strings should include every translation");
+            absolute_mapping_table[index + 1][locale_index] =
+                add_string(&translation.translated, &mut string_table);
+        }
+    }
+
+    TranslationTable {
+        locales: locale_names,
+        untranslated,
+        absolute_mapping_table,
+        string_table,
+        locale_table,
+    }
+}
+
+fn write_file_header(out: &mut String) {
+    out.push_str(
+        "\
+// Code generated by rust/tools/compile_translations. DO NOT EDIT.
+// source: po/*.po
+
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+// See end of file for extended copyright information.
+",
+    );
+}
+
+fn write_copyright_footer(out: &mut String) {
+    out.push_str(
+        "\
+// quick-lint-js finds bugs in JavaScript programs.
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+//
+// This file is part of quick-lint-js.
+//
+// quick-lint-js is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// quick-lint-js is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with quick-lint-js.  If not, see <https://www.gnu.org/licenses/>.
+",
+    );
+}
+
+// Returns the contents of rust/libs/i18n/src/translation_table_generated.rs.
+//
+// Locales missing from plural_forms use SOURCE_CODE_PLURAL_FORMS.
+pub fn write_translation_table_rust(
+    table: &TranslationTable,
+    plural_forms: &LocalePluralForms,
+) -> String {
+    let mut out: String = String::new();
+    write_file_header(&mut out);
+    out.push_str("\nuse crate::translation_table::*;\n\n");
+    out.push_str(&format!(
+        "pub const TRANSLATION_TABLE_LOCALE_COUNT: u32 = {};\n",
+        table.locales.len() - 1
+    ));
+    out.push_str(&format!(
+        "pub const TRANSLATION_TABLE_MAPPING_TABLE_SIZE: u16 = {};\n",
+        table.absolute_mapping_table.len()
+    ));
+
+    out.push_str(&format!(
+        "\npub const UNTRANSLATED_STRINGS: [&str; {}] = [\n",
+        table.untranslated.len()
+    ));
+    for untranslated in &table.untranslated {
+        out.push_str(&format!(
+            "    \"{}\",\n",
+            rust_string_literal_body(untranslated)
+        ));
+    }
+    out.push_str(
+        "];
+
+pub const TRANSLATION_DATA_MAPPING_TABLE: [TranslationTableMappingEntry;
+    TRANSLATION_TABLE_MAPPING_TABLE_SIZE as usize] = [
+",
+    );
+    for mapping_entry in &table.absolute_mapping_table {
+        let offsets: Vec<String> = mapping_entry
+            .iter()
+            .map(|offset| offset.to_string())
+            .collect();
+        out.push_str(&format!(
+            "    TranslationTableMappingEntry([{}]), //\n",
+            offsets.join(", ")
+        ));
+    }
+    out.push_str("];\n\npub const TRANSLATION_DATA_STRING_TABLE: &[u8] = ");
+    out.push_str(&rust_string_table(&table.string_table, "        "));
+    out.push_str(".as_bytes();\n\npub const TRANSLATION_DATA_LOCALE_TABLE: &str = ");
+    out.push_str(&rust_string_table(&table.locale_table, "        "));
+    out.push_str(
+        ";
+
+pub const TRANSLATION_DATA_PLURAL_FORMS_TABLE: [&str;
+    (TRANSLATION_TABLE_LOCALE_COUNT + 1) as usize] = [
+",
+    );
+    for locale_name in &table.locales {
+        let locale_plural_forms: &str = plural_forms
+            .get(locale_name)
+            .map_or(SOURCE_CODE_PLURAL_FORMS, |header| header.as_str());
+        out.push_str(&format!(
+            "    // {}\n    \"{}\",\n",
+            if locale_name.is_empty() {
+                "(untranslated)"
+            } else {
+                locale_name
+            },
+            rust_string_literal_body(locale_plural_forms)
+        ));
+    }
+    out.push_str("];\n\n");
+    write_copyright_footer(&mut out);
+    out
+}
+
+// Returns the contents of
+// rust/libs/i18n/tests/test_translation_table_generated.rs.
+pub fn write_translation_test_rust(locales: &Locales) -> String {
+    let locale_names: Vec<&str> = locale_names(locales);
+    let all_untranslated: Vec<&str> = all_untranslated(locales);
+    let mut out: String = String::new();
+    write_file_header(&mut out);
+    out.push_str(&format!(
+        "
+#![allow(clippy::redundant_static_lifetimes)]
+
+use cpp_vs_rust_i18n::qljs_translatable;
+use cpp_vs_rust_i18n::translation::*;
+use cpp_vs_rust_test::scoped_trace;
+
+#[rustfmt::skip]
+pub const TEST_LOCALE_NAMES: [&'static str; {}] = [
+",
+        locale_names.len()
+    ));
+    for locale_name in &locale_names {
+        out.push_str(&format!(
+            "    \"{}\",\n",
+            rust_string_literal_body(locale_name)
+        ));
+    }
+    out.push_str(&format!(
+        "];
+
+pub struct TranslatedString {{
+    pub translatable: TranslatableMessage,
+    pub expected_per_locale: [&'static str; {}],
+}}
+
+pub const TEST_TRANSLATION_TABLE: [TranslatedString; {}] = [
+",
+        locale_names.len(),
+        all_untranslated.len()
+    ));
+    for untranslated in &all_untranslated {
+        out.push_str(&format!(
+            "    TranslatedString{{\n        translatable: qljs_translatable!(\"{}\"),\n        expected_per_locale: [\n",
+            rust_string_literal_body(untranslated)
+        ));
+        for locale_name in &locale_names {
+            out.push_str(&format!(
+                "            \"{}\",\n",
+                rust_string_literal_body(look_up_translation(locales, locale_name, untranslated))
+            ));
+        }
+        out.push_str("        ],\n    },\n");
+    }
+    out.push_str(
+        "];
+
+#[test]
+fn full_translation_table() {
+    for (locale_index, locale_name) in TEST_LOCALE_NAMES.iter().enumerate() {
+        let mut messages: Translator = Translator::new_using_messages_from_source_code();
+        scoped_trace!(locale_name);
+        if locale_name.is_empty() {
+            messages.use_messages_from_source_code();
+        } else {
+            assert!(
+                messages.use_messages_from_locale(locale_name),
+                \"locale_name={:?}\",
+                locale_name,
+            );
+        }
+
+        for test_case in TEST_TRANSLATION_TABLE {
+            assert!(test_case.translatable.valid());
+            assert_eq!(
+                messages.translate(test_case.translatable),
+                test_case.expected_per_locale[locale_index],
+                \"locale_name={:?}\",
+                locale_name,
+            );
+        }
+    }
+}
+
+",
+    );
+    write_copyright_footer(&mut out);
+    out
+}
+
+// Returns the untranslated string if there is no translation.
+fn look_up_translation<'a>(
+    locales: &'a Locales,
+    locale_name: &str,
+    untranslated: &'a str,
+) -> &'a str {
+    locales
+        .get(locale_name)
+        .and_then(|translations| {
+            translations
+                .iter()
+                .find(|translation| translation.untranslated == untranslated)
+        })
+        .map_or(untranslated, |translation| translation.translated.as_str())
+}
+
+// Writes a list of 0-terminated strings as a multi-line Rust string literal.
+fn rust_string_table(strings: &[u8], line_prefix: &str) -> String {
+    let mut out: String = "\"\\\n".to_string();
+    let strings: &[u8] = strings
+        .strip_suffix(&[0])
+        .expect("strings should be 0-terminated");
+    for string in strings.split(|&byte| byte == 0) {
+        let string: &str = std::str::from_utf8(string).expect("strings should be UTF-8");
+        out.push_str(line_prefix);
+        // Rust ignores whitespace at the beginning of a line after a
+        // backslash-newline, so escape a leading space.
+        match string.strip_prefix(' ') {
+            Some(rest) => {
+                out.push_str("\\u{0020}");
+                out.push_str(&rust_string_literal_body(rest));
+            }
+            None => out.push_str(&rust_string_literal_body(string)),
+        }
+        out.push_str("\\0\\\n");
+    }
+    out.push('"');
+    out
+}
+
+fn rust_string_literal_body(s: &str) -> String {
+    let mut out: String = String::new();
+    for c in s.chars() {
+        if !('\u{20}'..'\u{7f}').contains(&c) {
+            out.push_str(&format!("\\u{{{:04x}}}", c as u32));
+        } else if c == '\\' || c == '"' {
+            out.push('\\');
+            out.push(c);
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn rust_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+pub fn po_dir() -> PathBuf {
+    rust_dir().join("../po")
+}
+
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+// Returns the contents of each file generated from po_dir. Skipped translations
+// are reported in warnings; see compile_po_entries.
+pub fn generate(po_dir: &Path, warnings: &mut Vec<String>) -> Result<Vec<GeneratedFile>, String> {
+    let locales: Locales = load_locales(po_dir, warnings)?;
+    let plural_forms: LocalePluralForms = load_plural_forms(po_dir)?;
+    let table: TranslationTable = create_translation_table(&locales);
+    Ok(vec![
+        GeneratedFile {
+            path: rust_dir().join("libs/i18n/src/translation_table_generated.rs"),
+            contents: write_translation_table_rust(&table, &plural_forms),
+        },
+        GeneratedFile {
+            path: rust_dir().join("libs/i18n/tests/test_translation_table_generated.rs"),
+            contents: write_translation_test_rust(&locales),
+        },
+    ])
+}
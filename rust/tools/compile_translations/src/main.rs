@@ -0,0 +1,59 @@
+// Generates rust/libs/i18n/src/translation_table_generated.rs and
+// rust/libs/i18n/tests/test_translation_table_generated.rs from the
+// translations in po/.
+//
+// Usage: cargo run -p cpp_vs_rust_compile_translations [-- --check]
+//
+// With --check, verify that the generated files are up to date instead of
+// writing them.
+
+use cpp_vs_rust_compile_translations::*;
+
+fn main() {
+    let check: bool = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => {
+            eprintln!("error: unexpected argument: {arg}");
+            std::process::exit(2);
+        }
+    };
+
+    let mut warnings: Vec<String> = vec![];
+    let result: Result<Vec<GeneratedFile>, String> = generate(&po_dir(), &mut warnings);
+    for warning in &warnings {
+        eprintln!("warning: {warning}");
+    }
+    let files: Vec<GeneratedFile> = match result {
+        Ok(files) => files,
+        Err(message) => {
+            eprintln!("error: {message}");
+            std::process::exit(1);
+        }
+    };
+    let mut ok: bool = true;
+    for file in files {
+        let result: Result<(), String> = if check {
+            let existing: String = std::fs::read_to_string(&file.path).unwrap_or_default();
+            if existing == file.contents {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} is out of date; run compile_translations",
+                    file.path.display()
+                ))
+            }
+        } else {
+            println!("Creating {} ...", file.path.display());
+            std::fs::write(&file.path, &file.contents)
+                .map_err(|error| format!("{}: {error}", file.path.display()))
+        };
+        if let Err(message) = result {
+            eprintln!("error: {message}");
+            ok = false;
+        }
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
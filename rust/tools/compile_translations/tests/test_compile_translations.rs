@@ -0,0 +1,424 @@
+use cpp_vs_rust_compile_translations::*;
+
+fn entry(msgid: &str, msgstr: &str) -> POEntry {
+    POEntry {
+        line: 1,
+        context: None,
+        msgid: msgid.to_string(),
+        msgstr: msgstr.to_string(),
+        is_fuzzy: false,
+    }
+}
+
+fn translation(untranslated: &str, translated: &str) -> TranslationEntry {
+    TranslationEntry {
+        untranslated: untranslated.to_string(),
+        translated: translated.to_string(),
+    }
+}
+
+#[test]
+fn parse_entries_with_comments_and_multi_line_strings() {
+    let entries: Vec<POEntry> = parse_po(
+        "# translator comment\n\
+         msgid \"\"\n\
+         msgstr \"\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n\
+         \n\
+         #: cpp/src/quick-lint-js/fe/diagnostic-types.h\n\
+         msgid \"'for' loop\"\n\
+         msgstr \"for-Schleife\"\n\
+         \n\
+         msgid \"\"\n\
+         \"long \"\n\
+         \"message\"\n\
+         msgstr \"lange \"\n\
+         \"Nachricht\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        entries,
+        vec![
+            POEntry {
+                line: 2,
+                ..entry("", "Content-Type: text/plain; charset=UTF-8\n")
+            },
+            POEntry {
+                line: 7,
+                ..entry("'for' loop", "for-Schleife")
+            },
+            POEntry {
+                line: 10,
+                ..entry("long message", "lange Nachricht")
+            },
+        ]
+    );
+    assert!(entries[0].is_header());
+    assert!(!entries[1].is_header());
+}
+
+#[test]
+fn parse_escape_sequences() {
+    let entries: Vec<POEntry> =
+        parse_po("msgid \"a\\\\b \\\"c\\\" \\t\\n\"\nmsgstr \"\"\n").unwrap();
+    assert_eq!(entries[0].msgid, "a\\b \"c\" \t\n");
+}
+
+#[test]
+fn parse_fuzzy_flag() {
+    let entries: Vec<POEntry> = parse_po(
+        "#, fuzzy\n\
+         msgid \"a\"\n\
+         msgstr \"A\"\n\
+         \n\
+         #, c-format, fuzzy\n\
+         msgid \"b\"\n\
+         msgstr \"B\"\n\
+         \n\
+         #, c-format\n\
+         msgid \"c\"\n\
+         msgstr \"C\"\n",
+    )
+    .unwrap();
+    let fuzzy: Vec<bool> = entries.iter().map(|entry| entry.is_fuzzy).collect();
+    assert_eq!(fuzzy, vec![true, true, false]);
+}
+
+#[test]
+fn parse_skips_obsolete_entries() {
+    let entries: Vec<POEntry> = parse_po(
+        "msgid \"a\"\n\
+         msgstr \"A\"\n\
+         \n\
+         #, fuzzy\n\
+         #~ msgid \"old\"\n\
+         #~ msgstr \"OLD\"\n\
+         \n\
+         #~ msgid \"older\"\n\
+         #~ msgstr \"\"\n\
+         #~ \"OLDER\"\n",
+    )
+    .unwrap();
+    assert_eq!(entries, vec![entry("a", "A")]);
+}
+
+#[test]
+fn parse_entries_without_blank_lines_between() {
+    let entries: Vec<POEntry> = parse_po(
+        "msgid \"a\"\n\
+         msgstr \"A\"\n\
+         #: file.rs\n\
+         msgid \"b\"\n\
+         msgstr \"B\"\n\
+         msgid \"c\"\n\
+         msgstr \"C\"\n",
+    )
+    .unwrap();
+    let msgids: Vec<&str> = entries.iter().map(|entry| entry.msgid.as_str()).collect();
+    assert_eq!(msgids, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn parse_context() {
+    let entries: Vec<POEntry> = parse_po(
+        "msgctxt \"statement\"\n\
+         msgid \"loop\"\n\
+         msgstr \"Schleife\"\n\
+         \n\
+         msgid \"loop\"\n\
+         msgstr \"Loop\"\n",
+    )
+    .unwrap();
+    assert_eq!(entries[0].context.as_deref(), Some("statement"));
+    assert_eq!(entries[0].untranslated(), "statement\u{4}loop");
+    assert!(!entries[0].is_header());
+    assert_eq!(entries[1].context, None);
+    assert_eq!(entries[1].untranslated(), "loop");
+}
+
+#[test]
+fn parse_rejects_malformed_files() {
+    for text in [
+        "msgid \"a\"\n",
+        "msgstr \"A\"\n",
+        "\"a\"\n",
+        "msgid \"a\nmsgstr \"A\"\n",
+        "msgid a\nmsgstr \"A\"\n",
+        "msgid \"a\\q\"\nmsgstr \"A\"\n",
+        "msgid \"a\" \"b\"\nmsgstr \"A\"\n",
+        "msgid \"a\"\nmsgid \"b\"\nmsgstr \"A\"\n",
+        "msgid \"a\"\nmsgstr \"A\"\nmsgstr \"B\"\n",
+        "msgid \"a\"\nmsgstr \"A\"\nmsgctxt \"x\"\n",
+        "msgid \"file\"\nmsgid_plural \"files\"\nmsgstr[0] \"Datei\"\n",
+        "msgid \"a\"\nmsgstr[0] \"A\"\n",
+        "msgfoo \"a\"\n",
+    ] {
+        assert!(parse_po(text).is_err(), "{text:?} should be rejected");
+    }
+}
+
+#[test]
+fn compile_skips_header_fuzzy_and_untranslated_entries() {
+    let translations: Vec<TranslationEntry> = compile_po_entries(
+        &[
+            entry("", "Content-Type: text/plain; charset=UTF-8\n"),
+            entry("z", "Z"),
+            POEntry {
+                is_fuzzy: true,
+                ..entry("fuzzy", "FUZZY")
+            },
+            entry("untranslated", ""),
+            entry("a", "A"),
+        ],
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(
+        translations,
+        vec![translation("a", "A"), translation("z", "Z")]
+    );
+}
+
+#[test]
+fn compile_keys_entries_with_context() {
+    let translations: Vec<TranslationEntry> = compile_po_entries(
+        &[
+            entry("loop", "Loop"),
+            POEntry {
+                context: Some("statement".to_string()),
+                ..entry("loop", "Schleife")
+            },
+            // A context with an empty msgid is not the header.
+            POEntry {
+                context: Some("empty".to_string()),
+                ..entry("", "leer")
+            },
+        ],
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(
+        translations,
+        vec![
+            translation("empty\u{4}", "leer"),
+            translation("loop", "Loop"),
+            translation("statement\u{4}loop", "Schleife"),
+        ]
+    );
+}
+
+#[test]
+fn compile_skips_translations_with_different_placeholders() {
+    let mut warnings: Vec<String> = vec![];
+    let translations: Vec<TranslationEntry> = compile_po_entries(
+        &[
+            POEntry {
+                line: 10,
+                ..entry("bad", "{0} is bad")
+            },
+            POEntry {
+                line: 20,
+                ..entry("{0} is missing", "missing")
+            },
+            POEntry {
+                line: 30,
+                ..entry("{0} is fine", "fine: {0}")
+            },
+            // Fuzzy entries are not checked.
+            POEntry {
+                line: 40,
+                is_fuzzy: true,
+                ..entry("fuzzy", "{0} is fuzzy")
+            },
+        ],
+        &mut warnings,
+    )
+    .unwrap();
+    assert_eq!(translations, vec![translation("{0} is fine", "fine: {0}")]);
+    assert_eq!(
+        warnings,
+        vec![
+            "line 10: skipping translation of \"bad\": translation has placeholders {0} but msgid has none",
+            "line 20: skipping translation of \"{0} is missing\": translation has placeholders none but msgid has {0}",
+        ]
+    );
+}
+
+#[test]
+fn compile_reports_every_duplicate() {
+    let mut warnings: Vec<String> = vec![];
+    let error: String = compile_po_entries(
+        &[
+            POEntry {
+                line: 10,
+                ..entry("fine", "fine")
+            },
+            POEntry {
+                line: 20,
+                ..entry("fine", "also fine")
+            },
+            POEntry {
+                line: 30,
+                ..entry("good", "good")
+            },
+            POEntry {
+                line: 40,
+                ..entry("good", "also good")
+            },
+        ],
+        &mut warnings,
+    )
+    .unwrap_err();
+    let lines: Vec<&str> = error.lines().collect();
+    assert_eq!(lines.len(), 2, "{error}");
+    assert!(lines[0].starts_with("line 20: duplicate"), "{error}");
+    assert!(lines[1].starts_with("line 40: duplicate"), "{error}");
+    assert_eq!(warnings, Vec::<String>::new());
+}
+
+#[test]
+fn compile_pot_translates_messages_to_themselves() {
+    let translations: Vec<TranslationEntry> = compile_pot_entries(
+        &[
+            POEntry {
+                is_fuzzy: true,
+                ..entry("", "")
+            },
+            entry("b", ""),
+            entry("a {0}", ""),
+        ],
+        &mut vec![],
+    )
+    .unwrap();
+    assert_eq!(
+        translations,
+        vec![translation("a {0}", "a {0}"), translation("b", "b")]
+    );
+}
+
+#[test]
+fn translation_table_puts_untranslated_locale_last() {
+    let mut locales: Locales = Locales::new();
+    locales.insert(
+        String::new(),
+        vec![translation("a", "a"), translation("b", "b")],
+    );
+    locales.insert("de".to_string(), vec![translation("b", "B")]);
+    locales.insert("fr".to_string(), vec![translation("a", "A")]);
+    assert_eq!(locale_names(&locales), vec!["", "de", "fr"]);
+    assert_eq!(all_untranslated(&locales), vec!["a", "b"]);
+
+    let table: TranslationTable = create_translation_table(&locales);
+    assert_eq!(table.locales, vec!["de", "fr", ""]);
+    assert_eq!(table.locale_table, b"de\0fr\0\0");
+    assert_eq!(table.untranslated, vec!["a", "b"]);
+
+    let read_string = |offset: u32| -> &[u8] {
+        let string: &[u8] = &table.string_table[offset as usize..];
+        &string[..string.iter().position(|&byte| byte == 0).unwrap()]
+    };
+    assert_eq!(table.absolute_mapping_table.len(), 3);
+    assert_eq!(table.absolute_mapping_table[0], vec![0, 0, 0]);
+    let [de_a, fr_a, source_a] = table.absolute_mapping_table[1][..] else {
+        panic!()
+    };
+    assert_eq!(de_a, 0);
+    assert_eq!(read_string(fr_a), b"A");
+    assert_eq!(read_string(source_a), b"a");
+    let [de_b, fr_b, source_b] = table.absolute_mapping_table[2][..] else {
+        panic!()
+    };
+    assert_eq!(read_string(de_b), b"B");
+    assert_eq!(fr_b, 0);
+    assert_eq!(read_string(source_b), b"b");
+}
+
+#[test]
+fn generated_rust_escapes_strings() {
+    let mut locales: Locales = Locales::new();
+    locales.insert(
+        String::new(),
+        vec![translation("say \"hi\"\\", "say \"hi\"\\")],
+    );
+    locales.insert(
+        "de".to_string(),
+        vec![translation("say \"hi\"\\", " sag \u{201e}hallo\u{201c}\n")],
+    );
+    let table: TranslationTable = create_translation_table(&locales);
+    let rust: String = write_translation_table_rust(&table, &LocalePluralForms::new());
+    assert!(
+        rust.contains("    \"say \\\"hi\\\"\\\\\",\n"),
+        "untranslated string should be escaped:\n{rust}"
+    );
+    assert!(
+        rust.contains("        \\u{0020}sag \\u{201e}hallo\\u{201c}\\u{000a}\\0\\\n"),
+        "leading space and non-ASCII characters should be escaped:\n{rust}"
+    );
+}
+
+#[test]
+fn parse_plural_forms_from_header() {
+    let entries: Vec<POEntry> = parse_po(
+        "msgid \"\"\n\
+         msgstr \"\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n\
+         \"Plural-Forms: nplurals=2; plural=(n > 1);\\n\"\n\
+         \n\
+         msgid \"Plural-Forms: not a header\"\n\
+         msgstr \"x\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        parse_plural_forms_header(&entries),
+        Some("nplurals=2; plural=(n > 1);".to_string())
+    );
+    assert_eq!(parse_plural_forms_header(&entries[1..]), None);
+}
+
+#[test]
+fn generated_rust_has_plural_forms_per_locale() {
+    let mut locales: Locales = Locales::new();
+    locales.insert(String::new(), vec![translation("a", "a")]);
+    locales.insert("fr".to_string(), vec![translation("a", "A")]);
+    locales.insert("sv".to_string(), vec![translation("a", "\u{c5}")]);
+    let mut plural_forms: LocalePluralForms = LocalePluralForms::new();
+    plural_forms.insert("fr".to_string(), "nplurals=2; plural=(n > 1);".to_string());
+    let table: TranslationTable = create_translation_table(&locales);
+    let rust: String = write_translation_table_rust(&table, &plural_forms);
+    assert!(
+        rust.contains(
+            "    // fr\n\
+             \x20   \"nplurals=2; plural=(n > 1);\",\n\
+             \x20   // sv\n\
+             \x20   \"nplurals=2; plural=(n != 1);\",\n\
+             \x20   // (untranslated)\n\
+             \x20   \"nplurals=2; plural=(n != 1);\",\n"
+        ),
+        "plural forms should be in locale table order:\n{rust}"
+    );
+}
+
+#[test]
+fn po_files_have_valid_translations() {
+    // Translations with different placeholders than their msgid are skipped
+    // with a warning, so they do not fail this test.
+    let locales: Locales =
+        load_locales(&po_dir(), &mut vec![]).unwrap_or_else(|message| panic!("{message}"));
+    assert!(locales.contains_key(""));
+    assert!(locales.contains_key("de"));
+}
+
+#[test]
+fn generated_files_are_up_to_date() {
+    let files: Vec<GeneratedFile> =
+        generate(&po_dir(), &mut vec![]).unwrap_or_else(|message| panic!("{message}"));
+    for file in files {
+        let existing: String = std::fs::read_to_string(&file.path)
+            .unwrap_or_else(|error| panic!("{}: {error}", file.path.display()));
+        assert!(
+            existing == file.contents,
+            "{} is out of date; run: cargo run -p cpp_vs_rust_compile_translations",
+            file.path.display()
+        );
+    }
+}
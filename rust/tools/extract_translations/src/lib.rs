@@ -0,0 +1,422 @@
+// Extracts qljs_translatable!("...") strings from the Rust sources and merges
+// them into po/messages.pot, like xgettext does for the C++ sources.
+//
+// Entries referenced only by Rust sources are generated from scratch. Entries
+// also referenced by other sources (such as C++ sources scanned by xgettext)
+// keep their text and gain references to the Rust sources.
+
+use cpp_vs_rust_compile_translations::*;
+use proc_macro2::Delimiter;
+use proc_macro2::TokenTree;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use syn::parse::ParseStream;
+use syn::parse::Parser;
+use syn::visit::Visit;
+
+// Rust sources scanned for qljs_translatable!, relative to the repository
+// root. Keep in sync with the C++ sources in tools/update-translator-sources.
+pub const RUST_SOURCE_FILES: &[&str] = &[
+    "rust/libs/fe/src/diagnostic_formatter.rs",
+    "rust/libs/fe/src/diagnostic_types.rs",
+    "rust/libs/fe/tests/test_diagnostic_formatter.rs",
+];
+
+// Comments starting with this tag which appear directly above a
+// qljs_translatable! are copied into messages.pot for translators.
+pub const TRANSLATOR_COMMENT_TAG: &str = "TRANSLATORS:";
+
+// Lines in messages.pot are wrapped to this many columns, matching xgettext.
+const PO_LINE_WIDTH: usize = 79;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtractedMessage {
+    pub msgid: String,
+    // 1-based line number of the qljs_translatable! invocation.
+    pub line: usize,
+    pub translator_comments: Vec<String>,
+}
+
+// Finds every qljs_translatable!("...") in Rust source code.
+//
+// The source code is parsed with syn. Invocations are found in expressions,
+// in the arguments of other macros, and in #[qljs_diagnostic] attributes.
+// Invocations inside comments and string literals are therefore ignored. An
+// invocation whose argument is not a string literal is an error.
+pub fn extract_messages(source: &str) -> Result<Vec<ExtractedMessage>, String> {
+    let file: syn::File = syn::parse_file(source)
+        .map_err(|error: syn::Error| format!("line {}: {error}", error.span().start().line))?;
+    let mut visitor: TranslatableVisitor = TranslatableVisitor {
+        invocations: vec![],
+        errors: vec![],
+    };
+    visitor.visit_file(&file);
+    if let Some((_, message)) = visitor.errors.into_iter().min() {
+        return Err(message);
+    }
+
+    // Attributes are visited before the items they are attached to, but
+    // messages should be in source order.
+    visitor.invocations.sort_by_key(|(position, _)| *position);
+    let source_lines: Vec<&str> = source.lines().collect();
+    Ok(visitor
+        .invocations
+        .into_iter()
+        .map(|((line, _column), msgid)| ExtractedMessage {
+            msgid,
+            line,
+            translator_comments: translator_comments(&source_lines, line),
+        })
+        .collect())
+}
+
+// (line, column) of a token. Lines are 1-based.
+type SourcePosition = (usize, usize);
+
+struct TranslatableVisitor {
+    invocations: Vec<(SourcePosition, String)>,
+    errors: Vec<(SourcePosition, String)>,
+}
+
+impl TranslatableVisitor {
+    // Finds qljs_translatable! invocations in tokens which syn did not parse,
+    // such as the arguments of a macro or an attribute.
+    fn visit_token_stream(&mut self, tokens: proc_macro2::TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, token) in tokens.iter().enumerate() {
+            match (token, tokens.get(i + 1), tokens.get(i + 2)) {
+                (
+                    TokenTree::Ident(name),
+                    Some(TokenTree::Punct(bang)),
+                    Some(TokenTree::Group(args)),
+                ) if name == "qljs_translatable" && bang.as_char() == '!' => {
+                    self.visit_invocation(name, args.delimiter(), args.stream());
+                }
+                (TokenTree::Group(group), _, _) => {
+                    self.visit_token_stream(group.stream());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_invocation(
+        &mut self,
+        name: &proc_macro2::Ident,
+        delimiter: Delimiter,
+        args: proc_macro2::TokenStream,
+    ) {
+        let position: SourcePosition = span_position(name.span());
+        let close: char = match delimiter {
+            Delimiter::Parenthesis => ')',
+            Delimiter::Bracket => ']',
+            Delimiter::Brace => '}',
+            Delimiter::None => ')',
+        };
+        let parser = |input: ParseStream| -> syn::Result<syn::LitStr> {
+            let msgid: syn::LitStr = input.parse().map_err(|error: syn::Error| {
+                syn::Error::new(error.span(), "expected a string literal")
+            })?;
+            if input.peek(syn::Token![,]) {
+                input.parse::<syn::Token![,]>()?;
+            }
+            if !input.is_empty() {
+                return Err(input.error(format!("expected '{close}' after string literal")));
+            }
+            Ok(msgid)
+        };
+        match parser.parse2(args) {
+            Ok(msgid) => self.invocations.push((position, msgid.value())),
+            Err(error) => self.errors.push((
+                position,
+                format!("line {}: qljs_translatable!: {error}", position.0),
+            )),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TranslatableVisitor {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        match mac.path.segments.last() {
+            Some(segment) if segment.ident == "qljs_translatable" => {
+                let delimiter: Delimiter = match mac.delimiter {
+                    syn::MacroDelimiter::Paren(_) => Delimiter::Parenthesis,
+                    syn::MacroDelimiter::Bracket(_) => Delimiter::Bracket,
+                    syn::MacroDelimiter::Brace(_) => Delimiter::Brace,
+                };
+                self.visit_invocation(&segment.ident, delimiter, mac.tokens.clone());
+            }
+            _ => self.visit_token_stream(mac.tokens.clone()),
+        }
+    }
+
+    fn visit_attribute(&mut self, attribute: &'ast syn::Attribute) {
+        if attribute.path().is_ident("qljs_diagnostic") {
+            if let syn::Meta::List(list) = &attribute.meta {
+                self.visit_token_stream(list.tokens.clone());
+            }
+        }
+    }
+}
+
+fn span_position(span: proc_macro2::Span) -> SourcePosition {
+    let start: proc_macro2::LineColumn = span.start();
+    (start.line, start.column)
+}
+
+// Returns the comments for translators directly above the given 1-based line:
+// the consecutive line comments ending on the previous line, starting at the
+// first comment with TRANSLATOR_COMMENT_TAG. Comments must be alone on their
+// line.
+fn translator_comments(source_lines: &[&str], line: usize) -> Vec<String> {
+    let comments: Vec<&str> = source_lines[..line - 1]
+        .iter()
+        .rev()
+        .map_while(|source_line: &&str| source_line.trim().strip_prefix("//"))
+        .map(|text: &str| text.strip_prefix(' ').unwrap_or(text))
+        .collect::<Vec<&str>>()
+        .into_iter()
+        .rev()
+        .collect();
+    match comments
+        .iter()
+        .position(|text: &&str| text.starts_with(TRANSLATOR_COMMENT_TAG))
+    {
+        Some(tag_index) => comments[tag_index..]
+            .iter()
+            .map(|text: &&str| text.to_string())
+            .collect(),
+        None => vec![],
+    }
+}
+
+// A qljs_translatable! string from a source file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceMessage {
+    // Relative to the repository root, such as
+    // "rust/libs/fe/src/diagnostic_types.rs".
+    pub path: String,
+    pub message: ExtractedMessage,
+}
+
+// Merges extracted messages into the contents of a .pot file.
+//
+// References to Rust sources ("#: rust/...") and extracted comments ("#.")
+// are replaced. An entry which was referenced only by Rust sources is removed
+// if it is no longer extracted. Entries only referenced by Rust sources are
+// written after every other entry, in extraction order.
+pub fn update_pot(pot: &str, messages: &[SourceMessage]) -> Result<String, String> {
+    struct RustMessage<'a> {
+        references: Vec<String>,
+        comments: Vec<&'a str>,
+        merged: bool,
+    }
+    let mut rust_messages: Vec<(&str, RustMessage)> = vec![];
+    let mut rust_message_indexes: HashMap<&str, usize> = HashMap::new();
+    for source_message in messages {
+        let msgid: &str = &source_message.message.msgid;
+        let index: usize = *rust_message_indexes.entry(msgid).or_insert_with(|| {
+            rust_messages.push((
+                msgid,
+                RustMessage {
+                    references: vec![],
+                    comments: vec![],
+                    merged: false,
+                },
+            ));
+            rust_messages.len() - 1
+        });
+        let rust_message: &mut RustMessage = &mut rust_messages[index].1;
+        rust_message.references.push(format!(
+            "{}:{}",
+            source_message.path, source_message.message.line
+        ));
+        for comment in &source_message.message.translator_comments {
+            if !rust_message.comments.contains(&comment.as_str()) {
+                rust_message.comments.push(comment);
+            }
+        }
+    }
+
+    let mut blocks: Vec<String> = vec![];
+    let pot: &str = pot.strip_suffix('\n').unwrap_or(pot);
+    for block in pot.split("\n\n") {
+        let entries: Vec<POEntry> = parse_po(block)?;
+        let entry: &POEntry = match entries.as_slice() {
+            [entry] if !entry.is_header() => entry,
+            [] | [_] => {
+                blocks.push(block.to_string());
+                continue;
+            }
+            _ => return Err(format!("expected one entry per paragraph:\n{block}")),
+        };
+        let mut references: Vec<&str> = vec![];
+        let mut other_lines: Vec<&str> = vec![];
+        let mut had_rust_references: bool = false;
+        for line in block.lines() {
+            if let Some(line_references) = line.strip_prefix("#:") {
+                for reference in line_references.split_whitespace() {
+                    if reference.starts_with("rust/") {
+                        had_rust_references = true;
+                    } else {
+                        references.push(reference);
+                    }
+                }
+            } else if !line.starts_with("#.") {
+                other_lines.push(line);
+            }
+        }
+        let rust_message: Option<&mut RustMessage> = rust_message_indexes
+            .get(entry.untranslated().as_str())
+            .map(|&index| &mut rust_messages[index].1);
+        if references.is_empty() && had_rust_references {
+            // Only referenced by Rust sources. If the message is still
+            // extracted, it will be regenerated below.
+            continue;
+        }
+        let rust_message: &mut RustMessage = match rust_message {
+            Some(rust_message) => rust_message,
+            None if had_rust_references => {
+                let mut out: String = String::new();
+                write_po_references(&mut out, &references);
+                blocks.push(out + &other_lines.join("\n"));
+                continue;
+            }
+            None => {
+                blocks.push(block.to_string());
+                continue;
+            }
+        };
+        rust_message.merged = true;
+        let mut out: String = String::new();
+        write_po_comments(&mut out, &rust_message.comments);
+        references.extend(rust_message.references.iter().map(String::as_str));
+        write_po_references(&mut out, &references);
+        blocks.push(out + &other_lines.join("\n"));
+    }
+
+    for (msgid, rust_message) in &rust_messages {
+        if rust_message.merged {
+            continue;
+        }
+        let mut out: String = String::new();
+        write_po_comments(&mut out, &rust_message.comments);
+        let references: Vec<&str> = rust_message.references.iter().map(String::as_str).collect();
+        write_po_references(&mut out, &references);
+        write_po_string(&mut out, "msgid", msgid);
+        out.push_str("msgstr \"\"");
+        blocks.push(out);
+    }
+
+    Ok(blocks.join("\n\n") + "\n")
+}
+
+fn write_po_comments(out: &mut String, comments: &[&str]) {
+    for comment in comments {
+        out.push_str("#. ");
+        out.push_str(comment);
+        out.push('\n');
+    }
+}
+
+// Writes "#:" lines, wrapping like xgettext.
+fn write_po_references(out: &mut String, references: &[&str]) {
+    let mut line: String = "#:".to_string();
+    for reference in references {
+        if line != "#:" && line.len() + 1 + reference.len() > PO_LINE_WIDTH {
+            out.push_str(&line);
+            out.push('\n');
+            line = "#:".to_string();
+        }
+        line.push(' ');
+        line.push_str(reference);
+    }
+    if line != "#:" {
+        out.push_str(&line);
+        out.push('\n');
+    }
+}
+
+// Writes a keyword and quoted string, wrapping like xgettext: if the string
+// does not fit on one line, it starts with "" and is split after spaces and
+// newlines.
+pub fn write_po_string(out: &mut String, keyword: &str, s: &str) {
+    let escaped: String = escape_po_string(s);
+    let has_inner_newline: bool = s.trim_end_matches('\n').contains('\n');
+    if keyword.len() + escaped.len() + 3 <= PO_LINE_WIDTH && !has_inner_newline {
+        out.push_str(&format!("{keyword} \"{escaped}\"\n"));
+        return;
+    }
+    out.push_str(&format!("{keyword} \"\"\n"));
+    let mut line: String = String::new();
+    let mut word: String = String::new();
+    let flush_word = |line: &mut String, word: &mut String, out: &mut String| {
+        if !line.is_empty() && line.len() + word.len() + 2 > PO_LINE_WIDTH {
+            out.push_str(&format!("\"{line}\"\n"));
+            line.clear();
+        }
+        line.push_str(word);
+        word.clear();
+    };
+    for c in s.chars() {
+        word.push_str(&escape_po_string(&c.to_string()));
+        if c == ' ' {
+            flush_word(&mut line, &mut word, out);
+        } else if c == '\n' {
+            flush_word(&mut line, &mut word, out);
+            out.push_str(&format!("\"{line}\"\n"));
+            line.clear();
+        }
+    }
+    flush_word(&mut line, &mut word, out);
+    if !line.is_empty() {
+        out.push_str(&format!("\"{line}\"\n"));
+    }
+}
+
+fn escape_po_string(s: &str) -> String {
+    let mut out: String = String::new();
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// Reads and scans each of RUST_SOURCE_FILES.
+pub fn extract_rust_source_files(repository_dir: &Path) -> Result<Vec<SourceMessage>, String> {
+    let mut messages: Vec<SourceMessage> = vec![];
+    for path in RUST_SOURCE_FILES {
+        let source: String = std::fs::read_to_string(repository_dir.join(path))
+            .map_err(|error| format!("{path}: {error}"))?;
+        let extracted: Vec<ExtractedMessage> =
+            extract_messages(&source).map_err(|message| format!("{path}: {message}"))?;
+        messages.extend(extracted.into_iter().map(|message| SourceMessage {
+            path: path.to_string(),
+            message,
+        }));
+    }
+    Ok(messages)
+}
+
+pub fn repository_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../..")
+}
+
+// Returns the updated contents of po/messages.pot.
+pub fn generate(repository_dir: &Path) -> Result<GeneratedFile, String> {
+    let path: PathBuf = repository_dir.join("po/messages.pot");
+    let pot: String =
+        std::fs::read_to_string(&path).map_err(|error| format!("{}: {error}", path.display()))?;
+    let messages: Vec<SourceMessage> = extract_rust_source_files(repository_dir)?;
+    let contents: String =
+        update_pot(&pot, &messages).map_err(|message| format!("{}: {message}", path.display()))?;
+    Ok(GeneratedFile { path, contents })
+}
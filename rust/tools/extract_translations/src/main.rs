@@ -0,0 +1,48 @@
+// Updates po/messages.pot with the qljs_translatable! strings in the Rust
+// sources.
+//
+// Usage: cargo run -p cpp_vs_rust_extract_translations [-- --check]
+//
+// With --check, verify that po/messages.pot is up to date instead of writing
+// it.
+
+use cpp_vs_rust_compile_translations::GeneratedFile;
+use cpp_vs_rust_extract_translations::*;
+
+fn main() {
+    let check: bool = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => {
+            eprintln!("error: unexpected argument: {arg}");
+            std::process::exit(2);
+        }
+    };
+
+    let file: GeneratedFile = match generate(&repository_dir()) {
+        Ok(file) => file,
+        Err(message) => {
+            eprintln!("error: {message}");
+            std::process::exit(1);
+        }
+    };
+    let result: Result<(), String> = if check {
+        let existing: String = std::fs::read_to_string(&file.path).unwrap_or_default();
+        if existing == file.contents {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} is out of date; run extract_translations",
+                file.path.display()
+            ))
+        }
+    } else {
+        println!("Updating {} ...", file.path.display());
+        std::fs::write(&file.path, &file.contents)
+            .map_err(|error| format!("{}: {error}", file.path.display()))
+    };
+    if let Err(message) = result {
+        eprintln!("error: {message}");
+        std::process::exit(1);
+    }
+}
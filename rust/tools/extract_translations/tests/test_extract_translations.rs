@@ -0,0 +1,296 @@
+use cpp_vs_rust_compile_translations::GeneratedFile;
+use cpp_vs_rust_extract_translations::*;
+
+fn message(msgid: &str, line: usize) -> ExtractedMessage {
+    ExtractedMessage {
+        msgid: msgid.to_string(),
+        line,
+        translator_comments: vec![],
+    }
+}
+
+fn source_message(path: &str, message: ExtractedMessage) -> SourceMessage {
+    SourceMessage {
+        path: path.to_string(),
+        message,
+    }
+}
+
+const POT_HEADER: &str = "# header comment\n\
+                          msgid \"\"\n\
+                          msgstr \"\"\n\
+                          \"Content-Type: text/plain; charset=UTF-8\\n\"\n";
+
+#[test]
+fn extract_invocations_with_line_numbers() {
+    assert_eq!(
+        extract_messages(
+            "use cpp_vs_rust_i18n::qljs_translatable;\n\
+             \n\
+             #[qljs_diagnostic(\n\
+             \x20   \"E0005\", DiagnosticSeverity::Error,\n\
+             \x20   (qljs_translatable!(\"BigInt literal contains decimal point\"), where_),\n\
+             )]\n\
+             fn f() -> TranslatableMessage { qljs_translatable! ( \"'for' loop\" , ) }\n"
+        ),
+        Ok(vec![
+            message("BigInt literal contains decimal point", 5),
+            message("'for' loop", 7),
+        ]),
+    );
+}
+
+#[test]
+fn extract_invocations_in_macro_arguments() {
+    assert_eq!(
+        extract_messages(
+            "fn f() {\n\
+             \x20   assert_eq!(translate(qljs_translatable!(\"nested\")), \"x\");\n\
+             \x20   vec![cpp_vs_rust_i18n::qljs_translatable![\"path\"]];\n\
+             }\n"
+        ),
+        Ok(vec![message("nested", 2), message("path", 3)]),
+    );
+}
+
+#[test]
+fn extract_ignores_comments_and_string_literals() {
+    assert_eq!(
+        extract_messages(
+            "// qljs_translatable!(\"in line comment\")\n\
+             /* qljs_translatable!(\"in block comment\") /* nested */ */\n\
+             const S: &str = \"qljs_translatable!(\\\"in string\\\")\";\n\
+             const R: &str = r#\"qljs_translatable!(\"in raw string\")\"#;\n\
+             fn f<'code>() { let c = '\"'; let l: &'code str = qljs_translatable!(\"real\"); }\n"
+        ),
+        Ok(vec![message("real", 5)]),
+    );
+}
+
+#[test]
+fn extract_decodes_escape_sequences() {
+    assert_eq!(
+        extract_messages(
+            "qljs_translatable!(\"unexpected '\\\\' in identifier\");\n\
+             qljs_translatable!(\"\\\"quoted\\\" \\u{e9}\\x41\\t\");\n\
+             qljs_translatable!(r\"raw \\ string\");\n\
+             qljs_translatable!(\"continued \\\n\
+             \x20   line\");\n"
+        ),
+        Ok(vec![
+            message("unexpected '\\' in identifier", 1),
+            message("\"quoted\" \u{e9}A\t", 2),
+            message("raw \\ string", 3),
+            message("continued line", 4),
+        ]),
+    );
+}
+
+#[test]
+fn extract_translator_comments_directly_above_invocation() {
+    let messages: Vec<ExtractedMessage> = extract_messages(
+        "// Not for translators.\n\
+         // TRANSLATORS: {1} is a keyword,\n\
+         // such as 'let'.\n\
+         qljs_translatable!(\"expected {1}\");\n\
+         \n\
+         // TRANSLATORS: separated by a blank line.\n\
+         \n\
+         qljs_translatable!(\"no comment\");\n\
+         // Not for translators either.\n\
+         qljs_translatable!(\"still no comment\");\n\
+         const X: (TranslatableMessage, i32) =\n\
+         \x20   (qljs_translatable!(\"trailing\"), 0); // TRANSLATORS: not alone on its line.\n\
+         qljs_translatable!(\"after trailing comment\");\n",
+    )
+    .unwrap();
+    assert_eq!(
+        messages
+            .iter()
+            .map(|message| (message.msgid.as_str(), message.translator_comments.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "expected {1}",
+                vec![
+                    "TRANSLATORS: {1} is a keyword,".to_string(),
+                    "such as 'let'.".to_string(),
+                ],
+            ),
+            ("no comment", vec![]),
+            ("still no comment", vec![]),
+            ("trailing", vec![]),
+            ("after trailing comment", vec![]),
+        ],
+    );
+}
+
+#[test]
+fn extract_rejects_non_literal_argument() {
+    assert_eq!(
+        extract_messages("\n\nqljs_translatable!(MESSAGE);\n"),
+        Err("line 3: qljs_translatable!: expected a string literal".to_string()),
+    );
+    assert_eq!(
+        extract_messages("qljs_translatable!(\"a\" \"b\");\n"),
+        Err("line 1: qljs_translatable!: expected ')' after string literal".to_string()),
+    );
+}
+
+#[test]
+fn extract_rejects_unclosed_string_literal() {
+    assert_eq!(
+        extract_messages("\nqljs_translatable!(\"oops);\n"),
+        Err("line 2: cannot parse string into token stream".to_string()),
+    );
+}
+
+#[test]
+fn update_pot_adds_rust_references_to_existing_entries() {
+    let pot: String = format!(
+        "{POT_HEADER}\n\
+         #: cpp/src/quick-lint-js/fe/diagnostic-types.h\n\
+         msgid \"unclosed string literal\"\n\
+         msgstr \"\"\n\
+         \n\
+         #: cpp/test/test-diagnostic-formatter.cpp\n\
+         msgid \"C++ only\"\n\
+         msgstr \"\"\n"
+    );
+    let updated: String = update_pot(
+        &pot,
+        &[
+            source_message(
+                "rust/libs/fe/src/diagnostic_types.rs",
+                message("unclosed string literal", 197),
+            ),
+            source_message(
+                "rust/libs/fe/src/diagnostic_types.rs",
+                message("unclosed string literal", 205),
+            ),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        updated,
+        format!(
+            "{POT_HEADER}\n\
+             #: cpp/src/quick-lint-js/fe/diagnostic-types.h\n\
+             #: rust/libs/fe/src/diagnostic_types.rs:197\n\
+             #: rust/libs/fe/src/diagnostic_types.rs:205\n\
+             msgid \"unclosed string literal\"\n\
+             msgstr \"\"\n\
+             \n\
+             #: cpp/test/test-diagnostic-formatter.cpp\n\
+             msgid \"C++ only\"\n\
+             msgstr \"\"\n"
+        ),
+    );
+}
+
+#[test]
+fn update_pot_appends_new_entries_with_translator_comments() {
+    let updated: String = update_pot(
+        POT_HEADER,
+        &[source_message(
+            "rust/libs/fe/src/diagnostic_types.rs",
+            ExtractedMessage {
+                msgid: "identifier looks like '{1}'".to_string(),
+                line: 368,
+                translator_comments: vec!["TRANSLATORS: {1} is ASCII.".to_string()],
+            },
+        )],
+    )
+    .unwrap();
+    assert_eq!(
+        updated,
+        format!(
+            "{POT_HEADER}\n\
+             #. TRANSLATORS: {{1}} is ASCII.\n\
+             #: rust/libs/fe/src/diagnostic_types.rs:368\n\
+             msgid \"identifier looks like '{{1}}'\"\n\
+             msgstr \"\"\n"
+        ),
+    );
+}
+
+#[test]
+fn update_pot_removes_stale_rust_entries_and_references() {
+    let pot: String = format!(
+        "{POT_HEADER}\n\
+         #. TRANSLATORS: stale comment.\n\
+         #: cpp/src/quick-lint-js/fe/diagnostic-types.h\n\
+         #: rust/libs/fe/src/diagnostic_types.rs:11\n\
+         msgid \"shared\"\n\
+         msgstr \"\"\n\
+         \n\
+         #: rust/libs/fe/src/diagnostic_types.rs:19\n\
+         msgid \"deleted from Rust\"\n\
+         msgstr \"\"\n"
+    );
+    assert_eq!(
+        update_pot(&pot, &[]).unwrap(),
+        format!(
+            "{POT_HEADER}\n\
+             #: cpp/src/quick-lint-js/fe/diagnostic-types.h\n\
+             msgid \"shared\"\n\
+             msgstr \"\"\n"
+        ),
+    );
+}
+
+#[test]
+fn update_pot_is_idempotent() {
+    let messages: Vec<SourceMessage> = vec![
+        source_message("rust/a.rs", message("shared", 1)),
+        source_message("rust/a.rs", message("new", 2)),
+        source_message("rust/b.rs", message("shared", 3)),
+    ];
+    let pot: String = format!(
+        "{POT_HEADER}\n\
+         #: cpp/a.cpp\n\
+         msgid \"shared\"\n\
+         msgstr \"\"\n"
+    );
+    let once: String = update_pot(&pot, &messages).unwrap();
+    assert_eq!(update_pot(&once, &messages).unwrap(), once);
+    assert!(once.contains("#: cpp/a.cpp rust/a.rs:1 rust/b.rs:3\nmsgid \"shared\"\n"));
+}
+
+#[test]
+fn long_strings_are_wrapped_like_xgettext() {
+    let mut out: String = String::new();
+    write_po_string(
+        &mut out,
+        "msgid",
+        "legacy octal escape sequence {0} is deprecated; use a hex escape sequence instead",
+    );
+    assert_eq!(
+        out,
+        "msgid \"\"\n\
+         \"legacy octal escape sequence {0} is deprecated; use a hex escape sequence \"\n\
+         \"instead\"\n",
+    );
+
+    let mut out: String = String::new();
+    write_po_string(&mut out, "msgid", "first\nsecond \"quoted\"");
+    assert_eq!(
+        out,
+        "msgid \"\"\n\
+         \"first\\n\"\n\
+         \"second \\\"quoted\\\"\"\n",
+    );
+}
+
+#[test]
+fn messages_pot_is_up_to_date() {
+    let file: GeneratedFile =
+        generate(&repository_dir()).unwrap_or_else(|message| panic!("{message}"));
+    let existing: String = std::fs::read_to_string(&file.path)
+        .unwrap_or_else(|error| panic!("{}: {error}", file.path.display()));
+    assert!(
+        existing == file.contents,
+        "{} is out of date; run: cargo run -p cpp_vs_rust_extract_translations",
+        file.path.display()
+    );
+}
@@ -0,0 +1,859 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+// A declaration in rust/libs/c_api/src/c_api.rs which is part of the C API.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Item {
+    // The '//' comment lines before the item, without the '//'.
+    pub comment: Vec<String>,
+    pub kind: ItemKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ItemKind {
+    // A pub struct which is not #[repr(C)]. C code only sees pointers to it.
+    Opaque {
+        name: String,
+    },
+    // A #[repr(C)] pub struct.
+    Struct {
+        name: String,
+        fields: Vec<Field>,
+    },
+    // A #[repr(C)] pub enum.
+    Enum {
+        name: String,
+        variants: Vec<Variant>,
+    },
+    Alias {
+        name: String,
+        type_: RustType,
+    },
+    Const {
+        name: String,
+        type_: RustType,
+        value: String,
+    },
+    // A #[no_mangle] pub extern "C" fn.
+    Function {
+        name: String,
+        params: Vec<Param>,
+        return_type: Option<RustType>,
+    },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    pub comment: Vec<String>,
+    pub name: String,
+    pub type_: RustType,
+    // A '//' comment on the same line as the field.
+    pub trailing_comment: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Variant {
+    pub comment: Vec<String>,
+    pub name: String,
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub type_: RustType,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RustType {
+    // The last segment of the type's path (e.g. "c_int" for
+    // "std::ffi::c_int").
+    Named(String),
+    Pointer {
+        mutable: bool,
+        pointee: Box<RustType>,
+    },
+    Array {
+        element: Box<RustType>,
+        length: String,
+    },
+    Function {
+        params: Vec<Param>,
+        return_type: Option<Box<RustType>>,
+    },
+}
+
+// Rust types which have a C equivalent.
+//
+// u8 is mapped to char because the C API uses u8 pointers for UTF-8 strings.
+const BUILTIN_TYPES: &[(&str, &str)] = &[
+    ("bool", "bool"),
+    ("c_char", "char"),
+    ("c_int", "int"),
+    ("c_size_t", "size_t"),
+    ("c_uint", "unsigned int"),
+    ("c_void", "void"),
+    ("i32", "int32_t"),
+    ("i64", "int64_t"),
+    ("u16", "uint16_t"),
+    ("u32", "uint32_t"),
+    ("u64", "uint64_t"),
+    ("u8", "char"),
+    ("usize", "size_t"),
+];
+
+fn builtin_c_type(rust_name: &str) -> Option<&'static str> {
+    BUILTIN_TYPES
+        .iter()
+        .find(|(rust, _)| *rust == rust_name)
+        .map(|(_, c)| *c)
+}
+
+// Parses the C API's declarations from the source code of c_api.rs.
+//
+// Only top-level pub items are considered. Function bodies and impl blocks
+// are skipped. The parser relies on the source being formatted by rustfmt
+// (e.g. top-level items start in the first column).
+pub fn parse_c_api(source: &str) -> Result<Vec<Item>, String> {
+    let mut items: Vec<Item> = vec![];
+    let mut comment: Vec<String> = vec![];
+    let mut attributes: Vec<String> = vec![];
+    let mut lines = source.lines().enumerate();
+    while let Some((line_index, line)) = lines.next() {
+        if line.trim().is_empty() {
+            comment.clear();
+            attributes.clear();
+            continue;
+        }
+        if let Some(comment_line) = parse_comment_line(line) {
+            comment.push(comment_line);
+            continue;
+        }
+        if line.starts_with("#[") {
+            attributes.push(line.to_string());
+            continue;
+        }
+
+        let mut text: String = line.to_string();
+        while !is_item_complete(&text) {
+            match lines.next() {
+                Some((_, line)) => {
+                    text.push('\n');
+                    text.push_str(line);
+                }
+                None => return Err(format!("line {}: unterminated item", line_index + 1)),
+            }
+        }
+        let kind: Option<ItemKind> = parse_item(&text, &attributes)
+            .map_err(|message: String| format!("line {}: {message}", line_index + 1))?;
+        if let Some(kind) = kind {
+            items.push(Item {
+                comment: std::mem::take(&mut comment),
+                kind,
+            });
+        }
+        comment.clear();
+        attributes.clear();
+    }
+    Ok(items)
+}
+
+fn parse_comment_line(line: &str) -> Option<String> {
+    let comment: &str = line.trim().strip_prefix("//")?;
+    Some(comment.strip_prefix(' ').unwrap_or(comment).to_string())
+}
+
+// Returns true if text ends with a ';' or a '}' outside of any brackets.
+fn is_item_complete(text: &str) -> bool {
+    let mut depth: i32 = 0;
+    let mut complete: bool = false;
+    for line in text.lines() {
+        let mut in_string: bool = false;
+        let mut escaped: bool = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '"' {
+                    in_string = false;
+                }
+                continue;
+            }
+            match c {
+                '/' if chars.peek() == Some(&'/') => break,
+                '"' => in_string = true,
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' => depth -= 1,
+                '}' => {
+                    depth -= 1;
+                    complete = depth == 0;
+                }
+                ';' => complete = depth == 0,
+                c if !c.is_whitespace() => complete = false,
+                _ => {}
+            }
+        }
+    }
+    complete
+}
+
+fn parse_item(text: &str, attributes: &[String]) -> Result<Option<ItemKind>, String> {
+    let Some(text) = text.strip_prefix("pub ") else {
+        return Ok(None);
+    };
+    let is_repr_c: bool = attributes.iter().any(|a| a == "#[repr(C)]");
+    let is_no_mangle: bool = attributes.iter().any(|a| a == "#[no_mangle]");
+
+    if let Some(rest) = text.strip_prefix("struct ") {
+        let (name, body) = split_name_and_body(rest)?;
+        if !is_repr_c {
+            return Ok(Some(ItemKind::Opaque { name }));
+        }
+        return Ok(Some(ItemKind::Struct {
+            name,
+            fields: parse_fields(body)?,
+        }));
+    }
+    if let Some(rest) = text.strip_prefix("enum ") {
+        let (name, body) = split_name_and_body(rest)?;
+        if !is_repr_c {
+            return Err(format!("enum {name} is not #[repr(C)]"));
+        }
+        return Ok(Some(ItemKind::Enum {
+            name,
+            variants: parse_variants(body)?,
+        }));
+    }
+    if let Some(rest) = text.strip_prefix("type ") {
+        let (name, type_) = rest
+            .trim_end()
+            .strip_suffix(';')
+            .and_then(|rest: &str| rest.split_once('='))
+            .ok_or_else(|| format!("malformed type alias: {text}"))?;
+        let name: &str = name.trim();
+        if builtin_c_type(name).is_some() {
+            // For example, c_size_t. Uses are written as the C type.
+            return Ok(None);
+        }
+        return Ok(Some(ItemKind::Alias {
+            name: name.to_string(),
+            type_: parse_type(type_)?,
+        }));
+    }
+    if let Some(rest) = text.strip_prefix("const ") {
+        let (declaration, value) = rest
+            .trim_end()
+            .strip_suffix(';')
+            .and_then(|rest: &str| rest.split_once('='))
+            .ok_or_else(|| format!("malformed const: {text}"))?;
+        let (name, type_) = declaration
+            .split_once(':')
+            .ok_or_else(|| format!("malformed const: {text}"))?;
+        return Ok(Some(ItemKind::Const {
+            name: name.trim().to_string(),
+            type_: parse_type(type_)?,
+            value: value.trim().to_string(),
+        }));
+    }
+    let extern_c_fn: Option<&str> = text
+        .strip_prefix("unsafe extern \"C\" fn ")
+        .or_else(|| text.strip_prefix("extern \"C\" fn "));
+    if let Some(rest) = extern_c_fn {
+        let signature: &str = &rest[..find_at_depth_zero(rest, '{').unwrap_or(rest.len())];
+        let (name, params, return_type) = parse_signature(signature)?;
+        if !is_no_mangle {
+            return Err(format!("extern \"C\" fn {name} is missing #[no_mangle]"));
+        }
+        return Ok(Some(ItemKind::Function {
+            name,
+            params,
+            return_type,
+        }));
+    }
+    if text.starts_with("fn ") || text.starts_with("unsafe fn ") || text.starts_with("use ") {
+        // Not part of the C API.
+        return Ok(None);
+    }
+    Err(format!(
+        "unsupported item: {}",
+        text.lines().next().unwrap()
+    ))
+}
+
+// Splits "Name {\n...\n}" into "Name" and the text between the braces.
+fn split_name_and_body(text: &str) -> Result<(String, &str), String> {
+    let (name, rest) = text
+        .split_once('{')
+        .ok_or_else(|| format!("expected '{{' after {text}"))?;
+    let body: &str = rest
+        .trim_end()
+        .strip_suffix('}')
+        .ok_or_else(|| format!("expected '}}' after {name}"))?;
+    Ok((name.trim().to_string(), body))
+}
+
+fn parse_fields(body: &str) -> Result<Vec<Field>, String> {
+    let mut fields: Vec<Field> = vec![];
+    let mut comment: Vec<String> = vec![];
+    for line in body.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(comment_line) = parse_comment_line(line) {
+            comment.push(comment_line);
+            continue;
+        }
+        let (declaration, trailing_comment) = match line.split_once("//") {
+            Some((declaration, trailing_comment)) => {
+                (declaration, Some(trailing_comment.trim().to_string()))
+            }
+            None => (line, None),
+        };
+        let declaration: &str = declaration.trim();
+        let declaration: &str = declaration.strip_suffix(',').unwrap_or(declaration);
+        let declaration: &str = declaration.strip_prefix("pub ").unwrap_or(declaration);
+        let (name, type_) = declaration
+            .split_once(':')
+            .ok_or_else(|| format!("malformed field: {}", line.trim()))?;
+        fields.push(Field {
+            comment: std::mem::take(&mut comment),
+            name: name.trim().to_string(),
+            type_: parse_type(type_)?,
+            trailing_comment,
+        });
+    }
+    Ok(fields)
+}
+
+fn parse_variants(body: &str) -> Result<Vec<Variant>, String> {
+    let mut variants: Vec<Variant> = vec![];
+    let mut comment: Vec<String> = vec![];
+    for line in body.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(comment_line) = parse_comment_line(line) {
+            comment.push(comment_line);
+            continue;
+        }
+        let declaration: &str = line.trim();
+        let declaration: &str = declaration.strip_suffix(',').unwrap_or(declaration);
+        let (name, value) = match declaration.split_once('=') {
+            Some((name, value)) => (name, Some(value.trim().to_string())),
+            None => (declaration, None),
+        };
+        let name: &str = name.trim();
+        if !is_identifier(name) {
+            return Err(format!("malformed enum variant: {}", line.trim()));
+        }
+        variants.push(Variant {
+            comment: std::mem::take(&mut comment),
+            name: name.to_string(),
+            value,
+        });
+    }
+    Ok(variants)
+}
+
+// Parses "name(params) -> ReturnType".
+fn parse_signature(text: &str) -> Result<(String, Vec<Param>, Option<RustType>), String> {
+    let (name, rest) = text
+        .split_once('(')
+        .ok_or_else(|| format!("expected '(' in {text}"))?;
+    let (params, return_type) = parse_params_and_return_type(rest)?;
+    Ok((name.trim().to_string(), params, return_type))
+}
+
+// Parses "params) -> ReturnType", where the '(' before params was already
+// parsed.
+fn parse_params_and_return_type(text: &str) -> Result<(Vec<Param>, Option<RustType>), String> {
+    let params_end: usize =
+        find_at_depth_zero(text, ')').ok_or_else(|| format!("expected ')' in {text}"))?;
+    let mut params: Vec<Param> = vec![];
+    for param in split_at_depth_zero(&text[..params_end], ',') {
+        let param: &str = param.trim();
+        if param.is_empty() {
+            // Trailing comma.
+            continue;
+        }
+        let (name, type_) = param
+            .split_once(':')
+            .ok_or_else(|| format!("malformed parameter: {param}"))?;
+        params.push(Param {
+            name: name.trim().to_string(),
+            type_: parse_type(type_)?,
+        });
+    }
+    let rest: &str = text[params_end + 1..].trim();
+    let return_type: Option<RustType> = if rest.is_empty() {
+        None
+    } else {
+        let return_type: &str = rest
+            .strip_prefix("->")
+            .ok_or_else(|| format!("unexpected text after parameters: {rest}"))?;
+        Some(parse_type(return_type)?)
+    };
+    Ok((params, return_type))
+}
+
+pub fn parse_type(text: &str) -> Result<RustType, String> {
+    let text: &str = text.trim();
+    if let Some(pointee) = text.strip_prefix("*const ") {
+        return Ok(RustType::Pointer {
+            mutable: false,
+            pointee: Box::new(parse_type(pointee)?),
+        });
+    }
+    if let Some(pointee) = text.strip_prefix("*mut ") {
+        return Ok(RustType::Pointer {
+            mutable: true,
+            pointee: Box::new(parse_type(pointee)?),
+        });
+    }
+    if let Some(array) = text
+        .strip_prefix('[')
+        .and_then(|a: &str| a.strip_suffix(']'))
+    {
+        let (element, length) = array
+            .rsplit_once(';')
+            .ok_or_else(|| format!("malformed array type: {text}"))?;
+        return Ok(RustType::Array {
+            element: Box::new(parse_type(element)?),
+            length: length.trim().to_string(),
+        });
+    }
+    let function: Option<&str> = text
+        .strip_prefix("unsafe extern \"C\" fn(")
+        .or_else(|| text.strip_prefix("extern \"C\" fn("));
+    if let Some(rest) = function {
+        let (params, return_type) = parse_params_and_return_type(rest)?;
+        return Ok(RustType::Function {
+            params,
+            return_type: return_type.map(Box::new),
+        });
+    }
+    let name: &str = text.rsplit("::").next().unwrap();
+    if !is_identifier(name) {
+        return Err(format!("unsupported type: {text}"));
+    }
+    Ok(RustType::Named(name.to_string()))
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty()
+        && !text.starts_with(|c: char| c.is_ascii_digit())
+        && text
+            .chars()
+            .all(|c: char| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn find_at_depth_zero(text: &str, needle: char) -> Option<usize> {
+    let mut depth: i32 = 0;
+    for (i, c) in text.char_indices() {
+        if c == needle && depth == 0 {
+            return Some(i);
+        }
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn split_at_depth_zero(text: &str, separator: char) -> Vec<&str> {
+    let mut parts: Vec<&str> = vec![];
+    let mut rest: &str = text;
+    while let Some(i) = find_at_depth_zero(rest, separator) {
+        parts.push(&rest[..i]);
+        rest = &rest[i + separator.len_utf8()..];
+    }
+    parts.push(rest);
+    parts
+}
+
+// Converts a Rust type name to a C type name. For example,
+// "QLJSWebDemoDiagnosticV2" becomes "qljs_web_demo_diagnostic_v2".
+pub fn c_type_name(rust_name: &str) -> String {
+    let chars: Vec<char> = rust_name.chars().collect();
+    let mut result: String = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev: char = chars[i - 1];
+            let next_is_lowercase: bool = chars.get(i + 1).is_some_and(|c| c.is_ascii_lowercase());
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lowercase)
+            {
+                result.push('_');
+            }
+        }
+        result.push(c.to_ascii_lowercase());
+    }
+    result
+}
+
+// The C names of the items, keyed by Rust name.
+struct CNames {
+    names: HashMap<String, String>,
+    // Aliases of C integer types. Constants of these types are written as enum
+    // members.
+    integer_aliases: Vec<String>,
+}
+
+impl CNames {
+    fn new(items: &[Item]) -> CNames {
+        let mut names: HashMap<String, String> = HashMap::new();
+        let mut integer_aliases: Vec<String> = vec![];
+        for item in items {
+            match &item.kind {
+                ItemKind::Opaque { name } | ItemKind::Struct { name, .. } => {
+                    names.insert(name.clone(), c_type_name(name));
+                }
+                ItemKind::Enum { name, variants } => {
+                    let c_name: String = c_type_name(name);
+                    for variant in variants {
+                        names.insert(
+                            format!("{name}::{}", variant.name),
+                            format!("{c_name}_{}", c_type_name(&variant.name)),
+                        );
+                    }
+                    names.insert(name.clone(), c_name);
+                }
+                ItemKind::Alias { name, type_ } => {
+                    if let RustType::Named(_) = type_ {
+                        integer_aliases.push(name.clone());
+                    }
+                    names.insert(name.clone(), c_type_name(name));
+                }
+                ItemKind::Const { name, type_, .. } => {
+                    let is_enum_member: bool = matches!(
+                        type_,
+                        RustType::Named(type_name) if integer_aliases.contains(type_name)
+                    );
+                    if is_enum_member {
+                        names.insert(name.clone(), name.to_ascii_lowercase());
+                    }
+                }
+                ItemKind::Function { .. } => {}
+            }
+        }
+        CNames {
+            names,
+            integer_aliases,
+        }
+    }
+
+    fn name(&self, rust_name: &str) -> String {
+        match self.names.get(rust_name) {
+            Some(name) => name.clone(),
+            None => rust_name.to_string(),
+        }
+    }
+
+    // If item is a constant which is written as an enum member, returns the
+    // name of the constant's type.
+    fn enum_member_const_type<'item>(&self, item: &'item Item) -> Option<&'item str> {
+        match &item.kind {
+            ItemKind::Const {
+                type_: RustType::Named(type_name),
+                ..
+            } if self.integer_aliases.contains(type_name) => Some(type_name),
+            _ => None,
+        }
+    }
+
+    // Returns the C spelling of type_, such as "const char*".
+    fn type_(&self, type_: &RustType) -> Result<String, String> {
+        match type_ {
+            RustType::Named(name) => match builtin_c_type(name) {
+                Some(c_name) => Ok(c_name.to_string()),
+                None => match self.names.get(name) {
+                    Some(c_name) => Ok(c_name.clone()),
+                    None => Err(format!("type {name} has no C equivalent")),
+                },
+            },
+            RustType::Pointer { mutable, pointee } => {
+                let pointee_c: String = self.type_(pointee)?;
+                Ok(match (mutable, &**pointee) {
+                    (true, _) => format!("{pointee_c}*"),
+                    (false, RustType::Pointer { .. }) => format!("{pointee_c} const*"),
+                    (false, _) => format!("const {pointee_c}*"),
+                })
+            }
+            RustType::Array { .. } | RustType::Function { .. } => Err(format!(
+                "type {type_:?} can only be used in a field or type alias"
+            )),
+        }
+    }
+
+    // Returns a C declaration of name with type type_, such as
+    // "char code[6]".
+    fn declaration(&self, type_: &RustType, name: &str) -> Result<String, String> {
+        match type_ {
+            RustType::Array { element, length } => {
+                Ok(format!("{} {name}[{length}]", self.type_(element)?))
+            }
+            _ => Ok(format!("{} {name}", self.type_(type_)?)),
+        }
+    }
+
+    fn return_type(&self, return_type: Option<&RustType>) -> Result<String, String> {
+        match return_type {
+            Some(type_) => self.type_(type_),
+            None => Ok(String::from("void")),
+        }
+    }
+
+    fn params(&self, params: &[Param]) -> Result<Vec<String>, String> {
+        params
+            .iter()
+            .map(|param: &Param| self.declaration(&param.type_, &param.name))
+            .collect()
+    }
+
+    // Replaces Rust names in a comment with their C names.
+    fn comment(&self, comment: &str) -> String {
+        let mut result: String = String::new();
+        let mut word: String = String::new();
+        for c in comment.chars().chain(std::iter::once('\0')) {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                word.push(c);
+                continue;
+            }
+            result.push_str(&self.name(&word));
+            word.clear();
+            if c != '\0' {
+                result.push(c);
+            }
+        }
+        result
+    }
+}
+
+const MAX_LINE_LENGTH: usize = 80;
+
+// Formats "prefix(params);", wrapping long lines like clang-format.
+fn format_call_like(prefix: &str, params: &[String]) -> String {
+    let params: Vec<String> = if params.is_empty() {
+        vec![String::from("void")]
+    } else {
+        params.to_vec()
+    };
+    let one_line: String = format!("{prefix}({});", params.join(", "));
+    if one_line.len() <= MAX_LINE_LENGTH {
+        return one_line;
+    }
+
+    let align: String = " ".repeat(prefix.len() + 1);
+    let aligned: String = format!("{prefix}({});", params.join(&format!(",\n{align}")));
+    if aligned
+        .lines()
+        .all(|line: &str| line.len() <= MAX_LINE_LENGTH)
+    {
+        return aligned;
+    }
+
+    let indented_one_line: String = format!("{prefix}(\n    {});", params.join(", "));
+    if indented_one_line
+        .lines()
+        .all(|line: &str| line.len() <= MAX_LINE_LENGTH)
+    {
+        return indented_one_line;
+    }
+    format!("{prefix}(\n    {});", params.join(",\n    "))
+}
+
+fn write_comment(out: &mut String, comment: &[String], indentation: &str, names: &CNames) {
+    for line in comment {
+        if line.is_empty() {
+            out.push_str(&format!("{indentation}//\n"));
+        } else {
+            out.push_str(&format!("{indentation}// {}\n", names.comment(line)));
+        }
+    }
+}
+
+// Returns the contents of rust/libs/c_api/include/quick-lint-js/c-api.h.
+pub fn write_c_header(items: &[Item]) -> Result<String, String> {
+    let names: CNames = CNames::new(items);
+    let mut out: String = String::new();
+    out.push_str(
+        "\
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+// See end of file for extended copyright information.
+
+// This file was generated by rust/tools/generate_c_api_header from
+// rust/libs/c_api/src/c_api.rs.
+
+#ifndef QUICK_LINT_JS_C_API_H
+#define QUICK_LINT_JS_C_API_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#if defined(__cplusplus)
+extern \"C\" {
+#endif
+",
+    );
+
+    let mut i: usize = 0;
+    while i < items.len() {
+        let item: &Item = &items[i];
+        out.push('\n');
+
+        if let Some(alias_name) = names.enum_member_const_type(item) {
+            // Write consecutive constants of the same type as one enum.
+            out.push_str("enum {\n");
+            let mut first: bool = true;
+            while let Some(item) = items.get(i) {
+                if names.enum_member_const_type(item) != Some(alias_name) {
+                    break;
+                }
+                let ItemKind::Const { name, value, .. } = &item.kind else {
+                    unreachable!();
+                };
+                if !first {
+                    out.push('\n');
+                }
+                first = false;
+                write_comment(&mut out, &item.comment, "  ", &names);
+                out.push_str(&format!("  {} = {value},\n", names.name(name)));
+                i += 1;
+            }
+            out.push_str("};\n");
+            continue;
+        }
+
+        write_comment(&mut out, &item.comment, "", &names);
+        match &item.kind {
+            ItemKind::Opaque { name } => {
+                let c_name: String = names.name(name);
+                out.push_str(&format!("typedef struct {c_name} {c_name};\n"));
+            }
+            ItemKind::Struct { name, fields } => {
+                let c_name: String = names.name(name);
+                out.push_str(&format!("typedef struct {c_name} {{\n"));
+                for field in fields {
+                    write_comment(&mut out, &field.comment, "  ", &names);
+                    out.push_str(&format!(
+                        "  {};",
+                        names.declaration(&field.type_, &field.name)?
+                    ));
+                    if let Some(trailing_comment) = &field.trailing_comment {
+                        out.push_str(&format!("  // {}", names.comment(trailing_comment)));
+                    }
+                    out.push('\n');
+                }
+                out.push_str(&format!("}} {c_name};\n"));
+            }
+            ItemKind::Enum { name, variants } => {
+                let c_name: String = names.name(name);
+                out.push_str(&format!("typedef enum {c_name} {{\n"));
+                for variant in variants {
+                    write_comment(&mut out, &variant.comment, "  ", &names);
+                    let variant_c_name: String = names.name(&format!("{name}::{}", variant.name));
+                    match &variant.value {
+                        Some(value) => out.push_str(&format!("  {variant_c_name} = {value},\n")),
+                        None => out.push_str(&format!("  {variant_c_name},\n")),
+                    }
+                }
+                out.push_str(&format!("}} {c_name};\n"));
+            }
+            ItemKind::Alias { name, type_ } => {
+                let c_name: String = names.name(name);
+                match type_ {
+                    RustType::Function {
+                        params,
+                        return_type,
+                    } => {
+                        let return_c_type: String = names.return_type(return_type.as_deref())?;
+                        out.push_str(&format_call_like(
+                            &format!("typedef {return_c_type} (*{c_name})"),
+                            &names.params(params)?,
+                        ));
+                        out.push('\n');
+                    }
+                    _ => {
+                        out.push_str(&format!(
+                            "typedef {};\n",
+                            names.declaration(type_, &c_name)?
+                        ));
+                    }
+                }
+            }
+            ItemKind::Const { name, value, .. } => {
+                if value.contains(' ') {
+                    out.push_str(&format!("#define {name} ({value})\n"));
+                } else {
+                    out.push_str(&format!("#define {name} {value}\n"));
+                }
+            }
+            ItemKind::Function {
+                name,
+                params,
+                return_type,
+            } => {
+                let return_c_type: String = names.return_type(return_type.as_ref())?;
+                out.push_str(&format_call_like(
+                    &format!("{return_c_type} {name}"),
+                    &names.params(params)?,
+                ));
+                out.push('\n');
+            }
+        }
+        i += 1;
+    }
+
+    out.push_str(
+        "
+#if defined(__cplusplus)
+}
+#endif
+
+#endif
+
+// quick-lint-js finds bugs in JavaScript programs.
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+//
+// This file is part of quick-lint-js.
+//
+// quick-lint-js is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// quick-lint-js is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with quick-lint-js.  If not, see <https://www.gnu.org/licenses/>.
+",
+    );
+    Ok(out)
+}
+
+pub fn input_path() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../libs/c_api/src/c_api.rs")
+}
+
+pub fn output_path() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../libs/c_api/include/quick-lint-js/c-api.h")
+}
+
+pub fn generate() -> Result<String, String> {
+    let path: std::path::PathBuf = input_path();
+    let source: String = std::fs::read_to_string(&path)
+        .map_err(|error: std::io::Error| format!("{}: {error}", path.display()))?;
+    let items: Vec<Item> =
+        parse_c_api(&source).map_err(|message: String| format!("{}:{message}", path.display()))?;
+    write_c_header(&items)
+}
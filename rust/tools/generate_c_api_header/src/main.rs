@@ -0,0 +1,41 @@
+// Generates rust/libs/c_api/include/quick-lint-js/c-api.h from the
+// declarations in rust/libs/c_api/src/c_api.rs.
+//
+// Usage: cargo run -p cpp_vs_rust_generate_c_api_header [-- --check]
+//
+// With --check, verify that the generated header is up to date instead of
+// writing it.
+
+use cpp_vs_rust_generate_c_api_header::*;
+
+fn main() {
+    let check: bool = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => {
+            eprintln!("error: unexpected argument: {arg}");
+            std::process::exit(2);
+        }
+    };
+
+    let path: std::path::PathBuf = output_path();
+    let result: Result<(), String> = generate().and_then(|generated: String| {
+        if check {
+            let existing: String = std::fs::read_to_string(&path).unwrap_or_default();
+            if existing != generated {
+                return Err(format!(
+                    "{} is out of date; run generate_c_api_header",
+                    path.display()
+                ));
+            }
+            Ok(())
+        } else {
+            println!("Creating {} ...", path.display());
+            std::fs::write(&path, generated).map_err(|error| format!("{}: {error}", path.display()))
+        }
+    });
+    if let Err(message) = result {
+        eprintln!("error: {message}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,318 @@
+use cpp_vs_rust_generate_c_api_header::*;
+
+fn named(name: &str) -> RustType {
+    RustType::Named(name.to_string())
+}
+
+fn const_pointer(pointee: RustType) -> RustType {
+    RustType::Pointer {
+        mutable: false,
+        pointee: Box::new(pointee),
+    }
+}
+
+#[test]
+fn c_type_names_are_snake_case() {
+    assert_eq!(c_type_name("QLJSSeverity"), "qljs_severity");
+    assert_eq!(c_type_name("QLJSWebDemoDocument"), "qljs_web_demo_document");
+    assert_eq!(
+        c_type_name("QLJSWebDemoDiagnosticV2"),
+        "qljs_web_demo_diagnostic_v2"
+    );
+    assert_eq!(c_type_name("Error"), "error");
+}
+
+#[test]
+fn parse_types() {
+    assert_eq!(parse_type("std::ffi::c_int"), Ok(named("c_int")));
+    assert_eq!(parse_type("*const u8"), Ok(const_pointer(named("u8"))));
+    assert_eq!(
+        parse_type("*mut QLJSWebDemoDocument"),
+        Ok(RustType::Pointer {
+            mutable: true,
+            pointee: Box::new(named("QLJSWebDemoDocument")),
+        })
+    );
+    assert_eq!(
+        parse_type("*const *const std::ffi::c_char"),
+        Ok(const_pointer(const_pointer(named("c_char"))))
+    );
+    assert_eq!(
+        parse_type("[std::ffi::c_char; 6]"),
+        Ok(RustType::Array {
+            element: Box::new(named("c_char")),
+            length: "6".to_string(),
+        })
+    );
+    assert!(parse_type("Vec<u8>").is_err());
+    assert!(parse_type("&str").is_err());
+}
+
+#[test]
+fn parse_items_with_comments() {
+    let items: Vec<Item> = parse_c_api(
+        "use crate::thing::*;
+
+// A thing.
+//
+// More about the thing.
+#[repr(C)]
+pub struct Thing {
+    pub code: [std::ffi::c_char; 6], // null-terminated
+    // Counts bytes.
+    pub size: c_size_t,
+}
+
+impl Default for Thing {
+    fn default() -> Self {
+        Thing { code: [0; 6], size: 0 }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub enum Color {
+    Red = 1,
+    // Not red.
+    Blue = 2,
+}
+
+pub struct Handle {
+    thing: Thing,
+}
+",
+    )
+    .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            Item {
+                comment: vec![
+                    "A thing.".to_string(),
+                    "".to_string(),
+                    "More about the thing.".to_string()
+                ],
+                kind: ItemKind::Struct {
+                    name: "Thing".to_string(),
+                    fields: vec![
+                        Field {
+                            comment: vec![],
+                            name: "code".to_string(),
+                            type_: RustType::Array {
+                                element: Box::new(named("c_char")),
+                                length: "6".to_string(),
+                            },
+                            trailing_comment: Some("null-terminated".to_string()),
+                        },
+                        Field {
+                            comment: vec!["Counts bytes.".to_string()],
+                            name: "size".to_string(),
+                            type_: named("c_size_t"),
+                            trailing_comment: None,
+                        },
+                    ],
+                },
+            },
+            Item {
+                comment: vec![],
+                kind: ItemKind::Enum {
+                    name: "Color".to_string(),
+                    variants: vec![
+                        Variant {
+                            comment: vec![],
+                            name: "Red".to_string(),
+                            value: Some("1".to_string()),
+                        },
+                        Variant {
+                            comment: vec!["Not red.".to_string()],
+                            name: "Blue".to_string(),
+                            value: Some("2".to_string()),
+                        },
+                    ],
+                },
+            },
+            Item {
+                comment: vec![],
+                kind: ItemKind::Opaque {
+                    name: "Handle".to_string(),
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_multi_line_functions_and_skip_private_functions() {
+    let items: Vec<Item> = parse_c_api(
+        "// Does the thing.
+#[no_mangle]
+pub unsafe extern \"C\" fn qljs_do_thing(
+    handle: *mut Handle,
+    data: *const std::ffi::c_void,
+    size: c_size_t,
+) -> bool {
+    helper(handle);
+    true
+}
+
+unsafe fn helper(handle: *mut Handle) {}
+
+#[no_mangle]
+pub unsafe extern \"C\" fn qljs_nothing() {}
+",
+    )
+    .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            Item {
+                comment: vec!["Does the thing.".to_string()],
+                kind: ItemKind::Function {
+                    name: "qljs_do_thing".to_string(),
+                    params: vec![
+                        Param {
+                            name: "handle".to_string(),
+                            type_: RustType::Pointer {
+                                mutable: true,
+                                pointee: Box::new(named("Handle")),
+                            },
+                        },
+                        Param {
+                            name: "data".to_string(),
+                            type_: const_pointer(named("c_void")),
+                        },
+                        Param {
+                            name: "size".to_string(),
+                            type_: named("c_size_t"),
+                        },
+                    ],
+                    return_type: Some(named("bool")),
+                },
+            },
+            Item {
+                comment: vec![],
+                kind: ItemKind::Function {
+                    name: "qljs_nothing".to_string(),
+                    params: vec![],
+                    return_type: None,
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn extern_function_without_no_mangle_is_an_error() {
+    let result: Result<Vec<Item>, String> =
+        parse_c_api("pub unsafe extern \"C\" fn qljs_hidden() {}\n");
+    assert!(result.unwrap_err().contains("#[no_mangle]"));
+}
+
+#[test]
+fn write_types_and_functions() {
+    let items: Vec<Item> = parse_c_api(
+        "pub struct Handle {
+    x: i32,
+}
+
+// Flags.
+pub type Options = std::ffi::c_int;
+
+// The first flag.
+pub const OPTIONS_FIRST_BIT: Options = 1 << 0;
+pub const OPTIONS_SECOND_BIT: Options = 1 << 1;
+
+pub const VERSION: u32 = 3;
+
+// Called with Handle.
+pub type Callback = unsafe extern \"C\" fn(user_data: *mut std::ffi::c_void);
+
+#[no_mangle]
+pub unsafe extern \"C\" fn qljs_names() -> *const *const std::ffi::c_char {
+    std::ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern \"C\" fn qljs_use(handle: *mut Handle, options: Options, callback: Callback) {}
+",
+    )
+    .unwrap();
+    let header: String = write_c_header(&items).unwrap();
+    let expected: &str = "
+typedef struct handle handle;
+
+// Flags.
+typedef int options;
+
+enum {
+  // The first flag.
+  options_first_bit = 1 << 0,
+
+  options_second_bit = 1 << 1,
+};
+
+#define VERSION 3
+
+// Called with handle.
+typedef void (*callback)(void* user_data);
+
+const char* const* qljs_names(void);
+
+void qljs_use(handle* handle, options options, callback callback);
+";
+    assert!(
+        header.contains(expected),
+        "expected:\n{expected}\ngot:\n{header}"
+    );
+}
+
+#[test]
+fn write_wraps_long_function_declarations() {
+    let items: Vec<Item> = parse_c_api(
+        "pub struct QLJSWebDemoDocument {}
+
+#[no_mangle]
+pub unsafe extern \"C\" fn qljs_web_demo_set_text(
+    document: *mut QLJSWebDemoDocument,
+    text_utf_8: *const std::ffi::c_void,
+    text_byte_count: c_size_t,
+) {
+}
+",
+    )
+    .unwrap();
+    let header: String = write_c_header(&items).unwrap();
+    let expected: &str = "
+void qljs_web_demo_set_text(qljs_web_demo_document* document,
+                            const void* text_utf_8,
+                            size_t text_byte_count);
+";
+    assert!(
+        header.contains(expected),
+        "expected:\n{expected}\ngot:\n{header}"
+    );
+}
+
+#[test]
+fn write_rejects_types_without_c_equivalent() {
+    let items: Vec<Item> = parse_c_api(
+        "#[no_mangle]
+pub unsafe extern \"C\" fn qljs_bad(s: *const RustOnlyType) {}
+",
+    )
+    .unwrap();
+    assert!(write_c_header(&items).unwrap_err().contains("RustOnlyType"));
+}
+
+#[test]
+fn generated_header_is_up_to_date() {
+    let generated: String = generate().unwrap_or_else(|message| panic!("{message}"));
+    let path: std::path::PathBuf = output_path();
+    let existing: String = std::fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+    assert!(
+        existing == generated,
+        "{} is out of date; run: cargo run -p cpp_vs_rust_generate_c_api_header",
+        path.display()
+    );
+}
@@ -0,0 +1,395 @@
+// Tables for confusable and mixed-script identifier detection
+// (check_confusable_identifier in rust/libs/fe/src/lex.rs).
+
+use crate::*;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub const SCRIPT_SET_WORDS: usize = 3;
+
+// Scripts which match any other script for mixed-script detection.
+const SCRIPTS_MATCHING_ALL: &[&str] = &["Common", "Inherited"];
+
+// Augmented script sets from UTS #39 section 5.1.
+const SCRIPT_AUGMENTATIONS: &[(&str, &[&str])] = &[
+    ("Han", &["Han_with_Bopomofo", "Japanese", "Korean"]),
+    ("Hiragana", &["Japanese"]),
+    ("Katakana", &["Japanese"]),
+    ("Hangul", &["Korean"]),
+    ("Bopomofo", &["Han_with_Bopomofo"]),
+];
+const AUGMENTED_SCRIPTS: &[&str] = &["Han_with_Bopomofo", "Japanese", "Korean"];
+
+// Script_Extensions values read from Scripts.txt, ScriptExtensions.txt, and
+// PropertyValueAliases.txt.
+pub struct ScriptExtensions {
+    // Long names of scripts other than Common and Inherited, ordered by each
+    // script's first code point.
+    pub script_names: Vec<String>,
+    // Indexes into script_names. Code points with a Script_Extensions value of
+    // Common or Inherited (or which are unassigned) are not listed.
+    pub code_point_scripts: BTreeMap<u32, Vec<usize>>,
+}
+
+pub fn load_script_extensions(ucd_dir: &Path) -> Result<ScriptExtensions, String> {
+    let aliases: HashMap<String, String> =
+        parse_script_aliases(&read_ucd_file(&ucd_dir.join("PropertyValueAliases.txt"))?)?;
+    parse_script_extensions(
+        &read_ucd_file(&ucd_dir.join("Scripts.txt"))?,
+        &read_ucd_file(&ucd_dir.join("ScriptExtensions.txt"))?,
+        &aliases,
+    )
+    .map_err(|message| format!("{}: {message}", ucd_dir.display()))
+}
+
+// Parses lines such as:
+//
+//   sc ; Latn                             ; Latin
+//
+// and returns a map from short script names to long script names.
+pub fn parse_script_aliases(text: &str) -> Result<HashMap<String, String>, String> {
+    let mut aliases: HashMap<String, String> = HashMap::new();
+    for (line_index, line) in text.lines().enumerate() {
+        let line: &str = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').map(|field: &str| field.trim()).collect();
+        if fields[0] != "sc" {
+            continue;
+        }
+        if fields.len() < 3 {
+            return Err(format!("line {}: malformed entry: {line}", line_index + 1));
+        }
+        aliases.insert(fields[1].to_string(), fields[2].to_string());
+    }
+    Ok(aliases)
+}
+
+// scripts_text is the contents of Scripts.txt (with long script names).
+// script_extensions_text is the contents of ScriptExtensions.txt (with short
+// script names). aliases maps short script names to long script names.
+pub fn parse_script_extensions(
+    scripts_text: &str,
+    script_extensions_text: &str,
+    aliases: &HashMap<String, String>,
+) -> Result<ScriptExtensions, String> {
+    let mut script_names: Vec<String> = vec![];
+    let mut script_indexes: HashMap<String, usize> = HashMap::new();
+    let mut code_point_scripts: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+
+    let mut scripts: Vec<(u32, u32, &str)> = parse_ucd_ranges(scripts_text)?;
+    scripts.sort_by_key(|&(first, _, _)| first);
+    for (first, last, script) in scripts {
+        if SCRIPTS_MATCHING_ALL.contains(&script) {
+            continue;
+        }
+        let next_index: usize = script_names.len();
+        let index: usize = *script_indexes.entry(script.to_string()).or_insert_with(|| {
+            script_names.push(script.to_string());
+            next_index
+        });
+        for code_point in first..=last {
+            code_point_scripts.insert(code_point, vec![index]);
+        }
+    }
+
+    for (first, last, short_names) in parse_ucd_ranges(script_extensions_text)? {
+        let mut indexes: Vec<usize> = vec![];
+        for short_name in short_names.split_whitespace() {
+            let name: &str = aliases
+                .get(short_name)
+                .ok_or_else(|| format!("unknown script: {short_name}"))?;
+            if SCRIPTS_MATCHING_ALL.contains(&name) {
+                continue;
+            }
+            indexes.push(
+                *script_indexes
+                    .get(name)
+                    .ok_or_else(|| format!("script has no code points: {name}"))?,
+            );
+        }
+        for code_point in first..=last {
+            if indexes.is_empty() {
+                code_point_scripts.remove(&code_point);
+            } else {
+                code_point_scripts.insert(code_point, indexes.clone());
+            }
+        }
+    }
+
+    Ok(ScriptExtensions {
+        script_names,
+        code_point_scripts,
+    })
+}
+
+pub struct ScriptExtensionSet {
+    // Sorted long script names.
+    pub names: Vec<String>,
+    pub bits: [u64; SCRIPT_SET_WORDS],
+}
+
+// A sorted, non-overlapping range of code points which share a
+// ScriptExtensionSet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScriptExtensionRange {
+    pub first: u32,
+    pub last: u32,
+    pub set_index: u16,
+}
+
+pub struct ScriptExtensionTables {
+    pub sets: Vec<ScriptExtensionSet>,
+    pub ranges: Vec<ScriptExtensionRange>,
+}
+
+// Returns the augmented Script_Extensions of identifier characters, except for
+// ASCII letters which are always Latin.
+pub fn build_script_extension_tables(
+    script_extensions: &ScriptExtensions,
+    properties: &IdentifierProperties,
+) -> Result<ScriptExtensionTables, String> {
+    let mut all_names: Vec<&str> = script_extensions
+        .script_names
+        .iter()
+        .map(|name: &String| name.as_str())
+        .collect();
+    all_names.extend_from_slice(AUGMENTED_SCRIPTS);
+    if all_names.len() > SCRIPT_SET_WORDS * 64 {
+        return Err(format!(
+            "too many scripts ({}); increase SCRIPT_SET_WORDS",
+            all_names.len()
+        ));
+    }
+
+    let mut sets: Vec<ScriptExtensionSet> = vec![];
+    let mut set_key_to_index: HashMap<Vec<String>, u16> = HashMap::new();
+    let mut set_index_for_names = |names: Vec<&str>| -> u16 {
+        let mut names: Vec<String> = names.iter().map(|name: &&str| name.to_string()).collect();
+        names.sort();
+        names.dedup();
+        let next_index: usize = sets.len();
+        *set_key_to_index.entry(names).or_insert_with_key(|names| {
+            assert!(next_index <= u16::MAX as usize, "too many distinct sets");
+            let mut bits: [u64; SCRIPT_SET_WORDS] = [0; SCRIPT_SET_WORDS];
+            for name in names {
+                let bit: usize = all_names.iter().position(|n| n == name).unwrap();
+                bits[bit / 64] |= 1 << (bit % 64);
+            }
+            sets.push(ScriptExtensionSet {
+                names: names.clone(),
+                bits,
+            });
+            next_index as u16
+        })
+    };
+
+    // ASCII letters are Latin.
+    let latin: u16 = set_index_for_names(vec!["Latin"]);
+    let mut ranges: Vec<ScriptExtensionRange> = vec![
+        ScriptExtensionRange {
+            first: 0x41,
+            last: 0x5a,
+            set_index: latin,
+        },
+        ScriptExtensionRange {
+            first: 0x61,
+            last: 0x7a,
+            set_index: latin,
+        },
+    ];
+    for (&code_point, indexes) in &script_extensions.code_point_scripts {
+        if code_point < 0x80 || !properties.is_js_identifier_part(code_point) {
+            continue;
+        }
+        let mut names: Vec<&str> = vec![];
+        for &index in indexes {
+            let name: &str = &script_extensions.script_names[index];
+            names.push(name);
+            if let Some((_, augmentations)) = SCRIPT_AUGMENTATIONS
+                .iter()
+                .find(|(script, _)| *script == name)
+            {
+                names.extend_from_slice(augmentations);
+            }
+        }
+        let set_index: u16 = set_index_for_names(names);
+        let last_range: &mut ScriptExtensionRange = ranges.last_mut().unwrap();
+        if last_range.last + 1 == code_point && last_range.set_index == set_index {
+            last_range.last = code_point;
+        } else {
+            ranges.push(ScriptExtensionRange {
+                first: code_point,
+                last: code_point,
+                set_index,
+            });
+        }
+    }
+    Ok(ScriptExtensionTables { sets, ranges })
+}
+
+// Parses confusables.txt from Unicode Technical Standard #39, keeping only
+// single code points whose prototypes are ASCII identifier characters.
+//
+// Returns sorted (code point, prototype) pairs.
+pub fn parse_confusables(
+    text: &str,
+    properties: &IdentifierProperties,
+) -> Result<Vec<(u32, String)>, String> {
+    let text: &str = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut confusables: Vec<(u32, String)> = vec![];
+    for (line_index, line) in text.lines().enumerate() {
+        let line: &str = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = || format!("line {}: malformed entry: {line}", line_index + 1);
+        let mut fields = line.split(';');
+        let mut parse_code_points = || -> Result<Vec<u32>, String> {
+            fields
+                .next()
+                .ok_or_else(error)?
+                .split_whitespace()
+                .map(|hex: &str| {
+                    u32::from_str_radix(hex, 16)
+                        .ok()
+                        .filter(|&code_point| code_point <= MAX_CODE_POINT)
+                        .ok_or_else(error)
+                })
+                .collect()
+        };
+        let source: Vec<u32> = parse_code_points()?;
+        let target: Vec<u32> = parse_code_points()?;
+        let [source] = source[..] else {
+            continue;
+        };
+        if source < 0x80 || !properties.is_js_identifier_part(source) {
+            continue;
+        }
+        if !target
+            .iter()
+            .all(|&c| c < 0x80 && properties.is_js_identifier_part(c))
+        {
+            continue;
+        }
+        confusables.push((
+            source,
+            target.iter().map(|&c| char::from_u32(c).unwrap()).collect(),
+        ));
+    }
+    confusables.sort();
+    Ok(confusables)
+}
+
+// Returns the contents of rust/libs/fe/src/lex_confusables_generated.rs (or its
+// sibling for another Unicode version).
+pub fn write_rust_confusables(
+    tables: &ScriptExtensionTables,
+    confusables: &[(u32, String)],
+    unicode_version: &str,
+) -> String {
+    let mut out: String = String::new();
+    out.push_str(&format!(
+        "\
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+// See end of file for extended copyright information.
+
+// This file was generated by rust/tools/generate_lex_unicode from Unicode {unicode_version}.
+
+pub const SCRIPT_SET_WORDS: usize = {SCRIPT_SET_WORDS};
+
+// A bit set of scripts. See SCRIPT_EXTENSION_SETS.
+pub type ScriptSet = [u64; SCRIPT_SET_WORDS];
+
+"
+    ));
+
+    out.push_str(&format!(
+        "\
+// Script_Extensions values of identifier characters, augmented as described by
+// UTS #39 section 5.1 (Mixed-Script Detection).
+#[rustfmt::skip]
+pub const SCRIPT_EXTENSION_SETS: [ScriptSet; {}] = [
+",
+        tables.sets.len()
+    ));
+    for set in &tables.sets {
+        let words: Vec<String> = set
+            .bits
+            .iter()
+            .map(|word: &u64| format!("0x{word:016x}"))
+            .collect();
+        out.push_str(&format!(
+            "    [{}], // {}\n",
+            words.join(", "),
+            set.names.join(" ")
+        ));
+    }
+    out.push_str("];\n\n");
+
+    out.push_str(&format!(
+        "\
+// Sorted, non-overlapping (first, last, SCRIPT_EXTENSION_SETS index) tuples.
+//
+// Identifier characters not listed here have a Script_Extensions value of
+// Common or Inherited, so they do not affect mixed-script detection.
+#[rustfmt::skip]
+pub const SCRIPT_EXTENSION_RANGES: [(u32, u32, u16); {}] = [
+",
+        tables.ranges.len()
+    ));
+    for range in &tables.ranges {
+        out.push_str(&format!(
+            "    (0x{:04x}, 0x{:04x}, {}),\n",
+            range.first, range.last, range.set_index
+        ));
+    }
+    out.push_str("];\n\n");
+
+    out.push_str(&format!(
+        "\
+// Sorted (code point, UTF-8 prototype) pairs from UTS #39's confusables.txt
+// whose prototypes are ASCII identifier characters.
+#[rustfmt::skip]
+pub const CONFUSABLE_PROTOTYPES: [(u32, &[u8]); {}] = [
+",
+        confusables.len()
+    ));
+    for (code_point, prototype) in confusables {
+        out.push_str(&format!("    (0x{code_point:04x}, b\"{prototype}\"),\n"));
+    }
+    out.push_str("];\n");
+
+    out.push_str(COPYRIGHT_FOOTER);
+    out
+}
+
+pub fn confusables_path() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("confusables.txt")
+}
+
+pub fn confusables_output_path(version: &UnicodeVersion) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../libs/fe/src")
+        .join(version.confusables_output_file_name)
+}
+
+// Script data and identifier properties are read from the same Unicode version
+// as the identifier tables generated by generate(version) so that the lexer's
+// tables agree with each other.
+pub fn generate_confusables(version: &UnicodeVersion) -> Result<String, String> {
+    let ucd_dir: std::path::PathBuf = ucd_dir(version);
+    let properties: IdentifierProperties = load_identifier_properties(&ucd_dir)?;
+    let script_extensions: ScriptExtensions = load_script_extensions(&ucd_dir)?;
+    let confusables_path: std::path::PathBuf = confusables_path();
+    let confusables: Vec<(u32, String)> =
+        parse_confusables(&read_ucd_file(&confusables_path)?, &properties)
+            .map_err(|message| format!("{}: {message}", confusables_path.display()))?;
+    Ok(write_rust_confusables(
+        &build_script_extension_tables(&script_extensions, &properties)?,
+        &confusables,
+        version.version,
+    ))
+}
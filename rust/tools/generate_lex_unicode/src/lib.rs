@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+pub mod confusables;
+
+pub const MAX_CODE_POINT: u32 = 0x10ffff;
+
+// Arbitrary. Found to produce the smallest tables.
+pub const UNICODE_TABLE_CHUNK_SIZE: usize = 256;
+
+const BITS_PER_BYTE: usize = 8;
+const BYTES_PER_CHUNK: usize = UNICODE_TABLE_CHUNK_SIZE / BITS_PER_BYTE;
+const MAX_CHUNK_INDEX: usize = u8::MAX as usize;
+
+// Each UTF-8 trie leaf covers the code points which share all but the last
+// continuation byte's six payload bits.
+pub const UTF8_TRIE_LEAF_SIZE: usize = 64;
+// Number of leaves covering the Basic Multilingual Plane (2-byte and 3-byte
+// sequences).
+pub const UTF8_TRIE_BMP_LEAF_COUNT: usize = 0x10000 / UTF8_TRIE_LEAF_SIZE;
+// Number of code points covered by each block of leaves for 4-byte sequences.
+pub const UTF8_TRIE_SUPPLEMENTARY_BLOCK_SIZE: usize = UTF8_TRIE_LEAF_SIZE * UTF8_TRIE_LEAF_SIZE;
+pub const UTF8_TRIE_SUPPLEMENTARY_BLOCK_COUNT: usize =
+    (MAX_CODE_POINT as usize + 1) / UTF8_TRIE_SUPPLEMENTARY_BLOCK_SIZE;
+
+pub struct UnicodeVersion {
+    // Name of the directory in ucd/ holding this version's data files.
+    pub version: &'static str,
+    // Name of the generated file in rust/libs/fe/src/.
+    pub output_file_name: &'static str,
+    // Name of the generated confusables file in rust/libs/fe/src/.
+    pub confusables_output_file_name: &'static str,
+}
+
+// The first version is the default. Other versions are selected with a cargo
+// feature on cpp_vs_rust_fe (see rust/libs/fe/Cargo.toml).
+pub const UNICODE_VERSIONS: &[UnicodeVersion] = &[
+    UnicodeVersion {
+        version: "15.0.0",
+        output_file_name: "lex_unicode_generated.rs",
+        confusables_output_file_name: "lex_confusables_generated.rs",
+    },
+    UnicodeVersion {
+        version: "17.0.0",
+        output_file_name: "lex_unicode_17_0_0_generated.rs",
+        confusables_output_file_name: "lex_confusables_17_0_0_generated.rs",
+    },
+];
+
+// A set of code points, indexed by code point.
+pub type CodePointSet = Vec<bool>;
+
+pub fn new_code_point_set() -> CodePointSet {
+    vec![false; MAX_CODE_POINT as usize + 1]
+}
+
+pub struct IdentifierProperties {
+    pub id_start: CodePointSet,
+    pub id_continue: CodePointSet,
+}
+
+impl IdentifierProperties {
+    // https://tc39.es/ecma262/#prod-IdentifierStartChar
+    pub fn is_js_identifier_start(&self, code_point: u32) -> bool {
+        matches!(code_point, 0x24 | 0x5f) || self.id_start[code_point as usize]
+    }
+
+    // https://tc39.es/ecma262/#prod-IdentifierPartChar
+    pub fn is_js_identifier_part(&self, code_point: u32) -> bool {
+        matches!(code_point, 0x24 | 0x200c | 0x200d) || self.id_continue[code_point as usize]
+    }
+}
+
+// Reads ID_Start and ID_Continue from DerivedCoreProperties.txt in ucd_dir.
+//
+// PropList.txt is read to check that both files come from the same Unicode
+// version: every Other_ID_Start and Other_ID_Continue code point must be in
+// ID_Start and ID_Continue respectively.
+pub fn load_identifier_properties(ucd_dir: &Path) -> Result<IdentifierProperties, String> {
+    let derived_core_properties: String =
+        read_ucd_file(&ucd_dir.join("DerivedCoreProperties.txt"))?;
+    let prop_list: String = read_ucd_file(&ucd_dir.join("PropList.txt"))?;
+    let properties = IdentifierProperties {
+        id_start: parse_ucd_property(&derived_core_properties, "ID_Start")?,
+        id_continue: parse_ucd_property(&derived_core_properties, "ID_Continue")?,
+    };
+    for (other_property, property, set) in [
+        ("Other_ID_Start", "ID_Start", &properties.id_start),
+        ("Other_ID_Continue", "ID_Continue", &properties.id_continue),
+    ] {
+        let other_set: CodePointSet = parse_ucd_property(&prop_list, other_property)?;
+        if let Some(code_point) = (0..=MAX_CODE_POINT)
+            .find(|&code_point| other_set[code_point as usize] && !set[code_point as usize])
+        {
+            return Err(format!(
+                "{}: {other_property} code point U+{code_point:04X} is not {property}; are DerivedCoreProperties.txt and PropList.txt from the same Unicode version?",
+                ucd_dir.display(),
+            ));
+        }
+    }
+    Ok(properties)
+}
+
+pub(crate) fn read_ucd_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+// Parses a Unicode Character Database file with lines such as:
+//
+//   0041..005A    ; ID_Start # L&  [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z
+//
+// and returns the code points which have the given property.
+pub fn parse_ucd_property(text: &str, property: &str) -> Result<CodePointSet, String> {
+    let mut set: CodePointSet = new_code_point_set();
+    for (first, last, line_property) in parse_ucd_ranges(text)? {
+        if line_property != property {
+            continue;
+        }
+        for code_point in first..=last {
+            set[code_point as usize] = true;
+        }
+    }
+    Ok(set)
+}
+
+// Parses a Unicode Character Database file with lines such as:
+//
+//   0041..005A    ; Latin # L&  [26] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER Z
+//
+// and returns (first code point, last code point, value) for each line.
+pub fn parse_ucd_ranges(text: &str) -> Result<Vec<(u32, u32, &str)>, String> {
+    let mut ranges: Vec<(u32, u32, &str)> = vec![];
+    for (line_index, line) in text.lines().enumerate() {
+        let line: &str = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = || format!("line {}: malformed entry: {line}", line_index + 1);
+        let (code_points, value) = line.split_once(';').ok_or_else(error)?;
+        let code_points: &str = code_points.trim();
+        let (first, last): (&str, &str) = code_points
+            .split_once("..")
+            .unwrap_or((code_points, code_points));
+        let parse_code_point = |hex: &str| -> Result<u32, String> {
+            u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|&code_point| code_point <= MAX_CODE_POINT)
+                .ok_or_else(error)
+        };
+        let first: u32 = parse_code_point(first)?;
+        let last: u32 = parse_code_point(last)?;
+        if first > last {
+            return Err(error());
+        }
+        ranges.push((first, last, value.trim()));
+    }
+    Ok(ranges)
+}
+
+// Chunked bit sets for look_up_in_unicode_table in rust/libs/fe/src/lex.rs.
+//
+// Each table is split into UNICODE_TABLE_CHUNK_SIZE-bit chunks. Identical
+// chunks are stored once in chunks, and each table is a list of chunk indexes.
+// Trailing all-zero chunks are omitted.
+pub struct UnicodeTables {
+    pub chunks: Vec<u8>,
+    pub identifier_start_chunk_indexes: Vec<u8>,
+    pub identifier_part_chunk_indexes: Vec<u8>,
+    pub utf_8_trie: UTF8Trie,
+}
+
+// Tables for look_up_identifier_character_utf_8 in rust/libs/fe/src/lex.rs,
+// keyed on UTF-8 bytes instead of on code points.
+//
+// Each leaf is a pair of bit sets (identifier start, identifier part) indexed
+// by the low six bits of a sequence's last byte. Identical leaves are stored
+// once.
+//
+// For a 2-byte or 3-byte sequence, bmp_leaf_indexes is indexed by the payload
+// bits of every byte except the last (i.e. by code_point >> 6).
+//
+// For a 4-byte sequence, supplementary_block_indexes is indexed by the payload
+// bits of the first two bytes (i.e. by code_point >> 12). The resulting block
+// is a run of UTF8_TRIE_LEAF_SIZE entries in supplementary_leaf_indexes
+// indexed by the payload bits of the third byte. Identical blocks are stored
+// once.
+pub struct UTF8Trie {
+    pub leaves: Vec<[u64; 2]>,
+    pub bmp_leaf_indexes: Vec<u16>,
+    pub supplementary_block_indexes: Vec<u8>,
+    pub supplementary_leaf_indexes: Vec<u16>,
+}
+
+impl UTF8Trie {
+    // Returns whether the leaf index type in the generated file can be u8.
+    pub fn has_small_leaf_indexes(&self) -> bool {
+        self.leaves.len() <= u8::MAX as usize + 1
+    }
+}
+
+pub fn build_unicode_tables(properties: &IdentifierProperties) -> UnicodeTables {
+    let identifier_start: Vec<u8> =
+        pack_bits(|code_point| properties.is_js_identifier_start(code_point));
+    let identifier_part: Vec<u8> =
+        pack_bits(|code_point| properties.is_js_identifier_part(code_point));
+
+    let mut chunks: Vec<u8> = vec![];
+    let mut chunk_to_index: HashMap<&[u8], u8> = HashMap::new();
+    let mut identifier_start_chunk_indexes: Vec<u8> =
+        chunk_indexes(&identifier_start, &mut chunk_to_index, &mut chunks);
+    let mut identifier_part_chunk_indexes: Vec<u8> =
+        chunk_indexes(&identifier_part, &mut chunk_to_index, &mut chunks);
+    let zeros_chunk_index: u8 =
+        chunk_indexes(&[0; BYTES_PER_CHUNK], &mut chunk_to_index, &mut chunks)[0];
+
+    for indexes in [
+        &mut identifier_start_chunk_indexes,
+        &mut identifier_part_chunk_indexes,
+    ] {
+        while indexes.last() == Some(&zeros_chunk_index) {
+            indexes.pop();
+        }
+    }
+    UnicodeTables {
+        chunks,
+        identifier_start_chunk_indexes,
+        identifier_part_chunk_indexes,
+        utf_8_trie: build_utf_8_trie(properties),
+    }
+}
+
+fn build_utf_8_trie(properties: &IdentifierProperties) -> UTF8Trie {
+    let mut leaves: Vec<[u64; 2]> = vec![];
+    let mut leaf_to_index: HashMap<[u64; 2], u16> = HashMap::new();
+    let mut leaf_index = |first_code_point: u32| -> u16 {
+        let mut leaf: [u64; 2] = [0, 0];
+        for bit in 0..UTF8_TRIE_LEAF_SIZE {
+            let code_point: u32 = first_code_point + bit as u32;
+            if properties.is_js_identifier_start(code_point) {
+                leaf[0] |= 1 << bit;
+            }
+            if properties.is_js_identifier_part(code_point) {
+                leaf[1] |= 1 << bit;
+            }
+        }
+        let next_index: usize = leaf_to_index.len();
+        *leaf_to_index.entry(leaf).or_insert_with(|| {
+            assert!(next_index <= u16::MAX as usize, "too many distinct leaves");
+            leaves.push(leaf);
+            next_index as u16
+        })
+    };
+
+    let bmp_leaf_indexes: Vec<u16> = (0..UTF8_TRIE_BMP_LEAF_COUNT)
+        .map(|leaf| leaf_index((leaf * UTF8_TRIE_LEAF_SIZE) as u32))
+        .collect();
+
+    let mut supplementary_leaf_indexes: Vec<u16> = vec![];
+    let mut block_to_index: HashMap<Vec<u16>, u8> = HashMap::new();
+    let supplementary_block_indexes: Vec<u8> = (0..UTF8_TRIE_SUPPLEMENTARY_BLOCK_COUNT)
+        .map(|block| {
+            // Blocks in the Basic Multilingual Plane are never looked up. Map
+            // them to an arbitrary block.
+            let first_code_point: usize = (block * UTF8_TRIE_SUPPLEMENTARY_BLOCK_SIZE).max(0x10000);
+            let block_leaf_indexes: Vec<u16> = (0..UTF8_TRIE_LEAF_SIZE)
+                .map(|leaf| leaf_index((first_code_point + leaf * UTF8_TRIE_LEAF_SIZE) as u32))
+                .collect();
+            let next_index: usize = block_to_index.len();
+            *block_to_index
+                .entry(block_leaf_indexes)
+                .or_insert_with_key(|block_leaf_indexes| {
+                    assert!(next_index <= u8::MAX as usize, "too many distinct blocks");
+                    supplementary_leaf_indexes.extend_from_slice(block_leaf_indexes);
+                    next_index as u8
+                })
+        })
+        .collect();
+
+    UTF8Trie {
+        leaves,
+        bmp_leaf_indexes,
+        supplementary_block_indexes,
+        supplementary_leaf_indexes,
+    }
+}
+
+// Splits data into chunks, adding new chunks to chunks, and returns the index
+// of each chunk.
+fn chunk_indexes<'data>(
+    data: &'data [u8],
+    chunk_to_index: &mut HashMap<&'data [u8], u8>,
+    chunks: &mut Vec<u8>,
+) -> Vec<u8> {
+    data.chunks(BYTES_PER_CHUNK)
+        .map(|chunk| {
+            let next_index: usize = chunk_to_index.len();
+            *chunk_to_index.entry(chunk).or_insert_with(|| {
+                assert!(next_index <= MAX_CHUNK_INDEX, "too many distinct chunks");
+                chunks.extend_from_slice(chunk);
+                next_index as u8
+            })
+        })
+        .collect()
+}
+
+fn pack_bits(predicate: impl Fn(u32) -> bool) -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![0; (MAX_CODE_POINT as usize + 1) / BITS_PER_BYTE];
+    for code_point in 0..=MAX_CODE_POINT {
+        if predicate(code_point) {
+            bytes[code_point as usize / BITS_PER_BYTE] |=
+                1 << (code_point as usize % BITS_PER_BYTE);
+        }
+    }
+    bytes
+}
+
+// Returns the contents of rust/libs/fe/src/lex_unicode_generated.rs (or a
+// sibling for another Unicode version).
+pub fn write_rust(tables: &UnicodeTables, unicode_version: &str) -> String {
+    let mut out: String = String::new();
+    out.push_str(&format!(
+        "\
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+// See end of file for extended copyright information.
+
+// This file was generated by rust/tools/generate_lex_unicode from Unicode {unicode_version}.
+
+type UnicodeTableChunkIndexType = u8;
+
+pub const UNICODE_TABLE_CHUNK_SIZE: usize = {UNICODE_TABLE_CHUNK_SIZE};
+
+"
+    ));
+
+    out.push_str(&format!(
+        "#[rustfmt::skip]\npub const UNICODE_TABLES_CHUNKS: [u8; {}] = [\n",
+        tables.chunks.len()
+    ));
+    out.push_str(&dump_integer_table(&tables.chunks, "    "));
+    out.push_str("\n];\n\n");
+
+    for (i, (name, indexes)) in [
+        (
+            "IDENTIFIER_START_CHUNK_INDEXES",
+            &tables.identifier_start_chunk_indexes,
+        ),
+        (
+            "IDENTIFIER_PART_CHUNK_INDEXES",
+            &tables.identifier_part_chunk_indexes,
+        ),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "#[rustfmt::skip]\npub const {name}: [UnicodeTableChunkIndexType; {}] = [\n",
+            indexes.len()
+        ));
+        out.push_str(&dump_integer_table(indexes, "    "));
+        out.push_str("\n];\n");
+    }
+
+    let trie: &UTF8Trie = &tables.utf_8_trie;
+    out.push_str(&format!(
+        "
+pub type UTF8TrieLeafIndexType = {};
+
+pub const UTF8_TRIE_LEAF_SIZE: usize = {UTF8_TRIE_LEAF_SIZE};
+
+",
+        if trie.has_small_leaf_indexes() {
+            "u8"
+        } else {
+            "u16"
+        }
+    ));
+    out.push_str(&format!(
+        "#[rustfmt::skip]\npub const UTF8_TRIE_LEAVES: [[u64; 2]; {}] = [\n",
+        trie.leaves.len()
+    ));
+    for [identifier_start, identifier_part] in &trie.leaves {
+        out.push_str(&format!(
+            "    [0x{identifier_start:016x}, 0x{identifier_part:016x}],\n"
+        ));
+    }
+    out.push_str("];\n");
+    for (name, type_name, indexes) in [
+        (
+            "UTF8_TRIE_BMP_LEAF_INDEXES",
+            "UTF8TrieLeafIndexType",
+            &trie.bmp_leaf_indexes,
+        ),
+        (
+            "UTF8_TRIE_SUPPLEMENTARY_BLOCK_INDEXES",
+            "u8",
+            &trie
+                .supplementary_block_indexes
+                .iter()
+                .map(|&index| index as u16)
+                .collect(),
+        ),
+        (
+            "UTF8_TRIE_SUPPLEMENTARY_LEAF_INDEXES",
+            "UTF8TrieLeafIndexType",
+            &trie.supplementary_leaf_indexes,
+        ),
+    ] {
+        out.push_str(&format!(
+            "\n#[rustfmt::skip]\npub const {name}: [{type_name}; {}] = [\n",
+            indexes.len()
+        ));
+        let width: usize = if type_name == "u8" || trie.has_small_leaf_indexes() {
+            2
+        } else {
+            4
+        };
+        out.push_str(&dump_integer_table_with_width(indexes, width, "    "));
+        out.push_str("\n];\n");
+    }
+
+    out.push_str(COPYRIGHT_FOOTER);
+    out
+}
+
+pub(crate) const COPYRIGHT_FOOTER: &str = "
+// quick-lint-js finds bugs in JavaScript programs.
+// Copyright (C) 2020  Matthew \"strager\" Glazar
+//
+// This file is part of quick-lint-js.
+//
+// quick-lint-js is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// quick-lint-js is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with quick-lint-js.  If not, see <https://www.gnu.org/licenses/>.
+";
+
+fn dump_integer_table(integers: &[u8], indentation: &str) -> String {
+    let integers: Vec<u16> = integers.iter().map(|&integer| integer as u16).collect();
+    dump_integer_table_with_width(&integers, 2, indentation)
+}
+
+fn dump_integer_table_with_width(integers: &[u16], width: usize, indentation: &str) -> String {
+    const INTEGERS_PER_LINE: usize = 8;
+    integers
+        .chunks(INTEGERS_PER_LINE)
+        .map(|line| {
+            let line: Vec<String> = line
+                .iter()
+                .map(|integer| format!("0x{integer:0width$x},"))
+                .collect();
+            format!("{indentation}{}", line.join(" "))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn ucd_dir(version: &UnicodeVersion) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("ucd")
+        .join(version.version)
+}
+
+pub fn output_path(version: &UnicodeVersion) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../libs/fe/src")
+        .join(version.output_file_name)
+}
+
+pub fn generate(version: &UnicodeVersion) -> Result<String, String> {
+    let properties: IdentifierProperties = load_identifier_properties(&ucd_dir(version))?;
+    Ok(write_rust(
+        &build_unicode_tables(&properties),
+        version.version,
+    ))
+}
@@ -0,0 +1,59 @@
+// Generates rust/libs/fe/src/lex_unicode_generated.rs (and its siblings for
+// other Unicode versions) from the Unicode Character Database files in ucd/,
+// and rust/libs/fe/src/lex_confusables_generated.rs (and its siblings) from
+// ucd/ and confusables.txt.
+//
+// Usage: cargo run -p cpp_vs_rust_generate_lex_unicode [-- --check]
+//
+// With --check, verify that the generated files are up to date instead of
+// writing them.
+
+use cpp_vs_rust_generate_lex_unicode::confusables::*;
+use cpp_vs_rust_generate_lex_unicode::*;
+
+fn main() {
+    let check: bool = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => {
+            eprintln!("error: unexpected argument: {arg}");
+            std::process::exit(2);
+        }
+    };
+
+    let mut outputs: Vec<(std::path::PathBuf, Result<String, String>)> = vec![];
+    for version in UNICODE_VERSIONS {
+        outputs.push((output_path(version), generate(version)));
+        outputs.push((
+            confusables_output_path(version),
+            generate_confusables(version),
+        ));
+    }
+
+    let mut ok: bool = true;
+    for (path, generated) in outputs {
+        let result: Result<(), String> = generated.and_then(|generated: String| {
+            if check {
+                let existing: String = std::fs::read_to_string(&path).unwrap_or_default();
+                if existing != generated {
+                    return Err(format!(
+                        "{} is out of date; run generate_lex_unicode",
+                        path.display()
+                    ));
+                }
+                Ok(())
+            } else {
+                println!("Creating {} ...", path.display());
+                std::fs::write(&path, generated)
+                    .map_err(|error| format!("{}: {error}", path.display()))
+            }
+        });
+        if let Err(message) = result {
+            eprintln!("error: {message}");
+            ok = false;
+        }
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,335 @@
+use cpp_vs_rust_generate_lex_unicode::confusables::*;
+use cpp_vs_rust_generate_lex_unicode::*;
+
+fn code_points(set: &CodePointSet) -> Vec<u32> {
+    (0..=MAX_CODE_POINT)
+        .filter(|&code_point| set[code_point as usize])
+        .collect()
+}
+
+#[test]
+fn parse_single_code_points_and_ranges() {
+    let set: CodePointSet = parse_ucd_property(
+        "0041..0043    ; ID_Start # L&   [3] LATIN CAPITAL LETTER A..LATIN CAPITAL LETTER C\n\
+         00AA          ; ID_Start # Lo       FEMININE ORDINAL INDICATOR\n\
+         10FFFF        ; ID_Start\n",
+        "ID_Start",
+    )
+    .unwrap();
+    assert_eq!(code_points(&set), vec![0x41, 0x42, 0x43, 0xaa, 0x10ffff]);
+}
+
+#[test]
+fn parse_ignores_comments_blank_lines_and_other_properties() {
+    let set: CodePointSet = parse_ucd_property(
+        "# DerivedCoreProperties-15.0.0.txt\n\
+         \n\
+         0030..0039    ; ID_Continue\n\
+         0041          ; ID_Start\n\
+         # 0042        ; ID_Start\n\
+         0043          ; ID_Start_Extra\n",
+        "ID_Start",
+    )
+    .unwrap();
+    assert_eq!(code_points(&set), vec![0x41]);
+}
+
+#[test]
+fn parse_rejects_malformed_lines() {
+    for text in [
+        "0041 ID_Start\n",
+        "zzzz ; ID_Start\n",
+        "0043..0041 ; ID_Start\n",
+        "110000 ; ID_Start\n",
+    ] {
+        let result: Result<CodePointSet, String> = parse_ucd_property(text, "ID_Start");
+        assert!(result.is_err(), "{text:?} should be rejected");
+    }
+}
+
+#[test]
+fn identifier_properties_include_javascript_extras() {
+    let mut id_start: CodePointSet = new_code_point_set();
+    id_start[0x61] = true;
+    let mut id_continue: CodePointSet = new_code_point_set();
+    id_continue[0x61] = true;
+    id_continue[0x30] = true;
+    let properties = IdentifierProperties {
+        id_start,
+        id_continue,
+    };
+
+    for c in ['$', '_', 'a'] {
+        assert!(properties.is_js_identifier_start(c as u32), "{c:?}");
+    }
+    for c in ['0', '\u{200c}', '\u{200d}'] {
+        assert!(!properties.is_js_identifier_start(c as u32), "{c:?}");
+    }
+    for c in ['$', 'a', '0', '\u{200c}', '\u{200d}'] {
+        assert!(properties.is_js_identifier_part(c as u32), "{c:?}");
+    }
+    assert!(!properties.is_js_identifier_part('-' as u32));
+}
+
+#[test]
+fn tables_deduplicate_chunks_and_strip_trailing_zeros() {
+    let mut id_start: CodePointSet = new_code_point_set();
+    id_start[0x61] = true;
+    let mut id_continue: CodePointSet = new_code_point_set();
+    id_continue[0x61] = true;
+    id_continue[0x1000] = true;
+    let properties = IdentifierProperties {
+        id_start,
+        id_continue,
+    };
+    let tables: UnicodeTables = build_unicode_tables(&properties);
+
+    let look_up = |indexes: &[u8], code_point: u32| -> bool {
+        let chunk_index_index: usize = code_point as usize / UNICODE_TABLE_CHUNK_SIZE;
+        let Some(&chunk_index) = indexes.get(chunk_index_index) else {
+            return false;
+        };
+        let bit: usize = code_point as usize % UNICODE_TABLE_CHUNK_SIZE;
+        let byte: u8 = tables.chunks[chunk_index as usize * UNICODE_TABLE_CHUNK_SIZE / 8 + bit / 8];
+        byte & (1 << (bit % 8)) != 0
+    };
+    for code_point in [0x24, 0x5f, 0x61] {
+        assert!(look_up(&tables.identifier_start_chunk_indexes, code_point));
+    }
+    for code_point in [0x24, 0x61, 0x200c, 0x200d, 0x1000] {
+        assert!(look_up(&tables.identifier_part_chunk_indexes, code_point));
+    }
+    assert!(!look_up(&tables.identifier_start_chunk_indexes, 0x1000));
+    assert!(!look_up(&tables.identifier_part_chunk_indexes, 0x10ffff));
+
+    // Chunks: start[0], part[0], zeros, part[0x1000 / 256], part[0x200c / 256].
+    assert_eq!(tables.chunks.len(), 5 * UNICODE_TABLE_CHUNK_SIZE / 8);
+    assert_eq!(tables.identifier_start_chunk_indexes, vec![0]);
+    assert_eq!(
+        tables.identifier_part_chunk_indexes.len(),
+        0x200c / UNICODE_TABLE_CHUNK_SIZE + 1
+    );
+}
+
+#[test]
+fn utf_8_trie_deduplicates_leaves_and_blocks() {
+    let mut id_start: CodePointSet = new_code_point_set();
+    id_start[0xe9] = true;
+    id_start[0x1d465] = true;
+    let mut id_continue: CodePointSet = new_code_point_set();
+    id_continue[0x5f] = true;
+    id_continue[0xe9] = true;
+    id_continue[0x1d465] = true;
+    let properties = IdentifierProperties {
+        id_start,
+        id_continue,
+    };
+    let trie: UTF8Trie = build_unicode_tables(&properties).utf_8_trie;
+
+    // Returns (is identifier start, is identifier part).
+    let look_up = |code_point: u32| -> (bool, bool) {
+        let leaf_index: u16 = if code_point < 0x10000 {
+            trie.bmp_leaf_indexes[code_point as usize / UTF8_TRIE_LEAF_SIZE]
+        } else {
+            let block_index: u8 = trie.supplementary_block_indexes
+                [code_point as usize / UTF8_TRIE_SUPPLEMENTARY_BLOCK_SIZE];
+            trie.supplementary_leaf_indexes[block_index as usize * UTF8_TRIE_LEAF_SIZE
+                + (code_point as usize / UTF8_TRIE_LEAF_SIZE) % UTF8_TRIE_LEAF_SIZE]
+        };
+        let [start, part] = trie.leaves[leaf_index as usize];
+        let bit: usize = code_point as usize % UTF8_TRIE_LEAF_SIZE;
+        ((start >> bit) & 1 != 0, (part >> bit) & 1 != 0)
+    };
+    for code_point in [0x24, 0x5f, 0xe9, 0x1d465] {
+        assert_eq!(look_up(code_point), (true, true), "U+{code_point:04X}");
+    }
+    for code_point in [0x200c, 0x200d] {
+        assert_eq!(look_up(code_point), (false, true), "U+{code_point:04X}");
+    }
+    for code_point in [0x20, 0xe8, 0xea, 0x1d464, 0x1d466, 0x10ffff] {
+        assert_eq!(look_up(code_point), (false, false), "U+{code_point:04X}");
+    }
+
+    // Leaves: ['$'], ['_'], zeros, [U+00E9], [U+200C and U+200D], [U+1D465].
+    assert_eq!(trie.leaves.len(), 6);
+    assert_eq!(trie.bmp_leaf_indexes.len(), UTF8_TRIE_BMP_LEAF_COUNT);
+    assert_eq!(
+        trie.supplementary_block_indexes.len(),
+        UTF8_TRIE_SUPPLEMENTARY_BLOCK_COUNT
+    );
+    // Blocks: zeros, [U+1D465].
+    assert_eq!(
+        trie.supplementary_leaf_indexes.len(),
+        2 * UTF8_TRIE_LEAF_SIZE
+    );
+    assert!(trie.has_small_leaf_indexes());
+}
+
+#[test]
+fn script_extensions_override_scripts() {
+    let aliases: std::collections::HashMap<String, String> = parse_script_aliases(
+        "sc ; Grek ; Greek\n\
+         sc ; Latn ; Latin\n\
+         sc ; Zyyy ; Common\n\
+         gc ; L    ; Letter\n",
+    )
+    .unwrap();
+    let script_extensions: ScriptExtensions = parse_script_extensions(
+        "0000..0040    ; Common\n\
+         0370..0373    ; Greek # L&   [4] GREEK CAPITAL LETTER HETA..GREEK SMALL LETTER ARCHAIC SAMPI\n\
+         0041..005A    ; Latin\n",
+        "0030          ; Grek Latn\n\
+         0371          ; Zyyy\n",
+        &aliases,
+    )
+    .unwrap();
+    // Scripts are ordered by their first code point.
+    assert_eq!(script_extensions.script_names, vec!["Latin", "Greek"]);
+    assert_eq!(
+        script_extensions.code_point_scripts.get(&0x30),
+        Some(&vec![1, 0])
+    );
+    assert_eq!(script_extensions.code_point_scripts.get(&0x31), None);
+    assert_eq!(
+        script_extensions.code_point_scripts.get(&0x370),
+        Some(&vec![1])
+    );
+    assert_eq!(script_extensions.code_point_scripts.get(&0x371), None);
+    assert_eq!(
+        script_extensions.code_point_scripts.get(&0x41),
+        Some(&vec![0])
+    );
+}
+
+#[test]
+fn script_extension_tables_are_augmented_and_merged() {
+    let mut id_continue: CodePointSet = new_code_point_set();
+    for code_point in [0x3041, 0x3042, 0x3043, 0x4e00] {
+        id_continue[code_point] = true;
+    }
+    let properties = IdentifierProperties {
+        id_start: new_code_point_set(),
+        id_continue,
+    };
+    let script_extensions = ScriptExtensions {
+        script_names: vec![
+            "Latin".to_string(),
+            "Hiragana".to_string(),
+            "Han".to_string(),
+        ],
+        code_point_scripts: [
+            (0x3041, vec![1]),
+            (0x3042, vec![1]),
+            (0x3043, vec![1]),
+            (0x3044, vec![1]), // Not an identifier character.
+            (0x4e00, vec![2]),
+        ]
+        .into_iter()
+        .collect(),
+    };
+    let tables: ScriptExtensionTables =
+        build_script_extension_tables(&script_extensions, &properties).unwrap();
+    let names: Vec<&Vec<String>> = tables.sets.iter().map(|set| &set.names).collect();
+    assert_eq!(
+        names,
+        vec![
+            &vec!["Latin".to_string()],
+            &vec!["Hiragana".to_string(), "Japanese".to_string()],
+            &vec![
+                "Han".to_string(),
+                "Han_with_Bopomofo".to_string(),
+                "Japanese".to_string(),
+                "Korean".to_string(),
+            ],
+        ]
+    );
+    // Bits are in script_names order followed by the augmented scripts.
+    assert_eq!(tables.sets[1].bits, [0b1_0010, 0, 0]);
+    assert_eq!(
+        tables.ranges,
+        vec![
+            ScriptExtensionRange {
+                first: 0x41,
+                last: 0x5a,
+                set_index: 0,
+            },
+            ScriptExtensionRange {
+                first: 0x61,
+                last: 0x7a,
+                set_index: 0,
+            },
+            ScriptExtensionRange {
+                first: 0x3041,
+                last: 0x3043,
+                set_index: 1,
+            },
+            ScriptExtensionRange {
+                first: 0x4e00,
+                last: 0x4e00,
+                set_index: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_confusables_keeps_ascii_identifier_prototypes() {
+    let mut id_continue: CodePointSet = new_code_point_set();
+    for code_point in [0x61, 0x63, 0x6d, 0x6e, 0x72, 0x430, 0x441] {
+        id_continue[code_point] = true;
+    }
+    let properties = IdentifierProperties {
+        id_start: new_code_point_set(),
+        id_continue,
+    };
+    let confusables: Vec<(u32, String)> = parse_confusables(
+        "\u{feff}# comment\n\
+         0441 ;\t0063 ;\tMA\t# ( \u{441} \u{2192} c )\n\
+         0430 ;\t0061 ;\tMA\t# ( \u{430} \u{2192} a )\n\
+         006D ;\t0072 006E ;\tMA\t# ( m \u{2192} rn )\n\
+         2024 ;\t002E ;\tMA\t# ( \u{2024} \u{2192} . )\n",
+        &properties,
+    )
+    .unwrap();
+    assert_eq!(
+        confusables,
+        vec![(0x430, "a".to_string()), (0x441, "c".to_string())]
+    );
+}
+
+#[test]
+fn vendored_ucd_files_are_consistent() {
+    for version in UNICODE_VERSIONS {
+        let properties: IdentifierProperties = load_identifier_properties(&ucd_dir(version))
+            .unwrap_or_else(|message| panic!("{message}"));
+        assert!(properties.id_start[0x41], "{}", version.version);
+        assert!(properties.id_continue[0x30], "{}", version.version);
+        load_script_extensions(&ucd_dir(version)).unwrap_or_else(|message| panic!("{message}"));
+    }
+}
+
+#[test]
+fn generated_files_are_up_to_date() {
+    for version in UNICODE_VERSIONS {
+        let generated: String = generate(version).unwrap_or_else(|message| panic!("{message}"));
+        let path: std::path::PathBuf = output_path(version);
+        let existing: String = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+        assert!(
+            existing == generated,
+            "{} is out of date; run: cargo run -p cpp_vs_rust_generate_lex_unicode",
+            path.display()
+        );
+
+        let generated: String =
+            generate_confusables(version).unwrap_or_else(|message| panic!("{message}"));
+        let path: std::path::PathBuf = confusables_output_path(version);
+        let existing: String = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+        assert!(
+            existing == generated,
+            "{} is out of date; run: cargo run -p cpp_vs_rust_generate_lex_unicode",
+            path.display()
+        );
+    }
+}
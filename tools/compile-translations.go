@@ -111,6 +111,7 @@ func main() {
 	}
 	locales[""] = ExtractGMOStrings(sourceGMO)
 
+	// Rust sources are generated by rust/tools/compile_translations.
 	table := CreateTranslationTable(locales)
 	if err := WriteTranslationTableHeader(&table, "cpp/src/quick-lint-js/i18n/translation-table-generated.h"); err != nil {
 		log.Fatal(err)
@@ -118,18 +119,12 @@ func main() {
 	if err := WriteTranslationTableSource(&table, "cpp/src/quick-lint-js/i18n/translation-table-generated.cpp"); err != nil {
 		log.Fatal(err)
 	}
-	if err := WriteTranslationTableRustSource(&table, "rust/libs/i18n/src/translation_table_generated.rs"); err != nil {
-		log.Fatal(err)
-	}
 	if err := WriteTranslationTestHeader(locales, "cpp/test/quick-lint-js/test-translation-table-generated.h"); err != nil {
 		log.Fatal(err)
 	}
 	if err := WriteTranslationTestSource(locales, "cpp/test/test-translation-table-generated.cpp"); err != nil {
 		log.Fatal(err)
 	}
-	if err := WriteTranslationTestRustSource(locales, "rust/libs/i18n/tests/test_translation_table_generated.rs"); err != nil {
-		log.Fatal(err)
-	}
 }
 
 func writeFileHeader(writer *bufio.Writer) {
@@ -549,73 +544,6 @@ const translation_table translation_data = {
 	return nil
 }
 
-func WriteTranslationTableRustSource(table *TranslationTable, path string) error {
-	outputFile, err := os.Create(path)
-	if err != nil {
-		log.Fatal(err)
-	}
-	defer outputFile.Close()
-	writer := bufio.NewWriter(outputFile)
-
-	writeFileHeader(writer)
-
-	writer.WriteString(
-		`
-use crate::translation_table::*;
-
-`)
-	fmt.Fprintf(writer, "pub const TRANSLATION_TABLE_LOCALE_COUNT: u32 = %d;\n", len(table.Locales)-1)
-	fmt.Fprintf(writer, "pub const TRANSLATION_TABLE_MAPPING_TABLE_SIZE: u16 = %d;\n", len(table.RelativeMappingTable))
-
-	fmt.Fprintf(writer, "\npub const UNTRANSLATED_STRINGS: [&str; %d] = [\n", len(table.ConstLookupTable))
-	for _, constLookupEntry := range table.ConstLookupTable {
-		fmt.Fprintf(writer, "    \"")
-		DumpRustStringLiteralBody(string(constLookupEntry.Untranslated), writer)
-		writer.WriteString("\",\n")
-	}
-
-	writer.WriteString(
-		`];
-
-pub const TRANSLATION_DATA_MAPPING_TABLE: [TranslationTableMappingEntry;
-    TRANSLATION_TABLE_MAPPING_TABLE_SIZE as usize] = [
-`)
-	// TODO(port): Write out RelativeMappingTable instead.
-	for _, mappingEntry := range table.AbsoluteMappingTable {
-		writer.WriteString("    TranslationTableMappingEntry([")
-		for i, stringOffset := range mappingEntry.StringOffsets {
-			if i != 0 {
-				writer.WriteString(", ")
-			}
-			fmt.Fprintf(writer, "%d", stringOffset)
-		}
-		fmt.Fprintf(writer, "]), //\n")
-	}
-	writer.WriteString(
-		`];
-
-pub const TRANSLATION_DATA_STRING_TABLE: &[u8] = `)
-	DumpRustStringTable(table.StringTable, "        ", writer)
-
-	writer.WriteString(
-		`.as_bytes();
-
-pub const TRANSLATION_DATA_LOCALE_TABLE: &str = `)
-
-	DumpRustStringTable(table.LocaleTable, "        ", writer)
-
-	writer.WriteString(
-		`;
-
-`)
-	WriteCopyrightFooter(writer)
-
-	if err := writer.Flush(); err != nil {
-		log.Fatal(err)
-	}
-	return nil
-}
-
 func WriteTranslationTestHeader(locales map[string][]TranslationEntry, path string) error {
 	outputFile, err := os.Create(path)
 	if err != nil {
@@ -730,52 +658,6 @@ const translated_string test_translation_table[] = {
 	return nil
 }
 
-func WriteTranslationTestRustSource(locales map[string][]TranslationEntry, path string) error {
-	outputFile, err := os.Create(path)
-	if err != nil {
-		log.Fatal(err)
-	}
-	defer outputFile.Close()
-	writer := bufio.NewWriter(outputFile)
-
-	localeNames := GetLocaleNames(locales)
-	allUntranslated := GetAllUntranslated(locales)
-
-	// Returns the untranslated string if there is no translation.
-	lookUpTranslation := func(localeName string, untranslated []byte) []byte {
-		for _, entry := range locales[localeName] {
-			if bytes.Equal(entry.Untranslated, untranslated) {
-				return entry.Translated
-			}
-		}
-		return untranslated
-	}
-
-	writeFileHeader(writer)
-
-	fmt.Fprintf(writer, `
-#![allow(clippy::redundant_static_lifetimes)]
-
-use cpp_vs_rust_i18n::qljs_translatable;
-use cpp_vs_rust_i18n::translation::*;
-use cpp_vs_rust_test::scoped_trace;
-
-#[rustfmt::skip]
-pub const TEST_LOCALE_NAMES: [&'static str; %d] = [
-`, len(localeNames))
-	for _, localeName := range localeNames {
-		fmt.Fprintf(writer, "    \"")
-		DumpRustStringLiteralBody(localeName, writer)
-		fmt.Fprintf(writer, "\",\n")
-	}
-	fmt.Fprintf(writer,
-		`];
-
-pub struct TranslatedString {
-    pub translatable: TranslatableMessage,
-    pub expected_per_locale: [&'static str; %d],
-}
-
 pub const TEST_TRANSLATION_TABLE: [TranslatedString; %d] = [
 `, len(localeNames), len(allUntranslated))
 	for _, untranslated := range allUntranslated {
@@ -886,35 +768,6 @@ func DumpCPPStringLiteralBody(s string, writer *bufio.Writer) {
 	}
 }
 
-func DumpRustStringTable(strings []byte, linePrefix string, writer *bufio.Writer) {
-	writer.WriteString("\"\\\n")
-	for len(strings) != 0 {
-		writer.WriteString(linePrefix)
-		stringLength := bytes.IndexByte(strings, 0)
-		s := string(strings[:stringLength])
-		if len(s) != 0 && s[0] == ' ' {
-			panic("TODO(port): Escape leading spaces, because Rust will ignore them.")
-		}
-		DumpRustStringLiteralBody(s, writer)
-		strings = strings[stringLength+1:]
-		writer.WriteString("\\0\\\n")
-	}
-	writer.WriteRune('"')
-}
-
-func DumpRustStringLiteralBody(s string, writer *bufio.Writer) {
-	for _, c := range s {
-		if c < 0x20 || c >= 0x7f {
-			fmt.Fprintf(writer, `\u{%04x}`, c)
-		} else if c == '\\' || c == '"' {
-			writer.WriteRune('\\')
-			writer.WriteRune(c)
-		} else {
-			writer.WriteRune(c)
-		}
-	}
-}
-
 // quick-lint-js finds bugs in JavaScript programs.
 // Copyright (C) 2020  Matthew "strager" Glazar
 //
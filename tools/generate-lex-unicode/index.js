@@ -65,24 +65,9 @@ function main() {
     identifierPartData,
   });
 
-  let rustOutputPath = path.join(
-    __dirname,
-    "..",
-    "..",
-    "rust",
-    "libs",
-    "fe",
-    "src",
-    "lex_unicode_generated.rs"
-  );
-  console.log(`Creating ${rustOutputPath} ...`);
-  writeRust(fs.createWriteStream(rustOutputPath), {
-    allChunks,
-    chunkDataToIndex,
-    zerosChunkIndex,
-    identifierStartData,
-    identifierPartData,
-  });
+  // rust/libs/fe/src/lex_unicode_generated.rs and
+  // rust/libs/fe/src/lex_confusables_generated.rs are generated by
+  // rust/tools/generate_lex_unicode.
 }
 
 let maxCodePoint = 0x10ffff;
@@ -177,79 +162,6 @@ static_assert(std::is_same_v<unicode_table_chunk_index_type, ${chunkIndexType}>)
   );
 }
 
-function writeRust(
-  output,
-  {
-    allChunks,
-    chunkDataToIndex,
-    zerosChunkIndex,
-    identifierStartData,
-    identifierPartData,
-  }
-) {
-  let chunkIndexType = "u8";
-
-  output.write(
-    `\
-// Copyright (C) 2020  Matthew "strager" Glazar
-// See end of file for extended copyright information.
-
-// This file was generated by tools/generate-lex-unicode/index.js.
-
-type UnicodeTableChunkIndexType = ${chunkIndexType};
-
-pub const UNICODE_TABLE_CHUNK_SIZE: usize = ${chunkSize};
-
-`
-  );
-
-  output.write(`#[rustfmt::skip]
-pub const UNICODE_TABLES_CHUNKS: [u8; ${allChunks.length / 8}] = [\n`);
-  output.write(dumpBitTableToString(allChunks, { indentation: "    " }));
-  output.write("\n];\n\n");
-
-  function dumpChunkIndexesTable(name, data /*: Array<bool> */) {
-    let chunkIndexes = chunk(data, chunkSize).map((c) =>
-      chunkDataToIndex.get(boolsToBigInt(c))
-    );
-    chunkIndexes = rstripArray(chunkIndexes, zerosChunkIndex);
-    output.write(
-      `#[rustfmt::skip]
-pub const ${name}: [UnicodeTableChunkIndexType; ${chunkIndexes.length}] = [\n`
-    );
-    output.write(
-      dumpIntegerTableToString(chunkIndexes, { indentation: "    " })
-    );
-    output.write("\n];\n");
-  }
-
-  dumpChunkIndexesTable("IDENTIFIER_START_CHUNK_INDEXES", identifierStartData);
-  output.write("\n");
-  dumpChunkIndexesTable("IDENTIFIER_PART_CHUNK_INDEXES", identifierPartData);
-
-  output.write(
-    `
-// quick-lint-js finds bugs in JavaScript programs.
-// Copyright (C) 2020  Matthew "strager" Glazar
-//
-// This file is part of quick-lint-js.
-//
-// quick-lint-js is free software: you can redistribute it and/or modify
-// it under the terms of the GNU General Public License as published by
-// the Free Software Foundation, either version 3 of the License, or
-// (at your option) any later version.
-//
-// quick-lint-js is distributed in the hope that it will be useful,
-// but WITHOUT ANY WARRANTY; without even the implied warranty of
-// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
-// GNU General Public License for more details.
-//
-// You should have received a copy of the GNU General Public License
-// along with quick-lint-js.  If not, see <https://www.gnu.org/licenses/>.
-`
-  );
-}
-
 function boolsToBigInt(bools /*: Array<bool> */) /*: BigInt */ {
   return BigInt(
     "0b0" +